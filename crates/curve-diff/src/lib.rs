@@ -0,0 +1,112 @@
+//! Reference implementation of the pool math in arbitrary-precision integers.
+//!
+//! Each function states the math as written in the spec, with no concern for
+//! overflow, so the program's fixed-width implementation can be checked
+//! against it. Results that do not fit the program's types are rejections.
+
+use flexible_token_exchange::state::CurveType;
+use num_bigint::BigUint;
+use num_traits::{ToPrimitive, Zero};
+
+/// Outcome of a swap: amount out, LP fee and protocol fee
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SwapQuote {
+    pub amount_out: u64,
+    pub lp_fee: u64,
+    pub protocol_fee: u64,
+}
+
+fn big(value: u64) -> BigUint {
+    BigUint::from(value)
+}
+
+/// Fee-adjusted swap against the curve. None when the swap is rejected:
+/// the fee cap is exceeded, the pool is empty or the output reserve would
+/// be drained.
+pub fn swap(
+    curve_type: CurveType,
+    reserve_in: u64,
+    reserve_out: u64,
+    amount_in: u64,
+    lp_fee_bps: u16,
+    protocol_fee_bps: u16,
+) -> Option<SwapQuote> {
+    let fee_bps = lp_fee_bps as u64 + protocol_fee_bps as u64;
+    if fee_bps > 1000 {
+        return None;
+    }
+    let amount_in_after_fee = big(amount_in) * big(10000 - fee_bps) / big(10000);
+    let amount_out = match curve_type {
+        CurveType::ConstantProduct => {
+            let denominator = big(reserve_in) + &amount_in_after_fee;
+            if denominator.is_zero() {
+                return None;
+            }
+            big(reserve_out) * &amount_in_after_fee / denominator
+        }
+        CurveType::ConstantSum => amount_in_after_fee.clone(),
+    };
+    if amount_out >= big(reserve_out) {
+        return None;
+    }
+    
+    let fee = big(amount_in) - amount_in_after_fee;
+    let protocol_fee = if fee_bps == 0 {
+        BigUint::zero()
+    } else {
+        &fee * big(protocol_fee_bps as u64) / big(fee_bps)
+    };
+    Some(SwapQuote {
+        amount_out: amount_out.to_u64()?,
+        lp_fee: (fee - &protocol_fee).to_u64()?,
+        protocol_fee: protocol_fee.to_u64()?,
+    })
+}
+
+/// Ratio-matched deposit: (token, SOL, LP). The first deposit mints the
+/// floor of the geometric mean of both sides.
+pub fn deposit(
+    token_reserve: u64,
+    sol_reserve: u64,
+    lp_supply: u64,
+    token_amount: u64,
+    sol_amount: u64,
+) -> Option<(u64, u64, u64)> {
+    let ratio = |amount: u64, numerator: u64, denominator: u64, fallback: u64| {
+        if denominator == 0 {
+            big(fallback)
+        } else {
+            big(amount) * big(numerator) / big(denominator)
+        }
+    };
+    let optimal_sol = ratio(token_amount, sol_reserve, token_reserve, sol_amount);
+    let optimal_token = ratio(sol_amount, token_reserve, sol_reserve, token_amount);
+    let final_token = big(token_amount).min(optimal_token);
+    let final_sol = big(sol_amount).min(optimal_sol);
+    
+    let lp_tokens = if lp_supply == 0 {
+        (&final_token * &final_sol).sqrt()
+    } else if token_reserve == 0 || sol_reserve == 0 {
+        // A one-sided pool has no price to size a share with
+        BigUint::zero()
+    } else {
+        (&final_token * big(lp_supply) / big(token_reserve))
+            .min(&final_sol * big(lp_supply) / big(sol_reserve))
+    };
+    Some((final_token.to_u64()?, final_sol.to_u64()?, lp_tokens.to_u64()?))
+}
+
+/// Pro-rata (token, SOL) share of the reserves redeemed by `lp_tokens`
+pub fn withdraw(token_reserve: u64, sol_reserve: u64, lp_supply: u64, lp_tokens: u64) -> Option<(u64, u64)> {
+    if lp_supply == 0 {
+        return Some((0, 0));
+    }
+    let share = |reserve: u64| big(reserve) * big(lp_tokens) / big(lp_supply);
+    Some((share(token_reserve).to_u64()?, share(sol_reserve).to_u64()?))
+}
+
+/// Largest acceptable difference between the program's first-deposit LP
+/// amount, computed with an f64 square root, and the exact one
+pub fn sqrt_tolerance(exact: u64) -> u64 {
+    (exact >> 50) + 1
+}
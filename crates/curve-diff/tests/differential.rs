@@ -0,0 +1,150 @@
+//! Randomized operation sequences run through the program's math and the
+//! arbitrary-precision reference, failing on any divergence.
+
+use curve_diff::{deposit, sqrt_tolerance, swap, withdraw, SwapQuote};
+use flexible_token_exchange::curve::{deposit_amounts, withdraw_amounts};
+use flexible_token_exchange::instructions::swap::{price_swap, SwapDirection};
+use flexible_token_exchange::state::{CurveType, LiquidityPool};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+const SEQUENCES: u64 = 500;
+const OPERATIONS: usize = 200;
+
+/// Log-uniform amount so every magnitude from dust to u64::MAX is covered
+fn amount(rng: &mut StdRng, max: u64) -> u64 {
+    let bits = rng.gen_range(0..=64u32);
+    let value = if bits == 0 { 0 } else { rng.gen::<u64>() >> (64 - bits) };
+    value.min(max)
+}
+
+struct Model {
+    pool: LiquidityPool,
+}
+
+impl Model {
+    fn new(rng: &mut StdRng, curve_type: CurveType) -> Self {
+        let lp_fee_bps = rng.gen_range(0..=1000);
+        let protocol_fee_bps = rng.gen_range(0..=1000 - lp_fee_bps);
+        Model {
+            pool: LiquidityPool {
+                curve_type,
+                lp_fee_bps,
+                protocol_fee_bps,
+                // Equal decimals, so constant sum trades base units 1:1 like the reference
+                decimals_recorded: true,
+                ..Default::default()
+            },
+        }
+    }
+
+    fn swap(&mut self, rng: &mut StdRng, context: &str) {
+        let pool = &self.pool;
+        let direction = if rng.gen() { SwapDirection::TokenToSol } else { SwapDirection::SolToToken };
+        let (reserve_in, reserve_out) = match direction {
+            SwapDirection::TokenToSol => (pool.token_reserve, pool.sol_reserve),
+            SwapDirection::SolToToken => (pool.sol_reserve, pool.token_reserve),
+        };
+        // Supply is finite: the reserve can never exceed u64::MAX
+        let amount_in = amount(rng, u64::MAX - reserve_in);
+        
+        let program = price_swap(pool, None, 0, direction, amount_in, 0, 0)
+            .ok()
+            .map(|(amount_out, lp_fee, protocol_fee)| SwapQuote {
+                amount_out,
+                lp_fee,
+                protocol_fee,
+            });
+        let reference = swap(
+            pool.curve_type,
+            reserve_in,
+            reserve_out,
+            amount_in,
+            pool.lp_fee_bps,
+            pool.protocol_fee_bps,
+        );
+        assert_eq!(program, reference, "{context}: swap {direction:?} {amount_in}");
+        
+        if let Some(quote) = reference {
+            let kept = amount_in - quote.protocol_fee;
+            let pool = &mut self.pool;
+            match direction {
+                SwapDirection::TokenToSol => {
+                    pool.token_reserve += kept;
+                    pool.sol_reserve -= quote.amount_out;
+                }
+                SwapDirection::SolToToken => {
+                    pool.sol_reserve += kept;
+                    pool.token_reserve -= quote.amount_out;
+                }
+            }
+        }
+    }
+
+    fn deposit(&mut self, rng: &mut StdRng, context: &str) {
+        let pool = &self.pool;
+        let token_amount = amount(rng, u64::MAX - pool.token_reserve);
+        let sol_amount = amount(rng, u64::MAX - pool.sol_reserve);
+        
+        let program = deposit_amounts(pool.token_reserve, pool.sol_reserve, pool.lp_supply, token_amount, sol_amount);
+        let Some(reference) = deposit(pool.token_reserve, pool.sol_reserve, pool.lp_supply, token_amount, sol_amount)
+        else {
+            panic!("{context}: reference deposit out of range");
+        };
+        let context = format!("{context}: deposit {token_amount}/{sol_amount}");
+        assert_eq!((program.0, program.1), (reference.0, reference.1), "{context}");
+        if pool.lp_supply == 0 {
+            // The program takes the first-deposit square root in f64
+            assert!(program.2.abs_diff(reference.2) <= sqrt_tolerance(reference.2), "{context}");
+        } else {
+            assert_eq!(program.2, reference.2, "{context}");
+        }
+        
+        let Some(lp_supply) = pool.lp_supply.checked_add(program.2) else {
+            return;
+        };
+        let pool = &mut self.pool;
+        pool.token_reserve += program.0;
+        pool.sol_reserve += program.1;
+        pool.lp_supply = lp_supply;
+    }
+
+    fn withdraw(&mut self, rng: &mut StdRng, context: &str) {
+        let pool = &self.pool;
+        let lp_tokens = amount(rng, pool.lp_supply);
+        
+        let program = withdraw_amounts(pool.token_reserve, pool.sol_reserve, pool.lp_supply, lp_tokens);
+        let reference = withdraw(pool.token_reserve, pool.sol_reserve, pool.lp_supply, lp_tokens);
+        assert_eq!(Some(program), reference, "{context}: withdraw {lp_tokens}");
+        
+        let pool = &mut self.pool;
+        pool.token_reserve -= program.0;
+        pool.sol_reserve -= program.1;
+        pool.lp_supply -= lp_tokens;
+    }
+}
+
+fn run(curve_type: CurveType) {
+    for seed in 0..SEQUENCES {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut model = Model::new(&mut rng, curve_type);
+        for step in 0..OPERATIONS {
+            let context = format!("{curve_type:?} seed {seed} step {step}");
+            match rng.gen_range(0..4) {
+                0 | 1 => model.swap(&mut rng, &context),
+                2 => model.deposit(&mut rng, &context),
+                _ => model.withdraw(&mut rng, &context),
+            }
+        }
+    }
+}
+
+#[test]
+fn constant_product_matches_reference() {
+    run(CurveType::ConstantProduct);
+}
+
+#[test]
+fn constant_sum_matches_reference() {
+    run(CurveType::ConstantSum);
+}
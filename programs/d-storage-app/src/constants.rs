@@ -0,0 +1,65 @@
+/// Length of the window over which fee changes are rate limited
+pub const FEE_CHANGE_WINDOW_SECS: i64 = 24 * 60 * 60;
+/// Maximum net fee change within one window, in basis points
+pub const MAX_FEE_CHANGE_PER_WINDOW_BPS: u16 = 50;
+/// Pool fee cap while the protocol config sets none, only the protocol
+/// admin can approve fees above it
+pub const DEFAULT_MAX_FEE_BPS: u16 = 200;
+
+/// Highest share of the token reserve a lending strategy may hold, in basis
+/// points, so at least half the reserve stays in the vault
+pub const MAX_STRATEGY_CAP_BPS: u16 = 5000;
+
+/// Delay between requesting and executing an emergency drain
+pub const EMERGENCY_DRAIN_TIMELOCK_SECS: i64 = 48 * 60 * 60;
+/// Delay between proposing and accepting an LP mint authority handoff
+pub const LP_MINT_HANDOFF_TIMELOCK_SECS: i64 = 7 * 24 * 60 * 60;
+
+/// Feature bits reported by `get_version`. Bits are never reused.
+pub const FEATURE_QUOTE_POOLS: u64 = 1 << 0;
+pub const FEATURE_INTERNAL_BALANCES: u64 = 1 << 1;
+pub const FEATURE_SIGNED_ORDERS: u64 = 1 << 2;
+pub const FEATURE_TOKEN_BADGES: u64 = 1 << 3;
+pub const FEATURE_POOL_INDEX: u64 = 1 << 4;
+pub const FEATURE_SWAP_RECIPIENT: u64 = 1 << 5;
+pub const FEATURE_DELEGATED_SWAPS: u64 = 1 << 6;
+pub const FEATURE_TWAP_OBSERVATIONS: u64 = 1 << 7;
+/// Features supported by this build
+pub const ENABLED_FEATURES: u64 = FEATURE_QUOTE_POOLS
+    | FEATURE_INTERNAL_BALANCES
+    | FEATURE_SIGNED_ORDERS
+    | FEATURE_TOKEN_BADGES
+    | FEATURE_POOL_INDEX
+    | FEATURE_SWAP_RECIPIENT
+    | FEATURE_DELEGATED_SWAPS
+    | FEATURE_TWAP_OBSERVATIONS;
+
+/// Maximum penalty on early LP withdrawals, in basis points
+pub const MAX_LP_PENALTY_BPS: u16 = 2000;
+/// Fixed-point scale of the LP locker reward accumulator
+pub const LP_LOCK_REWARD_PRECISION: u128 = 1_000_000_000_000;
+
+/// Bounty paid to `crank_observation` callers out of the quote-side protocol
+/// fees, in lamports or quote token base units
+pub const OBSERVATION_CRANK_BOUNTY: u64 = 10_000;
+
+/// Window of the TWAP written to pool snapshots
+pub const SNAPSHOT_TWAP_WINDOW_SECS: u32 = 30 * 60;
+
+/// Bond posted with a parameter proposal, slashed to the treasury on rejection
+pub const PARAM_PROPOSAL_BOND_LAMPORTS: u64 = 100_000_000;
+
+/// Largest unaccounted quote vault surplus `sweep_quote_dust` treats as
+/// rounding dust, in lamports or quote token base units
+pub const MAX_QUOTE_DUST: u64 = 100_000;
+
+/// Slots of missed allowance a swap stream catches up on in one execution,
+/// so a stream left idle cannot be executed as one large trade
+pub const STREAM_MAX_CATCHUP_SLOTS: u64 = 150;
+
+/// Share of a reaped pool's rent refunded to its creator, the rest goes to
+/// the treasury
+pub const REAP_CREATOR_SHARE_BPS: u64 = 5000;
+
+/// Most chunks `swap_split` fills a trade in, bounded by compute
+pub const MAX_SWAP_CHUNKS: u8 = 16;
@@ -0,0 +1,83 @@
+/// out = (reserve_out * in) / (reserve_in + in)
+pub fn swap_output(reserve_in: u64, reserve_out: u64, amount_in: u64) -> Option<u128> {
+    let numerator = reserve_out as u128 * amount_in as u128;
+    let denominator = reserve_in as u128 + amount_in as u128;
+    numerator.checked_div(denominator)
+}
+
+/// Pool value in quote, scaled by 2^32, with the token priced at `price_x64`
+/// (quote per token, Q64.64): 2 * sqrt(k * price). It depends on the
+/// invariant alone, so swapping along the curve cannot move it.
+pub fn fair_value_x32(reserve_token: u64, reserve_quote: u64, price_x64: u128) -> Option<u128> {
+    let sqrt_k = isqrt(reserve_token as u128 * reserve_quote as u128);
+    // sqrt of a Q64.64 price carries 32 fractional bits
+    let sqrt_price_x32 = isqrt(price_x64);
+    sqrt_k.checked_mul(sqrt_price_x32)?.checked_mul(2)
+}
+
+/// Integer square root, rounded down
+fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+    // Start above the root and walk down with Newton steps
+    let mut x = 1u128 << (128 - n.leading_zeros()).div_ceil(2);
+    loop {
+        let next = (x + n / x) / 2;
+        if next >= x {
+            return x;
+        }
+        x = next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rounds_down_in_favour_of_the_pool() {
+        assert_eq!(swap_output(1_000_000, 2_000_000, 1_000), Some(1_998));
+        assert_eq!(swap_output(10, 10, 1), Some(0));
+    }
+
+    #[test]
+    fn never_drains_the_output_reserve() {
+        assert!(swap_output(1, u64::MAX, u64::MAX).unwrap() < u64::MAX as u128);
+    }
+
+    #[test]
+    fn invariant_does_not_decrease() {
+        let (reserve_in, reserve_out) = (5_000_000u64, 3_000_000u64);
+        for amount_in in [1, 7, 1_000, 123_456, 5_000_000, 1 << 40] {
+            let amount_out = swap_output(reserve_in, reserve_out, amount_in).unwrap();
+            let k_before = reserve_in as u128 * reserve_out as u128;
+            let k_after = (reserve_in as u128 + amount_in as u128) * (reserve_out as u128 - amount_out);
+            assert!(k_after >= k_before);
+        }
+    }
+
+    #[test]
+    fn empty_pool_is_rejected() {
+        assert_eq!(swap_output(0, 0, 0), None);
+    }
+
+    #[test]
+    fn isqrt_rounds_down() {
+        for n in [0u128, 1, 2, 3, 4, 15, 16, 17, 1 << 64, u64::MAX as u128, u128::MAX] {
+            let root = isqrt(n);
+            assert!(root * root <= n);
+            assert!((root + 1).checked_mul(root + 1).is_none_or(|square| square > n));
+        }
+    }
+
+    #[test]
+    fn fair_value_ignores_reserve_manipulation() {
+        // 4_000 tokens and 1_000 SOL at a fair price of 0.25 SOL per token
+        let price_x64 = 1u128 << 62;
+        assert_eq!(fair_value_x32(4_000, 1_000, price_x64), Some(2_000 << 32));
+        
+        // Pushing the spot price up 4x along the same curve leaves it alone
+        assert_eq!(fair_value_x32(2_000, 2_000, price_x64), Some(2_000 << 32));
+    }
+}
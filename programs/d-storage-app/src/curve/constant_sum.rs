@@ -0,0 +1,37 @@
+/// `amount` base units of a side with `decimals_from` decimals in base units
+/// of a side with `decimals_to`, one whole unit for one whole unit. Rounds
+/// down; None on overflow.
+pub fn convert(amount: u128, decimals_from: u8, decimals_to: u8) -> Option<u128> {
+    if decimals_to >= decimals_from {
+        amount.checked_mul(10u128.checked_pow((decimals_to - decimals_from) as u32)?)
+    } else {
+        Some(10u128.checked_pow((decimals_from - decimals_to) as u32).map_or(0, |scale| amount / scale))
+    }
+}
+
+/// out = in in whole units, the remaining-reserve check rejects swaps that
+/// deplete a side
+pub fn swap_output(amount_in: u64, decimals_in: u8, decimals_out: u8) -> Option<u128> {
+    convert(amount_in as u128, decimals_in, decimals_out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prices_one_to_one() {
+        assert_eq!(swap_output(0, 6, 6), Some(0));
+        assert_eq!(swap_output(42, 6, 6), Some(42));
+        assert_eq!(swap_output(u64::MAX, 9, 9), Some(u64::MAX as u128));
+    }
+
+    #[test]
+    fn scales_between_decimals() {
+        // 1.5 of a 6-decimal stablecoin is 1.5 of a 9-decimal one
+        assert_eq!(swap_output(1_500_000, 6, 9), Some(1_500_000_000));
+        assert_eq!(swap_output(1_500_000_999, 9, 6), Some(1_500_000));
+        assert_eq!(swap_output(u64::MAX, 0, 40), None);
+        assert_eq!(swap_output(u64::MAX, 40, 0), Some(0));
+    }
+}
@@ -0,0 +1,76 @@
+/// Ratio-matched deposit for the given reserves. Returns the (token, SOL, LP)
+/// amounts; the first deposit mints the geometric mean of both sides.
+pub fn deposit_amounts(
+    token_reserve: u64,
+    sol_reserve: u64,
+    lp_supply: u64,
+    token_amount: u64,
+    sol_amount: u64,
+) -> (u64, u64, u64) {
+    let optimal_sol_amount = mul_div(token_amount, sol_reserve, token_reserve).unwrap_or(sol_amount);
+    let optimal_token_amount = mul_div(sol_amount, token_reserve, sol_reserve).unwrap_or(token_amount);
+    
+    let final_token_amount = std::cmp::min(token_amount, optimal_token_amount);
+    let final_sol_amount = std::cmp::min(sol_amount, optimal_sol_amount);
+    
+    // Calculate LP tokens to mint
+    let lp_tokens = if lp_supply == 0 {
+        (final_token_amount as f64 * final_sol_amount as f64).sqrt() as u64
+    } else {
+        std::cmp::min(
+            mul_div(final_token_amount, lp_supply, token_reserve).unwrap_or(0),
+            mul_div(final_sol_amount, lp_supply, sol_reserve).unwrap_or(0),
+        )
+    };
+    
+    (final_token_amount, final_sol_amount, lp_tokens)
+}
+
+/// Pro-rata (token, SOL) share of the reserves redeemed by `lp_tokens`
+pub fn withdraw_amounts(token_reserve: u64, sol_reserve: u64, lp_supply: u64, lp_tokens: u64) -> (u64, u64) {
+    (
+        mul_div(token_reserve, lp_tokens, lp_supply).unwrap_or(0),
+        mul_div(sol_reserve, lp_tokens, lp_supply).unwrap_or(0),
+    )
+}
+
+/// a * b / c rounded down, with a 128-bit intermediate. None if `c` is zero
+/// or the result does not fit in a u64.
+fn mul_div(a: u64, b: u64, c: u64) -> Option<u64> {
+    (a as u128 * b as u128)
+        .checked_div(c as u128)
+        .and_then(|result| u64::try_from(result).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_deposit_mints_geometric_mean() {
+        assert_eq!(deposit_amounts(0, 0, 0, 400, 100), (400, 100, 200));
+    }
+
+    #[test]
+    fn excess_side_is_left_with_the_user() {
+        // Pool ratio is 4 tokens per lamport
+        assert_eq!(deposit_amounts(4_000, 1_000, 2_000, 800, 500), (800, 200, 400));
+        assert_eq!(deposit_amounts(4_000, 1_000, 2_000, 8_000, 100), (400, 100, 200));
+    }
+
+    #[test]
+    fn large_amounts_do_not_overflow() {
+        let reserve = u64::MAX / 2;
+        assert_eq!(
+            deposit_amounts(reserve, reserve, reserve, 1 << 40, 1 << 40),
+            (1 << 40, 1 << 40, 1 << 40)
+        );
+        assert_eq!(withdraw_amounts(reserve, reserve / 2, reserve, reserve / 2), (reserve / 2, reserve / 4));
+    }
+
+    #[test]
+    fn lp_share_rounds_down() {
+        let (_, _, lp_tokens) = deposit_amounts(3_000, 3_000, 1_000, 2, 2);
+        assert_eq!(lp_tokens, 0);
+    }
+}
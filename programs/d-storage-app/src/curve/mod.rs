@@ -0,0 +1,144 @@
+pub mod constant_product;
+pub mod constant_sum;
+pub mod liquidity;
+#[cfg(kani)]
+mod proofs;
+
+pub use liquidity::{deposit_amounts, withdraw_amounts};
+
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::state::CurveType;
+
+/// Output amount for a fee-adjusted input according to the pool curve.
+/// Constant sum pools trade whole units one to one, scaling between the
+/// input and output side decimals.
+pub fn swap_output(
+    curve_type: CurveType,
+    reserve_in: u64,
+    reserve_out: u64,
+    amount_in: u64,
+    (decimals_in, decimals_out): (u8, u8),
+) -> Result<u64> {
+    let amount_out = match curve_type {
+        CurveType::ConstantProduct => constant_product::swap_output(reserve_in, reserve_out, amount_in)
+            .ok_or(ExchangeError::InsufficientLiquidity)?,
+        CurveType::ConstantSum => constant_sum::swap_output(amount_in, decimals_in, decimals_out)
+            .ok_or(ExchangeError::MathOverflow)?,
+    };
+    u64::try_from(amount_out).map_err(|_| error!(ExchangeError::MathOverflow))
+}
+
+/// Price impact in bps of receiving `amount_out` for a fee-adjusted input,
+/// relative to the pool's spot price before the swap. Constant sum pools
+/// trade at a fixed price and have no impact.
+pub fn price_impact_bps(
+    curve_type: CurveType,
+    reserve_in: u64,
+    reserve_out: u64,
+    amount_in: u64,
+    amount_out: u64,
+) -> u16 {
+    if curve_type == CurveType::ConstantSum || amount_in == 0 || reserve_out == 0 {
+        return 0;
+    }
+    // Output at the spot price would be amount_in * reserve_out / reserve_in
+    let spot_out = amount_in as u128 * reserve_out as u128;
+    let executed = amount_out as u128 * reserve_in as u128;
+    let ratio_bps = match executed.checked_mul(10000) {
+        Some(scaled) => scaled / spot_out,
+        // Only reachable with amounts near u64::MAX; a tiny spot_out then
+        // means the output beat the spot price and the impact is zero
+        None => executed / (spot_out / 10000).max(1),
+    };
+    10000u128.saturating_sub(ratio_bps) as u16
+}
+
+/// Part of a single-sided SOL `amount` to swap into tokens so the rest pairs
+/// with the tokens bought at the post-swap reserve ratio: the largest swap
+/// whose output the remaining SOL can still match. `fee_bps` is taken off
+/// the swap input; protocol fees leaving the reserves are ignored, so a few
+/// tokens may be left unpaired. `decimals` are the token and quote side's.
+pub fn zap_swap_amount(
+    curve_type: CurveType,
+    token_reserve: u64,
+    sol_reserve: u64,
+    amount: u64,
+    fee_bps: u64,
+    (token_decimals, quote_decimals): (u8, u8),
+) -> u64 {
+    let pairs_with_rest = |swap_amount: u64| {
+        let amount_in = (swap_amount as u128 * (10000 - fee_bps.min(10000)) as u128 / 10000) as u64;
+        let Ok(tokens_out) = swap_output(curve_type, sol_reserve, token_reserve, amount_in, (quote_decimals, token_decimals)) else {
+            return false;
+        };
+        if tokens_out >= token_reserve {
+            return false;
+        }
+        // SOL needed to pair `tokens_out` at the reserves after the swap
+        let sol_needed = tokens_out as u128 * (sol_reserve as u128 + swap_amount as u128)
+            / (token_reserve - tokens_out) as u128;
+        sol_needed <= (amount - swap_amount) as u128
+    };
+    
+    let (mut low, mut high) = (0, amount);
+    while low < high {
+        let mid = low + (high - low).div_ceil(2);
+        if pairs_with_rest(mid) {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+    low
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dispatches_on_curve_type() {
+        assert_eq!(swap_output(CurveType::ConstantProduct, 1000, 1000, 1000, (0, 0)).unwrap(), 500);
+        assert_eq!(swap_output(CurveType::ConstantSum, 1000, 1000, 1000, (0, 0)).unwrap(), 1000);
+        assert_eq!(swap_output(CurveType::ConstantSum, 1000, 1000, 1000, (6, 9)).unwrap(), 1_000_000);
+    }
+
+    #[test]
+    fn price_impact_against_spot() {
+        // 1000 in against 1000/1000 reserves returns 500, half the spot output
+        assert_eq!(price_impact_bps(CurveType::ConstantProduct, 1000, 1000, 1000, 500), 5000);
+        // Small trades barely move the price
+        let out = swap_output(CurveType::ConstantProduct, 1_000_000, 2_000_000, 1000, (0, 0)).unwrap();
+        assert_eq!(price_impact_bps(CurveType::ConstantProduct, 1_000_000, 2_000_000, 1000, out), 10);
+        assert_eq!(price_impact_bps(CurveType::ConstantSum, 1000, 1000, 1000, 1000), 0);
+        // Huge reserves must not overflow the scaled ratio
+        let (reserve, amount_in) = (1u64 << 63, 1u64 << 63);
+        let out = swap_output(CurveType::ConstantProduct, reserve, reserve, amount_in, (0, 0)).unwrap();
+        assert_eq!(price_impact_bps(CurveType::ConstantProduct, reserve, reserve, amount_in, out), 5000);
+    }
+
+    #[test]
+    fn empty_pool_has_no_liquidity() {
+        assert_eq!(
+            swap_output(CurveType::ConstantProduct, 0, 0, 0, (0, 0)).unwrap_err(),
+            ExchangeError::InsufficientLiquidity.into()
+        );
+    }
+
+    #[test]
+    fn zap_splits_sol_to_match_the_new_ratio() {
+        // Without fees a constant product zap swaps about half of a small deposit
+        let swap_amount = zap_swap_amount(CurveType::ConstantProduct, 1_000_000, 1_000_000, 10_000, 0, (0, 0));
+        assert!((4_970..=4_990).contains(&swap_amount));
+        let tokens_out = swap_output(CurveType::ConstantProduct, 1_000_000, 1_000_000, swap_amount, (0, 0)).unwrap();
+        let (tokens, sol, _) =
+            deposit_amounts(1_000_000 - tokens_out, 1_000_000 + swap_amount, 1_000_000, tokens_out, 10_000 - swap_amount);
+        assert!(tokens_out - tokens <= 1 && 10_000 - swap_amount - sol <= 2);
+        
+        // Constant sum pools trade 1:1, pairing still follows the reserve ratio
+        assert_eq!(zap_swap_amount(CurveType::ConstantSum, 1_000_000, 1_000_000, 10_000, 0, (0, 0)), 4_975);
+        assert_eq!(zap_swap_amount(CurveType::ConstantProduct, 0, 0, 10_000, 30, (0, 0)), 0);
+    }
+}
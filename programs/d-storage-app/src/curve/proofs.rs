@@ -0,0 +1,64 @@
+//! Kani harnesses over the pool math, run with `cargo kani -p d-storage-app`.
+//! CI runs them on every change to the curve module.
+
+use super::*;
+
+/// A constant product swap never pays out the whole output reserve
+#[kani::proof]
+fn constant_product_never_drains_reserve() {
+    let (reserve_in, reserve_out, amount_in): (u64, u64, u64) = kani::any();
+    kani::assume(reserve_in > 0 && reserve_out > 0);
+    let amount_out = constant_product::swap_output(reserve_in, reserve_out, amount_in).unwrap();
+    assert!(amount_out < reserve_out as u128);
+    assert!(swap_output(CurveType::ConstantProduct, reserve_in, reserve_out, amount_in, (0, 0)).is_ok());
+}
+
+/// x * y never decreases across a constant product swap
+#[kani::proof]
+fn constant_product_invariant_does_not_decrease() {
+    let (reserve_in, reserve_out, amount_in): (u64, u64, u64) = kani::any();
+    // Keeps (reserve_in + amount_in) * reserve_out within a u128
+    kani::assume(reserve_in > 0 && reserve_in < 1 << 63 && amount_in < 1 << 63);
+    let amount_out = constant_product::swap_output(reserve_in, reserve_out, amount_in).unwrap();
+    let k_before = reserve_in as u128 * reserve_out as u128;
+    let k_after = (reserve_in as u128 + amount_in as u128) * (reserve_out as u128 - amount_out);
+    assert!(k_after >= k_before);
+}
+
+/// Constant sum pays exactly the input, which always fits a u64
+#[kani::proof]
+fn constant_sum_is_one_to_one() {
+    let (reserve_in, reserve_out, amount_in): (u64, u64, u64) = kani::any();
+    assert_eq!(swap_output(CurveType::ConstantSum, reserve_in, reserve_out, amount_in, (0, 0)).unwrap(), amount_in);
+}
+
+/// Price impact never overflows, whatever the amounts
+#[kani::proof]
+fn price_impact_does_not_overflow() {
+    let (reserve_in, reserve_out, amount_in, amount_out): (u64, u64, u64, u64) = kani::any();
+    let curve_type = if kani::any() { CurveType::ConstantProduct } else { CurveType::ConstantSum };
+    assert!(price_impact_bps(curve_type, reserve_in, reserve_out, amount_in, amount_out) <= 10000);
+}
+
+/// Burning part of the LP supply never pays out more than the reserves
+#[kani::proof]
+fn withdraw_stays_within_reserves() {
+    let (token_reserve, sol_reserve, lp_supply, lp_tokens): (u64, u64, u64, u64) = kani::any();
+    kani::assume(lp_tokens <= lp_supply);
+    let (token_amount, sol_amount) = withdraw_amounts(token_reserve, sol_reserve, lp_supply, lp_tokens);
+    assert!(token_amount <= token_reserve && sol_amount <= sol_reserve);
+}
+
+/// A deposit into a funded pool uses at most what was offered and mints no
+/// more than its share, so existing LPs are never diluted
+#[kani::proof]
+fn deposit_does_not_dilute() {
+    let (token_reserve, sol_reserve, lp_supply, token_amount, sol_amount): (u64, u64, u64, u64, u64) =
+        kani::any();
+    kani::assume(token_reserve > 0 && sol_reserve > 0 && lp_supply > 0);
+    let (final_token, final_sol, lp_tokens) =
+        deposit_amounts(token_reserve, sol_reserve, lp_supply, token_amount, sol_amount);
+    assert!(final_token <= token_amount && final_sol <= sol_amount);
+    assert!(lp_tokens as u128 * token_reserve as u128 <= final_token as u128 * lp_supply as u128);
+    assert!(lp_tokens as u128 * sol_reserve as u128 <= final_sol as u128 * lp_supply as u128);
+}
@@ -0,0 +1,267 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum ExchangeError {
+    #[msg("Slippage tolerance exceeded")]
+    SlippageExceeded,
+    #[msg("Insufficient liquidity in the pool")]
+    InsufficientLiquidity,
+    #[msg("Invalid token mint")]
+    InvalidTokenMint,
+    #[msg("Pool not initialized")]
+    PoolNotInitialized,
+    #[msg("Unauthorized access")]
+    Unauthorized,
+    #[msg("Invalid fee rate - must be between 0 and 1000 basis points (10%)")]
+    InvalidFeeRate,
+    #[msg("Pool has been sunset - only withdrawals are allowed")]
+    PoolSunset,
+    #[msg("Sunset time must be in the future")]
+    InvalidSunsetTime,
+    #[msg("Liquidity provider is not on the pool allowlist")]
+    LiquidityProviderNotAllowed,
+    #[msg("Invalid wSOL account - must be a native mint account owned by the user")]
+    InvalidWsolAccount,
+    #[msg("No withdrawal from a supported AMM into the snapshotted accounts found since `begin_migration`")]
+    MigrationSourceNotFound,
+    #[msg("Math overflow")]
+    MathOverflow,
+    #[msg("Strategy cap must be at most 5000 basis points")]
+    InvalidStrategyCap,
+    #[msg("Lending program is not supported")]
+    UnsupportedStrategy,
+    #[msg("Withdraw all strategy funds before switching programs")]
+    StrategyFundsDeployed,
+    #[msg("Lending strategy is not enabled for this pool")]
+    StrategyNotConfigured,
+    #[msg("Deployment would exceed the strategy cap")]
+    StrategyCapExceeded,
+    #[msg("Invalid strategy accounts")]
+    InvalidStrategyAccounts,
+    #[msg("Invalid strategy amount")]
+    InvalidStrategyAmount,
+    #[msg("Invalid oracle configuration")]
+    InvalidOracleConfig,
+    #[msg("Invalid or missing oracle account")]
+    InvalidOracle,
+    #[msg("Oracle is stale or its confidence interval is too wide")]
+    OracleDegraded,
+    #[msg("Swap would move the pool price too far from the oracle price")]
+    OraclePriceDeviation,
+    #[msg("An emergency drain is pending")]
+    EmergencyDrainPending,
+    #[msg("No emergency drain is pending")]
+    NoEmergencyDrainPending,
+    #[msg("Guardian must be a separate key from the pool admin")]
+    InvalidGuardian,
+    #[msg("Emergency recovery address is not configured")]
+    RecoveryNotConfigured,
+    #[msg("Timelock has not expired yet")]
+    TimelockNotExpired,
+    #[msg("Referral share must be at most 10000 basis points")]
+    InvalidReferralShare,
+    #[msg("Referral codes must be 1-16 characters of [A-Za-z0-9_-]")]
+    InvalidReferralCode,
+    #[msg("Referral payout account does not match the referral code")]
+    InvalidReferralAccount,
+    #[msg("Fee change exceeds the limit for the current 24h window")]
+    FeeChangeTooLarge,
+    #[msg("Swaps are paused for this pool")]
+    SwapsPaused,
+    #[msg("Deposits are paused for this pool")]
+    DepositsPaused,
+    #[msg("Swap cooldown account is required during the launch window")]
+    SwapCooldownRequired,
+    #[msg("Wallet must wait for the launch cooldown before swapping again")]
+    SwapCooldownActive,
+    #[msg("Launch window is still active")]
+    LaunchWindowActive,
+    #[msg("Quote mint must be a token other than the pool token and wrapped SOL")]
+    InvalidQuoteMint,
+    #[msg("Quote token account is required for token-quoted pools")]
+    InvalidQuoteAccount,
+    #[msg("Internal balance is too low")]
+    InsufficientInternalBalance,
+    #[msg("Internal token vault and token account are required for SPL token balances")]
+    InvalidInternalVault,
+    #[msg("Internal balance must be empty before it can be closed")]
+    InternalBalanceNotEmpty,
+    #[msg("Settlement accounts must be (internal balance, vault, destination) triples owned by the signer")]
+    InvalidSettlementAccounts,
+    #[msg("Token name, symbol or metadata URI is empty or too long")]
+    InvalidTokenMetadata,
+    #[msg("Pool liquidity cannot exceed the minted supply")]
+    InvalidLaunchSupply,
+    #[msg("Recipient account does not match the swap output")]
+    InvalidRecipient,
+    #[msg("Signer is not the approved delegate of a source account for the input mint")]
+    InvalidDelegateAccount,
+    #[msg("Order must be signed by its owner in an Ed25519 instruction right before the fill")]
+    InvalidOrderSignature,
+    #[msg("Order has expired")]
+    OrderExpired,
+    #[msg("Order does not match the pool or owner accounts")]
+    InvalidOrder,
+    #[msg("Pool index page is not the page the next pool belongs to")]
+    InvalidPoolIndexPage,
+    #[msg("Pool is already listed in the pool index")]
+    PoolAlreadyIndexed,
+    #[msg("LP reward vault is required while lockers share withdrawal penalties")]
+    LpLockVaultRequired,
+    #[msg("LP withdrawal penalty exceeds the maximum")]
+    InvalidLpPenalty,
+    #[msg("Locked LP tokens cannot be withdrawn before the penalty period ends")]
+    LpLockActive,
+    #[msg("Vesting needs a positive duration, a cliff within it and the vesting accounts")]
+    InvalidVestingSchedule,
+    #[msg("No vested LP tokens to claim")]
+    NothingVested,
+    #[msg("Amount must be greater than zero")]
+    InvalidAmount,
+    #[msg("Admin NFT must be a fixed-supply, zero-decimal mint held by the signer")]
+    InvalidAdminNft,
+    #[msg("Creator royalty exceeds the protocol cap")]
+    InvalidCreatorRoyalty,
+    #[msg("Fee holiday slot is out of range or its window is empty or already over")]
+    InvalidFeeHoliday,
+    #[msg("Reward epoch root is already set")]
+    RewardRootAlreadySet,
+    #[msg("Reward claim is not in the epoch's Merkle root")]
+    InvalidRewardProof,
+    #[msg("TWAP window is empty or older than the oldest price observation")]
+    ObservationWindowUnavailable,
+    #[msg("Latest price observation is less than an interval old")]
+    ObservationTooRecent,
+    #[msg("Price observation buffer is required to refresh the pool snapshot")]
+    ObservationsRequired,
+    #[msg("Vault or mint account does not match the address recorded on the pool")]
+    InvalidVault,
+    #[msg("Price impact exceeds the caller's limit")]
+    PriceImpactTooHigh,
+    #[msg("Price impact limit must be at most 10000 bps")]
+    InvalidPriceImpactLimit,
+    #[msg("LP mint handoff is not proposed to this signer or its timelock has not elapsed")]
+    LpMintHandoffNotReady,
+    #[msg("LP mint authority has been handed off to a successor program")]
+    LpMintMigrated,
+    #[msg("Fee tier breakpoints must be strictly ascending with unused tiers last")]
+    InvalidFeeTiers,
+    #[msg("Guardian set must hold distinct keys and a threshold between one and its size")]
+    InvalidGuardianSet,
+    #[msg("Not enough guardians signed")]
+    GuardianQuorumNotMet,
+    #[msg("Pool was paused by the guardians, only the protocol admin can unpause it")]
+    GuardianPaused,
+    #[msg("Pool is not paused by the guardians")]
+    NotGuardianPaused,
+    #[msg("Custody authority is not an account of the approved custody program")]
+    InvalidCustodyAuthority,
+    #[msg("Token vault is already held by an external custody program")]
+    VaultCustodyAssigned,
+    #[msg("Creator has reached their token launch quota")]
+    CreatorQuotaExceeded,
+    #[msg("TVL checkpoint for today is already written")]
+    TvlCheckpointTooRecent,
+    #[msg("TVL history does not cover the window or the pool has no liquidity")]
+    TvlHistoryUnavailable,
+    #[msg("Protocol emergency stop blocks this instruction, withdrawals remain open")]
+    ProtocolHalted,
+    #[msg("Protocol status must be re-enabled in stages: halted, swaps only, live")]
+    InvalidProtocolStatusTransition,
+    #[msg("Quote vault holds no dust or more surplus than the dust threshold")]
+    InvalidDustAmount,
+    #[msg("Partner fee exceeds the protocol maximum")]
+    PartnerFeeTooHigh,
+    #[msg("Partner fee account is missing or does not hold the output asset")]
+    InvalidPartnerAccount,
+    #[msg("Crank job is already registered")]
+    CrankJobExists,
+    #[msg("Crank registry is full")]
+    CrankRegistryFull,
+    #[msg("Crank job is not registered")]
+    CrankJobNotFound,
+    #[msg("No circuit breaker is tripped, or the trip is already recorded for this slot")]
+    NoCircuitBreakerTripped,
+    #[msg("Only available on SOL-quoted pools")]
+    NativeQuoteOnly,
+    #[msg("Launch auction parameters are invalid or the pool already exists")]
+    InvalidAuctionParams,
+    #[msg("Launch auction is not in the phase this instruction needs")]
+    AuctionPhaseMismatch,
+    #[msg("Revealed price tick and salt do not match the bid commitment")]
+    InvalidBidReveal,
+    #[msg("Launch auction raised no SOL, cancel it instead")]
+    AuctionNotClearable,
+    #[msg("Launch auction can still seed its pool")]
+    AuctionNotCancellable,
+    #[msg("Exit auction needs a positive duration and a start price at or above its floor")]
+    InvalidExitAuction,
+    #[msg("Swap stream has nothing due or the owner's balance is empty")]
+    StreamNotDue,
+    #[msg("Outflow cap must be at most 10000 basis points")]
+    InvalidOutflowCaps,
+    #[msg("Swap would take the reserve outflow over the pool's cap for the window")]
+    OutflowCapExceeded,
+    #[msg("Fee range must stay within 10% and contain the default fee")]
+    InvalidFeeRange,
+    #[msg("Fee rate outside the protocol range requires the protocol admin")]
+    FeeOutsideProtocolRange,
+    #[msg("Route pools must be distinct, share the quote asset and the signer")]
+    InvalidRoute,
+    #[msg("Pool is frozen by the compliance authority - only withdrawals are allowed")]
+    PoolFrozen,
+    #[msg("A non-zero reason code is required")]
+    InvalidReasonCode,
+    #[msg("Account is not a resizable program account or already has the current size")]
+    InvalidAccountLayout,
+    #[msg("Pool is not empty, reaping is disabled or its grace period has not passed")]
+    PoolNotReapable,
+    #[msg("Fee shares must be distinct, non-zero and add up to 10000 basis points")]
+    InvalidFeeSplit,
+    #[msg("Fee recipient accounts must match the fee splitter")]
+    InvalidFeeRecipient,
+    #[msg("Stake tiers must ascend in stake and discount, with discounts of at most 10%")]
+    InvalidStakeTiers,
+    #[msg("Unstake amount exceeds the staked amount")]
+    InsufficientStake,
+    #[msg("Feature is turned off for this pool")]
+    FeatureDisabled,
+    #[msg("Pool flags must be non-zero and only use known bits")]
+    InvalidPoolFlags,
+    #[msg("Transaction swaps the pool both ways from different signers")]
+    SandwichDetected,
+    #[msg("Pool guards against sandwiches - the instructions sysvar is required")]
+    InstructionsSysvarRequired,
+    #[msg("Chunk count must be between 1 and 16 and at most the amount in")]
+    InvalidChunkCount,
+    #[msg("Rent refund account must be the account's rent payer")]
+    InvalidRentPayer,
+    #[msg("Pool accounting invariant violated")]
+    InvariantViolation,
+    #[msg("Allowlist entry expiry must be in the future")]
+    InvalidAllowlistExpiry,
+    #[msg("Allowlist entry has not expired")]
+    AllowlistEntryNotExpired,
+    #[msg("Revenue already reported this epoch")]
+    RevenueAlreadyReported,
+    #[msg("Account to receive the LP tokens is missing")]
+    LpAccountRequired,
+    #[msg("Tokens are lent to the strategy, recall them with `recall_from_strategy` first")]
+    StrategyLiquidityRequired,
+    #[msg("LP mint still has tokens outstanding")]
+    LpMintInUse,
+    #[msg("Pool assets are still held in the internal vaults, settle them first")]
+    InternalCreditsUnsettled,
+    #[msg("Migration must begin with `begin_migration` earlier in the same transaction")]
+    MigrationNotBegun,
+    #[msg("Migrated amounts exceed what the source AMM withdrawal paid out")]
+    MigrationExceedsWithdrawal,
+    #[msg("Pool has not recorded its mint decimals, call `sync_pool_decimals` first")]
+    PoolDecimalsUnknown,
+    #[msg("Changing the guardian or recovery address needs the current guardian or the protocol admin to co-sign")]
+    RecoveryChangeNotApproved,
+    #[msg("Recall exceeds the strategy funds above the cap")]
+    StrategyWithinCap,
+    #[msg("Order has not expired yet")]
+    OrderNotExpired,
+}
@@ -0,0 +1,790 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::{DustDestination, PoolParams, SwapDirection};
+use crate::state::{AuctionStatus, BreakerKind, CrankJobKind, FeeShare, FeeTier, ParamChange, ProtocolStatus, StakeTier};
+
+#[event]
+pub struct SwapEvent {
+    pub user: Pubkey,
+    pub token_in: Pubkey,
+    pub token_out: Pubkey,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub recipient: Pubkey,
+    /// Impact of the swap on the pool price, in bps of the spot output
+    pub price_impact_bps: u16,
+}
+
+#[event]
+pub struct LiquidityAddedEvent {
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    pub token_amount: u64,
+    pub sol_amount: u64,
+    pub lp_tokens: u64,
+    /// Amounts offered; the difference stays with the provider
+    pub token_requested: u64,
+    pub sol_requested: u64,
+}
+
+#[event]
+pub struct LiquidityMigratedEvent {
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    pub source_program: Pubkey,
+    pub token_amount: u64,
+    pub sol_amount: u64,
+    pub lp_tokens: u64,
+}
+
+#[event]
+pub struct FeeUpdateEvent {
+    pub pool: Pubkey,
+    pub old_fee_rate: u16,
+    pub new_fee_rate: u16,
+    pub updated_by: Pubkey,
+}
+
+#[event]
+pub struct ProtocolFeeUpdateEvent {
+    pub pool: Pubkey,
+    pub old_protocol_fee_bps: u16,
+    pub new_protocol_fee_bps: u16,
+    pub updated_by: Pubkey,
+}
+
+#[event]
+pub struct ProtocolFeesCollectedEvent {
+    pub pool: Pubkey,
+    pub admin: Pubkey,
+    pub token_amount: u64,
+    pub quote_amount: u64,
+}
+
+#[event]
+pub struct SellFeeUpdateEvent {
+    pub pool: Pubkey,
+    pub old_sell_fee_rate: u16,
+    pub new_sell_fee_rate: u16,
+    pub updated_by: Pubkey,
+}
+
+#[event]
+pub struct FeeHolidayEvent {
+    pub pool: Pubkey,
+    pub index: u8,
+    pub start: i64,
+    pub end: i64,
+    pub fee_rate: u16,
+    pub updated_by: Pubkey,
+}
+
+#[event]
+pub struct FeeTiersUpdateEvent {
+    pub pool: Pubkey,
+    pub fee_tiers: Vec<FeeTier>,
+    pub updated_by: Pubkey,
+}
+
+#[event]
+pub struct SunsetScheduledEvent {
+    pub pool: Pubkey,
+    pub sunset_at: i64,
+    pub scheduled_by: Pubkey,
+}
+
+#[event]
+pub struct LpAllowlistUpdateEvent {
+    pub pool: Pubkey,
+    pub enabled: bool,
+    pub updated_by: Pubkey,
+}
+
+#[event]
+pub struct LpAllowlistEntryEvent {
+    pub pool: Pubkey,
+    pub provider: Pubkey,
+    pub allowed: bool,
+}
+
+#[event]
+pub struct StrategyConfiguredEvent {
+    pub pool: Pubkey,
+    pub strategy_program: Pubkey,
+    pub cap_bps: u16,
+}
+
+#[event]
+pub struct StrategyDeployedEvent {
+    pub pool: Pubkey,
+    pub amount: u64,
+    pub collateral_minted: u64,
+}
+
+#[event]
+pub struct StrategyWithdrawnEvent {
+    pub pool: Pubkey,
+    pub collateral_amount: u64,
+    pub tokens_received: u64,
+    pub yield_amount: u64,
+    pub loss: u64,
+    pub emergency: bool,
+}
+
+#[event]
+pub struct OracleConfiguredEvent {
+    pub pool: Pubkey,
+    pub oracle: Pubkey,
+    pub feed_id: [u8; 32],
+    pub max_deviation_bps: u16,
+    pub max_confidence_bps: u16,
+    pub max_staleness_secs: i64,
+    pub fallback_to_curve: bool,
+}
+
+#[event]
+pub struct RebalanceIncentiveConfiguredEvent {
+    pub pool: Pubkey,
+    pub discount_bps: u16,
+    pub min_deviation_bps: u16,
+    pub updated_by: Pubkey,
+}
+
+#[event]
+pub struct OracleDegradedEvent {
+    pub pool: Pubkey,
+    pub publish_time: i64,
+    pub confidence_bps: u64,
+}
+
+#[event]
+pub struct CircuitBreakerEvent {
+    pub pool: Pubkey,
+    pub kind: BreakerKind,
+    pub magnitude: u64,
+    pub slot: u64,
+    /// Trips recorded for the pool so far
+    pub trip_count: u64,
+}
+
+#[event]
+pub struct EmergencyRecoveryConfiguredEvent {
+    pub pool: Pubkey,
+    pub recovery_address: Pubkey,
+    pub guardian: Pubkey,
+}
+
+#[event]
+pub struct EmergencyDrainRequestedEvent {
+    pub pool: Pubkey,
+    pub requested_at: i64,
+    pub executable_at: i64,
+}
+
+#[event]
+pub struct EmergencyDrainCancelledEvent {
+    pub pool: Pubkey,
+    pub cancelled_by: Pubkey,
+}
+
+#[event]
+pub struct EmergencyDrainEvent {
+    pub pool: Pubkey,
+    pub recovery_address: Pubkey,
+    pub token_amount: u64,
+    pub sol_amount: u64,
+}
+
+#[event]
+pub struct ReferralFeeShareUpdateEvent {
+    pub pool: Pubkey,
+    pub share_bps: u16,
+    pub updated_by: Pubkey,
+}
+
+#[event]
+pub struct OutflowCapsUpdateEvent {
+    pub pool: Pubkey,
+    pub window_secs: u32,
+    pub max_token_outflow_bps: u16,
+    pub max_quote_outflow_bps: u16,
+    pub updated_by: Pubkey,
+}
+
+#[event]
+pub struct ReferralCodeEvent {
+    pub code: String,
+    pub recipient: Pubkey,
+    pub owner: Pubkey,
+    pub closed: bool,
+}
+
+#[event]
+pub struct BuyAndLpEvent {
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    /// SOL taken from the user in total
+    pub sol_in: u64,
+    pub sol_swapped: u64,
+    pub tokens_bought: u64,
+    /// Amounts deposited as liquidity
+    pub token_amount: u64,
+    pub sol_amount: u64,
+    pub lp_tokens: u64,
+    /// Bought tokens that could not be paired, sent to the user
+    pub token_refund: u64,
+}
+
+#[event]
+pub struct PartnerFeePaidEvent {
+    pub pool: Pubkey,
+    pub recipient: Pubkey,
+    /// Output mint the fee was paid in, default for native SOL
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ReferralPaidEvent {
+    pub pool: Pubkey,
+    pub code: String,
+    pub recipient: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct AuthorityRenouncedEvent {
+    pub pool: Pubkey,
+    pub renounced_by: Pubkey,
+}
+
+#[event]
+pub struct PauseFlagsUpdateEvent {
+    pub pool: Pubkey,
+    pub swaps_paused: bool,
+    pub deposits_paused: bool,
+    pub updated_by: Pubkey,
+}
+
+#[event]
+pub struct LaunchCooldownUpdateEvent {
+    pub pool: Pubkey,
+    pub cooldown_slots: u64,
+    pub launch_window_end: i64,
+}
+
+#[event]
+pub struct InternalBalanceEvent {
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub deposit: bool,
+    pub balance: u64,
+}
+
+#[event]
+pub struct InternalCreditsSettledEvent {
+    pub pool: Pubkey,
+    /// Pool tokens moved from the internal token vault into the token vault
+    pub token_amount: u64,
+    /// Pool quote moved from the internal vaults into the SOL/quote vault
+    pub quote_amount: u64,
+}
+
+#[event]
+pub struct TokenLaunchedEvent {
+    pub token_mint: Pubkey,
+    pub pool: Pubkey,
+    pub creator: Pubkey,
+    pub total_supply: u64,
+    pub pool_token_amount: u64,
+    pub pool_sol_amount: u64,
+    pub lp_tokens: u64,
+    pub mint_authority_revoked: bool,
+}
+
+#[event]
+pub struct SignedOrderFilledEvent {
+    pub owner: Pubkey,
+    pub filler: Pubkey,
+    pub pool: Pubkey,
+    pub nonce: u64,
+    pub amount_in: u64,
+    pub amount_out: u64,
+}
+
+#[event]
+pub struct ProtocolAdminUpdateEvent {
+    pub old_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+#[event]
+pub struct TokenBadgeEvent {
+    pub mint: Pubkey,
+    pub badged: bool,
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct CustodyApprovalEvent {
+    pub custody_program: Pubkey,
+    pub approved: bool,
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct VaultCustodyAssignedEvent {
+    pub pool: Pubkey,
+    pub custody_program: Pubkey,
+    pub custody_authority: Pubkey,
+    pub assigned_by: Pubkey,
+}
+
+#[event]
+pub struct HealthReport {
+    pub pool: Pubkey,
+    pub token_vault_balance: u64,
+    pub expected_token_vault_balance: u64,
+    pub token_discrepancy: i64,
+    pub sol_vault_balance: u64,
+    pub expected_sol_vault_balance: u64,
+    pub sol_discrepancy: i64,
+    pub lp_supply: u64,
+    pub lp_mint_supply: u64,
+    pub healthy: bool,
+}
+
+#[event]
+pub struct LpPenaltyEvent {
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    pub penalty: u64,
+    pub to_lockers: bool,
+}
+
+#[event]
+pub struct LpLockConfigEvent {
+    pub pool: Pubkey,
+    pub penalty_bps: u16,
+    pub penalty_until: i64,
+}
+
+#[event]
+pub struct LpLockEvent {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub locked: bool,
+    pub reward_claimed: u64,
+}
+
+#[event]
+pub struct LpVestingClaimEvent {
+    pub pool: Pubkey,
+    pub beneficiary: Pubkey,
+    pub amount: u64,
+    pub claimed: u64,
+    pub total: u64,
+}
+
+#[event]
+pub struct LpBurnedEvent {
+    pub pool: Pubkey,
+    pub burner: Pubkey,
+    pub amount: u64,
+    pub share_of_supply_bps: u16,
+    pub lp_supply_after: u64,
+    pub by_admin: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminNftBoundEvent {
+    pub pool: Pubkey,
+    pub nft_mint: Pubkey,
+    pub bound_by: Pubkey,
+}
+
+#[event]
+pub struct MaxCreatorRoyaltyUpdateEvent {
+    pub max_royalty_bps: u16,
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct LaunchQuotaUpdateEvent {
+    pub max_launches_per_creator: u32,
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct MaxPartnerFeeUpdateEvent {
+    pub max_partner_fee_bps: u16,
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct FeeRangeUpdateEvent {
+    pub default_fee_bps: u16,
+    pub min_fee_bps: u16,
+    /// 0 when the default cap applies
+    pub max_fee_bps: u16,
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct CreatorQuotaUpdateEvent {
+    pub creator: Pubkey,
+    /// Override of the protocol default, `None` when cleared
+    pub limit: Option<u32>,
+    pub launches: u32,
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct CreatorRoyaltyUpdateEvent {
+    pub pool: Pubkey,
+    pub creator: Pubkey,
+    pub royalty_bps: u16,
+    pub updated_by: Pubkey,
+}
+
+#[event]
+pub struct RoyaltiesClaimedEvent {
+    pub pool: Pubkey,
+    pub creator: Pubkey,
+    pub token_amount: u64,
+    pub quote_amount: u64,
+}
+
+#[event]
+pub struct RewardEpochFundedEvent {
+    pub pool: Pubkey,
+    pub epoch: u64,
+    pub reward_mint: Pubkey,
+    pub funder: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct RewardRootSetEvent {
+    pub pool: Pubkey,
+    pub epoch: u64,
+    pub merkle_root: [u8; 32],
+}
+
+#[event]
+pub struct RewardClaimedEvent {
+    pub pool: Pubkey,
+    pub epoch: u64,
+    pub claimant: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ObservationCrankedEvent {
+    pub pool: Pubkey,
+    pub cranker: Pubkey,
+    pub timestamp: i64,
+    pub bounty: u64,
+}
+
+#[event]
+pub struct TvlCheckpointEvent {
+    pub pool: Pubkey,
+    pub timestamp: i64,
+    /// Reserves in quote terms
+    pub tvl: u64,
+    pub lp_supply: u64,
+    pub price_x64: u128,
+}
+
+#[event]
+pub struct LpVirtualPriceEvent {
+    pub pool: Pubkey,
+    /// Quote value per LP base unit, Q64.64
+    pub virtual_price_x64: u128,
+    pub lp_supply: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LpMintHandoffProposedEvent {
+    pub pool: Pubkey,
+    /// Default when a pending proposal is cancelled
+    pub successor: Pubkey,
+    pub executable_at: i64,
+}
+
+#[event]
+pub struct LpMintHandoffEvent {
+    pub pool: Pubkey,
+    pub lp_mint: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct ParamProposalEvent {
+    pub pool: Pubkey,
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub change: ParamChange,
+    pub bond: u64,
+}
+
+#[event]
+pub struct ParamProposalResolvedEvent {
+    pub pool: Pubkey,
+    pub id: u64,
+    pub approved: bool,
+    pub resolved_by: Pubkey,
+}
+
+#[event]
+pub struct GuardianSetUpdateEvent {
+    pub guardians: Vec<Pubkey>,
+    pub threshold: u8,
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct GuardianPauseEvent {
+    pub pool: Pubkey,
+    /// Signers passed with the pause, including any non-guardians
+    pub guardians: Vec<Pubkey>,
+}
+
+#[event]
+pub struct ComplianceAuthorityUpdateEvent {
+    pub old_authority: Pubkey,
+    /// Default when the role is disabled
+    pub new_authority: Pubkey,
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct PoolFreezeEvent {
+    pub pool: Pubkey,
+    pub frozen: bool,
+    /// Reason code of the takedown or of its release
+    pub reason_code: u16,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProtocolStatusEvent {
+    pub previous: ProtocolStatus,
+    pub status: ProtocolStatus,
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct PoolCreationSponsoredEvent {
+    pub pool: Pubkey,
+    pub sponsor: Pubkey,
+    pub creator: Pubkey,
+    /// Rent the sponsor paid for the created accounts
+    pub rent: u64,
+}
+
+#[event]
+pub struct QuoteDustSweptEvent {
+    pub pool: Pubkey,
+    pub amount: u64,
+    pub destination: DustDestination,
+    /// Quote reserve after the sweep
+    pub sol_reserve: u64,
+}
+
+#[event]
+pub struct CrankJobEvent {
+    pub pool: Pubkey,
+    pub kind: CrankJobKind,
+    /// False when the job was removed
+    pub registered: bool,
+}
+
+#[event]
+pub struct LaunchAuctionStartedEvent {
+    pub auction: Pubkey,
+    pub token_mint: Pubkey,
+    pub creator: Pubkey,
+    pub supply_for_sale: u64,
+    pub pool_token_amount: u64,
+    pub reserve_price_x64: u128,
+    pub bid_end: i64,
+    pub reveal_end: i64,
+}
+
+#[event]
+pub struct AuctionBidEvent {
+    pub auction: Pubkey,
+    pub bidder: Pubkey,
+    pub sol_amount: u64,
+    /// Unset while the bid is sealed
+    pub tick: Option<u8>,
+}
+
+#[event]
+pub struct LaunchAuctionSettledEvent {
+    pub auction: Pubkey,
+    pub token_mint: Pubkey,
+    pub status: AuctionStatus,
+    pub clearing_price_x64: u128,
+    pub raised_sol: u64,
+    pub tokens_sold: u64,
+    /// Tokens seeded into the pool next to the raised SOL
+    pub pool_tokens: u64,
+}
+
+#[event]
+pub struct AuctionBidClaimedEvent {
+    pub auction: Pubkey,
+    pub bidder: Pubkey,
+    pub tick: Option<u8>,
+    pub tokens: u64,
+    /// SOL returned for the unfilled part of the bid
+    pub refund: u64,
+}
+
+/// Start, fill or close of an LP exit auction. Starts and closes carry no buyer;
+/// a close reports the LP tokens returned and the total proceeds.
+#[event]
+pub struct ExitAuctionEvent {
+    pub pool: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Option<Pubkey>,
+    pub lp_amount: u64,
+    pub price_x64: u128,
+    pub lamports: u64,
+    /// LP tokens still on sale
+    pub remaining: u64,
+}
+
+/// Opening, execution or close of a swap stream. Opens report no amounts,
+/// closes the stream's lifetime totals.
+#[event]
+pub struct SwapStreamEvent {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub direction: SwapDirection,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    /// Input left to convert
+    pub remaining: u64,
+}
+
+#[event]
+pub struct AccountResizedEvent {
+    pub account: Pubkey,
+    pub old_len: u32,
+    pub new_len: u32,
+    /// Rent payer when growing, refund recipient when shrinking
+    pub payer: Pubkey,
+}
+
+#[event]
+pub struct ReapGracePeriodUpdateEvent {
+    pub reap_grace_secs: u32,
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct PoolReapEvent {
+    pub pool: Pubkey,
+    pub token_mint: Pubkey,
+    pub empty_since: i64,
+    /// False when the call only started the grace period
+    pub reaped: bool,
+    pub treasury_refund: u64,
+    pub creator_refund: u64,
+}
+
+/// Emitted by legacy entrypoints kept for one release after being replaced
+#[event]
+pub struct DeprecatedEntrypointEvent {
+    pub entrypoint: String,
+    pub replacement: String,
+    pub caller: Pubkey,
+}
+
+#[event]
+pub struct PoolParamsUpdateEvent {
+    pub pool: Pubkey,
+    pub params: PoolParams,
+    /// Oracle the pool is bound to after the update
+    pub oracle: Pubkey,
+    pub updated_by: Pubkey,
+}
+
+#[event]
+pub struct FeeSplitterUpdateEvent {
+    /// Empty when fees go back to the protocol admin
+    pub shares: Vec<FeeShare>,
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct ProtocolFeeShareEvent {
+    pub pool: Pubkey,
+    pub recipient: Pubkey,
+    pub token_amount: u64,
+    pub quote_amount: u64,
+}
+
+#[event]
+pub struct StakeDiscountConfigUpdateEvent {
+    pub stake_mint: Pubkey,
+    pub cooldown_secs: u32,
+    pub tiers: Vec<StakeTier>,
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct DiscountStakeEvent {
+    pub owner: Pubkey,
+    /// Staked, or moved to the unstake cooldown
+    pub amount: u64,
+    pub staked: bool,
+    /// Unstaked tokens paid out after their cooldown
+    pub withdrawn: u64,
+    pub effective_stake: u64,
+}
+
+#[event]
+pub struct PoolFlagsUpdateEvent {
+    pub pool: Pubkey,
+    pub old_flags: u32,
+    pub new_flags: u32,
+    pub updated_by: Pubkey,
+}
+
+#[event]
+pub struct LpLockCompounderEvent {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub compounder: Pubkey,
+}
+
+#[event]
+pub struct EpochRevenueEvent {
+    pub pool: Pubkey,
+    /// Revenue accumulated from the start of `from_epoch` (0 = since the
+    /// pool was created) until the report in `epoch`, all in quote
+    pub from_epoch: u64,
+    pub epoch: u64,
+    pub volume: u64,
+    pub lp_fees: u64,
+    pub protocol_fees: u64,
+    pub creator_fees: u64,
+}
+
+#[event]
+pub struct PoolDecimalsSyncedEvent {
+    pub pool: Pubkey,
+    pub token_decimals: u8,
+    pub quote_decimals: u8,
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::instruction::AuthorityType;
+use anchor_spl::token::{self, Mint, SetAuthority, Token};
+
+use crate::errors::ExchangeError;
+use crate::events::LpMintHandoffEvent;
+use crate::state::LiquidityPool;
+
+#[derive(Accounts)]
+pub struct AcceptLpMintHandoff<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.lp_mint_successor == successor.key() @ ExchangeError::LpMintHandoffNotReady
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    /// Proposed authority, signing via CPI when it is a program PDA
+    pub successor: Signer<'info>,
+    /// CHECK: Pool authority PDA, current LP mint authority
+    #[account(
+        seeds = [b"pool_authority", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+    #[account(mut, address = pool.lp_mint @ ExchangeError::InvalidVault)]
+    pub lp_mint: Account<'info, Mint>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Hand the LP mint authority to the proposed successor once the timelock
+/// has elapsed. Deposits close on this program; exits keep working since
+/// burning LP only needs the holder's signature.
+pub fn handler(ctx: Context<AcceptLpMintHandoff>) -> Result<()> {
+    let pool = &ctx.accounts.pool;
+    require!(!pool.lp_mint_migrated, ExchangeError::LpMintMigrated);
+    require!(
+        pool.lp_mint_successor != Pubkey::default()
+            && Clock::get()?.unix_timestamp >= pool.lp_mint_handoff_at,
+        ExchangeError::LpMintHandoffNotReady
+    );
+    
+    let token_mint = pool.token_mint;
+    let successor = ctx.accounts.successor.key();
+    token::set_authority(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            SetAuthority {
+                current_authority: ctx.accounts.pool_authority.to_account_info(),
+                account_or_mint: ctx.accounts.lp_mint.to_account_info(),
+            },
+            &[&[b"pool_authority", token_mint.as_ref(), &[ctx.bumps.pool_authority]]],
+        ),
+        AuthorityType::MintTokens,
+        Some(successor),
+    )?;
+    
+    let pool = &mut ctx.accounts.pool;
+    pool.lp_mint_migrated = true;
+    pool.lp_mint_successor = Pubkey::default();
+    pool.lp_mint_handoff_at = 0;
+    
+    emit!(LpMintHandoffEvent {
+        pool: pool.key(),
+        lp_mint: pool.lp_mint,
+        new_authority: successor,
+    });
+    
+    Ok(())
+}
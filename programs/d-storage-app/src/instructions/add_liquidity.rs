@@ -0,0 +1,252 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
+
+use crate::curve::deposit_amounts;
+use crate::errors::ExchangeError;
+use crate::events::LiquidityAddedEvent;
+use crate::quote::QuoteVault;
+use crate::state::{DepositReceipt, LiquidityPool, LpAllowlistEntry, PoolFlags, ProtocolConfig};
+
+/// Amounts a deposit actually used, the excess side staying with the provider
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DepositAmounts {
+    pub token_amount: u64,
+    /// SOL, or quote tokens
+    pub sol_amount: u64,
+    pub lp_tokens: u64,
+}
+
+#[derive(Accounts)]
+pub struct AddLiquidity<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        has_one = token_vault @ ExchangeError::InvalidVault,
+        has_one = sol_vault @ ExchangeError::InvalidVault
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        mut,
+        associated_token::mint = pool.token_mint,
+        associated_token::authority = user
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"token_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub token_vault: Account<'info, TokenAccount>,
+    /// CHECK: SOL vault, or the quote token vault of a token-quoted pool
+    #[account(
+        mut,
+        seeds = [b"sol_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: AccountInfo<'info>,
+    /// Required only when the pool restricts liquidity providers
+    #[account(
+        seeds = [b"lp_allowlist", pool.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub lp_allowlist_entry: Option<Account<'info, LpAllowlistEntry>>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    /// CHECK: Pool authority PDA, mints the LP tokens
+    #[account(
+        seeds = [b"pool_authority", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+    #[account(mut, address = pool.lp_mint @ ExchangeError::InvalidVault)]
+    pub lp_mint: Account<'info, Mint>,
+    /// Receives the LP tokens, created for first-time providers
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = lp_mint,
+        associated_token::authority = user
+    )]
+    pub user_lp_account: Account<'info, TokenAccount>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// Source of the quote deposit, required for token-quoted pools
+    #[account(
+        mut,
+        token::mint = pool.quote_mint,
+        token::authority = user
+    )]
+    pub user_quote_account: Option<Account<'info, TokenAccount>>,
+    /// Records the deposit and its entry price when passed
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = DepositReceipt::LEN,
+        seeds = [b"deposit_receipt", pool.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub deposit_receipt: Option<Box<Account<'info, DepositReceipt>>>,
+    /// Protocol-wide emergency stop status
+    #[account(seeds = [b"protocol_config"], bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+}
+
+impl<'info> AddLiquidity<'info> {
+    /// Move the ratio-matched amounts into the vaults and account for the LP
+    /// tokens. Returns the amounts actually used.
+    pub fn deposit(
+        &mut self,
+        bumps: &AddLiquidityBumps,
+        token_amount: u64,
+        sol_amount: u64,
+        min_lp_tokens: u64,
+    ) -> Result<DepositAmounts> {
+        require!(
+            self.protocol_config.deposits_enabled(),
+            ExchangeError::ProtocolHalted
+        );
+        let now = Clock::get()?.unix_timestamp;
+        let allowlisted = self.lp_allowlist_entry.as_ref().is_some_and(|entry| !entry.is_expired(now));
+        let pool = &mut self.pool;
+        check_deposit(pool, allowlisted, now)?;
+        
+        // Match the current reserve ratio and size the LP share
+        let (final_token_amount, final_sol_amount, lp_tokens) = deposit_amounts(
+            pool.token_reserve,
+            pool.sol_reserve,
+            pool.lp_supply,
+            token_amount,
+            sol_amount,
+        );
+        
+        require!(lp_tokens >= min_lp_tokens, ExchangeError::SlippageExceeded);
+        
+        // Transfer tokens to vault
+        if final_token_amount > 0 {
+            token::transfer(
+                CpiContext::new(
+                    self.token_program.to_account_info(),
+                    Transfer {
+                        from: self.user_token_account.to_account_info(),
+                        to: self.token_vault.to_account_info(),
+                        authority: self.user.to_account_info(),
+                    },
+                ),
+                final_token_amount,
+            )?;
+        }
+        
+        if final_sol_amount > 0 {
+            QuoteVault {
+                pool,
+                vault: &self.sol_vault,
+                token_program: self.token_program.as_ref(),
+                system_program: self.system_program.as_ref(),
+            }
+            .deposit(
+                self.user.as_ref(),
+                self.user_quote_account.as_ref().map(|account| account.as_ref()),
+                final_sol_amount,
+            )?;
+        }
+        
+        mint_lp_tokens(
+            self.token_program.as_ref(),
+            self.lp_mint.as_ref(),
+            self.user_lp_account.as_ref(),
+            &self.pool_authority,
+            pool.token_mint,
+            bumps.pool_authority,
+            lp_tokens,
+        )?;
+        
+        if let Some(receipt) = &mut self.deposit_receipt {
+            receipt.owner = self.user.key();
+            receipt.pool = pool.key();
+            receipt.record_deposit(
+                final_token_amount,
+                final_sol_amount,
+                lp_tokens,
+                pool.token_value_in_quote(final_token_amount),
+            );
+            receipt.updated_at = now;
+        }
+        
+        // Update pool reserves
+        pool.update_price_cumulative(now);
+        pool.token_reserve += final_token_amount;
+        pool.sol_reserve += final_sol_amount;
+        pool.lp_supply += lp_tokens;
+        pool.empty_since = 0;
+        
+        emit!(LiquidityAddedEvent {
+            pool: pool.key(),
+            user: self.user.key(),
+            token_amount: final_token_amount,
+            sol_amount: final_sol_amount,
+            lp_tokens,
+            token_requested: token_amount,
+            sol_requested: sol_amount,
+        });
+        
+        assert_pool_invariants!(pool, Some(self.token_vault.as_ref()), Some(&self.sol_vault), Some(self.lp_mint.as_ref()));
+        Ok(DepositAmounts {
+            token_amount: final_token_amount,
+            sol_amount: final_sol_amount,
+            lp_tokens,
+        })
+    }
+}
+
+pub fn handler(
+    ctx: Context<AddLiquidity>,
+    token_amount: u64,
+    sol_amount: u64,
+    min_lp_tokens: u64,
+) -> Result<DepositAmounts> {
+    ctx.accounts.deposit(&ctx.bumps, token_amount, sol_amount, min_lp_tokens)
+}
+
+/// Sunset, freeze, pause and allowlist checks shared by every deposit path
+pub fn check_deposit(pool: &LiquidityPool, allowlisted: bool, now: i64) -> Result<()> {
+    require!(!pool.is_sunset(now), ExchangeError::PoolSunset);
+    require!(!pool.is_frozen(), ExchangeError::PoolFrozen);
+    require!(!pool.deposits_paused, ExchangeError::DepositsPaused);
+    require!(!pool.lp_mint_migrated, ExchangeError::LpMintMigrated);
+    
+    // Restricted pools only accept liquidity from allowlisted providers
+    if pool.features_enabled(PoolFlags::LP_ALLOWLIST) {
+        require!(allowlisted, ExchangeError::LiquidityProviderNotAllowed);
+    }
+    Ok(())
+}
+
+/// Mint `amount` LP tokens to a provider, signed by the pool authority
+pub fn mint_lp_tokens<'info>(
+    token_program: &AccountInfo<'info>,
+    lp_mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    pool_authority: &AccountInfo<'info>,
+    token_mint: Pubkey,
+    pool_authority_bump: u8,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    token::mint_to(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            MintTo {
+                mint: lp_mint.clone(),
+                to: to.clone(),
+                authority: pool_authority.clone(),
+            },
+            &[&[b"pool_authority", token_mint.as_ref(), &[pool_authority_bump]]],
+        ),
+        amount,
+    )
+}
@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::curve::deposit_amounts;
+use crate::errors::ExchangeError;
+use crate::events::LiquidityAddedEvent;
+use crate::state::{InternalBalance, LiquidityPool, LpAllowlistEntry, PoolFlags, ProtocolConfig};
+use super::add_liquidity::{check_deposit, mint_lp_tokens, DepositAmounts};
+
+#[derive(Accounts)]
+pub struct AddLiquidityInternal<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        has_one = token_vault @ ExchangeError::InvalidVault,
+        has_one = sol_vault @ ExchangeError::InvalidVault
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"token_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub token_vault: Account<'info, TokenAccount>,
+    /// CHECK: SOL vault, or the quote token vault of a token-quoted pool
+    #[account(
+        mut,
+        seeds = [b"sol_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"internal_balance", user.key().as_ref(), pool.token_mint.as_ref()],
+        bump
+    )]
+    pub token_balance: Account<'info, InternalBalance>,
+    #[account(
+        mut,
+        seeds = [b"internal_balance", user.key().as_ref(), pool.quote_mint.as_ref()],
+        bump
+    )]
+    pub quote_balance: Account<'info, InternalBalance>,
+    /// Required only when the pool restricts liquidity providers
+    #[account(
+        seeds = [b"lp_allowlist", pool.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub lp_allowlist_entry: Option<Account<'info, LpAllowlistEntry>>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    /// CHECK: Pool authority PDA, mints the LP tokens
+    #[account(
+        seeds = [b"pool_authority", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+    #[account(mut, address = pool.lp_mint @ ExchangeError::InvalidVault)]
+    pub lp_mint: Account<'info, Mint>,
+    /// Receives the LP tokens, created for first-time providers
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = lp_mint,
+        associated_token::authority = user
+    )]
+    pub user_lp_account: Account<'info, TokenAccount>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// Protocol-wide emergency stop status
+    #[account(seeds = [b"protocol_config"], bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+}
+
+pub fn handler(
+    ctx: Context<AddLiquidityInternal>,
+    token_amount: u64,
+    sol_amount: u64,
+    min_lp_tokens: u64,
+) -> Result<DepositAmounts> {
+    let accounts = ctx.accounts;
+    require!(
+        accounts.protocol_config.deposits_enabled(),
+        ExchangeError::ProtocolHalted
+    );
+    require!(accounts.pool.features_enabled(PoolFlags::INTERNAL_BALANCES), ExchangeError::FeatureDisabled);
+    let pool = &accounts.pool;
+    let now = Clock::get()?.unix_timestamp;
+    let allowlisted = accounts.lp_allowlist_entry.as_ref().is_some_and(|entry| !entry.is_expired(now));
+    check_deposit(pool, allowlisted, now)?;
+    
+    // Match the current reserve ratio and size the LP share
+    let (final_token_amount, final_sol_amount, lp_tokens) = deposit_amounts(
+        pool.token_reserve,
+        pool.sol_reserve,
+        pool.lp_supply,
+        token_amount,
+        sol_amount,
+    );
+    
+    require!(lp_tokens >= min_lp_tokens, ExchangeError::SlippageExceeded);
+    
+    // The deposit stays in the internal vaults as pool credit
+    accounts.token_balance.debit(final_token_amount)?;
+    accounts.quote_balance.debit(final_sol_amount)?;
+    
+    mint_lp_tokens(
+        accounts.token_program.as_ref(),
+        accounts.lp_mint.as_ref(),
+        accounts.user_lp_account.as_ref(),
+        &accounts.pool_authority,
+        accounts.pool.token_mint,
+        ctx.bumps.pool_authority,
+        lp_tokens,
+    )?;
+    
+    // Update pool reserves
+    let pool = &mut accounts.pool;
+    pool.update_price_cumulative(Clock::get()?.unix_timestamp);
+    pool.token_reserve += final_token_amount;
+    pool.sol_reserve += final_sol_amount;
+    pool.lp_supply += lp_tokens;
+    pool.internal_token_credit = pool
+        .internal_token_credit
+        .checked_add(final_token_amount)
+        .ok_or(ExchangeError::MathOverflow)?;
+    pool.internal_quote_credit = pool
+        .internal_quote_credit
+        .checked_add(final_sol_amount)
+        .ok_or(ExchangeError::MathOverflow)?;
+    pool.empty_since = 0;
+    
+    emit!(LiquidityAddedEvent {
+        pool: pool.key(),
+        user: accounts.user.key(),
+        token_amount: final_token_amount,
+        sol_amount: final_sol_amount,
+        lp_tokens,
+        token_requested: token_amount,
+        sol_requested: sol_amount,
+    });
+    
+    assert_pool_invariants!(pool, Some(accounts.token_vault.as_ref()), Some(&accounts.sol_vault), Some(accounts.lp_mint.as_ref()));
+    Ok(DepositAmounts {
+        token_amount: final_token_amount,
+        sol_amount: final_sol_amount,
+        lp_tokens,
+    })
+}
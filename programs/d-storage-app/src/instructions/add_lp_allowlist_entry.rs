@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::ExchangeError;
+use crate::events::LpAllowlistEntryEvent;
+use crate::state::{LiquidityPool, LpAllowlistEntry};
+
+#[derive(Accounts)]
+#[instruction(provider: Pubkey)]
+pub struct AddLpAllowlistEntry<'info> {
+    #[account(
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.is_admin(&admin.key(), admin_nft_account.as_deref()) @ ExchangeError::Unauthorized
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(
+        init,
+        payer = admin,
+        space = LpAllowlistEntry::LEN,
+        seeds = [b"lp_allowlist", pool.key().as_ref(), provider.as_ref()],
+        bump
+    )]
+    pub lp_allowlist_entry: Account<'info, LpAllowlistEntry>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    /// Admin NFT held by the signer, required once the pool is bound to one
+    pub admin_nft_account: Option<Account<'info, TokenAccount>>,
+}
+
+pub fn handler(
+    ctx: Context<AddLpAllowlistEntry>,
+    provider: Pubkey,
+    expires_at: i64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(expires_at == 0 || expires_at > now, ExchangeError::InvalidAllowlistExpiry);
+    
+    let entry = &mut ctx.accounts.lp_allowlist_entry;
+    entry.pool = ctx.accounts.pool.key();
+    entry.provider = provider;
+    entry.added_at = now;
+    entry.expires_at = expires_at;
+    entry.rent_payer = ctx.accounts.admin.key();
+    
+    emit!(LpAllowlistEntryEvent {
+        pool: entry.pool,
+        provider,
+        allowed: true,
+    });
+    
+    Ok(())
+}
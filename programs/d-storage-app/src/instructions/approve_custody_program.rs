@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::events::CustodyApprovalEvent;
+use crate::state::{CustodyApproval, ProtocolConfig};
+
+#[derive(Accounts)]
+#[instruction(custody_program: Pubkey)]
+pub struct ApproveCustodyProgram<'info> {
+    #[account(
+        seeds = [b"protocol_config"],
+        bump,
+        constraint = protocol_config.admin == admin.key() @ ExchangeError::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        init,
+        payer = admin,
+        space = CustodyApproval::LEN,
+        seeds = [b"custody_approval", custody_program.as_ref()],
+        bump
+    )]
+    pub custody_approval: Account<'info, CustodyApproval>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ApproveCustodyProgram>, custody_program: Pubkey) -> Result<()> {
+    let custody_approval = &mut ctx.accounts.custody_approval;
+    custody_approval.custody_program = custody_program;
+    custody_approval.approved_by = ctx.accounts.admin.key();
+    custody_approval.approved_at = Clock::get()?.unix_timestamp;
+    
+    emit!(CustodyApprovalEvent {
+        custody_program,
+        approved: true,
+        admin: custody_approval.approved_by,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::instruction::AuthorityType;
+use anchor_spl::token::{self, SetAuthority, Token, TokenAccount};
+
+use crate::errors::ExchangeError;
+use crate::events::VaultCustodyAssignedEvent;
+use crate::state::{CustodyApproval, LiquidityPool};
+
+#[derive(Accounts)]
+pub struct AssignVaultCustody<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        has_one = token_vault @ ExchangeError::InvalidVault,
+        constraint = pool.is_admin(&admin.key(), admin_nft_account.as_deref()) @ ExchangeError::Unauthorized
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    /// CHECK: Pool authority PDA, current token vault owner
+    #[account(
+        seeds = [b"pool_authority", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"token_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub token_vault: Account<'info, TokenAccount>,
+    #[account(
+        seeds = [b"custody_approval", custody_approval.custody_program.as_ref()],
+        bump
+    )]
+    pub custody_approval: Account<'info, CustodyApproval>,
+    /// CHECK: Custody account created by the approved program, becomes the vault owner
+    #[account(
+        constraint = *custody_authority.owner == custody_approval.custody_program @ ExchangeError::InvalidCustodyAuthority
+    )]
+    pub custody_authority: UncheckedAccount<'info>,
+    pub admin: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    /// Admin NFT held by the signer, required once the pool is bound to one
+    pub admin_nft_account: Option<Account<'info, TokenAccount>>,
+}
+
+/// Hand ownership of the token vault to an approved custody program. The
+/// custody program must then approve the pool authority as delegate, which
+/// is all swaps and withdrawals need to settle out of the vault.
+pub fn handler(ctx: Context<AssignVaultCustody>) -> Result<()> {
+    require!(
+        ctx.accounts.pool.custody_authority == Pubkey::default(),
+        ExchangeError::VaultCustodyAssigned
+    );
+    
+    let token_mint = ctx.accounts.pool.token_mint;
+    let custody_authority = ctx.accounts.custody_authority.key();
+    token::set_authority(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            SetAuthority {
+                current_authority: ctx.accounts.pool_authority.to_account_info(),
+                account_or_mint: ctx.accounts.token_vault.to_account_info(),
+            },
+            &[&[b"pool_authority", token_mint.as_ref(), &[ctx.bumps.pool_authority]]],
+        ),
+        AuthorityType::AccountOwner,
+        Some(custody_authority),
+    )?;
+    
+    let pool = &mut ctx.accounts.pool;
+    pool.custody_authority = custody_authority;
+    
+    emit!(VaultCustodyAssignedEvent {
+        pool: pool.key(),
+        custody_program: ctx.accounts.custody_approval.custody_program,
+        custody_authority,
+        assigned_by: ctx.accounts.admin.key(),
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use anchor_spl::token::TokenAccount;
+
+use crate::state::MigrationSnapshot;
+
+#[derive(Accounts)]
+pub struct BeginMigration<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    /// Receives the tokens withdrawn from the source AMM
+    #[account(token::authority = user)]
+    pub user_token_account: Account<'info, TokenAccount>,
+    /// Receives the wSOL, or quote tokens, withdrawn from the source AMM
+    #[account(token::authority = user)]
+    pub user_quote_account: Account<'info, TokenAccount>,
+    /// Left over by a transaction that never reached `migrate_liquidity`
+    /// when it already exists, and overwritten
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = MigrationSnapshot::LEN,
+        seeds = [b"migration_snapshot", user.key().as_ref()],
+        bump
+    )]
+    pub migration_snapshot: Account<'info, MigrationSnapshot>,
+    /// CHECK: Instructions sysvar, records where the migration begins
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<BeginMigration>) -> Result<()> {
+    let snapshot = &mut ctx.accounts.migration_snapshot;
+    snapshot.user = ctx.accounts.user.key();
+    snapshot.token_account = ctx.accounts.user_token_account.key();
+    snapshot.token_balance = ctx.accounts.user_token_account.amount;
+    snapshot.quote_account = ctx.accounts.user_quote_account.key();
+    snapshot.quote_balance = ctx.accounts.user_quote_account.amount;
+    snapshot.slot = Clock::get()?.slot;
+    snapshot.instruction_index = sysvar::instructions::load_current_index_checked(&ctx.accounts.instructions)?;
+    Ok(())
+}
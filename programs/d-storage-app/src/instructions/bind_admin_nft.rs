@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+
+use crate::errors::ExchangeError;
+use crate::events::AdminNftBoundEvent;
+use crate::state::LiquidityPool;
+
+#[derive(Accounts)]
+pub struct BindAdminNft<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.is_admin(&admin.key(), admin_nft_account.as_deref()) @ ExchangeError::Unauthorized
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    pub admin: Signer<'info>,
+    /// Admin NFT held by the signer, required once the pool is bound to one
+    pub admin_nft_account: Option<Account<'info, TokenAccount>>,
+    
+    /// NFT to bind admin rights to. Omit to unbind and return the rights to the signer's key.
+    #[account(
+        constraint = nft_mint.supply == 1 && nft_mint.decimals == 0 @ ExchangeError::InvalidAdminNft,
+        constraint = nft_mint.mint_authority.is_none() @ ExchangeError::InvalidAdminNft
+    )]
+    pub nft_mint: Option<Account<'info, Mint>>,
+    /// Signer's account holding `nft_mint`, so rights cannot be bound to an NFT the team does not hold
+    #[account(
+        constraint = nft_mint.as_ref().is_some_and(|mint| nft_account.mint == mint.key()) @ ExchangeError::InvalidAdminNft,
+        constraint = nft_account.owner == admin.key() && nft_account.amount == 1 @ ExchangeError::InvalidAdminNft
+    )]
+    pub nft_account: Option<Account<'info, TokenAccount>>,
+}
+
+pub fn handler(ctx: Context<BindAdminNft>) -> Result<()> {
+    let nft_mint = match (&ctx.accounts.nft_mint, &ctx.accounts.nft_account) {
+        (Some(nft_mint), Some(_)) => nft_mint.key(),
+        (None, None) => Pubkey::default(),
+        _ => return err!(ExchangeError::InvalidAdminNft),
+    };
+    
+    let pool = &mut ctx.accounts.pool;
+    pool.admin_nft_mint = nft_mint;
+    // While bound, `admin` only records that the pool still has an admin
+    pool.admin = Some(ctx.accounts.admin.key());
+    
+    emit!(AdminNftBoundEvent {
+        pool: pool.key(),
+        nft_mint,
+        bound_by: ctx.accounts.admin.key(),
+    });
+    
+    Ok(())
+}
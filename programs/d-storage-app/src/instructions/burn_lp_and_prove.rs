@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+
+use crate::errors::ExchangeError;
+use crate::events::LpBurnedEvent;
+use crate::state::{LiquidityPool, LpBurnRecord};
+
+#[derive(Accounts)]
+pub struct BurnLpAndProve<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(mut)]
+    pub burner: Signer<'info>,
+    #[account(mut, address = pool.lp_mint @ ExchangeError::InvalidVault)]
+    pub lp_mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = pool.lp_mint,
+        token::authority = burner
+    )]
+    pub burner_lp_account: Account<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = burner,
+        space = LpBurnRecord::LEN,
+        seeds = [b"lp_burn", pool.key().as_ref(), burner.key().as_ref()],
+        bump
+    )]
+    pub lp_burn_record: Account<'info, LpBurnRecord>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Burn LP tokens without withdrawing, leaving their share of the reserves
+/// to the remaining providers, and record the burn for rug-check tools
+pub fn handler(ctx: Context<BurnLpAndProve>, amount: u64) -> Result<()> {
+    require!(amount > 0, ExchangeError::InvalidAmount);
+    
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.lp_mint.to_account_info(),
+                from: ctx.accounts.burner_lp_account.to_account_info(),
+                authority: ctx.accounts.burner.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    let now = Clock::get()?.unix_timestamp;
+    let pool = &mut ctx.accounts.pool;
+    let share_of_supply_bps = (amount as u128 * 10000 / pool.lp_supply.max(1) as u128) as u16;
+    pool.lp_supply -= amount;
+    
+    let record = &mut ctx.accounts.lp_burn_record;
+    record.record(pool.key(), ctx.accounts.burner.key(), amount, share_of_supply_bps, now);
+    
+    emit!(LpBurnedEvent {
+        pool: record.pool,
+        burner: record.burner,
+        amount,
+        share_of_supply_bps,
+        lp_supply_after: pool.lp_supply,
+        by_admin: pool.admin == Some(record.burner),
+        timestamp: now,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,223 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::curve::{deposit_amounts, zap_swap_amount};
+use crate::errors::ExchangeError;
+use crate::events::BuyAndLpEvent;
+use crate::oracle::{check_oracle, rebalance_discount_bps};
+use crate::state::{LiquidityPool, LpAllowlistEntry, ProtocolConfig, SwapCooldown};
+use super::add_liquidity::{check_deposit, mint_lp_tokens, DepositAmounts};
+use super::swap::{check_opposing_swaps, price_swap, reserves_after_swap, set_aside_fees, throttle_outflow, SwapDirection};
+
+#[derive(Accounts)]
+pub struct BuyAndLp<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        has_one = token_vault @ ExchangeError::InvalidVault,
+        has_one = sol_vault @ ExchangeError::InvalidVault
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    /// Receives the bought tokens that could not be paired
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = token_mint,
+        associated_token::authority = user
+    )]
+    pub user_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [b"token_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub token_vault: Box<Account<'info, TokenAccount>>,
+    /// CHECK: SOL vault
+    #[account(
+        mut,
+        seeds = [b"sol_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: AccountInfo<'info>,
+    /// CHECK: Pool authority PDA, mints the LP tokens
+    #[account(
+        seeds = [b"pool_authority", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+    #[account(mut, address = pool.lp_mint @ ExchangeError::InvalidVault)]
+    pub lp_mint: Box<Account<'info, Mint>>,
+    /// Receives the LP tokens, created for first-time providers
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = lp_mint,
+        associated_token::authority = user
+    )]
+    pub user_lp_account: Box<Account<'info, TokenAccount>>,
+    #[account(address = pool.token_mint @ ExchangeError::InvalidTokenMint)]
+    pub token_mint: Box<Account<'info, Mint>>,
+    /// Protocol-wide emergency stop status
+    #[account(seeds = [b"protocol_config"], bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// Required only when the pool restricts liquidity providers
+    #[account(
+        seeds = [b"lp_allowlist", pool.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub lp_allowlist_entry: Option<Account<'info, LpAllowlistEntry>>,
+    /// CHECK: Price feed recorded on the pool, required when the oracle guard is on
+    #[account(address = pool.oracle @ ExchangeError::InvalidOracle)]
+    pub oracle: Option<UncheckedAccount<'info>>,
+    /// Per-wallet cooldown tracker, required during the launch window
+    #[account(
+        mut,
+        seeds = [b"swap_cooldown", pool.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub swap_cooldown: Option<Account<'info, SwapCooldown>>,
+    /// CHECK: Instructions sysvar, required when the pool guards against sandwiches
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: Option<UncheckedAccount<'info>>,
+}
+
+/// One-click LP from SOL alone: buy tokens with part of `sol_amount`, at an
+/// average price of at most `max_price_x64` (lamports per token, Q64.64),
+/// and deposit them with the rest. The bought tokens stay in the vault;
+/// any that cannot be paired are sent to the user.
+pub fn handler(
+    ctx: Context<BuyAndLp>,
+    sol_amount: u64,
+    max_price_x64: u128,
+    min_lp_tokens: u64,
+) -> Result<DepositAmounts> {
+    let now = Clock::get()?.unix_timestamp;
+    let accounts = ctx.accounts;
+    require!(accounts.pool.is_native_quote(), ExchangeError::NativeQuoteOnly);
+    require!(accounts.protocol_config.deposits_enabled(), ExchangeError::ProtocolHalted);
+    let allowlisted = accounts.lp_allowlist_entry.as_ref().is_some_and(|entry| !entry.is_expired(now));
+    check_deposit(&accounts.pool, allowlisted, now)?;
+    check_opposing_swaps(&accounts.pool, accounts.instructions.as_deref())?;
+    
+    // Buy the token side
+    let direction = SwapDirection::SolToToken;
+    let swap_amount = zap_swap_amount(
+        accounts.pool.curve_type,
+        accounts.pool.token_reserve,
+        accounts.pool.sol_reserve,
+        sol_amount,
+        (accounts.pool.lp_fee_bps + accounts.pool.protocol_fee_bps) as u64,
+        accounts.pool.unit_decimals(),
+    );
+    let fee_discount_bps = rebalance_discount_bps(&accounts.pool, accounts.oracle.as_deref(), direction, now)?;
+    let (tokens_bought, lp_fee, protocol_fee) = price_swap(
+        &accounts.pool,
+        accounts.swap_cooldown.as_deref_mut(),
+        now,
+        direction,
+        swap_amount,
+        0,
+        fee_discount_bps,
+    )?;
+    require!(
+        max_price_x64
+            .checked_mul(tokens_bought as u128)
+            .is_none_or(|max_cost| (swap_amount as u128) << 64 <= max_cost),
+        ExchangeError::SlippageExceeded
+    );
+    
+    let set_aside = set_aside_fees(&mut accounts.pool, direction, swap_amount, lp_fee, protocol_fee, now);
+    throttle_outflow(&mut accounts.pool, direction, tokens_bought, now)?;
+    let (new_token_reserve, new_sol_reserve) =
+        reserves_after_swap(&accounts.pool, direction, swap_amount - set_aside, tokens_bought);
+    check_oracle(&accounts.pool, accounts.oracle.as_deref(), new_token_reserve, new_sol_reserve, now)?;
+    
+    let pool = &mut accounts.pool;
+    pool.update_price_cumulative(now);
+    pool.token_reserve = new_token_reserve;
+    pool.sol_reserve = new_sol_reserve;
+    
+    // Pair the bought tokens with the remaining SOL
+    let (token_amount, deposit_sol_amount, lp_tokens) = deposit_amounts(
+        pool.token_reserve,
+        pool.sol_reserve,
+        pool.lp_supply,
+        tokens_bought,
+        sol_amount - swap_amount,
+    );
+    require!(lp_tokens >= min_lp_tokens, ExchangeError::SlippageExceeded);
+    pool.token_reserve += token_amount;
+    pool.sol_reserve += deposit_sol_amount;
+    pool.lp_supply += lp_tokens;
+    pool.empty_since = 0;
+    
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: accounts.user.to_account_info(),
+                to: accounts.sol_vault.to_account_info(),
+            },
+        ),
+        swap_amount + deposit_sol_amount,
+    )?;
+    
+    let token_mint = pool.token_mint;
+    let token_refund = tokens_bought - token_amount;
+    if token_refund > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                accounts.token_program.to_account_info(),
+                Transfer {
+                    from: accounts.token_vault.to_account_info(),
+                    to: accounts.user_token_account.to_account_info(),
+                    authority: accounts.pool_authority.to_account_info(),
+                },
+                &[&[b"pool_authority", token_mint.as_ref(), &[ctx.bumps.pool_authority]]],
+            ),
+            token_refund,
+        )?;
+    }
+    
+    mint_lp_tokens(
+        accounts.token_program.as_ref(),
+        accounts.lp_mint.as_ref().as_ref(),
+        accounts.user_lp_account.as_ref().as_ref(),
+        &accounts.pool_authority,
+        token_mint,
+        ctx.bumps.pool_authority,
+        lp_tokens,
+    )?;
+    
+    emit!(BuyAndLpEvent {
+        pool: accounts.pool.key(),
+        user: accounts.user.key(),
+        sol_in: swap_amount + deposit_sol_amount,
+        sol_swapped: swap_amount,
+        tokens_bought,
+        token_amount,
+        sol_amount: deposit_sol_amount,
+        lp_tokens,
+        token_refund,
+    });
+    
+    assert_pool_invariants!(
+        &accounts.pool,
+        Some(accounts.token_vault.as_ref().as_ref()),
+        Some(&accounts.sol_vault),
+        Some(accounts.lp_mint.as_ref().as_ref())
+    );
+    Ok(DepositAmounts {
+        token_amount,
+        sol_amount: deposit_sol_amount,
+        lp_tokens,
+    })
+}
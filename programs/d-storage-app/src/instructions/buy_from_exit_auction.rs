@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer as SystemTransfer};
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::ExchangeError;
+use crate::events::ExitAuctionEvent;
+use crate::state::{ExitAuction, ProtocolConfig};
+
+#[derive(Accounts)]
+pub struct BuyFromExitAuction<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    #[account(
+        mut,
+        has_one = seller,
+        seeds = [b"exit_auction", exit_auction.pool.as_ref(), exit_auction.seller.as_ref()],
+        bump = exit_auction.bump
+    )]
+    pub exit_auction: Box<Account<'info, ExitAuction>>,
+    
+    #[account(
+        mut,
+        seeds = [b"exit_auction_vault", exit_auction.key().as_ref()],
+        bump
+    )]
+    pub exit_auction_vault: Box<Account<'info, TokenAccount>>,
+    
+    /// CHECK: Seller of the auction, receives the lamports
+    #[account(mut)]
+    pub seller: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        token::mint = exit_auction_vault.mint,
+        token::authority = buyer
+    )]
+    pub buyer_lp_account: Box<Account<'info, TokenAccount>>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    /// Protocol-wide emergency stop status
+    #[account(seeds = [b"protocol_config"], bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+}
+
+/// Buy `lp_amount` of the escrowed LP tokens at the current asking price,
+/// failing if it is above `max_price_x64`
+pub fn handler(ctx: Context<BuyFromExitAuction>, lp_amount: u64, max_price_x64: u128) -> Result<u64> {
+    require!(
+        ctx.accounts.protocol_config.swaps_enabled(),
+        ExchangeError::ProtocolHalted
+    );
+    let exit_auction = &mut ctx.accounts.exit_auction;
+    require!(lp_amount > 0 && lp_amount <= exit_auction.remaining, ExchangeError::InvalidAmount);
+    
+    let price_x64 = exit_auction.price_at(Clock::get()?.unix_timestamp);
+    require!(price_x64 <= max_price_x64, ExchangeError::SlippageExceeded);
+    let lamports = ExitAuction::cost(lp_amount, price_x64).ok_or(ExchangeError::MathOverflow)?;
+    
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            SystemTransfer {
+                from: ctx.accounts.buyer.to_account_info(),
+                to: ctx.accounts.seller.to_account_info(),
+            },
+        ),
+        lamports,
+    )?;
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.exit_auction_vault.to_account_info(),
+                to: ctx.accounts.buyer_lp_account.to_account_info(),
+                authority: exit_auction.to_account_info(),
+            },
+            &[&[
+                b"exit_auction",
+                exit_auction.pool.as_ref(),
+                exit_auction.seller.as_ref(),
+                &[exit_auction.bump],
+            ]],
+        ),
+        lp_amount,
+    )?;
+    
+    exit_auction.remaining -= lp_amount;
+    exit_auction.sold += lp_amount;
+    exit_auction.proceeds = exit_auction.proceeds.saturating_add(lamports);
+    
+    emit!(ExitAuctionEvent {
+        pool: exit_auction.pool,
+        seller: exit_auction.seller,
+        buyer: Some(ctx.accounts.buyer.key()),
+        lp_amount,
+        price_x64,
+        lamports,
+        remaining: exit_auction.remaining,
+    });
+    
+    Ok(lamports)
+}
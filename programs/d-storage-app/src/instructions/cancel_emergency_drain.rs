@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::ExchangeError;
+use crate::events::EmergencyDrainCancelledEvent;
+use crate::state::LiquidityPool;
+
+#[derive(Accounts)]
+pub struct CancelEmergencyDrain<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.is_admin(&signer.key(), admin_nft_account.as_deref()) || pool.guardian == signer.key() @ ExchangeError::Unauthorized
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    pub signer: Signer<'info>,
+    /// Admin NFT held by the signer, required once the pool is bound to one
+    pub admin_nft_account: Option<Account<'info, TokenAccount>>,
+}
+
+pub fn handler(ctx: Context<CancelEmergencyDrain>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    require!(pool.drain_requested_at != 0, ExchangeError::NoEmergencyDrainPending);
+    pool.drain_requested_at = 0;
+    
+    emit!(EmergencyDrainCancelledEvent {
+        pool: pool.key(),
+        cancelled_by: ctx.accounts.signer.key(),
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::ExchangeError;
+use crate::events::LaunchAuctionSettledEvent;
+use crate::state::{AuctionStatus, LaunchAuction};
+
+#[derive(Accounts)]
+pub struct CancelLaunchAuction<'info> {
+    #[account(
+        mut,
+        seeds = [b"launch_auction", auction.token_mint.as_ref()],
+        bump = auction.bump
+    )]
+    pub auction: Box<Account<'info, LaunchAuction>>,
+    
+    #[account(
+        mut,
+        seeds = [b"launch_auction_vault", auction.token_mint.as_ref()],
+        bump
+    )]
+    pub auction_vault: Box<Account<'info, TokenAccount>>,
+    
+    /// CHECK: Pool PDA of the mint, seeding fails once someone else created it
+    #[account(
+        seeds = [b"pool", auction.token_mint.as_ref()],
+        bump
+    )]
+    pub pool: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        token::mint = auction.token_mint,
+        token::authority = auction.creator
+    )]
+    pub creator_token_account: Box<Account<'info, TokenAccount>>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+/// Call off an auction that cannot seed its pool, because no bid was revealed
+/// or the pool was created outside the auction. The inventory goes back to
+/// the creator and every bid is refunded in full.
+pub fn handler(ctx: Context<CancelLaunchAuction>) -> Result<()> {
+    let auction = &mut ctx.accounts.auction;
+    require!(
+        auction.status == AuctionStatus::Open && Clock::get()?.unix_timestamp >= auction.reveal_end,
+        ExchangeError::AuctionPhaseMismatch
+    );
+    require!(
+        !auction.has_demand() || !ctx.accounts.pool.data_is_empty(),
+        ExchangeError::AuctionNotCancellable
+    );
+    auction.status = AuctionStatus::Cancelled;
+    
+    let token_mint = auction.token_mint;
+    let inventory = ctx.accounts.auction_vault.amount;
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.auction_vault.to_account_info(),
+                to: ctx.accounts.creator_token_account.to_account_info(),
+                authority: auction.to_account_info(),
+            },
+            &[&[b"launch_auction", token_mint.as_ref(), &[auction.bump]]],
+        ),
+        inventory,
+    )?;
+    
+    emit!(LaunchAuctionSettledEvent {
+        auction: auction.key(),
+        token_mint,
+        status: auction.status,
+        clearing_price_x64: 0,
+        raised_sol: 0,
+        tokens_sold: 0,
+        pool_tokens: 0,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::events::TvlCheckpointEvent;
+use crate::state::{CrankJobKind, CrankRegistry, LiquidityPool, TvlCheckpoint, TvlHistory};
+
+#[derive(Accounts)]
+pub struct CheckpointTvl<'info> {
+    #[account(
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(
+        mut,
+        seeds = [b"tvl_history", pool.key().as_ref()],
+        bump
+    )]
+    pub tvl_history: Box<Account<'info, TvlHistory>>,
+    /// Crank registry, the job is rescheduled when passed
+    #[account(
+        mut,
+        seeds = [b"crank_registry"],
+        bump
+    )]
+    pub crank_registry: Option<Box<Account<'info, CrankRegistry>>>,
+}
+
+/// Append today's checkpoint. Permissionless, allowed once per UTC day.
+pub fn handler(ctx: Context<CheckpointTvl>) -> Result<()> {
+    let pool = &ctx.accounts.pool;
+    let checkpoint = TvlCheckpoint::of_pool(pool, Clock::get()?.unix_timestamp);
+    require!(
+        ctx.accounts.tvl_history.record(checkpoint),
+        ExchangeError::TvlCheckpointTooRecent
+    );
+    if let Some(crank_registry) = &mut ctx.accounts.crank_registry {
+        crank_registry.reschedule(CrankJobKind::TvlCheckpoint, pool.key(), checkpoint.timestamp);
+    }
+    
+    emit!(TvlCheckpointEvent {
+        pool: pool.key(),
+        timestamp: checkpoint.timestamp,
+        tvl: checkpoint.tvl() as u64,
+        lp_supply: checkpoint.lp_supply,
+        price_x64: checkpoint.price_x64,
+    });
+    
+    Ok(())
+}
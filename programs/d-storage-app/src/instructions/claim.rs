@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::ExchangeError;
+use crate::events::RewardClaimedEvent;
+use crate::merkle::{reward_leaf, verify_proof};
+use crate::state::{RewardClaim, RewardEpoch};
+
+#[derive(Accounts)]
+pub struct Claim<'info> {
+    #[account(
+        mut,
+        seeds = [b"reward_epoch", reward_epoch.pool.as_ref(), reward_epoch.epoch.to_le_bytes().as_ref()],
+        bump = reward_epoch.bump
+    )]
+    pub reward_epoch: Account<'info, RewardEpoch>,
+    #[account(
+        mut,
+        seeds = [b"reward_vault", reward_epoch.key().as_ref()],
+        bump
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+    /// Created once per claimant, so each leaf pays out at most once
+    #[account(
+        init,
+        payer = claimant,
+        space = RewardClaim::LEN,
+        seeds = [b"reward_claim", reward_epoch.key().as_ref(), claimant.key().as_ref()],
+        bump
+    )]
+    pub reward_claim: Account<'info, RewardClaim>,
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+    #[account(mut, token::mint = reward_epoch.reward_mint)]
+    pub claimant_token_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Claim `amount` of an epoch's rewards with a proof that (claimant, amount)
+/// is a leaf of the epoch's Merkle root
+pub fn handler(ctx: Context<Claim>, amount: u64, proof: Vec<[u8; 32]>) -> Result<u64> {
+    let reward_epoch = &ctx.accounts.reward_epoch;
+    let claimant = ctx.accounts.claimant.key();
+    require!(reward_epoch.has_root(), ExchangeError::InvalidRewardProof);
+    require!(
+        verify_proof(&proof, &reward_epoch.merkle_root, reward_leaf(&claimant, amount)),
+        ExchangeError::InvalidRewardProof
+    );
+    
+    let seeds: &[&[u8]] = &[
+        b"reward_epoch",
+        reward_epoch.pool.as_ref(),
+        &reward_epoch.epoch.to_le_bytes(),
+        &[reward_epoch.bump],
+    ];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.reward_vault.to_account_info(),
+                to: ctx.accounts.claimant_token_account.to_account_info(),
+                authority: reward_epoch.to_account_info(),
+            },
+            &[seeds],
+        ),
+        amount,
+    )?;
+    
+    let reward_epoch = &mut ctx.accounts.reward_epoch;
+    reward_epoch.total_claimed += amount;
+    
+    let reward_claim = &mut ctx.accounts.reward_claim;
+    reward_claim.reward_epoch = reward_epoch.key();
+    reward_claim.claimant = claimant;
+    reward_claim.amount = amount;
+    reward_claim.claimed_at = Clock::get()?.unix_timestamp;
+    
+    emit!(RewardClaimedEvent {
+        pool: reward_epoch.pool,
+        epoch: reward_epoch.epoch,
+        claimant,
+        amount,
+    });
+    
+    Ok(amount)
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::ExchangeError;
+use crate::events::AuctionBidClaimedEvent;
+use crate::state::{AuctionBid, AuctionStatus, LaunchAuction};
+
+#[derive(Accounts)]
+pub struct ClaimAuctionBid<'info> {
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"launch_auction", auction.token_mint.as_ref()],
+        bump = auction.bump
+    )]
+    pub auction: Box<Account<'info, LaunchAuction>>,
+    
+    #[account(
+        mut,
+        close = bidder,
+        seeds = [b"auction_bid", auction.key().as_ref(), bidder.key().as_ref()],
+        bump
+    )]
+    pub bid: Account<'info, AuctionBid>,
+    
+    #[account(
+        mut,
+        seeds = [b"launch_auction_vault", auction.token_mint.as_ref()],
+        bump
+    )]
+    pub auction_vault: Box<Account<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        token::mint = auction.token_mint,
+        token::authority = bidder
+    )]
+    pub bidder_token_account: Box<Account<'info, TokenAccount>>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+/// Collect the tokens won by a bid and the SOL it did not spend
+pub fn handler(ctx: Context<ClaimAuctionBid>) -> Result<u64> {
+    let auction = &ctx.accounts.auction;
+    require!(auction.status != AuctionStatus::Open, ExchangeError::AuctionPhaseMismatch);
+    
+    let bid = &ctx.accounts.bid;
+    let (tokens, refund) = auction.allocation(bid.sol_amount, bid.tick);
+    if tokens > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.auction_vault.to_account_info(),
+                    to: ctx.accounts.bidder_token_account.to_account_info(),
+                    authority: auction.to_account_info(),
+                },
+                &[&[b"launch_auction", auction.token_mint.as_ref(), &[auction.bump]]],
+            ),
+            tokens,
+        )?;
+    }
+    if refund > 0 {
+        **auction.to_account_info().try_borrow_mut_lamports()? -= refund;
+        **ctx.accounts.bidder.try_borrow_mut_lamports()? += refund;
+    }
+    
+    emit!(AuctionBidClaimedEvent {
+        auction: auction.key(),
+        bidder: bid.bidder,
+        tick: bid.tick,
+        tokens,
+        refund,
+    });
+    
+    Ok(tokens)
+}
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::ExchangeError;
+use crate::events::RoyaltiesClaimedEvent;
+use crate::quote::QuoteVault;
+use crate::state::{LiquidityPool, TokenRegistry};
+
+#[derive(Accounts)]
+pub struct ClaimRoyalties<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        has_one = token_vault @ ExchangeError::InvalidVault,
+        has_one = sol_vault @ ExchangeError::InvalidVault
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(
+        seeds = [b"token_registry", pool.token_mint.as_ref()],
+        bump,
+        constraint = token_registry.creator == creator.key() @ ExchangeError::Unauthorized
+    )]
+    pub token_registry: Account<'info, TokenRegistry>,
+    /// CHECK: Pool authority PDA
+    #[account(
+        seeds = [b"pool_authority", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"token_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub token_vault: Account<'info, TokenAccount>,
+    /// CHECK: SOL vault, or the quote token vault of a token-quoted pool
+    #[account(
+        mut,
+        seeds = [b"sol_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: AccountInfo<'info>,
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(mut, token::mint = pool.token_mint)]
+    pub creator_token_account: Account<'info, TokenAccount>,
+    /// Creator's quote token account, required for token-quoted pools
+    #[account(mut, token::mint = pool.quote_mint)]
+    pub creator_quote_account: Option<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Pay the creator every royalty accrued in both vaults
+pub fn handler(ctx: Context<ClaimRoyalties>) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let pool = &accounts.pool;
+    let token_amount = pool.royalty_owed_token;
+    let quote_amount = pool.royalty_owed_quote;
+    let token_mint = pool.token_mint;
+    
+    if token_amount > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                accounts.token_program.to_account_info(),
+                Transfer {
+                    from: accounts.token_vault.to_account_info(),
+                    to: accounts.creator_token_account.to_account_info(),
+                    authority: accounts.pool_authority.to_account_info(),
+                },
+                &[&[b"pool_authority", token_mint.as_ref(), &[ctx.bumps.pool_authority]]],
+            ),
+            token_amount,
+        )?;
+    }
+    if quote_amount > 0 {
+        let recipient = if pool.is_native_quote() {
+            accounts.creator.to_account_info()
+        } else {
+            accounts
+                .creator_quote_account
+                .as_ref()
+                .ok_or(ExchangeError::InvalidQuoteAccount)?
+                .to_account_info()
+        };
+        QuoteVault {
+            pool,
+            vault: &accounts.sol_vault,
+            token_program: accounts.token_program.as_ref(),
+            system_program: accounts.system_program.as_ref(),
+        }
+        .withdraw(
+            ctx.bumps.sol_vault,
+            &accounts.pool_authority,
+            ctx.bumps.pool_authority,
+            &recipient,
+            quote_amount,
+        )?;
+    }
+    
+    let pool = &mut ctx.accounts.pool;
+    pool.royalty_owed_token = 0;
+    pool.royalty_owed_quote = 0;
+    
+    emit!(RoyaltiesClaimedEvent {
+        pool: pool.key(),
+        creator: ctx.accounts.creator.key(),
+        token_amount,
+        quote_amount,
+    });
+    
+    assert_pool_invariants!(
+        &ctx.accounts.pool,
+        Some(ctx.accounts.token_vault.as_ref()),
+        Some(&ctx.accounts.sol_vault),
+        None
+    );
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::ExchangeError;
+use crate::events::LpVestingClaimEvent;
+use crate::state::{LiquidityPool, LpVesting};
+
+#[derive(Accounts)]
+pub struct ClaimVestedLp<'info> {
+    #[account(
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(
+        mut,
+        seeds = [b"lp_vesting", pool.key().as_ref()],
+        bump,
+        constraint = lp_vesting.beneficiary == beneficiary.key() @ ExchangeError::Unauthorized
+    )]
+    pub lp_vesting: Account<'info, LpVesting>,
+    #[account(
+        mut,
+        seeds = [b"lp_vesting_vault", pool.key().as_ref()],
+        bump
+    )]
+    pub lp_vesting_vault: Account<'info, TokenAccount>,
+    pub beneficiary: Signer<'info>,
+    #[account(
+        mut,
+        token::mint = pool.lp_mint,
+        token::authority = beneficiary
+    )]
+    pub beneficiary_lp_account: Account<'info, TokenAccount>,
+    /// CHECK: Pool authority PDA, owns the vesting escrow
+    #[account(
+        seeds = [b"pool_authority", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<ClaimVestedLp>) -> Result<u64> {
+    let lp_vesting = &mut ctx.accounts.lp_vesting;
+    let amount = lp_vesting.claimable(Clock::get()?.unix_timestamp);
+    require!(amount > 0, ExchangeError::NothingVested);
+    lp_vesting.claimed += amount;
+    
+    let token_mint = ctx.accounts.pool.token_mint;
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.lp_vesting_vault.to_account_info(),
+                to: ctx.accounts.beneficiary_lp_account.to_account_info(),
+                authority: ctx.accounts.pool_authority.to_account_info(),
+            },
+            &[&[b"pool_authority", token_mint.as_ref(), &[ctx.bumps.pool_authority]]],
+        ),
+        amount,
+    )?;
+    
+    emit!(LpVestingClaimEvent {
+        pool: ctx.accounts.pool.key(),
+        beneficiary: lp_vesting.beneficiary,
+        amount,
+        claimed: lp_vesting.claimed,
+        total: lp_vesting.total,
+    });
+    
+    Ok(amount)
+}
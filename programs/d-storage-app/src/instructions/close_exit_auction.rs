@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer};
+
+use crate::events::ExitAuctionEvent;
+use crate::state::ExitAuction;
+
+#[derive(Accounts)]
+pub struct CloseExitAuction<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    
+    #[account(
+        mut,
+        close = seller,
+        has_one = seller,
+        seeds = [b"exit_auction", exit_auction.pool.as_ref(), seller.key().as_ref()],
+        bump = exit_auction.bump
+    )]
+    pub exit_auction: Box<Account<'info, ExitAuction>>,
+    
+    #[account(
+        mut,
+        seeds = [b"exit_auction_vault", exit_auction.key().as_ref()],
+        bump
+    )]
+    pub exit_auction_vault: Box<Account<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        token::mint = exit_auction_vault.mint,
+        token::authority = seller
+    )]
+    pub seller_lp_account: Box<Account<'info, TokenAccount>>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+/// End the auction, returning the unsold LP tokens to the seller
+pub fn handler(ctx: Context<CloseExitAuction>) -> Result<()> {
+    let exit_auction = &ctx.accounts.exit_auction;
+    let signer_seeds: &[&[u8]] = &[
+        b"exit_auction",
+        exit_auction.pool.as_ref(),
+        exit_auction.seller.as_ref(),
+        &[exit_auction.bump],
+    ];
+    
+    let unsold = ctx.accounts.exit_auction_vault.amount;
+    if unsold > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.exit_auction_vault.to_account_info(),
+                    to: ctx.accounts.seller_lp_account.to_account_info(),
+                    authority: exit_auction.to_account_info(),
+                },
+                &[signer_seeds],
+            ),
+            unsold,
+        )?;
+    }
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.exit_auction_vault.to_account_info(),
+            destination: ctx.accounts.seller.to_account_info(),
+            authority: exit_auction.to_account_info(),
+        },
+        &[signer_seeds],
+    ))?;
+    
+    emit!(ExitAuctionEvent {
+        pool: exit_auction.pool,
+        seller: exit_auction.seller,
+        buyer: None,
+        lp_amount: unsold,
+        price_x64: 0,
+        lamports: exit_auction.proceeds,
+        remaining: 0,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::state::InternalBalance;
+
+#[derive(Accounts)]
+pub struct CloseInternalBalance<'info> {
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [b"internal_balance", user.key().as_ref(), internal_balance.mint.as_ref()],
+        bump
+    )]
+    pub internal_balance: Account<'info, InternalBalance>,
+    pub user: Signer<'info>,
+    /// CHECK: Whoever paid the rent, refunded on close
+    #[account(mut, address = internal_balance.rent_refund_to() @ ExchangeError::InvalidRentPayer)]
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CloseInternalBalance>) -> Result<()> {
+    require!(
+        ctx.accounts.internal_balance.amount == 0,
+        ExchangeError::InternalBalanceNotEmpty
+    );
+    Ok(())
+}
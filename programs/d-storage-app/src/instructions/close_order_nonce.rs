@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::state::OrderNonce;
+
+#[derive(Accounts)]
+pub struct CloseOrderNonce<'info> {
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [b"order_nonce", order_nonce.owner.as_ref(), order_nonce.nonce.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub order_nonce: Account<'info, OrderNonce>,
+    /// CHECK: Filler that paid the rent, refunded on close
+    #[account(mut, address = order_nonce.rent_payer @ ExchangeError::InvalidRentPayer)]
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+/// Close the nonce of an expired order. Anyone may call this: settlement
+/// rejects the order after its expiry, so it cannot be replayed.
+pub fn handler(ctx: Context<CloseOrderNonce>) -> Result<()> {
+    require!(
+        ctx.accounts.order_nonce.is_expired(Clock::get()?.unix_timestamp),
+        ExchangeError::OrderNotExpired
+    );
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::events::ReferralCodeEvent;
+use crate::state::ReferralCode;
+
+#[derive(Accounts)]
+pub struct CloseReferralCode<'info> {
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"referral", referral_code.code.as_bytes()],
+        bump,
+        constraint = referral_code.owner == owner.key() @ ExchangeError::Unauthorized
+    )]
+    pub referral_code: Account<'info, ReferralCode>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CloseReferralCode>) -> Result<()> {
+    let referral_code = &ctx.accounts.referral_code;
+    
+    emit!(ReferralCodeEvent {
+        code: referral_code.code.clone(),
+        recipient: referral_code.recipient,
+        owner: referral_code.owner,
+        closed: true,
+    });
+    
+    Ok(())
+}
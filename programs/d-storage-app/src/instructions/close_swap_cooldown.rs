@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::state::{LiquidityPool, SwapCooldown};
+
+#[derive(Accounts)]
+pub struct CloseSwapCooldown<'info> {
+    #[account(
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [b"swap_cooldown", pool.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub swap_cooldown: Account<'info, SwapCooldown>,
+    pub user: Signer<'info>,
+    /// CHECK: Whoever paid the rent, refunded on close
+    #[account(mut, address = swap_cooldown.rent_refund_to() @ ExchangeError::InvalidRentPayer)]
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CloseSwapCooldown>) -> Result<()> {
+    require!(
+        !ctx.accounts.pool.in_launch_window(Clock::get()?.unix_timestamp),
+        ExchangeError::LaunchWindowActive
+    );
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::events::SwapStreamEvent;
+use crate::state::SwapStream;
+
+#[derive(Accounts)]
+pub struct CloseSwapStream<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        close = rent_payer,
+        has_one = owner,
+        seeds = [b"swap_stream", swap_stream.pool.as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub swap_stream: Account<'info, SwapStream>,
+    /// CHECK: Whoever paid the rent, refunded on close
+    #[account(mut, address = swap_stream.rent_refund_to() @ ExchangeError::InvalidRentPayer)]
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+/// Stop the stream. The unconverted input never left the internal balance.
+pub fn handler(ctx: Context<CloseSwapStream>) -> Result<()> {
+    let swap_stream = &ctx.accounts.swap_stream;
+    
+    emit!(SwapStreamEvent {
+        pool: swap_stream.pool,
+        owner: swap_stream.owner,
+        direction: swap_stream.direction,
+        amount_in: swap_stream.total_in,
+        amount_out: swap_stream.total_out,
+        remaining: 0,
+    });
+    
+    Ok(())
+}
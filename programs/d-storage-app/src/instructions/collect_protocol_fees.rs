@@ -0,0 +1,184 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::ExchangeError;
+use crate::events::{ProtocolFeeShareEvent, ProtocolFeesCollectedEvent};
+use crate::quote::QuoteVault;
+use crate::state::{FeeSplitter, LiquidityPool, ProtocolConfig};
+
+#[derive(Accounts)]
+pub struct CollectProtocolFees<'info> {
+    #[account(
+        seeds = [b"protocol_config"],
+        bump,
+        constraint = protocol_config.admin == admin.key() @ ExchangeError::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        has_one = token_vault @ ExchangeError::InvalidVault,
+        has_one = sol_vault @ ExchangeError::InvalidVault
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    /// CHECK: Pool authority PDA
+    #[account(
+        seeds = [b"pool_authority", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"token_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub token_vault: Account<'info, TokenAccount>,
+    /// CHECK: SOL vault, or the quote token vault of a token-quoted pool
+    #[account(
+        mut,
+        seeds = [b"sol_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: AccountInfo<'info>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(mut, token::mint = pool.token_mint)]
+    pub admin_token_account: Account<'info, TokenAccount>,
+    /// Admin's quote token account, required for token-quoted pools
+    #[account(mut, token::mint = pool.quote_mint)]
+    pub admin_quote_account: Option<Account<'info, TokenAccount>>,
+    /// Splits the fees between its recipients instead of paying the admin
+    #[account(seeds = [b"fee_splitter"], bump)]
+    pub fee_splitter: Option<Account<'info, FeeSplitter>>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CollectProtocolFees<'info> {
+    fn pay_token(&self, pool_authority_bump: u8, to: &AccountInfo<'info>, amount: u64) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        let token_mint = self.pool.token_mint;
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.token_vault.to_account_info(),
+                    to: to.clone(),
+                    authority: self.pool_authority.to_account_info(),
+                },
+                &[&[b"pool_authority", token_mint.as_ref(), &[pool_authority_bump]]],
+            ),
+            amount,
+        )
+    }
+    
+    fn pay_quote(&self, bumps: &CollectProtocolFeesBumps, to: &AccountInfo<'info>, amount: u64) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        QuoteVault {
+            pool: &self.pool,
+            vault: &self.sol_vault,
+            token_program: self.token_program.as_ref(),
+            system_program: self.system_program.as_ref(),
+        }
+        .withdraw(bumps.sol_vault, &self.pool_authority, bumps.pool_authority, to, amount)
+    }
+}
+
+/// Owner of `account` if it is a token account of `mint`
+fn token_account_owner(account: &AccountInfo, mint: Pubkey) -> Result<Pubkey> {
+    require_keys_eq!(*account.owner, token::ID, ExchangeError::InvalidFeeRecipient);
+    let token_account = TokenAccount::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    require_keys_eq!(token_account.mint, mint, ExchangeError::InvalidFeeRecipient);
+    Ok(token_account.owner)
+}
+
+/// Pay every protocol fee accrued in both vaults to the fee splitter's
+/// recipients, or to the protocol admin when no splitter is passed or it is
+/// empty. With a splitter the remaining accounts are, per recipient in
+/// splitter order, its token account and its quote destination (the wallet
+/// itself for SOL-quoted pools).
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, CollectProtocolFees<'info>>) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let pool = &accounts.pool;
+    let token_amount = pool.protocol_fees_token;
+    let quote_amount = pool.protocol_fees_quote;
+    
+    match accounts.fee_splitter.as_ref().filter(|splitter| !splitter.shares.is_empty()) {
+        Some(splitter) => {
+            require!(
+                ctx.remaining_accounts.len() == 2 * splitter.shares.len(),
+                ExchangeError::InvalidFeeRecipient
+            );
+            let token_cuts = splitter.split(token_amount);
+            let quote_cuts = splitter.split(quote_amount);
+            for (i, share) in splitter.shares.iter().enumerate() {
+                let token_account = &ctx.remaining_accounts[2 * i];
+                let quote_account = &ctx.remaining_accounts[2 * i + 1];
+                require_keys_eq!(
+                    token_account_owner(token_account, pool.token_mint)?,
+                    share.recipient,
+                    ExchangeError::InvalidFeeRecipient
+                );
+                let quote_recipient = if pool.is_native_quote() {
+                    quote_account.key()
+                } else {
+                    token_account_owner(quote_account, pool.quote_mint)?
+                };
+                require_keys_eq!(quote_recipient, share.recipient, ExchangeError::InvalidFeeRecipient);
+                
+                accounts.pay_token(ctx.bumps.pool_authority, token_account, token_cuts[i])?;
+                accounts.pay_quote(&ctx.bumps, quote_account, quote_cuts[i])?;
+                
+                emit!(ProtocolFeeShareEvent {
+                    pool: pool.key(),
+                    recipient: share.recipient,
+                    token_amount: token_cuts[i],
+                    quote_amount: quote_cuts[i],
+                });
+            }
+        }
+        None => {
+            accounts.pay_token(
+                ctx.bumps.pool_authority,
+                &accounts.admin_token_account.to_account_info(),
+                token_amount,
+            )?;
+            if quote_amount > 0 {
+                let recipient = if pool.is_native_quote() {
+                    accounts.admin.to_account_info()
+                } else {
+                    accounts
+                        .admin_quote_account
+                        .as_ref()
+                        .ok_or(ExchangeError::InvalidQuoteAccount)?
+                        .to_account_info()
+                };
+                accounts.pay_quote(&ctx.bumps, &recipient, quote_amount)?;
+            }
+        }
+    }
+    
+    let pool = &mut ctx.accounts.pool;
+    pool.protocol_fees_token = 0;
+    pool.protocol_fees_quote = 0;
+    
+    emit!(ProtocolFeesCollectedEvent {
+        pool: pool.key(),
+        admin: ctx.accounts.admin.key(),
+        token_amount,
+        quote_amount,
+    });
+    
+    assert_pool_invariants!(
+        &ctx.accounts.pool,
+        Some(ctx.accounts.token_vault.as_ref()),
+        Some(&ctx.accounts.sol_vault),
+        None
+    );
+    Ok(())
+}
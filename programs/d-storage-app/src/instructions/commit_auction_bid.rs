@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+use crate::errors::ExchangeError;
+use crate::events::AuctionBidEvent;
+use crate::state::{AuctionBid, AuctionStatus, LaunchAuction, ProtocolConfig};
+
+#[derive(Accounts)]
+pub struct CommitAuctionBid<'info> {
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"launch_auction", auction.token_mint.as_ref()],
+        bump = auction.bump
+    )]
+    pub auction: Box<Account<'info, LaunchAuction>>,
+    #[account(
+        init,
+        payer = bidder,
+        space = AuctionBid::LEN,
+        seeds = [b"auction_bid", auction.key().as_ref(), bidder.key().as_ref()],
+        bump
+    )]
+    pub bid: Account<'info, AuctionBid>,
+    pub system_program: Program<'info, System>,
+    /// Protocol-wide emergency stop status
+    #[account(seeds = [b"protocol_config"], bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+}
+
+/// Place a sealed bid: `sol_amount` is escrowed in the auction while the
+/// price tick stays hidden behind `commitment` until the reveal window
+pub fn handler(ctx: Context<CommitAuctionBid>, commitment: [u8; 32], sol_amount: u64) -> Result<()> {
+    require!(
+        ctx.accounts.protocol_config.deposits_enabled(),
+        ExchangeError::ProtocolHalted
+    );
+    let auction = &mut ctx.accounts.auction;
+    require!(
+        auction.status == AuctionStatus::Open && Clock::get()?.unix_timestamp < auction.bid_end,
+        ExchangeError::AuctionPhaseMismatch
+    );
+    require!(sol_amount > 0, ExchangeError::InvalidAmount);
+    
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.bidder.to_account_info(),
+                to: auction.to_account_info(),
+            },
+        ),
+        sol_amount,
+    )?;
+    auction.committed_sol = auction
+        .committed_sol
+        .checked_add(sol_amount)
+        .ok_or(ExchangeError::MathOverflow)?;
+    
+    let bid = &mut ctx.accounts.bid;
+    bid.auction = auction.key();
+    bid.bidder = ctx.accounts.bidder.key();
+    bid.commitment = commitment;
+    bid.sol_amount = sol_amount;
+    bid.tick = None;
+    
+    emit!(AuctionBidEvent {
+        auction: bid.auction,
+        bidder: bid.bidder,
+        sol_amount,
+        tick: None,
+    });
+    
+    Ok(())
+}
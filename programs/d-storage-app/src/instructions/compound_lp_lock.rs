@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::ExchangeError;
+use crate::events::LpLockEvent;
+use crate::state::{LiquidityPool, LpLock};
+
+#[derive(Accounts)]
+pub struct CompoundLpLock<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(
+        mut,
+        seeds = [b"lp_lock", pool.key().as_ref(), lp_lock.owner.as_ref()],
+        bump,
+        constraint = lp_lock.is_compounder(&compounder.key()) @ ExchangeError::Unauthorized
+    )]
+    pub lp_lock: Account<'info, LpLock>,
+    pub compounder: Signer<'info>,
+    /// CHECK: Pool authority PDA, owns the lock and reward vaults
+    #[account(
+        seeds = [b"pool_authority", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"lp_lock_vault", pool.key().as_ref()],
+        bump
+    )]
+    pub lp_lock_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"lp_reward_vault", pool.key().as_ref()],
+        bump
+    )]
+    pub lp_reward_vault: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Harvest a lock's rewards straight into the lock vault on the owner's
+/// behalf. Tokens only move between the pool's own vaults.
+pub fn handler(ctx: Context<CompoundLpLock>) -> Result<u64> {
+    let reward = ctx.accounts.lp_lock.compound(ctx.accounts.pool.lp_lock_reward_per_share);
+    ctx.accounts.pool.lp_locked_total += reward;
+    
+    if reward > 0 {
+        let token_mint = ctx.accounts.pool.token_mint;
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.lp_reward_vault.to_account_info(),
+                    to: ctx.accounts.lp_lock_vault.to_account_info(),
+                    authority: ctx.accounts.pool_authority.to_account_info(),
+                },
+                &[&[b"pool_authority", token_mint.as_ref(), &[ctx.bumps.pool_authority]]],
+            ),
+            reward,
+        )?;
+    }
+    
+    emit!(LpLockEvent {
+        pool: ctx.accounts.pool.key(),
+        owner: ctx.accounts.lp_lock.owner,
+        amount: reward,
+        locked: true,
+        reward_claimed: reward,
+    });
+    
+    Ok(reward)
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::ExchangeError;
+use crate::events::EmergencyRecoveryConfiguredEvent;
+use crate::state::{LiquidityPool, ProtocolConfig};
+
+#[derive(Accounts)]
+pub struct ConfigureEmergencyRecovery<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.is_admin(&admin.key(), admin_nft_account.as_deref()) @ ExchangeError::Unauthorized
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    pub admin: Signer<'info>,
+    /// Admin NFT held by the signer, required once the pool is bound to one
+    pub admin_nft_account: Option<Account<'info, TokenAccount>>,
+    /// The current guardian, or the protocol admin, approving a change of
+    /// the guardian or the recovery address
+    pub co_signer: Option<Signer<'info>>,
+    /// Identifies the protocol admin, required when it is the co-signer
+    #[account(seeds = [b"protocol_config"], bump)]
+    pub protocol_config: Option<Box<Account<'info, ProtocolConfig>>>,
+}
+
+pub fn handler(
+    ctx: Context<ConfigureEmergencyRecovery>,
+    recovery_address: Pubkey,
+    guardian: Pubkey,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    require!(pool.drain_requested_at == 0, ExchangeError::EmergencyDrainPending);
+    require!(Some(guardian) != pool.admin, ExchangeError::InvalidGuardian);
+    
+    // Otherwise the admin could name a second key of its own as guardian.
+    // Without a guardian yet only the protocol admin can approve one.
+    if recovery_address != pool.recovery_address || guardian != pool.guardian {
+        let co_signer = ctx.accounts.co_signer.as_ref().map(|signer| signer.key());
+        let protocol_admin = ctx.accounts.protocol_config.as_ref().map(|config| config.admin);
+        let guardian_approved = pool.guardian != Pubkey::default() && co_signer == Some(pool.guardian);
+        require!(
+            guardian_approved || (co_signer.is_some() && co_signer == protocol_admin),
+            ExchangeError::RecoveryChangeNotApproved
+        );
+    }
+    
+    pool.recovery_address = recovery_address;
+    pool.guardian = guardian;
+    
+    emit!(EmergencyRecoveryConfiguredEvent {
+        pool: pool.key(),
+        recovery_address,
+        guardian,
+    });
+    
+    Ok(())
+}
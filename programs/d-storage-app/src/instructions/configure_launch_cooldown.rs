@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::ExchangeError;
+use crate::events::LaunchCooldownUpdateEvent;
+use crate::state::LiquidityPool;
+
+#[derive(Accounts)]
+pub struct ConfigureLaunchCooldown<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.is_admin(&admin.key(), admin_nft_account.as_deref()) @ ExchangeError::Unauthorized
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    pub admin: Signer<'info>,
+    /// Admin NFT held by the signer, required once the pool is bound to one
+    pub admin_nft_account: Option<Account<'info, TokenAccount>>,
+}
+
+pub fn handler(
+    ctx: Context<ConfigureLaunchCooldown>,
+    cooldown_slots: u64,
+    launch_window_end: i64,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    pool.launch_cooldown_slots = cooldown_slots;
+    pool.launch_window_end = launch_window_end;
+    
+    emit!(LaunchCooldownUpdateEvent {
+        pool: pool.key(),
+        cooldown_slots,
+        launch_window_end,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::constants::MAX_LP_PENALTY_BPS;
+use crate::errors::ExchangeError;
+use crate::events::LpLockConfigEvent;
+use crate::state::LiquidityPool;
+
+#[derive(Accounts)]
+pub struct ConfigureLpLock<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.is_admin(&admin.key(), admin_nft_account.as_deref()) @ ExchangeError::Unauthorized
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// CHECK: Pool authority PDA, owns the lock and reward vaults
+    #[account(
+        seeds = [b"pool_authority", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+    #[account(address = pool.lp_mint @ ExchangeError::InvalidVault)]
+    pub lp_mint: Account<'info, Mint>,
+    /// Holds locked LP tokens
+    #[account(
+        init_if_needed,
+        payer = admin,
+        token::mint = lp_mint,
+        token::authority = pool_authority,
+        seeds = [b"lp_lock_vault", pool.key().as_ref()],
+        bump
+    )]
+    pub lp_lock_vault: Account<'info, TokenAccount>,
+    /// Holds penalty LP tokens until lockers claim them
+    #[account(
+        init_if_needed,
+        payer = admin,
+        token::mint = lp_mint,
+        token::authority = pool_authority,
+        seeds = [b"lp_reward_vault", pool.key().as_ref()],
+        bump
+    )]
+    pub lp_reward_vault: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    /// Admin NFT held by the signer, required once the pool is bound to one
+    pub admin_nft_account: Option<Account<'info, TokenAccount>>,
+}
+
+pub fn handler(
+    ctx: Context<ConfigureLpLock>,
+    penalty_bps: u16,
+    penalty_until: i64,
+) -> Result<()> {
+    require!(penalty_bps <= MAX_LP_PENALTY_BPS, ExchangeError::InvalidLpPenalty);
+    
+    let pool = &mut ctx.accounts.pool;
+    pool.lp_penalty_bps = penalty_bps;
+    pool.lp_penalty_until = penalty_until;
+    
+    emit!(LpLockConfigEvent {
+        pool: pool.key(),
+        penalty_bps,
+        penalty_until,
+    });
+    
+    Ok(())
+}
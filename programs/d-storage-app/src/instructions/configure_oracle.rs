@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+
+use crate::errors::ExchangeError;
+use crate::events::OracleConfiguredEvent;
+use crate::oracle::read_oracle_price;
+use crate::state::{LiquidityPool, PoolFlags};
+
+#[derive(Accounts)]
+pub struct ConfigureOracle<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.is_admin(&admin.key(), admin_nft_account.as_deref()) @ ExchangeError::Unauthorized
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(address = pool.token_mint)]
+    pub token_mint: Account<'info, Mint>,
+    /// CHECK: Parsed and validated as a Pyth price update for the feed id in the handler
+    pub oracle: UncheckedAccount<'info>,
+    pub admin: Signer<'info>,
+    /// Admin NFT held by the signer, required once the pool is bound to one
+    pub admin_nft_account: Option<Account<'info, TokenAccount>>,
+}
+
+/// Oracle guard settings, shared with `update_pool_params`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct OracleParams {
+    /// Pyth feed id the oracle account must publish
+    pub feed_id: [u8; 32],
+    pub max_deviation_bps: u16,
+    pub max_confidence_bps: u16,
+    pub max_staleness_secs: i64,
+    pub fallback_to_curve: bool,
+}
+
+impl OracleParams {
+    /// Validate and write the settings to the pool, returning the oracle
+    /// it is now bound to (default when the deviation guard is off)
+    pub fn apply(
+        &self,
+        pool: &mut LiquidityPool,
+        oracle: &AccountInfo,
+        token_decimals: u8,
+    ) -> Result<Pubkey> {
+        require!(self.max_deviation_bps <= 10000, ExchangeError::InvalidOracleConfig);
+        require!(self.max_confidence_bps <= 10000, ExchangeError::InvalidOracleConfig);
+        require!(self.max_staleness_secs > 0, ExchangeError::InvalidOracleConfig);
+        
+        // Make sure the feed can actually be read before relying on it
+        let oracle = if self.max_deviation_bps == 0 {
+            Pubkey::default()
+        } else {
+            read_oracle_price(oracle, &self.feed_id)?;
+            oracle.key()
+        };
+        
+        pool.oracle = oracle;
+        pool.oracle_feed_id = self.feed_id;
+        pool.token_decimals = token_decimals;
+        pool.oracle_max_deviation_bps = self.max_deviation_bps;
+        pool.oracle_max_confidence_bps = self.max_confidence_bps;
+        pool.oracle_max_staleness = self.max_staleness_secs;
+        pool.oracle_fallback_to_curve = self.fallback_to_curve;
+        pool.set_features(PoolFlags::ORACLE_GUARD, oracle != Pubkey::default());
+        Ok(oracle)
+    }
+}
+
+pub fn handler(
+    ctx: Context<ConfigureOracle>,
+    feed_id: [u8; 32],
+    max_deviation_bps: u16,
+    max_confidence_bps: u16,
+    max_staleness_secs: i64,
+    fallback_to_curve: bool,
+) -> Result<()> {
+    let params = OracleParams {
+        feed_id,
+        max_deviation_bps,
+        max_confidence_bps,
+        max_staleness_secs,
+        fallback_to_curve,
+    };
+    let decimals = ctx.accounts.token_mint.decimals;
+    let pool = &mut ctx.accounts.pool;
+    let oracle = params.apply(pool, &ctx.accounts.oracle, decimals)?;
+    
+    emit!(OracleConfiguredEvent {
+        pool: pool.key(),
+        oracle,
+        feed_id,
+        max_deviation_bps,
+        max_confidence_bps,
+        max_staleness_secs,
+        fallback_to_curve,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::ExchangeError;
+use crate::events::RebalanceIncentiveConfiguredEvent;
+use crate::state::LiquidityPool;
+
+#[derive(Accounts)]
+pub struct ConfigureRebalanceIncentive<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.is_admin(&admin.key(), admin_nft_account.as_deref()) @ ExchangeError::Unauthorized
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    pub admin: Signer<'info>,
+    /// Admin NFT held by the signer, required once the pool is bound to one
+    pub admin_nft_account: Option<Account<'info, TokenAccount>>,
+}
+
+/// Discount the LP fee by `discount_bps` on swaps that move the pool price
+/// back toward the oracle once it deviates by `min_deviation_bps` or more.
+/// A zero discount turns the incentive off.
+pub fn handler(
+    ctx: Context<ConfigureRebalanceIncentive>,
+    discount_bps: u16,
+    min_deviation_bps: u16,
+) -> Result<()> {
+    require!(discount_bps <= 1000, ExchangeError::InvalidFeeRate);
+    require!(min_deviation_bps <= 10000, ExchangeError::InvalidOracleConfig);
+    
+    let pool = &mut ctx.accounts.pool;
+    require!(
+        discount_bps == 0 || pool.oracle != Pubkey::default(),
+        ExchangeError::InvalidOracle
+    );
+    pool.rebalance_discount_bps = discount_bps;
+    pool.rebalance_min_deviation_bps = min_deviation_bps;
+    
+    emit!(RebalanceIncentiveConfiguredEvent {
+        pool: pool.key(),
+        discount_bps,
+        min_deviation_bps,
+        updated_by: ctx.accounts.admin.key(),
+    });
+    
+    Ok(())
+}
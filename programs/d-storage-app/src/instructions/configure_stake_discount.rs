@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::errors::ExchangeError;
+use crate::events::StakeDiscountConfigUpdateEvent;
+use crate::state::{ProtocolConfig, StakeDiscountConfig, StakeTier};
+
+#[derive(Accounts)]
+pub struct ConfigureStakeDiscount<'info> {
+    #[account(
+        seeds = [b"protocol_config"],
+        bump,
+        constraint = protocol_config.admin == admin.key() @ ExchangeError::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = StakeDiscountConfig::LEN,
+        seeds = [b"stake_discount_config"],
+        bump
+    )]
+    pub stake_discount_config: Account<'info, StakeDiscountConfig>,
+    /// Protocol token, fixed once the stake vault exists
+    pub stake_mint: Account<'info, Mint>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        token::mint = stake_mint,
+        token::authority = stake_discount_config,
+        seeds = [b"stake_vault"],
+        bump
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Set the stake tiers and cooldown. Empty tiers turn the discounts off
+/// while letting stakers withdraw as usual.
+pub fn handler(
+    ctx: Context<ConfigureStakeDiscount>,
+    cooldown_secs: u32,
+    tiers: Vec<StakeTier>,
+) -> Result<()> {
+    StakeDiscountConfig::validate_tiers(&tiers)?;
+    
+    let config = &mut ctx.accounts.stake_discount_config;
+    config.stake_mint = ctx.accounts.stake_mint.key();
+    config.cooldown_secs = cooldown_secs;
+    config.tiers = tiers.clone();
+    
+    emit!(StakeDiscountConfigUpdateEvent {
+        stake_mint: config.stake_mint,
+        cooldown_secs,
+        tiers,
+        admin: ctx.accounts.admin.key(),
+    });
+    
+    Ok(())
+}
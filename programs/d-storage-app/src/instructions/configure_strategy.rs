@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::constants::MAX_STRATEGY_CAP_BPS;
+use crate::errors::ExchangeError;
+use crate::events::StrategyConfiguredEvent;
+use crate::state::LiquidityPool;
+use crate::strategy::lending_adapter;
+
+#[derive(Accounts)]
+pub struct ConfigureStrategy<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.is_admin(&admin.key(), admin_nft_account.as_deref()) @ ExchangeError::Unauthorized
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    /// CHECK: Pool authority PDA
+    #[account(
+        seeds = [b"pool_authority", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+    /// Collateral token account of the lending market, owned by the pool authority
+    #[account(token::authority = pool_authority)]
+    pub strategy_collateral_account: Account<'info, TokenAccount>,
+    pub admin: Signer<'info>,
+    /// Admin NFT held by the signer, required once the pool is bound to one
+    pub admin_nft_account: Option<Account<'info, TokenAccount>>,
+}
+
+pub fn handler(
+    ctx: Context<ConfigureStrategy>,
+    strategy_program: Pubkey,
+    cap_bps: u16,
+) -> Result<()> {
+    require!(cap_bps <= MAX_STRATEGY_CAP_BPS, ExchangeError::InvalidStrategyCap);
+    require!(
+        lending_adapter(&strategy_program).is_some(),
+        ExchangeError::UnsupportedStrategy
+    );
+    
+    let pool = &mut ctx.accounts.pool;
+    // Switching protocols or collateral accounts requires all funds to be withdrawn first
+    require!(
+        pool.strategy_collateral == 0
+            || (pool.strategy_program == strategy_program
+                && pool.strategy_collateral_account == ctx.accounts.strategy_collateral_account.key()),
+        ExchangeError::StrategyFundsDeployed
+    );
+    
+    pool.strategy_program = strategy_program;
+    pool.strategy_collateral_account = ctx.accounts.strategy_collateral_account.key();
+    pool.strategy_cap_bps = cap_bps;
+    
+    emit!(StrategyConfiguredEvent {
+        pool: pool.key(),
+        strategy_program,
+        cap_bps,
+    });
+    
+    Ok(())
+}
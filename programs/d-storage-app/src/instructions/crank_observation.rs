@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::constants::OBSERVATION_CRANK_BOUNTY;
+use crate::errors::ExchangeError;
+use crate::events::ObservationCrankedEvent;
+use crate::quote::QuoteVault;
+use crate::state::{CrankJobKind, CrankRegistry, LiquidityPool, Observation, PriceObservations};
+
+#[derive(Accounts)]
+pub struct CrankObservation<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        has_one = sol_vault @ ExchangeError::InvalidVault
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(
+        mut,
+        seeds = [b"observations", pool.key().as_ref()],
+        bump
+    )]
+    pub observations: Box<Account<'info, PriceObservations>>,
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+    /// CHECK: Pool authority PDA
+    #[account(
+        seeds = [b"pool_authority", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+    /// CHECK: SOL vault, or the quote token vault of a token-quoted pool
+    #[account(
+        mut,
+        seeds = [b"sol_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: AccountInfo<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    /// Cranker's quote token account, receives the bounty on token-quoted pools
+    #[account(mut, token::mint = pool.quote_mint)]
+    pub cranker_quote_account: Option<Account<'info, TokenAccount>>,
+    /// Crank registry, the job is rescheduled when passed
+    #[account(
+        mut,
+        seeds = [b"crank_registry"],
+        bump
+    )]
+    pub crank_registry: Option<Box<Account<'info, CrankRegistry>>>,
+}
+
+/// Record an observation from the current reserves during quiet periods.
+/// Allowed once per observation interval; the cranker is paid a small
+/// bounty out of the quote-side protocol fees, when there are any.
+pub fn handler(ctx: Context<CrankObservation>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let price_cumulative = ctx.accounts.pool.price_cumulative_at(now);
+    let recorded = ctx.accounts.observations.record(Observation {
+        timestamp: now,
+        price_cumulative,
+    });
+    require!(recorded, ExchangeError::ObservationTooRecent);
+    
+    // Token-quoted pools only pay crankers who pass a quote account
+    let accounts = &ctx.accounts;
+    let recipient = if accounts.pool.is_native_quote() {
+        Some(accounts.cranker.to_account_info())
+    } else {
+        accounts
+            .cranker_quote_account
+            .as_ref()
+            .map(|account| account.to_account_info())
+    };
+    let bounty = OBSERVATION_CRANK_BOUNTY.min(accounts.pool.protocol_fees_quote);
+    let bounty = match recipient {
+        Some(recipient) if bounty > 0 => {
+            QuoteVault {
+                pool: &accounts.pool,
+                vault: &accounts.sol_vault,
+                token_program: accounts.token_program.as_ref(),
+                system_program: accounts.system_program.as_ref(),
+            }
+            .withdraw(
+                ctx.bumps.sol_vault,
+                &accounts.pool_authority,
+                ctx.bumps.pool_authority,
+                &recipient,
+                bounty,
+            )?;
+            bounty
+        }
+        _ => 0,
+    };
+    
+    let pool = &mut ctx.accounts.pool;
+    pool.protocol_fees_quote -= bounty;
+    if let Some(crank_registry) = &mut ctx.accounts.crank_registry {
+        crank_registry.reschedule(CrankJobKind::PriceObservation, pool.key(), now);
+    }
+    
+    emit!(ObservationCrankedEvent {
+        pool: pool.key(),
+        cranker: ctx.accounts.cranker.key(),
+        timestamp: now,
+        bounty,
+    });
+    
+    Ok(())
+}
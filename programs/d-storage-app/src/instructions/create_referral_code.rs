@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::events::ReferralCodeEvent;
+use crate::state::ReferralCode;
+
+#[derive(Accounts)]
+#[instruction(code: String)]
+pub struct CreateReferralCode<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = ReferralCode::LEN,
+        seeds = [b"referral", code.as_bytes()],
+        bump
+    )]
+    pub referral_code: Account<'info, ReferralCode>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateReferralCode>,
+    code: String,
+    recipient: Pubkey,
+) -> Result<()> {
+    require!(ReferralCode::is_valid_code(&code), ExchangeError::InvalidReferralCode);
+    
+    let referral_code = &mut ctx.accounts.referral_code;
+    referral_code.code = code;
+    referral_code.recipient = recipient;
+    referral_code.owner = ctx.accounts.owner.key();
+    referral_code.created_at = Clock::get()?.unix_timestamp;
+    
+    emit!(ReferralCodeEvent {
+        code: referral_code.code.clone(),
+        recipient,
+        owner: referral_code.owner,
+        closed: false,
+    });
+    
+    Ok(())
+}
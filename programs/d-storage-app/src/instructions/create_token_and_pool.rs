@@ -0,0 +1,432 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, MintTo, SetAuthority, Token, TokenAccount};
+use anchor_spl::token::spl_token::instruction::AuthorityType;
+
+use crate::errors::ExchangeError;
+use crate::events::{LpBurnedEvent, TokenLaunchedEvent};
+use crate::state::{
+    CreatorQuota, CurveType, LiquidityPool, LpBurnRecord, LpVesting, PoolFlags, PoolIndexPage, PoolRegistry,
+    ProtocolConfig, TokenRegistry,
+};
+use super::add_liquidity::mint_lp_tokens;
+use super::initialize_pool::{create_sol_vault, emit_creation_sponsored};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct LaunchParams {
+    pub name: String,
+    pub symbol: String,
+    pub metadata_uri: String,
+    pub decimals: u8,
+    /// Tokens minted in total, the part not seeded into the pool goes to the creator
+    pub total_supply: u64,
+    pub initial_token_amount: u64,
+    pub initial_sol_amount: u64,
+    pub fee_rate: u16,
+    /// Separate fee rate on sells, `fee_rate` applies to both sides when unset
+    pub sell_fee_rate: Option<u16>,
+    pub curve_type: CurveType,
+    /// Remove the mint authority once the supply is minted
+    pub revoke_mint_authority: bool,
+    /// Where the initial LP goes
+    pub initial_lp: LaunchLp,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub enum LaunchLp {
+    /// Minted to the creator
+    Creator,
+    /// Minted into the pool's burn vault, which nothing withdraws from, and
+    /// recorded as burned by the creator for rug-check tools
+    Burn,
+    /// Vested linearly to the creator
+    Vest(LpVestingParams),
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct LpVestingParams {
+    /// Seconds after launch before anything vests
+    pub cliff_secs: i64,
+    /// Seconds after launch until everything is vested
+    pub duration_secs: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(params: LaunchParams)]
+pub struct CreateTokenAndPool<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = payer,
+        mint::decimals = params.decimals,
+        mint::authority = creator
+    )]
+    pub token_mint: Box<Account<'info, Mint>>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = TokenRegistry::LEN,
+        seeds = [b"token_registry", token_mint.key().as_ref()],
+        bump
+    )]
+    pub token_registry: Box<Account<'info, TokenRegistry>>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = LiquidityPool::LEN,
+        seeds = [b"pool", token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+    
+    /// CHECK: Pool authority PDA
+    #[account(
+        seeds = [b"pool_authority", token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+    
+    #[account(
+        init,
+        payer = payer,
+        token::mint = token_mint,
+        token::authority = pool_authority,
+        seeds = [b"token_vault", token_mint.key().as_ref()],
+        bump
+    )]
+    pub token_vault: Box<Account<'info, TokenAccount>>,
+    
+    /// CHECK: SOL vault - will be created as System Program owned account
+    #[account(
+        mut,
+        seeds = [b"sol_vault", token_mint.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: AccountInfo<'info>,
+    
+    /// Left behind by `reap_pool`, so a re-created pool reuses it
+    #[account(
+        init_if_needed,
+        payer = payer,
+        mint::decimals = 6,
+        mint::authority = pool_authority,
+        seeds = [b"lp_mint", token_mint.key().as_ref()],
+        bump
+    )]
+    pub lp_mint: Box<Account<'info, Mint>>,
+    
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = token_mint,
+        associated_token::authority = creator
+    )]
+    pub creator_token_account: Box<Account<'info, TokenAccount>>,
+    
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PoolRegistry::LEN,
+        seeds = [b"pool_registry"],
+        bump
+    )]
+    pub pool_registry: Box<Account<'info, PoolRegistry>>,
+    
+    /// Index page the new pool is appended to
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PoolIndexPage::LEN,
+        seeds = [b"pool_index", pool_registry.next_page().to_le_bytes().as_ref()],
+        bump
+    )]
+    pub pool_index_page: Box<Account<'info, PoolIndexPage>>,
+    
+    #[account(seeds = [b"protocol_config"], bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+    
+    /// Launches made by the creator, checked against their quota
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = CreatorQuota::LEN,
+        seeds = [b"creator_quota", creator.key().as_ref()],
+        bump
+    )]
+    pub creator_quota: Box<Account<'info, CreatorQuota>>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    
+    /// Vesting schedule, required when the initial LP vests
+    #[account(
+        init,
+        payer = payer,
+        space = LpVesting::LEN,
+        seeds = [b"lp_vesting", pool.key().as_ref()],
+        bump
+    )]
+    pub lp_vesting: Option<Box<Account<'info, LpVesting>>>,
+    
+    /// Escrow holding the vesting LP tokens
+    #[account(
+        init,
+        payer = payer,
+        token::mint = lp_mint,
+        token::authority = pool_authority,
+        seeds = [b"lp_vesting_vault", pool.key().as_ref()],
+        bump
+    )]
+    pub lp_vesting_vault: Option<Box<Account<'info, TokenAccount>>>,
+    
+    /// Receives the initial LP tokens, required when they go to the creator
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = lp_mint,
+        associated_token::authority = creator
+    )]
+    pub creator_lp_account: Option<Box<Account<'info, TokenAccount>>>,
+    
+    /// Holds burned initial LP tokens for good, required when they are burned
+    #[account(
+        init,
+        payer = payer,
+        token::mint = lp_mint,
+        token::authority = pool_authority,
+        seeds = [b"lp_burn_vault", pool.key().as_ref()],
+        bump
+    )]
+    pub lp_burn_vault: Option<Box<Account<'info, TokenAccount>>>,
+    
+    /// Creator's burn record, required when the initial LP is burned
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = LpBurnRecord::LEN,
+        seeds = [b"lp_burn", pool.key().as_ref(), creator.key().as_ref()],
+        bump
+    )]
+    pub lp_burn_record: Option<Box<Account<'info, LpBurnRecord>>>,
+    
+    /// Pays the rent of the created accounts: the creator, or a launchpad
+    /// sponsoring the launch
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Protocol admin co-signing a fee outside the protocol range
+    pub protocol_admin: Option<Signer<'info>>,
+}
+
+pub fn handler(ctx: Context<CreateTokenAndPool>, mut params: LaunchParams) -> Result<u64> {
+    let protocol_config = &ctx.accounts.protocol_config;
+    let protocol_admin = ctx.accounts.protocol_admin.as_ref().map(|admin| admin.key());
+    params.fee_rate = protocol_config.resolve_fee(params.fee_rate);
+    require!(params.fee_rate <= 1000, ExchangeError::InvalidFeeRate); // Max 10%
+    require!(protocol_config.deposits_enabled(), ExchangeError::ProtocolHalted);
+    require!(ctx.accounts.lp_mint.supply == 0, ExchangeError::LpMintInUse);
+    require!(
+        params.sell_fee_rate.is_none_or(|rate| rate <= 1000),
+        ExchangeError::InvalidFeeRate
+    );
+    require!(
+        protocol_config.fee_allowed(params.fee_rate, protocol_admin)
+            && params.sell_fee_rate.is_none_or(|rate| protocol_config.fee_allowed(rate, protocol_admin)),
+        ExchangeError::FeeOutsideProtocolRange
+    );
+    require!(
+        TokenRegistry::is_valid_metadata(&params.name, &params.symbol, &params.metadata_uri),
+        ExchangeError::InvalidTokenMetadata
+    );
+    require!(
+        params.initial_token_amount <= params.total_supply,
+        ExchangeError::InvalidLaunchSupply
+    );
+    
+    let creator_quota = &mut ctx.accounts.creator_quota;
+    creator_quota.creator = ctx.accounts.creator.key();
+    require!(
+        creator_quota.record_launch(ctx.accounts.protocol_config.max_launches_per_creator),
+        ExchangeError::CreatorQuotaExceeded
+    );
+    
+    // Create SOL vault as System Program owned account
+    let token_mint = ctx.accounts.token_mint.key();
+    create_sol_vault(
+        &ctx.accounts.system_program,
+        &ctx.accounts.payer,
+        &ctx.accounts.creator,
+        &ctx.accounts.sol_vault,
+        &[b"sol_vault", token_mint.as_ref(), &[ctx.bumps.sol_vault]],
+        params.initial_sol_amount,
+    )?;
+    
+    // Mint the supply: pool liquidity into the vault, the rest to the creator
+    for (to, amount) in [
+        (ctx.accounts.token_vault.to_account_info(), params.initial_token_amount),
+        (
+            ctx.accounts.creator_token_account.to_account_info(),
+            params.total_supply - params.initial_token_amount,
+        ),
+    ] {
+        if amount > 0 {
+            token::mint_to(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    MintTo {
+                        mint: ctx.accounts.token_mint.to_account_info(),
+                        to,
+                        authority: ctx.accounts.creator.to_account_info(),
+                    },
+                ),
+                amount,
+            )?;
+        }
+    }
+    
+    if params.revoke_mint_authority {
+        token::set_authority(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                SetAuthority {
+                    current_authority: ctx.accounts.creator.to_account_info(),
+                    account_or_mint: ctx.accounts.token_mint.to_account_info(),
+                },
+            ),
+            AuthorityType::MintTokens,
+            None,
+        )?;
+    }
+    
+    let now = Clock::get()?.unix_timestamp;
+    let registry = &mut ctx.accounts.token_registry;
+    registry.token_mint = token_mint;
+    registry.name = params.name;
+    registry.symbol = params.symbol;
+    registry.decimals = params.decimals;
+    registry.metadata_uri = params.metadata_uri;
+    registry.creator = ctx.accounts.creator.key();
+    registry.total_supply = params.total_supply;
+    registry.is_active = true;
+    registry.created_at = now;
+    
+    let pool = &mut ctx.accounts.pool;
+    pool.token_mint = token_mint;
+    pool.token_vault = ctx.accounts.token_vault.key();
+    pool.sol_vault = ctx.accounts.sol_vault.key();
+    pool.lp_mint = ctx.accounts.lp_mint.key();
+    pool.token_reserve = params.initial_token_amount;
+    pool.sol_reserve = params.initial_sol_amount;
+    pool.lp_fee_bps = params.fee_rate;
+    pool.sell_fee_override = params.sell_fee_rate;
+    pool.curve_type = params.curve_type;
+    pool.flags = PoolFlags::DEFAULT | PoolFlags::ENABLED_BITS;
+    pool.pool_authority = ctx.accounts.pool_authority.key();
+    pool.is_initialized = true;
+    pool.created_at = now;
+    pool.price_last_updated = pool.created_at;
+    pool.admin = Some(ctx.accounts.creator.key());
+    pool.token_decimals = params.decimals;
+    pool.quote_decimals = 9;
+    pool.decimals_recorded = true;
+    pool.indexed = true;
+    ctx.accounts.pool_registry.append(&mut ctx.accounts.pool_index_page, pool.key())?;
+    
+    // Mint initial LP tokens (geometric mean of reserves) to the creator,
+    // into escrow when they vest, or into the burn vault
+    pool.lp_supply =
+        (params.initial_token_amount as f64 * params.initial_sol_amount as f64).sqrt() as u64;
+    
+    let lp_destination = match params.initial_lp {
+        LaunchLp::Creator => ctx
+            .accounts
+            .creator_lp_account
+            .as_ref()
+            .ok_or(ExchangeError::LpAccountRequired)?
+            .to_account_info(),
+        LaunchLp::Burn => {
+            let (Some(lp_burn_vault), Some(lp_burn_record)) =
+                (&ctx.accounts.lp_burn_vault, &mut ctx.accounts.lp_burn_record)
+            else {
+                return err!(ExchangeError::LpAccountRequired);
+            };
+            lp_burn_record.record(pool.key(), ctx.accounts.creator.key(), pool.lp_supply, 10000, now);
+            emit!(LpBurnedEvent {
+                pool: pool.key(),
+                burner: ctx.accounts.creator.key(),
+                amount: pool.lp_supply,
+                share_of_supply_bps: 10000,
+                lp_supply_after: pool.lp_supply,
+                by_admin: true,
+                timestamp: now,
+            });
+            lp_burn_vault.to_account_info()
+        }
+        LaunchLp::Vest(schedule) => {
+            require!(
+                schedule.duration_secs > 0
+                    && (0..=schedule.duration_secs).contains(&schedule.cliff_secs),
+                ExchangeError::InvalidVestingSchedule
+            );
+            let (Some(lp_vesting), Some(lp_vesting_vault)) =
+                (&mut ctx.accounts.lp_vesting, &ctx.accounts.lp_vesting_vault)
+            else {
+                return err!(ExchangeError::InvalidVestingSchedule);
+            };
+            lp_vesting.pool = pool.key();
+            lp_vesting.beneficiary = ctx.accounts.creator.key();
+            lp_vesting.total = pool.lp_supply;
+            lp_vesting.claimed = 0;
+            lp_vesting.start = now;
+            lp_vesting.cliff_end = now + schedule.cliff_secs;
+            lp_vesting.end = now + schedule.duration_secs;
+            lp_vesting_vault.to_account_info()
+        }
+    };
+    mint_lp_tokens(
+        ctx.accounts.token_program.as_ref(),
+        ctx.accounts.lp_mint.as_ref().as_ref(),
+        &lp_destination,
+        &ctx.accounts.pool_authority,
+        token_mint,
+        ctx.bumps.pool_authority,
+        pool.lp_supply,
+    )?;
+    let creator_lp_tokens = match params.initial_lp {
+        LaunchLp::Creator => pool.lp_supply,
+        LaunchLp::Burn | LaunchLp::Vest(_) => 0,
+    };
+    
+    emit_creation_sponsored(
+        &ctx.accounts.payer,
+        &ctx.accounts.creator,
+        pool.key(),
+        &[
+            &ctx.accounts.token_mint.to_account_info(),
+            &ctx.accounts.token_registry.to_account_info(),
+            &pool.to_account_info(),
+            &ctx.accounts.token_vault.to_account_info(),
+            &ctx.accounts.sol_vault,
+            &ctx.accounts.lp_mint.to_account_info(),
+            &ctx.accounts.creator_token_account.to_account_info(),
+        ],
+    )?;
+    
+    emit!(TokenLaunchedEvent {
+        token_mint,
+        pool: pool.key(),
+        creator: ctx.accounts.creator.key(),
+        total_supply: params.total_supply,
+        pool_token_amount: params.initial_token_amount,
+        pool_sol_amount: params.initial_sol_amount,
+        lp_tokens: pool.lp_supply,
+        mint_authority_revoked: params.revoke_mint_authority,
+    });
+    
+    Ok(creator_lp_tokens)
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::events::StrategyDeployedEvent;
+use crate::strategy::lending_adapter;
+use super::manage_strategy::ManageStrategy;
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ManageStrategy<'info>>,
+    amount: u64,
+) -> Result<()> {
+    require!(
+        ctx.accounts.protocol_config.deposits_enabled(),
+        ExchangeError::ProtocolHalted
+    );
+    let pool = &ctx.accounts.pool;
+    require!(pool.strategy_cap_bps > 0, ExchangeError::StrategyNotConfigured);
+    
+    let cap = pool.strategy_cap();
+    let deployed = pool
+        .strategy_deployed
+        .checked_add(amount)
+        .ok_or(ExchangeError::MathOverflow)?;
+    require!(deployed <= cap, ExchangeError::StrategyCapExceeded);
+    
+    let token_mint = pool.token_mint;
+    let pool_authority_bump = ctx.bumps.pool_authority;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"pool_authority", token_mint.as_ref(), &[pool_authority_bump]]];
+    
+    let collateral_before = ctx.accounts.strategy_collateral_account.amount;
+    let adapter = lending_adapter(&pool.strategy_program).ok_or(ExchangeError::UnsupportedStrategy)?;
+    adapter.deposit(
+        &ctx.accounts.strategy_cpi(ctx.remaining_accounts, signer_seeds),
+        amount,
+    )?;
+    ctx.accounts.strategy_collateral_account.reload()?;
+    let collateral_minted = ctx.accounts.strategy_collateral_account.amount - collateral_before;
+    
+    let pool = &mut ctx.accounts.pool;
+    pool.strategy_deployed = deployed;
+    pool.strategy_collateral += collateral_minted;
+    
+    emit!(StrategyDeployedEvent {
+        pool: pool.key(),
+        amount,
+        collateral_minted,
+    });
+    
+    assert_pool_invariants!(&ctx.accounts.pool, Some(ctx.accounts.token_vault.as_ref()), None, None);
+    Ok(())
+}
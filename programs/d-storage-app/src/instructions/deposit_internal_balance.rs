@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+
+use crate::errors::ExchangeError;
+use crate::events::InternalBalanceEvent;
+use super::internal_balance_transfer::InternalBalanceTransfer;
+
+pub fn handler(ctx: Context<InternalBalanceTransfer>, amount: u64) -> Result<()> {
+    require!(
+        ctx.accounts.protocol_config.swaps_enabled(),
+        ExchangeError::ProtocolHalted
+    );
+    let accounts = ctx.accounts;
+    match accounts.token_accounts()? {
+        None => {
+            // The first SOL deposit also funds the vault's rent exemption
+            let rent = if accounts.internal_vault.lamports() == 0 {
+                Rent::get()?.minimum_balance(0)
+            } else {
+                0
+            };
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: accounts.user.to_account_info(),
+                        to: accounts.internal_vault.to_account_info(),
+                    },
+                ),
+                amount + rent,
+            )?;
+        }
+        Some((internal_token_vault, user_token_account)) => {
+            token::transfer(
+                CpiContext::new(
+                    accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: user_token_account,
+                        to: internal_token_vault,
+                        authority: accounts.user.to_account_info(),
+                    },
+                ),
+                amount,
+            )?;
+        }
+    }
+    
+    let internal_balance = &mut accounts.internal_balance;
+    internal_balance.credit(amount)?;
+    
+    emit!(InternalBalanceEvent {
+        owner: internal_balance.owner,
+        mint: internal_balance.mint,
+        amount,
+        deposit: true,
+        balance: internal_balance.amount,
+    });
+    
+    Ok(())
+}
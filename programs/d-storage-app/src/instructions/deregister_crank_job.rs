@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::ExchangeError;
+use crate::events::CrankJobEvent;
+use crate::state::{CrankJobKind, CrankRegistry, LiquidityPool};
+
+#[derive(Accounts)]
+pub struct DeregisterCrankJob<'info> {
+    #[account(
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.is_admin(&admin.key(), admin_nft_account.as_deref()) @ ExchangeError::Unauthorized
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(
+        mut,
+        seeds = [b"crank_registry"],
+        bump
+    )]
+    pub crank_registry: Box<Account<'info, CrankRegistry>>,
+    pub admin: Signer<'info>,
+    /// Admin NFT held by the signer, required once the pool is bound to one
+    pub admin_nft_account: Option<Account<'info, TokenAccount>>,
+}
+
+/// Remove one of the pool's crank jobs, freeing its registry slot
+pub fn handler(ctx: Context<DeregisterCrankJob>, kind: CrankJobKind) -> Result<()> {
+    let pool = ctx.accounts.pool.key();
+    ctx.accounts.crank_registry.deregister(kind, pool)?;
+    
+    emit!(CrankJobEvent {
+        pool,
+        kind,
+        registered: false,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::events::DiscountStakeEvent;
+use crate::state::{ProtocolConfig, StakeDiscountConfig, StakePosition};
+
+#[derive(Accounts)]
+pub struct DiscountStakeTransfer<'info> {
+    #[account(seeds = [b"stake_discount_config"], bump)]
+    pub stake_discount_config: Account<'info, StakeDiscountConfig>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        mut,
+        token::mint = stake_discount_config.stake_mint,
+        token::authority = user
+    )]
+    pub user_stake_account: Account<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = StakePosition::LEN,
+        seeds = [b"stake_position", user.key().as_ref()],
+        bump
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+    #[account(
+        mut,
+        seeds = [b"stake_vault"],
+        bump
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    /// Protocol-wide emergency stop status
+    #[account(seeds = [b"protocol_config"], bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+}
+
+impl<'info> DiscountStakeTransfer<'info> {
+    /// Move unstaked tokens out of the stake vault to the user
+    pub fn pay_from_vault(&self, config_bump: u8, amount: u64) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.stake_vault.to_account_info(),
+                    to: self.user_stake_account.to_account_info(),
+                    authority: self.stake_discount_config.to_account_info(),
+                },
+                &[&[b"stake_discount_config", &[config_bump]]],
+            ),
+            amount,
+        )
+    }
+
+    /// Record a stake, unstake or withdrawal
+    pub fn emit_event(&self, amount: u64, staked: bool, withdrawn: u64, now: i64) {
+        emit!(DiscountStakeEvent {
+            owner: self.user.key(),
+            amount,
+            staked,
+            withdrawn,
+            effective_stake: self.stake_position.effective_stake(now),
+        });
+    }
+}
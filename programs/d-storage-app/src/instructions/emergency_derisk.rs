@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+use super::manage_strategy::ManageStrategy;
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ManageStrategy<'info>>,
+) -> Result<()> {
+    ctx.accounts.pool.strategy_cap_bps = 0;
+    let collateral_amount = ctx.accounts.pool.strategy_collateral;
+    let pool_authority_bump = ctx.bumps.pool_authority;
+    ctx.accounts.redeem(ctx.remaining_accounts, pool_authority_bump, collateral_amount, true)
+}
@@ -0,0 +1,156 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::constants::EMERGENCY_DRAIN_TIMELOCK_SECS;
+use crate::errors::ExchangeError;
+use crate::events::EmergencyDrainEvent;
+use crate::quote::QuoteVault;
+use crate::state::LiquidityPool;
+
+#[derive(Accounts)]
+pub struct EmergencyDrain<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.is_admin(&admin.key(), admin_nft_account.as_deref()) @ ExchangeError::Unauthorized,
+        constraint = pool.guardian == guardian.key() @ ExchangeError::InvalidGuardian,
+        has_one = token_vault @ ExchangeError::InvalidVault,
+        has_one = sol_vault @ ExchangeError::InvalidVault
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    pub admin: Signer<'info>,
+    pub guardian: Signer<'info>,
+    /// CHECK: Pool authority PDA
+    #[account(
+        seeds = [b"pool_authority", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"token_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub token_vault: Account<'info, TokenAccount>,
+    /// CHECK: SOL vault, or the quote token vault of a token-quoted pool
+    #[account(
+        mut,
+        seeds = [b"sol_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: AccountInfo<'info>,
+    /// CHECK: Recovery address registered on the pool
+    #[account(mut, address = pool.recovery_address @ ExchangeError::Unauthorized)]
+    pub recovery_address: AccountInfo<'info>,
+    #[account(
+        mut,
+        token::mint = pool.token_mint,
+        token::authority = recovery_address
+    )]
+    pub recovery_token_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    /// Receives the quote tokens, required for token-quoted pools
+    #[account(
+        mut,
+        token::mint = pool.quote_mint,
+        token::authority = recovery_address
+    )]
+    pub recovery_quote_account: Option<Account<'info, TokenAccount>>,
+    /// Admin NFT held by the signer, required once the pool is bound to one
+    pub admin_nft_account: Option<Account<'info, TokenAccount>>,
+}
+
+pub fn handler(ctx: Context<EmergencyDrain>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let pool = &ctx.accounts.pool;
+    require!(pool.drain_requested_at != 0, ExchangeError::NoEmergencyDrainPending);
+    require!(
+        now >= pool.drain_requested_at + EMERGENCY_DRAIN_TIMELOCK_SECS,
+        ExchangeError::TimelockNotExpired
+    );
+    require!(
+        pool.internal_token_credit == 0 && pool.internal_quote_credit == 0,
+        ExchangeError::InternalCreditsUnsettled
+    );
+    
+    // Owed royalties and protocol fees stay behind for their usual claims
+    let token_mint = pool.token_mint;
+    let token_amount = ctx
+        .accounts
+        .token_vault
+        .amount
+        .saturating_sub(pool.royalty_owed_token + pool.protocol_fees_token);
+    if token_amount > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.token_vault.to_account_info(),
+                    to: ctx.accounts.recovery_token_account.to_account_info(),
+                    authority: ctx.accounts.pool_authority.to_account_info(),
+                },
+                &[&[b"pool_authority", token_mint.as_ref(), &[ctx.bumps.pool_authority]]],
+            ),
+            token_amount,
+        )?;
+    }
+    
+    let quote_owed = pool.royalty_owed_quote + pool.protocol_fees_quote;
+    let (sol_amount, recipient) = if pool.is_native_quote() {
+        // Keep the vault rent exempt so it can be reused if the pool is restored
+        let sol_amount = ctx
+            .accounts
+            .sol_vault
+            .lamports()
+            .saturating_sub(Rent::get()?.minimum_balance(0) + quote_owed);
+        (sol_amount, ctx.accounts.recovery_address.to_account_info())
+    } else {
+        let quote_vault =
+            TokenAccount::try_deserialize(&mut &ctx.accounts.sol_vault.try_borrow_data()?[..])?;
+        let recovery_quote_account = ctx
+            .accounts
+            .recovery_quote_account
+            .as_ref()
+            .ok_or(ExchangeError::InvalidQuoteAccount)?;
+        (quote_vault.amount.saturating_sub(quote_owed), recovery_quote_account.to_account_info())
+    };
+    if sol_amount > 0 {
+        QuoteVault {
+            pool,
+            vault: &ctx.accounts.sol_vault,
+            token_program: ctx.accounts.token_program.as_ref(),
+            system_program: ctx.accounts.system_program.as_ref(),
+        }
+        .withdraw(
+            ctx.bumps.sol_vault,
+            &ctx.accounts.pool_authority,
+            ctx.bumps.pool_authority,
+            &recipient,
+            sol_amount,
+        )?;
+    }
+    
+    let pool = &mut ctx.accounts.pool;
+    pool.update_price_cumulative(now);
+    pool.token_reserve = 0;
+    pool.sol_reserve = 0;
+    pool.drain_requested_at = 0;
+    pool.sunset_at = now;
+    
+    emit!(EmergencyDrainEvent {
+        pool: pool.key(),
+        recovery_address: pool.recovery_address,
+        token_amount,
+        sol_amount,
+    });
+    
+    assert_pool_invariants!(
+        &ctx.accounts.pool,
+        Some(ctx.accounts.token_vault.as_ref()),
+        Some(&ctx.accounts.sol_vault),
+        None
+    );
+    Ok(())
+}
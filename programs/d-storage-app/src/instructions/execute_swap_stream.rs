@@ -0,0 +1,229 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::errors::ExchangeError;
+use crate::events::{SwapEvent, SwapStreamEvent};
+use crate::internal_vault::InternalVault;
+use crate::oracle::{check_oracle, rebalance_discount_bps, record_breaker_trip};
+use crate::state::{
+    BreakerHistory, InternalBalance, LiquidityPool, PoolFlags, ProtocolConfig, SwapCooldown, SwapStream,
+};
+use super::swap::{
+    check_opposing_swaps, price_swap, reserves_after_swap, set_aside_fees, swap_price_impact, throttle_outflow, SwapDirection, SwapResult,
+};
+use super::swap_internal::{internal_quote_vault, InternalSwapSettlement};
+
+#[derive(Accounts)]
+pub struct ExecuteSwapStream<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.lp_fee_bps <= 1000 @ ExchangeError::InvalidFeeRate,
+        has_one = token_vault @ ExchangeError::InvalidVault,
+        has_one = sol_vault @ ExchangeError::InvalidVault
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+    #[account(
+        mut,
+        has_one = pool,
+        seeds = [b"swap_stream", pool.key().as_ref(), swap_stream.owner.as_ref()],
+        bump
+    )]
+    pub swap_stream: Box<Account<'info, SwapStream>>,
+    /// CHECK: Pool authority PDA
+    #[account(
+        seeds = [b"pool_authority", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"token_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub token_vault: Box<Account<'info, TokenAccount>>,
+    /// CHECK: SOL vault, or the quote token vault of a token-quoted pool
+    #[account(
+        mut,
+        seeds = [b"sol_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"internal_balance", swap_stream.owner.as_ref(), pool.token_mint.as_ref()],
+        bump
+    )]
+    pub token_balance: Box<Account<'info, InternalBalance>>,
+    #[account(
+        mut,
+        seeds = [b"internal_balance", swap_stream.owner.as_ref(), pool.quote_mint.as_ref()],
+        bump
+    )]
+    pub quote_balance: Box<Account<'info, InternalBalance>>,
+    /// CHECK: Internal vault PDA, holds SOL balances
+    #[account(
+        mut,
+        seeds = [b"internal_vault"],
+        bump
+    )]
+    pub internal_vault: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"internal_vault", pool.token_mint.as_ref()],
+        bump
+    )]
+    pub internal_token_vault: Box<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    /// Protocol-wide emergency stop status
+    #[account(seeds = [b"protocol_config"], bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+    /// Internal vault for the quote mint, required for token-quoted pools
+    #[account(
+        mut,
+        seeds = [b"internal_vault", pool.quote_mint.as_ref()],
+        bump
+    )]
+    pub internal_quote_vault: Option<Box<Account<'info, TokenAccount>>>,
+    /// CHECK: Price feed recorded on the pool, required when the oracle guard is on
+    #[account(address = pool.oracle @ ExchangeError::InvalidOracle)]
+    pub oracle: Option<UncheckedAccount<'info>>,
+    /// Owner's cooldown tracker, required during the launch window
+    #[account(
+        mut,
+        seeds = [b"swap_cooldown", pool.key().as_ref(), swap_stream.owner.as_ref()],
+        bump
+    )]
+    pub swap_cooldown: Option<Account<'info, SwapCooldown>>,
+    /// Circuit breaker history, records skipped oracle guards when passed
+    #[account(
+        mut,
+        seeds = [b"breaker_history", pool.key().as_ref()],
+        bump
+    )]
+    pub breaker_history: Option<Box<Account<'info, BreakerHistory>>>,
+    /// CHECK: Instructions sysvar, required when the pool guards against sandwiches
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: Option<UncheckedAccount<'info>>,
+}
+
+/// Convert the input the stream accrued since it last ran. Permissionless, so
+/// anyone interacting with the pool can bundle it in front of their own trade.
+pub fn handler(ctx: Context<ExecuteSwapStream>) -> Result<SwapResult> {
+    let accounts = ctx.accounts;
+    require!(accounts.protocol_config.swaps_enabled(), ExchangeError::ProtocolHalted);
+    require!(accounts.pool.features_enabled(PoolFlags::INTERNAL_BALANCES), ExchangeError::FeatureDisabled);
+    check_opposing_swaps(&accounts.pool, accounts.instructions.as_deref())?;
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+    
+    let direction = accounts.swap_stream.direction;
+    let available = match direction {
+        SwapDirection::TokenToSol => accounts.token_balance.amount,
+        SwapDirection::SolToToken => accounts.quote_balance.amount,
+    };
+    let amount_in = accounts.swap_stream.due(clock.slot).min(available);
+    require!(amount_in > 0, ExchangeError::StreamNotDue);
+    let min_amount_out = accounts.swap_stream.min_amount_out(amount_in);
+    
+    let fee_discount_bps = rebalance_discount_bps(&accounts.pool, accounts.oracle.as_deref(), direction, now)?;
+    let (amount_out, lp_fee, protocol_fee) = price_swap(
+        &accounts.pool,
+        accounts.swap_cooldown.as_deref_mut(),
+        now,
+        direction,
+        amount_in,
+        min_amount_out,
+        fee_discount_bps,
+    )?;
+    let price_impact_bps =
+        swap_price_impact(&accounts.pool, direction, amount_in - lp_fee - protocol_fee, amount_out);
+    let set_aside = set_aside_fees(&mut accounts.pool, direction, amount_in, lp_fee, protocol_fee, now);
+    throttle_outflow(&mut accounts.pool, direction, amount_out, now)?;
+    let (new_token_reserve, new_sol_reserve) =
+        reserves_after_swap(&accounts.pool, direction, amount_in - set_aside, amount_out);
+    let breaker = check_oracle(&accounts.pool, accounts.oracle.as_deref(), new_token_reserve, new_sol_reserve, now)?;
+    if let (Some(breaker), Some(history)) = (breaker, &mut accounts.breaker_history) {
+        record_breaker_trip(history, breaker)?;
+    }
+    
+    match direction {
+        SwapDirection::TokenToSol => {
+            accounts.token_balance.debit(amount_in)?;
+            accounts.quote_balance.credit(amount_out)?;
+        }
+        SwapDirection::SolToToken => {
+            accounts.quote_balance.debit(amount_in)?;
+            accounts.token_balance.credit(amount_out)?;
+        }
+    }
+    let from_vault = accounts.pool.book_internal_swap(
+        matches!(direction, SwapDirection::TokenToSol),
+        amount_in,
+        amount_out,
+    )?;
+    InternalSwapSettlement {
+        pool: &accounts.pool,
+        pool_authority: &accounts.pool_authority,
+        pool_authority_bump: ctx.bumps.pool_authority,
+        token_vault: accounts.token_vault.as_ref().as_ref(),
+        sol_vault: &accounts.sol_vault,
+        sol_vault_bump: ctx.bumps.sol_vault,
+        internal_vault: InternalVault {
+            vault: &accounts.internal_vault,
+            bump: ctx.bumps.internal_vault,
+            token_program: accounts.token_program.as_ref(),
+            system_program: accounts.system_program.as_ref(),
+        },
+        internal_token_vault: accounts.internal_token_vault.as_ref().as_ref(),
+        internal_quote_vault: internal_quote_vault(
+            &accounts.pool,
+            accounts.internal_quote_vault.as_deref(),
+        )?,
+    }
+    .settle(direction, from_vault)?;
+    
+    let pool = &mut accounts.pool;
+    pool.update_price_cumulative(now);
+    pool.token_reserve = new_token_reserve;
+    pool.sol_reserve = new_sol_reserve;
+    
+    let swap_stream = &mut accounts.swap_stream;
+    swap_stream.remaining -= amount_in;
+    swap_stream.last_slot = clock.slot;
+    swap_stream.total_in = swap_stream.total_in.saturating_add(amount_in);
+    swap_stream.total_out = swap_stream.total_out.saturating_add(amount_out);
+    
+    let (token_in, token_out) = match direction {
+        SwapDirection::TokenToSol => (pool.token_mint, pool.quote_mint), // default = SOL out
+        SwapDirection::SolToToken => (pool.quote_mint, pool.token_mint), // default = SOL in
+    };
+    emit!(SwapEvent {
+        user: swap_stream.owner,
+        token_in,
+        token_out,
+        amount_in,
+        amount_out,
+        recipient: swap_stream.owner,
+        price_impact_bps,
+    });
+    emit!(SwapStreamEvent {
+        pool: pool.key(),
+        owner: swap_stream.owner,
+        direction,
+        amount_in,
+        amount_out,
+        remaining: swap_stream.remaining,
+    });
+    
+    assert_pool_invariants!(pool, Some(accounts.token_vault.as_ref().as_ref()), Some(&accounts.sol_vault), None);
+    Ok(SwapResult {
+        amount_in,
+        amount_out,
+        fee: lp_fee + protocol_fee,
+        price_after_x64: pool.spot_price_x64(),
+    })
+}
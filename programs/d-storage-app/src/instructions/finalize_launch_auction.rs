@@ -0,0 +1,214 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::errors::ExchangeError;
+use crate::events::LaunchAuctionSettledEvent;
+use crate::state::{
+    AuctionStatus, LaunchAuction, LiquidityPool, PoolFlags, PoolIndexPage, PoolRegistry, ProtocolConfig,
+};
+use super::add_liquidity::mint_lp_tokens;
+
+#[derive(Accounts)]
+pub struct FinalizeLaunchAuction<'info> {
+    /// Anyone may settle the auction and pay the rent of the pool accounts
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"launch_auction", token_mint.key().as_ref()],
+        bump = auction.bump
+    )]
+    pub auction: Box<Account<'info, LaunchAuction>>,
+    
+    #[account(
+        mut,
+        seeds = [b"launch_auction_vault", token_mint.key().as_ref()],
+        bump
+    )]
+    pub auction_vault: Box<Account<'info, TokenAccount>>,
+    
+    pub token_mint: Box<Account<'info, Mint>>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = LiquidityPool::LEN,
+        seeds = [b"pool", token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+    
+    /// CHECK: Pool authority PDA
+    #[account(
+        seeds = [b"pool_authority", token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+    
+    #[account(
+        init,
+        payer = payer,
+        token::mint = token_mint,
+        token::authority = pool_authority,
+        seeds = [b"token_vault", token_mint.key().as_ref()],
+        bump
+    )]
+    pub token_vault: Box<Account<'info, TokenAccount>>,
+    
+    /// CHECK: SOL vault - will be created as System Program owned account
+    #[account(
+        mut,
+        seeds = [b"sol_vault", token_mint.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: AccountInfo<'info>,
+    
+    /// Left behind by `reap_pool`, so a re-created pool reuses it
+    #[account(
+        init_if_needed,
+        payer = payer,
+        mint::decimals = 6,
+        mint::authority = pool_authority,
+        seeds = [b"lp_mint", token_mint.key().as_ref()],
+        bump
+    )]
+    pub lp_mint: Box<Account<'info, Mint>>,
+    
+    #[account(seeds = [b"protocol_config"], bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+    
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PoolRegistry::LEN,
+        seeds = [b"pool_registry"],
+        bump
+    )]
+    pub pool_registry: Box<Account<'info, PoolRegistry>>,
+    
+    /// Index page the new pool is appended to
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PoolIndexPage::LEN,
+        seeds = [b"pool_index", pool_registry.next_page().to_le_bytes().as_ref()],
+        bump
+    )]
+    pub pool_index_page: Box<Account<'info, PoolIndexPage>>,
+    
+    /// Holds the seeded LP tokens for good: owned by the pool authority,
+    /// which no instruction moves them out of
+    #[account(
+        init,
+        payer = payer,
+        token::mint = lp_mint,
+        token::authority = pool_authority,
+        seeds = [b"lp_burn_vault", pool.key().as_ref()],
+        bump
+    )]
+    pub lp_burn_vault: Box<Account<'info, TokenAccount>>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Clear the auction at its uniform price and seed the pool with the raised
+/// SOL, the reserved inventory and whatever supply went unsold
+pub fn handler(ctx: Context<FinalizeLaunchAuction>) -> Result<()> {
+    require!(ctx.accounts.protocol_config.deposits_enabled(), ExchangeError::ProtocolHalted);
+    require!(ctx.accounts.lp_mint.supply == 0, ExchangeError::LpMintInUse);
+    let auction = &mut ctx.accounts.auction;
+    require!(
+        auction.status == AuctionStatus::Open && Clock::get()?.unix_timestamp >= auction.reveal_end,
+        ExchangeError::AuctionPhaseMismatch
+    );
+    let raised = auction.clear().ok_or(ExchangeError::MathOverflow)?;
+    require!(raised > 0, ExchangeError::AuctionNotClearable);
+    auction.status = AuctionStatus::Cleared;
+    
+    // Create SOL vault as System Program owned account, then hand it the raised SOL
+    let token_mint = ctx.accounts.token_mint.key();
+    anchor_lang::system_program::create_account(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::CreateAccount {
+                from: ctx.accounts.payer.to_account_info(),
+                to: ctx.accounts.sol_vault.to_account_info(),
+            },
+            &[&[b"sol_vault", token_mint.as_ref(), &[ctx.bumps.sol_vault]]],
+        ),
+        Rent::get()?.minimum_balance(0),
+        0,
+        &anchor_lang::system_program::ID,
+    )?;
+    **auction.to_account_info().try_borrow_mut_lamports()? -= raised;
+    **ctx.accounts.sol_vault.try_borrow_mut_lamports()? += raised;
+    
+    let pool_tokens = auction.pool_tokens();
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.auction_vault.to_account_info(),
+                to: ctx.accounts.token_vault.to_account_info(),
+                authority: auction.to_account_info(),
+            },
+            &[&[b"launch_auction", token_mint.as_ref(), &[auction.bump]]],
+        ),
+        pool_tokens,
+    )?;
+    
+    let now = Clock::get()?.unix_timestamp;
+    let pool = &mut ctx.accounts.pool;
+    pool.token_mint = token_mint;
+    pool.token_vault = ctx.accounts.token_vault.key();
+    pool.sol_vault = ctx.accounts.sol_vault.key();
+    pool.lp_mint = ctx.accounts.lp_mint.key();
+    pool.token_reserve = pool_tokens;
+    pool.sol_reserve = raised;
+    pool.lp_fee_bps = auction.fee_rate;
+    pool.curve_type = auction.curve_type;
+    pool.flags = PoolFlags::DEFAULT | PoolFlags::ENABLED_BITS;
+    pool.pool_authority = ctx.accounts.pool_authority.key();
+    pool.is_initialized = true;
+    pool.created_at = now;
+    pool.price_last_updated = now;
+    pool.admin = Some(auction.creator);
+    pool.token_decimals = ctx.accounts.token_mint.decimals;
+    pool.quote_decimals = 9;
+    pool.decimals_recorded = true;
+    pool.indexed = true;
+    ctx.accounts.pool_registry.append(&mut ctx.accounts.pool_index_page, pool.key())?;
+    
+    // Nobody holds a claim on the seeded LP: it is minted into the burn
+    // vault and the launch liquidity stays locked for good
+    pool.lp_supply = (pool_tokens as f64 * raised as f64).sqrt() as u64;
+    mint_lp_tokens(
+        ctx.accounts.token_program.as_ref(),
+        ctx.accounts.lp_mint.as_ref().as_ref(),
+        ctx.accounts.lp_burn_vault.as_ref().as_ref(),
+        &ctx.accounts.pool_authority,
+        token_mint,
+        ctx.bumps.pool_authority,
+        pool.lp_supply,
+    )?;
+    
+    emit!(LaunchAuctionSettledEvent {
+        auction: auction.key(),
+        token_mint,
+        status: auction.status,
+        clearing_price_x64: auction.clearing_price_x64,
+        raised_sol: raised,
+        tokens_sold: auction.tokens_sold,
+        pool_tokens,
+    });
+    
+    assert_pool_invariants!(
+        &ctx.accounts.pool,
+        Some(ctx.accounts.token_vault.as_ref().as_ref()),
+        Some(&ctx.accounts.sol_vault),
+        Some(ctx.accounts.lp_mint.as_ref().as_ref())
+    );
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::errors::ExchangeError;
+use crate::events::RewardEpochFundedEvent;
+use crate::state::{LiquidityPool, ProtocolConfig, RewardEpoch};
+
+#[derive(Accounts)]
+#[instruction(epoch: u64)]
+pub struct FundEpoch<'info> {
+    #[account(
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(
+        init_if_needed,
+        payer = funder,
+        space = RewardEpoch::LEN,
+        seeds = [b"reward_epoch", pool.key().as_ref(), epoch.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub reward_epoch: Account<'info, RewardEpoch>,
+    /// Holds the epoch's rewards, owned by the epoch PDA
+    #[account(
+        init_if_needed,
+        payer = funder,
+        token::mint = reward_mint,
+        token::authority = reward_epoch,
+        seeds = [b"reward_vault", reward_epoch.key().as_ref()],
+        bump
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+    pub reward_mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub funder: Signer<'info>,
+    #[account(
+        mut,
+        token::mint = reward_mint,
+        token::authority = funder
+    )]
+    pub funder_token_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    /// Protocol-wide emergency stop status
+    #[account(seeds = [b"protocol_config"], bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+}
+
+/// Deposit rewards for `epoch`. The first deposit creates the epoch and makes
+/// the signer its funder; only the funder can top it up before the root is set.
+pub fn handler(ctx: Context<FundEpoch>, epoch: u64, amount: u64) -> Result<()> {
+    require!(
+        ctx.accounts.protocol_config.deposits_enabled(),
+        ExchangeError::ProtocolHalted
+    );
+    require!(amount > 0, ExchangeError::InvalidAmount);
+    
+    let reward_epoch = &mut ctx.accounts.reward_epoch;
+    if reward_epoch.funder == Pubkey::default() {
+        reward_epoch.pool = ctx.accounts.pool.key();
+        reward_epoch.epoch = epoch;
+        reward_epoch.reward_mint = ctx.accounts.reward_mint.key();
+        reward_epoch.funder = ctx.accounts.funder.key();
+        reward_epoch.bump = ctx.bumps.reward_epoch;
+    }
+    require_keys_eq!(reward_epoch.funder, ctx.accounts.funder.key(), ExchangeError::Unauthorized);
+    require!(!reward_epoch.has_root(), ExchangeError::RewardRootAlreadySet);
+    reward_epoch.total_funded = reward_epoch
+        .total_funded
+        .checked_add(amount)
+        .ok_or(ExchangeError::MathOverflow)?;
+    
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.funder_token_account.to_account_info(),
+                to: ctx.accounts.reward_vault.to_account_info(),
+                authority: ctx.accounts.funder.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    emit!(RewardEpochFundedEvent {
+        pool: ctx.accounts.pool.key(),
+        epoch,
+        reward_mint: ctx.accounts.reward_mint.key(),
+        funder: ctx.accounts.funder.key(),
+        amount,
+    });
+    
+    Ok(())
+}
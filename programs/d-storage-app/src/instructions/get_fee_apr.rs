@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{FeeMetrics, LiquidityPool};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct FeeApr {
+    /// LP fees earned over the last 24h, in quote
+    pub fees_24h: u64,
+    /// LP fees earned over the last 7d, in quote
+    pub fees_7d: u64,
+    /// 24h fees annualized against the current reserves value
+    pub apr_bps_24h: u64,
+    /// 7d fees annualized against the current reserves value
+    pub apr_bps_7d: u64,
+}
+
+#[derive(Accounts)]
+pub struct GetFeeApr<'info> {
+    #[account(
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+}
+
+/// Fee totals and the APR they imply, for gauges and UIs
+pub fn handler(ctx: Context<GetFeeApr>) -> Result<FeeApr> {
+    let pool = &ctx.accounts.pool;
+    let (fees_24h, fees_7d) = pool.fee_metrics.totals(Clock::get()?.unix_timestamp);
+    let value = pool.reserves_value_in_quote();
+    let apr_bps = |fees: u64, days: u128| {
+        if value == 0 {
+            return 0;
+        }
+        let apr_bps = fees as u128 * 365 * 10000 / days / value;
+        apr_bps.min(u64::MAX as u128) as u64
+    };
+    
+    Ok(FeeApr {
+        fees_24h,
+        fees_7d,
+        apr_bps_24h: apr_bps(fees_24h, 1),
+        apr_bps_7d: apr_bps(fees_7d, FeeMetrics::DAILY_BUCKETS as u128),
+    })
+}
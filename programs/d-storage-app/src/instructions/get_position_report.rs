@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{DepositReceipt, LiquidityPool, PositionReport};
+
+#[derive(Accounts)]
+pub struct GetPositionReport<'info> {
+    #[account(
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(
+        seeds = [b"deposit_receipt", pool.key().as_ref(), deposit_receipt.owner.as_ref()],
+        bump
+    )]
+    pub deposit_receipt: Account<'info, DepositReceipt>,
+}
+
+/// Impermanent loss and P&L of a receipt's position, for UIs and withdrawal
+/// previews
+pub fn handler(ctx: Context<GetPositionReport>) -> Result<PositionReport> {
+    Ok(ctx.accounts.deposit_receipt.report(&ctx.accounts.pool))
+}
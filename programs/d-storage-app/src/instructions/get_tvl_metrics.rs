@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::state::{LiquidityPool, TvlCheckpoint, TvlHistory};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct TvlMetrics {
+    /// Checkpoint the window starts at
+    pub since: i64,
+    /// Current TVL in quote terms
+    pub tvl: u64,
+    /// Annualized LP value growth over the window, fees included
+    pub apr_bps: i64,
+    /// Impermanent loss over the window, net of fees
+    pub impermanent_loss_bps: u16,
+}
+
+#[derive(Accounts)]
+pub struct GetTvlMetrics<'info> {
+    #[account(
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(
+        seeds = [b"tvl_history", pool.key().as_ref()],
+        bump
+    )]
+    pub tvl_history: Box<Account<'info, TvlHistory>>,
+}
+
+/// APR and impermanent loss from the last checkpoint at least `window_days`
+/// old up to now
+pub fn handler(ctx: Context<GetTvlMetrics>, window_days: u16) -> Result<TvlMetrics> {
+    let now = Clock::get()?.unix_timestamp;
+    let current = TvlCheckpoint::of_pool(&ctx.accounts.pool, now);
+    let from = ctx
+        .accounts
+        .tvl_history
+        .at_or_before(now - window_days as i64 * TvlHistory::INTERVAL_SECS)
+        .ok_or(error!(ExchangeError::TvlHistoryUnavailable))?;
+    
+    Ok(TvlMetrics {
+        since: from.timestamp,
+        tvl: current.tvl() as u64,
+        apr_bps: TvlHistory::apr_bps(from, &current).ok_or(error!(ExchangeError::TvlHistoryUnavailable))?,
+        impermanent_loss_bps: TvlHistory::impermanent_loss_bps(from, &current)
+            .ok_or(error!(ExchangeError::TvlHistoryUnavailable))?,
+    })
+}
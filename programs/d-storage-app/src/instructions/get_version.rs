@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::ENABLED_FEATURES;
+
+#[derive(Accounts)]
+pub struct GetVersion {}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ProgramVersion {
+    pub major: u16,
+    pub minor: u16,
+    pub patch: u16,
+    /// `FEATURE_*` bits supported by this build
+    pub features: u64,
+}
+
+pub fn handler(_ctx: Context<GetVersion>) -> Result<ProgramVersion> {
+    Ok(ProgramVersion {
+        major: env!("CARGO_PKG_VERSION_MAJOR").parse().unwrap_or_default(),
+        minor: env!("CARGO_PKG_VERSION_MINOR").parse().unwrap_or_default(),
+        patch: env!("CARGO_PKG_VERSION_PATCH").parse().unwrap_or_default(),
+        features: ENABLED_FEATURES,
+    })
+}
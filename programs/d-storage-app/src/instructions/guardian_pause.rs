@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::events::GuardianPauseEvent;
+use crate::state::{LiquidityPool, ProtocolConfig};
+
+#[derive(Accounts)]
+pub struct GuardianPause<'info> {
+    #[account(
+        seeds = [b"protocol_config"],
+        bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    // Guardian signers are passed as remaining accounts
+}
+
+/// Halt swaps and deposits on a pool once a quorum of guardians has signed.
+/// Only the protocol admin can lift a guardian pause.
+pub fn handler(ctx: Context<GuardianPause>) -> Result<()> {
+    let guardians: Vec<Pubkey> = ctx
+        .remaining_accounts
+        .iter()
+        .filter(|account| account.is_signer)
+        .map(|account| account.key())
+        .collect();
+    require!(
+        ctx.accounts.protocol_config.guardian_quorum(guardians.iter()),
+        ExchangeError::GuardianQuorumNotMet
+    );
+    
+    let pool = &mut ctx.accounts.pool;
+    pool.swaps_paused = true;
+    pool.deposits_paused = true;
+    pool.guardian_paused = true;
+    
+    emit!(GuardianPauseEvent {
+        pool: pool.key(),
+        guardians,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::events::PauseFlagsUpdateEvent;
+use crate::state::{LiquidityPool, ProtocolConfig};
+
+#[derive(Accounts)]
+pub struct GuardianUnpause<'info> {
+    #[account(
+        seeds = [b"protocol_config"],
+        bump,
+        constraint = protocol_config.admin == admin.key() @ ExchangeError::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    pub admin: Signer<'info>,
+}
+
+/// Lift a guardian pause, resuming swaps and deposits
+pub fn handler(ctx: Context<GuardianUnpause>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    require!(pool.guardian_paused, ExchangeError::NotGuardianPaused);
+    pool.swaps_paused = false;
+    pool.deposits_paused = false;
+    pool.guardian_paused = false;
+    
+    emit!(PauseFlagsUpdateEvent {
+        pool: pool.key(),
+        swaps_paused: false,
+        deposits_paused: false,
+        updated_by: ctx.accounts.admin.key(),
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{BreakerHistory, LiquidityPool};
+
+#[derive(Accounts)]
+pub struct InitBreakerHistory<'info> {
+    #[account(
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(
+        init,
+        payer = payer,
+        space = BreakerHistory::LEN,
+        seeds = [b"breaker_history", pool.key().as_ref()],
+        bump
+    )]
+    pub breaker_history: Box<Account<'info, BreakerHistory>>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Create a pool's circuit breaker history. Anyone can pay for it.
+pub fn handler(ctx: Context<InitBreakerHistory>) -> Result<()> {
+    ctx.accounts.breaker_history.pool = ctx.accounts.pool.key();
+    Ok(())
+}
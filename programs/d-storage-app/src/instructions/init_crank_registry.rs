@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::state::CrankRegistry;
+
+#[derive(Accounts)]
+pub struct InitCrankRegistry<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = CrankRegistry::LEN,
+        seeds = [b"crank_registry"],
+        bump
+    )]
+    pub crank_registry: Box<Account<'info, CrankRegistry>>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the program-wide crank registry. Anyone can pay for it.
+pub fn handler(_ctx: Context<InitCrankRegistry>) -> Result<()> {
+    Ok(())
+}
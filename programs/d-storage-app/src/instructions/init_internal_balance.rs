@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::state::InternalBalance;
+
+#[derive(Accounts)]
+#[instruction(mint: Pubkey)]
+pub struct InitInternalBalance<'info> {
+    #[account(
+        init,
+        payer = rent_payer,
+        space = InternalBalance::LEN,
+        seeds = [b"internal_balance", user.key().as_ref(), mint.as_ref()],
+        bump
+    )]
+    pub internal_balance: Account<'info, InternalBalance>,
+    pub user: Signer<'info>,
+    /// Funds the account, the user or a sponsoring dApp
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitInternalBalance>, mint: Pubkey) -> Result<()> {
+    let internal_balance = &mut ctx.accounts.internal_balance;
+    internal_balance.owner = ctx.accounts.user.key();
+    internal_balance.mint = mint;
+    internal_balance.amount = 0;
+    internal_balance.rent_payer = ctx.accounts.rent_payer.key();
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct InitInternalVault<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub mint: Account<'info, Mint>,
+    /// CHECK: Internal vault PDA, authority of every internal token vault
+    #[account(
+        seeds = [b"internal_vault"],
+        bump
+    )]
+    pub internal_vault: AccountInfo<'info>,
+    #[account(
+        init,
+        payer = payer,
+        token::mint = mint,
+        token::authority = internal_vault,
+        seeds = [b"internal_vault", mint.key().as_ref()],
+        bump
+    )]
+    pub internal_token_vault: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(_ctx: Context<InitInternalVault>) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{LiquidityPool, Observation, PriceObservations};
+
+#[derive(Accounts)]
+pub struct InitObservations<'info> {
+    #[account(
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(
+        init,
+        payer = payer,
+        space = PriceObservations::LEN,
+        seeds = [b"observations", pool.key().as_ref()],
+        bump
+    )]
+    pub observations: Box<Account<'info, PriceObservations>>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Create a pool's observation buffer, seeded with the accumulator now.
+/// Anyone can pay for it.
+pub fn handler(ctx: Context<InitObservations>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let observations = &mut ctx.accounts.observations;
+    observations.pool = ctx.accounts.pool.key();
+    observations.record(Observation {
+        timestamp: now,
+        price_cumulative: ctx.accounts.pool.price_cumulative_at(now),
+    });
+    Ok(())
+}
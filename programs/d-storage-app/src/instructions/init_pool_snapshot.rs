@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{LiquidityPool, PoolSnapshot, PriceObservations};
+
+#[derive(Accounts)]
+pub struct InitPoolSnapshot<'info> {
+    #[account(
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(
+        seeds = [b"observations", pool.key().as_ref()],
+        bump
+    )]
+    pub observations: Box<Account<'info, PriceObservations>>,
+    #[account(
+        init,
+        payer = payer,
+        space = PoolSnapshot::LEN,
+        seeds = [b"pool_snapshot", pool.key().as_ref()],
+        bump
+    )]
+    pub pool_snapshot: Account<'info, PoolSnapshot>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Create a pool's snapshot account, filled from the pool now. Anyone can
+/// pay for it; the pool needs an observation buffer for the TWAP.
+pub fn handler(ctx: Context<InitPoolSnapshot>) -> Result<()> {
+    let clock = Clock::get()?;
+    let pool_snapshot = &mut ctx.accounts.pool_snapshot;
+    pool_snapshot.pool = ctx.accounts.pool.key();
+    pool_snapshot.refresh(
+        &ctx.accounts.pool,
+        &ctx.accounts.observations,
+        clock.unix_timestamp,
+        clock.slot,
+    );
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::program::FlexibleTokenExchange;
+use crate::state::ProtocolConfig;
+
+#[derive(Accounts)]
+pub struct InitProtocolConfig<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = ProtocolConfig::LEN,
+        seeds = [b"protocol_config"],
+        bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()) @ ExchangeError::Unauthorized)]
+    pub program: Program<'info, FlexibleTokenExchange>,
+    /// Only the program's upgrade authority may create the config
+    #[account(constraint = program_data.upgrade_authority_address == Some(authority.key()) @ ExchangeError::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitProtocolConfig>, admin: Pubkey) -> Result<()> {
+    let protocol_config = &mut ctx.accounts.protocol_config;
+    protocol_config.admin = admin;
+    protocol_config.created_at = Clock::get()?.unix_timestamp;
+    Ok(())
+}
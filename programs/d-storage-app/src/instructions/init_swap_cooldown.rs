@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{LiquidityPool, SwapCooldown};
+
+#[derive(Accounts)]
+pub struct InitSwapCooldown<'info> {
+    #[account(
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(
+        init,
+        payer = rent_payer,
+        space = SwapCooldown::LEN,
+        seeds = [b"swap_cooldown", pool.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub swap_cooldown: Account<'info, SwapCooldown>,
+    pub user: Signer<'info>,
+    /// Funds the account, the user or a sponsoring dApp
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitSwapCooldown>) -> Result<()> {
+    let swap_cooldown = &mut ctx.accounts.swap_cooldown;
+    swap_cooldown.pool = ctx.accounts.pool.key();
+    swap_cooldown.wallet = ctx.accounts.user.key();
+    swap_cooldown.last_swap_slot = 0;
+    swap_cooldown.rent_payer = ctx.accounts.rent_payer.key();
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{LiquidityPool, TvlCheckpoint, TvlHistory};
+
+#[derive(Accounts)]
+pub struct InitTvlHistory<'info> {
+    #[account(
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(
+        init,
+        payer = payer,
+        space = TvlHistory::LEN,
+        seeds = [b"tvl_history", pool.key().as_ref()],
+        bump
+    )]
+    pub tvl_history: Box<Account<'info, TvlHistory>>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Create a pool's TVL history, seeded with a checkpoint of the pool now.
+/// Anyone can pay for it.
+pub fn handler(ctx: Context<InitTvlHistory>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let tvl_history = &mut ctx.accounts.tvl_history;
+    tvl_history.pool = ctx.accounts.pool.key();
+    tvl_history.record(TvlCheckpoint::of_pool(&ctx.accounts.pool, now));
+    Ok(())
+}
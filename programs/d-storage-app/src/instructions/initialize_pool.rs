@@ -0,0 +1,284 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::errors::ExchangeError;
+use crate::events::PoolCreationSponsoredEvent;
+use crate::state::{CurveType, LiquidityPool, PoolFlags, PoolIndexPage, PoolRegistry, ProtocolConfig, TokenBadge};
+use super::add_liquidity::mint_lp_tokens;
+
+#[derive(Accounts)]
+pub struct InitializePool<'info> {
+    /// CHECK: Token mint account - must be a valid SPL token mint
+    #[account(
+        constraint = token_mint.mint_authority.is_some() @ ExchangeError::InvalidTokenMint
+    )]
+    pub token_mint: Account<'info, Mint>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = LiquidityPool::LEN,
+        seeds = [b"pool", token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Pool authority PDA
+    #[account(
+        seeds = [b"pool_authority", token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+    
+    #[account(
+        init,
+        payer = payer,
+        token::mint = token_mint,
+        token::authority = pool_authority,
+        seeds = [b"token_vault", token_mint.key().as_ref()],
+        bump
+    )]
+    pub token_vault: Account<'info, TokenAccount>,
+    
+    /// CHECK: SOL vault - will be created as System Program owned account
+    #[account(
+        mut,
+        seeds = [b"sol_vault", token_mint.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: AccountInfo<'info>,
+    
+    /// Left behind by `reap_pool`, so a re-created pool reuses it
+    #[account(
+        init_if_needed,
+        payer = payer,
+        mint::decimals = 6,
+        mint::authority = pool_authority,
+        seeds = [b"lp_mint", token_mint.key().as_ref()],
+        bump
+    )]
+    pub lp_mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = authority
+    )]
+    pub authority_token_account: Account<'info, TokenAccount>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+    
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PoolRegistry::LEN,
+        seeds = [b"pool_registry"],
+        bump
+    )]
+    pub pool_registry: Box<Account<'info, PoolRegistry>>,
+    
+    /// Index page the new pool is appended to
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PoolIndexPage::LEN,
+        seeds = [b"pool_index", pool_registry.next_page().to_le_bytes().as_ref()],
+        bump
+    )]
+    pub pool_index_page: Box<Account<'info, PoolIndexPage>>,
+    
+    /// Badge of a vetted token mint, recorded on the pool when present
+    #[account(
+        seeds = [b"token_badge", token_mint.key().as_ref()],
+        bump
+    )]
+    pub token_badge: Option<Account<'info, TokenBadge>>,
+    
+    /// Pays the rent of the created accounts: the creator, or a launchpad
+    /// sponsoring the pool
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Protocol admin co-signing a fee outside the protocol range
+    pub protocol_admin: Option<Signer<'info>>,
+    
+    /// Receives the initial LP tokens
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = lp_mint,
+        associated_token::authority = authority
+    )]
+    pub authority_lp_account: Box<Account<'info, TokenAccount>>,
+    
+    /// Protocol-wide emergency stop status and fee range
+    #[account(seeds = [b"protocol_config"], bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+}
+
+pub fn handler(
+    ctx: Context<InitializePool>,
+    initial_token_amount: u64,
+    initial_sol_amount: u64,
+    fee_rate: u16,
+    curve_type: CurveType,
+) -> Result<u64> {
+    let protocol_config = &ctx.accounts.protocol_config;
+    // Validate fee rate, 0 picks the protocol default
+    let fee_rate = protocol_config.resolve_fee(fee_rate);
+    require!(fee_rate <= 1000, ExchangeError::InvalidFeeRate); // Max 10%
+    require!(
+        protocol_config.fee_allowed(fee_rate, ctx.accounts.protocol_admin.as_ref().map(|admin| admin.key())),
+        ExchangeError::FeeOutsideProtocolRange
+    );
+    require!(protocol_config.deposits_enabled(), ExchangeError::ProtocolHalted);
+    require!(ctx.accounts.lp_mint.supply == 0, ExchangeError::LpMintInUse);
+    
+    // Create SOL vault as System Program owned account
+    let token_mint = ctx.accounts.token_mint.key();
+    create_sol_vault(
+        &ctx.accounts.system_program,
+        &ctx.accounts.payer,
+        &ctx.accounts.authority,
+        &ctx.accounts.sol_vault,
+        &[b"sol_vault", token_mint.as_ref(), &[ctx.bumps.sol_vault]],
+        initial_sol_amount,
+    )?;
+    
+    let pool = &mut ctx.accounts.pool;
+    pool.token_mint = ctx.accounts.token_mint.key();
+    pool.token_vault = ctx.accounts.token_vault.key();
+    pool.sol_vault = ctx.accounts.sol_vault.key();
+    pool.lp_mint = ctx.accounts.lp_mint.key();
+    pool.token_reserve = initial_token_amount;
+    pool.sol_reserve = initial_sol_amount;
+    pool.lp_supply = 0;
+    pool.lp_fee_bps = fee_rate;
+    pool.curve_type = curve_type;
+    pool.flags = PoolFlags::DEFAULT | PoolFlags::ENABLED_BITS;
+    pool.pool_authority = ctx.accounts.pool_authority.key();
+    pool.is_initialized = true;
+    pool.created_at = Clock::get()?.unix_timestamp;
+    pool.price_last_updated = pool.created_at;
+    pool.admin = Some(ctx.accounts.authority.key());
+    pool.badged = ctx.accounts.token_badge.is_some();
+    pool.sunset_at = 0;
+    pool.lp_allowlist_enabled = false;
+    pool.token_decimals = ctx.accounts.token_mint.decimals;
+    pool.quote_decimals = 9;
+    pool.decimals_recorded = true;
+    pool.indexed = true;
+    ctx.accounts.pool_registry.append(&mut ctx.accounts.pool_index_page, pool.key())?;
+    
+    // Transfer initial liquidity
+    if initial_token_amount > 0 {
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.authority_token_account.to_account_info(),
+                    to: ctx.accounts.token_vault.to_account_info(),
+                    authority: ctx.accounts.authority.to_account_info(),
+                },
+            ),
+            initial_token_amount,
+        )?;
+    }
+
+    // Mint initial LP tokens (geometric mean of reserves)
+    let initial_lp_tokens = (initial_token_amount as f64 * initial_sol_amount as f64).sqrt() as u64;
+    pool.lp_supply = initial_lp_tokens;
+    mint_lp_tokens(
+        ctx.accounts.token_program.as_ref(),
+        ctx.accounts.lp_mint.as_ref(),
+        ctx.accounts.authority_lp_account.as_ref().as_ref(),
+        &ctx.accounts.pool_authority,
+        token_mint,
+        ctx.bumps.pool_authority,
+        initial_lp_tokens,
+    )?;
+    
+    emit_creation_sponsored(
+        &ctx.accounts.payer,
+        &ctx.accounts.authority,
+        pool.key(),
+        &[
+            &pool.to_account_info(),
+            &ctx.accounts.token_vault.to_account_info(),
+            &ctx.accounts.sol_vault,
+            &ctx.accounts.lp_mint.to_account_info(),
+            &ctx.accounts.authority_lp_account.to_account_info(),
+        ],
+    )?;
+
+    Ok(initial_lp_tokens)
+}
+
+/// Create the system-owned SOL vault, its rent paid by `payer` and the
+/// initial SOL by `creator`
+pub fn create_sol_vault<'info>(
+    system_program: &Program<'info, System>,
+    payer: &Signer<'info>,
+    creator: &Signer<'info>,
+    sol_vault: &AccountInfo<'info>,
+    sol_vault_seeds: &[&[u8]],
+    initial_sol_amount: u64,
+) -> Result<()> {
+    anchor_lang::system_program::create_account(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            anchor_lang::system_program::CreateAccount {
+                from: payer.to_account_info(),
+                to: sol_vault.clone(),
+            },
+            &[sol_vault_seeds],
+        ),
+        Rent::get()?.minimum_balance(0),
+        0,
+        &anchor_lang::system_program::ID,
+    )?;
+    if initial_sol_amount > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: creator.to_account_info(),
+                    to: sol_vault.clone(),
+                },
+            ),
+            initial_sol_amount,
+        )?;
+    }
+    Ok(())
+}
+
+/// Announce a pool whose account rent a launchpad paid for the creator,
+/// when `payer` is not the creator
+pub fn emit_creation_sponsored(
+    payer: &Signer,
+    creator: &Signer,
+    pool: Pubkey,
+    created_accounts: &[&AccountInfo],
+) -> Result<()> {
+    if payer.key() == creator.key() {
+        return Ok(());
+    }
+    let rent = Rent::get()?;
+    emit!(PoolCreationSponsoredEvent {
+        pool,
+        sponsor: payer.key(),
+        creator: creator.key(),
+        rent: created_accounts
+            .iter()
+            .map(|account| rent.minimum_balance(account.data_len()))
+            .sum(),
+    });
+    Ok(())
+}
@@ -0,0 +1,228 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::errors::ExchangeError;
+use crate::state::{CurveType, LiquidityPool, PoolFlags, PoolIndexPage, PoolRegistry, ProtocolConfig, TokenBadge};
+use super::add_liquidity::mint_lp_tokens;
+use super::initialize_pool::emit_creation_sponsored;
+
+#[derive(Accounts)]
+pub struct InitializeQuotePool<'info> {
+    #[account(
+        constraint = token_mint.mint_authority.is_some() @ ExchangeError::InvalidTokenMint
+    )]
+    pub token_mint: Account<'info, Mint>,
+    
+    /// Quote token (e.g. USDC). Native SOL pools use `initialize_pool` instead.
+    #[account(
+        constraint = quote_mint.key() != token_mint.key() @ ExchangeError::InvalidQuoteMint,
+        constraint = quote_mint.key() != token::spl_token::native_mint::ID @ ExchangeError::InvalidQuoteMint
+    )]
+    pub quote_mint: Account<'info, Mint>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = LiquidityPool::LEN,
+        seeds = [b"pool", token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Pool authority PDA
+    #[account(
+        seeds = [b"pool_authority", token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+    
+    #[account(
+        init,
+        payer = payer,
+        token::mint = token_mint,
+        token::authority = pool_authority,
+        seeds = [b"token_vault", token_mint.key().as_ref()],
+        bump
+    )]
+    pub token_vault: Account<'info, TokenAccount>,
+    
+    /// Quote token vault, created at the address SOL pools use for their SOL vault
+    #[account(
+        init,
+        payer = payer,
+        token::mint = quote_mint,
+        token::authority = pool_authority,
+        seeds = [b"sol_vault", token_mint.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: Account<'info, TokenAccount>,
+    
+    /// Left behind by `reap_pool`, so a re-created pool reuses it
+    #[account(
+        init_if_needed,
+        payer = payer,
+        mint::decimals = 6,
+        mint::authority = pool_authority,
+        seeds = [b"lp_mint", token_mint.key().as_ref()],
+        bump
+    )]
+    pub lp_mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = authority
+    )]
+    pub authority_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        associated_token::mint = quote_mint,
+        associated_token::authority = authority
+    )]
+    pub authority_quote_account: Account<'info, TokenAccount>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+    
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PoolRegistry::LEN,
+        seeds = [b"pool_registry"],
+        bump
+    )]
+    pub pool_registry: Box<Account<'info, PoolRegistry>>,
+    
+    /// Index page the new pool is appended to
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PoolIndexPage::LEN,
+        seeds = [b"pool_index", pool_registry.next_page().to_le_bytes().as_ref()],
+        bump
+    )]
+    pub pool_index_page: Box<Account<'info, PoolIndexPage>>,
+    
+    /// Badge of a vetted token mint, recorded on the pool when present
+    #[account(
+        seeds = [b"token_badge", token_mint.key().as_ref()],
+        bump
+    )]
+    pub token_badge: Option<Account<'info, TokenBadge>>,
+    
+    /// Pays the rent of the created accounts: the creator, or a launchpad
+    /// sponsoring the pool
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Protocol admin co-signing a fee outside the protocol range
+    pub protocol_admin: Option<Signer<'info>>,
+    
+    /// Receives the initial LP tokens
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = lp_mint,
+        associated_token::authority = authority
+    )]
+    pub authority_lp_account: Box<Account<'info, TokenAccount>>,
+    
+    /// Protocol-wide emergency stop status and fee range
+    #[account(seeds = [b"protocol_config"], bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeQuotePool>,
+    initial_token_amount: u64,
+    initial_quote_amount: u64,
+    fee_rate: u16,
+    curve_type: CurveType,
+) -> Result<u64> {
+    let protocol_config = &ctx.accounts.protocol_config;
+    let fee_rate = protocol_config.resolve_fee(fee_rate);
+    require!(fee_rate <= 1000, ExchangeError::InvalidFeeRate); // Max 10%
+    require!(
+        protocol_config.fee_allowed(fee_rate, ctx.accounts.protocol_admin.as_ref().map(|admin| admin.key())),
+        ExchangeError::FeeOutsideProtocolRange
+    );
+    require!(protocol_config.deposits_enabled(), ExchangeError::ProtocolHalted);
+    require!(ctx.accounts.lp_mint.supply == 0, ExchangeError::LpMintInUse);
+    
+    // Transfer initial liquidity
+    for (from, to, amount) in [
+        (&ctx.accounts.authority_token_account, &ctx.accounts.token_vault, initial_token_amount),
+        (&ctx.accounts.authority_quote_account, &ctx.accounts.sol_vault, initial_quote_amount),
+    ] {
+        if amount > 0 {
+            token::transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: from.to_account_info(),
+                        to: to.to_account_info(),
+                        authority: ctx.accounts.authority.to_account_info(),
+                    },
+                ),
+                amount,
+            )?;
+        }
+    }
+    
+    let pool = &mut ctx.accounts.pool;
+    pool.token_mint = ctx.accounts.token_mint.key();
+    pool.token_vault = ctx.accounts.token_vault.key();
+    pool.sol_vault = ctx.accounts.sol_vault.key();
+    pool.lp_mint = ctx.accounts.lp_mint.key();
+    pool.token_reserve = initial_token_amount;
+    pool.sol_reserve = initial_quote_amount;
+    pool.lp_fee_bps = fee_rate;
+    pool.curve_type = curve_type;
+    pool.flags = PoolFlags::DEFAULT | PoolFlags::ENABLED_BITS;
+    pool.pool_authority = ctx.accounts.pool_authority.key();
+    pool.is_initialized = true;
+    pool.created_at = Clock::get()?.unix_timestamp;
+    pool.price_last_updated = pool.created_at;
+    pool.admin = Some(ctx.accounts.authority.key());
+    pool.badged = ctx.accounts.token_badge.is_some();
+    pool.quote_mint = ctx.accounts.quote_mint.key();
+    pool.token_decimals = ctx.accounts.token_mint.decimals;
+    pool.quote_decimals = ctx.accounts.quote_mint.decimals;
+    pool.decimals_recorded = true;
+    pool.indexed = true;
+    ctx.accounts.pool_registry.append(&mut ctx.accounts.pool_index_page, pool.key())?;
+    
+    // Mint initial LP tokens (geometric mean of reserves)
+    pool.lp_supply = (initial_token_amount as f64 * initial_quote_amount as f64).sqrt() as u64;
+    mint_lp_tokens(
+        ctx.accounts.token_program.as_ref(),
+        ctx.accounts.lp_mint.as_ref(),
+        ctx.accounts.authority_lp_account.as_ref().as_ref(),
+        &ctx.accounts.pool_authority,
+        pool.token_mint,
+        ctx.bumps.pool_authority,
+        pool.lp_supply,
+    )?;
+    
+    emit_creation_sponsored(
+        &ctx.accounts.payer,
+        &ctx.accounts.authority,
+        pool.key(),
+        &[
+            &pool.to_account_info(),
+            &ctx.accounts.token_vault.to_account_info(),
+            &ctx.accounts.sol_vault.to_account_info(),
+            &ctx.accounts.lp_mint.to_account_info(),
+            &ctx.accounts.authority_lp_account.to_account_info(),
+        ],
+    )?;
+    
+    Ok(pool.lp_supply)
+}
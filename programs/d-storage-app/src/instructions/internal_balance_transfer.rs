@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::errors::ExchangeError;
+use crate::internal_vault::InternalVault;
+use crate::state::{InternalBalance, ProtocolConfig};
+
+#[derive(Accounts)]
+pub struct InternalBalanceTransfer<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"internal_balance", user.key().as_ref(), internal_balance.mint.as_ref()],
+        bump
+    )]
+    pub internal_balance: Account<'info, InternalBalance>,
+    /// CHECK: Internal vault PDA, holds SOL balances
+    #[account(
+        mut,
+        seeds = [b"internal_vault"],
+        bump
+    )]
+    pub internal_vault: AccountInfo<'info>,
+    /// Internal token vault for the balance mint, required for SPL tokens
+    #[account(
+        mut,
+        seeds = [b"internal_vault", internal_balance.mint.as_ref()],
+        bump
+    )]
+    pub internal_token_vault: Option<Account<'info, TokenAccount>>,
+    /// User's token account for the balance mint, required for SPL tokens
+    #[account(
+        mut,
+        token::mint = internal_balance.mint,
+        token::authority = user
+    )]
+    pub user_token_account: Option<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    /// Protocol-wide emergency stop status
+    #[account(seeds = [b"protocol_config"], bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+}
+
+impl<'info> InternalBalanceTransfer<'info> {
+    pub fn internal_vault(&self, bump: u8) -> InternalVault<'_, 'info> {
+        InternalVault {
+            vault: &self.internal_vault,
+            bump,
+            token_program: self.token_program.as_ref(),
+            system_program: self.system_program.as_ref(),
+        }
+    }
+
+    /// Internal token vault and user token account, `None` for SOL balances
+    pub fn token_accounts(&self) -> Result<Option<(AccountInfo<'info>, AccountInfo<'info>)>> {
+        if self.internal_balance.is_native() {
+            return Ok(None);
+        }
+        match (&self.internal_token_vault, &self.user_token_account) {
+            (Some(internal_token_vault), Some(user_token_account)) => Ok(Some((
+                internal_token_vault.to_account_info(),
+                user_token_account.to_account_info(),
+            ))),
+            _ => err!(ExchangeError::InvalidInternalVault),
+        }
+    }
+}
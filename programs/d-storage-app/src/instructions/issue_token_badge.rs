@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::errors::ExchangeError;
+use crate::events::TokenBadgeEvent;
+use crate::state::{ProtocolConfig, TokenBadge};
+
+#[derive(Accounts)]
+pub struct IssueTokenBadge<'info> {
+    #[account(
+        seeds = [b"protocol_config"],
+        bump,
+        constraint = protocol_config.admin == admin.key() @ ExchangeError::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub mint: Account<'info, Mint>,
+    #[account(
+        init,
+        payer = admin,
+        space = TokenBadge::LEN,
+        seeds = [b"token_badge", mint.key().as_ref()],
+        bump
+    )]
+    pub token_badge: Account<'info, TokenBadge>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<IssueTokenBadge>) -> Result<()> {
+    let token_badge = &mut ctx.accounts.token_badge;
+    token_badge.mint = ctx.accounts.mint.key();
+    token_badge.issued_by = ctx.accounts.admin.key();
+    token_badge.issued_at = Clock::get()?.unix_timestamp;
+    
+    emit!(TokenBadgeEvent {
+        mint: token_badge.mint,
+        badged: true,
+        admin: token_badge.issued_by,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+
+use crate::errors::ExchangeError;
+use super::lp_lock_transfer::LpLockTransfer;
+
+pub fn handler(ctx: Context<LpLockTransfer>, amount: u64) -> Result<()> {
+    require!(
+        ctx.accounts.protocol_config.deposits_enabled(),
+        ExchangeError::ProtocolHalted
+    );
+    let reward = ctx.accounts.claim_reward(ctx.bumps.pool_authority)?;
+    
+    if amount > 0 {
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.user_lp_account.to_account_info(),
+                    to: ctx.accounts.lp_lock_vault.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+    }
+    
+    let reward_per_share = ctx.accounts.pool.lp_lock_reward_per_share;
+    let lp_lock = &mut ctx.accounts.lp_lock;
+    lp_lock.amount += amount;
+    lp_lock.sync_reward_debt(reward_per_share);
+    ctx.accounts.pool.lp_locked_total += amount;
+    
+    ctx.accounts.emit_event(amount, true, reward);
+    
+    Ok(())
+}
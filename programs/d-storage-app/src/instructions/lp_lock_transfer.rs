@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::events::LpLockEvent;
+use crate::state::{LiquidityPool, LpLock, ProtocolConfig};
+
+#[derive(Accounts)]
+pub struct LpLockTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        mut,
+        token::mint = pool.lp_mint,
+        token::authority = user
+    )]
+    pub user_lp_account: Account<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = LpLock::LEN,
+        seeds = [b"lp_lock", pool.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub lp_lock: Account<'info, LpLock>,
+    /// CHECK: Pool authority PDA, owns the lock and reward vaults
+    #[account(
+        seeds = [b"pool_authority", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"lp_lock_vault", pool.key().as_ref()],
+        bump
+    )]
+    pub lp_lock_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"lp_reward_vault", pool.key().as_ref()],
+        bump
+    )]
+    pub lp_reward_vault: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    /// Protocol-wide emergency stop status
+    #[account(seeds = [b"protocol_config"], bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+}
+
+impl<'info> LpLockTransfer<'info> {
+    /// Pay the locker's share of penalties collected since the last claim
+    pub fn claim_reward(&mut self, pool_authority_bump: u8) -> Result<u64> {
+        let lp_lock = &mut self.lp_lock;
+        lp_lock.pool = self.pool.key();
+        lp_lock.owner = self.user.key();
+        
+        let reward = lp_lock.pending_reward(self.pool.lp_lock_reward_per_share);
+        lp_lock.sync_reward_debt(self.pool.lp_lock_reward_per_share);
+        self.pay_from_vault(&self.lp_reward_vault, pool_authority_bump, reward)?;
+        Ok(reward)
+    }
+
+    /// Move LP tokens out of one of the pool's LP vaults to the user
+    pub fn pay_from_vault(
+        &self,
+        vault: &Account<'info, TokenAccount>,
+        pool_authority_bump: u8,
+        amount: u64,
+    ) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        let token_mint = self.pool.token_mint;
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: vault.to_account_info(),
+                    to: self.user_lp_account.to_account_info(),
+                    authority: self.pool_authority.to_account_info(),
+                },
+                &[&[b"pool_authority", token_mint.as_ref(), &[pool_authority_bump]]],
+            ),
+            amount,
+        )
+    }
+
+    /// Record a lock, unlock or claim
+    pub fn emit_event(&self, amount: u64, locked: bool, reward_claimed: u64) {
+        emit!(LpLockEvent {
+            pool: self.pool.key(),
+            owner: self.user.key(),
+            amount,
+            locked,
+            reward_claimed,
+        });
+    }
+}
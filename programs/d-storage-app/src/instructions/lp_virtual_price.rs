@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::events::LpVirtualPriceEvent;
+use crate::state::LiquidityPool;
+
+#[derive(Accounts)]
+pub struct LpVirtualPrice<'info> {
+    #[account(
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+}
+
+/// Value of one LP base unit in quote terms as Q64.64. Also emitted as an
+/// event so keepers calling it on a schedule leave a price history.
+pub fn handler(ctx: Context<LpVirtualPrice>) -> Result<u128> {
+    let pool = &ctx.accounts.pool;
+    let virtual_price_x64 = pool.lp_virtual_price_x64();
+    
+    emit!(LpVirtualPriceEvent {
+        pool: pool.key(),
+        virtual_price_x64,
+        lp_supply: pool.lp_supply,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(virtual_price_x64)
+}
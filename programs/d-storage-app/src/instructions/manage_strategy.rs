@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::errors::ExchangeError;
+use crate::events::StrategyWithdrawnEvent;
+use crate::state::{LiquidityPool, ProtocolConfig};
+use crate::strategy::{StrategyCpi, lending_adapter};
+
+#[derive(Accounts)]
+pub struct ManageStrategy<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.is_admin(&admin.key(), admin_nft_account.as_deref()) @ ExchangeError::Unauthorized,
+        has_one = token_vault @ ExchangeError::InvalidVault
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    /// CHECK: Pool authority PDA
+    #[account(
+        seeds = [b"pool_authority", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"token_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub token_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = pool.strategy_collateral_account @ ExchangeError::InvalidStrategyAccounts
+    )]
+    pub strategy_collateral_account: Account<'info, TokenAccount>,
+    /// CHECK: Lending program recorded on the pool
+    #[account(address = pool.strategy_program @ ExchangeError::UnsupportedStrategy)]
+    pub strategy_program: AccountInfo<'info>,
+    pub admin: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    /// Admin NFT held by the signer, required once the pool is bound to one
+    pub admin_nft_account: Option<Account<'info, TokenAccount>>,
+    /// Protocol-wide emergency stop status
+    #[account(seeds = [b"protocol_config"], bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+}
+
+impl<'info> ManageStrategy<'info> {
+    pub fn strategy_cpi<'a>(
+        &'a self,
+        remaining_accounts: &'a [AccountInfo<'info>],
+        signer_seeds: &'a [&'a [&'a [u8]]],
+    ) -> StrategyCpi<'a, 'info> {
+        StrategyCpi {
+            lending_program: &self.strategy_program,
+            token_vault: self.token_vault.as_ref(),
+            collateral_account: self.strategy_collateral_account.as_ref(),
+            pool_authority: &self.pool_authority,
+            token_program: self.token_program.as_ref(),
+            remaining_accounts,
+            signer_seeds,
+        }
+    }
+
+    /// Redeem collateral and split what comes back into principal and yield
+    pub fn redeem(
+        &mut self,
+        remaining_accounts: &[AccountInfo<'info>],
+        pool_authority_bump: u8,
+        collateral_amount: u64,
+        emergency: bool,
+    ) -> Result<()> {
+        let token_mint = self.pool.token_mint;
+        let signer_seeds: &[&[&[u8]]] = &[&[b"pool_authority", token_mint.as_ref(), &[pool_authority_bump]]];
+        let token_vault = self.token_vault.to_account_info();
+        let cpi = StrategyCpi {
+            lending_program: &self.strategy_program,
+            token_vault: &token_vault,
+            collateral_account: self.strategy_collateral_account.as_ref(),
+            pool_authority: &self.pool_authority,
+            token_program: self.token_program.as_ref(),
+            remaining_accounts,
+            signer_seeds,
+        };
+        redeem_collateral(&mut self.pool, &mut self.token_vault, &cpi, collateral_amount, emergency)
+    }
+}
+
+/// Redeem `collateral_amount` through the pool's lending adapter and split
+/// what comes back into principal and yield
+pub fn redeem_collateral(
+    pool: &mut Account<LiquidityPool>,
+    token_vault: &mut Account<TokenAccount>,
+    cpi: &StrategyCpi,
+    collateral_amount: u64,
+    emergency: bool,
+) -> Result<()> {
+    require!(
+        collateral_amount > 0 && collateral_amount <= pool.strategy_collateral,
+        ExchangeError::InvalidStrategyAmount
+    );
+    
+    let vault_before = token_vault.amount;
+    let adapter = lending_adapter(&pool.strategy_program).ok_or(ExchangeError::UnsupportedStrategy)?;
+    adapter.withdraw(cpi, collateral_amount)?;
+    token_vault.reload()?;
+    let tokens_received = token_vault.amount - vault_before;
+    
+    let principal = pool.strategy_principal(collateral_amount);
+    let yield_amount = tokens_received.saturating_sub(principal);
+    // A redemption at a loss takes the shortfall out of the reserve
+    let loss = principal.saturating_sub(tokens_received);
+    
+    pool.strategy_deployed -= principal;
+    pool.strategy_collateral -= collateral_amount;
+    pool.strategy_yield += yield_amount;
+    pool.update_price_cumulative(Clock::get()?.unix_timestamp);
+    pool.token_reserve = pool.token_reserve + yield_amount - loss;
+    
+    emit!(StrategyWithdrawnEvent {
+        pool: pool.key(),
+        collateral_amount,
+        tokens_received,
+        yield_amount,
+        loss,
+        emergency,
+    });
+    
+    assert_pool_invariants!(pool, Some(token_vault.as_ref()), None, None);
+    Ok(())
+}
@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use anchor_spl::token::{self, CloseAccount, TokenAccount};
+
+use crate::errors::ExchangeError;
+use crate::events::LiquidityMigratedEvent;
+use crate::instruction;
+use crate::state::MigrationSnapshot;
+use super::add_liquidity::*;
+
+#[derive(Accounts)]
+pub struct MigrateLiquidity<'info> {
+    pub deposit: AddLiquidity<'info>,
+    /// wSOL account holding the migrated SOL, closed into the user. Required
+    /// for SOL pools
+    #[account(
+        mut,
+        token::authority = deposit.user,
+        constraint = user_wsol_account.mint == token::spl_token::native_mint::ID @ ExchangeError::InvalidWsolAccount
+    )]
+    pub user_wsol_account: Option<Account<'info, TokenAccount>>,
+    /// CHECK: Instructions sysvar used to find the source AMM withdrawal
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+    /// Balances recorded by `begin_migration`, closed into the user
+    #[account(
+        mut,
+        seeds = [b"migration_snapshot", deposit.user.key().as_ref()],
+        bump
+    )]
+    pub migration_snapshot: Account<'info, MigrationSnapshot>,
+}
+
+/// AMM programs liquidity can be migrated from (Raydium AMM v4, Raydium CPMM, Orca Whirlpools)
+pub const MIGRATION_SOURCE_PROGRAMS: [Pubkey; 3] = [
+    pubkey!("675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8"),
+    pubkey!("CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C"),
+    pubkey!("whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc"),
+];
+
+/// Find the supported AMM withdrawal between this transaction's
+/// `begin_migration` and the current instruction. It must pay into both
+/// snapshotted accounts, and besides it only pool creation may run in between,
+/// so the balance increases since the snapshot are what the AMM paid out.
+fn find_migration_source(instructions: &AccountInfo, snapshot: &MigrationSnapshot) -> Result<Pubkey> {
+    let current_index = sysvar::instructions::load_current_index_checked(instructions)?;
+    require!(
+        snapshot.slot == Clock::get()?.slot && snapshot.instruction_index < current_index,
+        ExchangeError::MigrationNotBegun
+    );
+    let begin = sysvar::instructions::load_instruction_at_checked(snapshot.instruction_index as usize, instructions)?;
+    require!(
+        begin.program_id == crate::ID && begin.data.starts_with(instruction::BeginMigration::DISCRIMINATOR),
+        ExchangeError::MigrationNotBegun
+    );
+    
+    let mut source_program = None;
+    for index in snapshot.instruction_index + 1..current_index {
+        let ix = sysvar::instructions::load_instruction_at_checked(index as usize, instructions)?;
+        if MIGRATION_SOURCE_PROGRAMS.contains(&ix.program_id) {
+            let pays_into = |account: Pubkey| ix.accounts.iter().any(|meta| meta.pubkey == account);
+            require!(
+                pays_into(snapshot.token_account) && pays_into(snapshot.quote_account),
+                ExchangeError::MigrationSourceNotFound
+            );
+            source_program = Some(ix.program_id);
+        } else {
+            require!(
+                ix.program_id == crate::ID
+                    && (ix.data.starts_with(instruction::InitializePool::DISCRIMINATOR)
+                        || ix.data.starts_with(instruction::InitializeQuotePool::DISCRIMINATOR)),
+                ExchangeError::MigrationSourceNotFound
+            );
+        }
+    }
+    source_program.ok_or(error!(ExchangeError::MigrationSourceNotFound))
+}
+
+pub fn handler(
+    ctx: Context<MigrateLiquidity>,
+    token_amount: u64,
+    sol_amount: u64,
+    min_lp_tokens: u64,
+) -> Result<DepositAmounts> {
+    let snapshot = &ctx.accounts.migration_snapshot;
+    let source_program = find_migration_source(&ctx.accounts.instructions, snapshot)?;
+    
+    // Only what the withdrawal paid into the snapshotted accounts migrates
+    let deposit = &ctx.accounts.deposit;
+    let quote_account = if deposit.pool.is_native_quote() {
+        ctx.accounts.user_wsol_account.as_ref().ok_or(ExchangeError::InvalidWsolAccount)?
+    } else {
+        deposit.user_quote_account.as_ref().ok_or(ExchangeError::InvalidQuoteAccount)?
+    };
+    require!(
+        deposit.user_token_account.key() == snapshot.token_account && quote_account.key() == snapshot.quote_account,
+        ExchangeError::MigrationSourceNotFound
+    );
+    let (token_received, quote_received) = snapshot.received(deposit.user_token_account.amount, quote_account.amount);
+    require!(
+        token_amount <= token_received && sol_amount <= quote_received,
+        ExchangeError::MigrationExceedsWithdrawal
+    );
+    ctx.accounts.migration_snapshot.close(ctx.accounts.deposit.user.to_account_info())?;
+    
+    // Withdrawals from other AMMs usually pay out wSOL, unwrap it first
+    if let Some(wsol_account) = &ctx.accounts.user_wsol_account {
+        token::close_account(CpiContext::new(
+            ctx.accounts.deposit.token_program.to_account_info(),
+            CloseAccount {
+                account: wsol_account.to_account_info(),
+                destination: ctx.accounts.deposit.user.to_account_info(),
+                authority: ctx.accounts.deposit.user.to_account_info(),
+            },
+        ))?;
+    }
+    
+    let deposited =
+        ctx.accounts.deposit.deposit(&ctx.bumps.deposit, token_amount, sol_amount, min_lp_tokens)?;
+    
+    emit!(LiquidityMigratedEvent {
+        pool: ctx.accounts.deposit.pool.key(),
+        user: ctx.accounts.deposit.user.key(),
+        source_program,
+        token_amount: deposited.token_amount,
+        sol_amount: deposited.sol_amount,
+        lp_tokens: deposited.lp_tokens,
+    });
+    
+    assert_pool_invariants!(
+        &ctx.accounts.deposit.pool,
+        Some(ctx.accounts.deposit.token_vault.as_ref()),
+        Some(&ctx.accounts.deposit.sol_vault),
+        Some(ctx.accounts.deposit.lp_mint.as_ref())
+    );
+    Ok(deposited)
+}
@@ -0,0 +1,283 @@
+// Every instruction module exposes its entrypoint as `handler`; the program
+// calls them by path, so the colliding glob re-exports are never used
+#![allow(ambiguous_glob_reexports)]
+
+pub mod accept_lp_mint_handoff;
+pub mod add_liquidity;
+pub mod add_liquidity_internal;
+pub mod add_lp_allowlist_entry;
+pub mod approve_custody_program;
+pub mod assign_vault_custody;
+pub mod begin_migration;
+pub mod bind_admin_nft;
+pub mod burn_lp_and_prove;
+pub mod buy_and_lp;
+pub mod buy_from_exit_auction;
+pub mod cancel_emergency_drain;
+pub mod cancel_launch_auction;
+pub mod checkpoint_tvl;
+pub mod claim;
+pub mod claim_auction_bid;
+pub mod claim_royalties;
+pub mod claim_vested_lp;
+pub mod clear_pool_flags;
+pub mod close_exit_auction;
+pub mod close_internal_balance;
+pub mod close_order_nonce;
+pub mod close_referral_code;
+pub mod close_swap_cooldown;
+pub mod close_swap_stream;
+pub mod collect_protocol_fees;
+pub mod commit_auction_bid;
+pub mod compound_lp_lock;
+pub mod configure_emergency_recovery;
+pub mod configure_launch_cooldown;
+pub mod configure_lp_lock;
+pub mod configure_oracle;
+pub mod configure_rebalance_incentive;
+pub mod configure_stake_discount;
+pub mod configure_strategy;
+pub mod crank_observation;
+pub mod create_referral_code;
+pub mod create_token_and_pool;
+pub mod deploy_to_strategy;
+pub mod deposit_internal_balance;
+pub mod deregister_crank_job;
+pub mod discount_stake_transfer;
+pub mod emergency_derisk;
+pub mod emergency_drain;
+pub mod execute_swap_stream;
+pub mod finalize_launch_auction;
+pub mod fund_epoch;
+pub mod get_fee_apr;
+pub mod get_position_report;
+pub mod get_tvl_metrics;
+pub mod get_version;
+pub mod guardian_pause;
+pub mod guardian_unpause;
+pub mod init_breaker_history;
+pub mod init_crank_registry;
+pub mod init_internal_balance;
+pub mod init_internal_vault;
+pub mod init_observations;
+pub mod init_pool_snapshot;
+pub mod init_protocol_config;
+pub mod init_swap_cooldown;
+pub mod init_tvl_history;
+pub mod initialize_pool;
+pub mod initialize_quote_pool;
+pub mod internal_balance_transfer;
+pub mod issue_token_badge;
+pub mod lock_lp;
+pub mod lp_lock_transfer;
+pub mod lp_virtual_price;
+pub mod manage_strategy;
+pub mod migrate_liquidity;
+pub mod observe;
+pub mod open_swap_stream;
+pub mod propose_lp_mint_handoff;
+pub mod propose_param_change;
+pub mod prune_lp_allowlist_entry;
+pub mod quote_add_liquidity;
+pub mod quote_remove_liquidity;
+pub mod reap_pool;
+pub mod recall_from_strategy;
+pub mod refresh_pool_badge;
+pub mod register_crank_job;
+pub mod register_pool;
+pub mod remove_liquidity;
+pub mod remove_lp_allowlist_entry;
+pub mod renounce_authority;
+pub mod report_circuit_breaker;
+pub mod report_epoch_revenue;
+pub mod request_emergency_drain;
+pub mod resize_account;
+pub mod resolve_param_proposal;
+pub mod reveal_auction_bid;
+pub mod revoke_custody_program;
+pub mod revoke_token_badge;
+pub mod schedule_sunset;
+pub mod set_compliance_authority;
+pub mod set_creator_quota;
+pub mod set_creator_royalty;
+pub mod set_fee_holiday;
+pub mod set_fee_range;
+pub mod set_fee_splitter;
+pub mod set_fee_tiers;
+pub mod set_guardian_set;
+pub mod set_launch_quota;
+pub mod set_lp_allowlist;
+pub mod set_lp_lock_compounder;
+pub mod set_max_creator_royalty;
+pub mod set_max_partner_fee;
+pub mod set_outflow_caps;
+pub mod set_pause_flags;
+pub mod set_pool_flags;
+pub mod set_pool_freeze;
+pub mod set_protocol_admin;
+pub mod set_protocol_fee;
+pub mod set_protocol_status;
+pub mod set_reap_grace_period;
+pub mod set_referral_fee_share;
+pub mod set_root;
+pub mod settle_batch;
+pub mod settle_internal_credits;
+pub mod settle_signed_order;
+pub mod simulate_swap;
+pub mod stake_for_discount;
+pub mod start_exit_auction;
+pub mod start_launch_auction;
+pub mod swap;
+pub mod swap_internal;
+pub mod swap_legacy;
+pub mod swap_route;
+pub mod swap_split;
+pub mod swap_with_receipt;
+pub mod sweep_quote_dust;
+pub mod sync_pool_decimals;
+pub mod unlock_lp;
+pub mod unstake_for_discount;
+pub mod update_pool_fee;
+pub mod update_pool_fee_pda;
+pub mod update_pool_flags;
+pub mod update_pool_params;
+pub mod update_pool_snapshot;
+pub mod update_referral_code;
+pub mod update_sell_fee;
+pub mod verify_pool_health;
+pub mod withdraw_from_strategy;
+pub mod withdraw_internal_balance;
+
+pub use accept_lp_mint_handoff::*;
+pub use add_liquidity::*;
+pub use add_liquidity_internal::*;
+pub use add_lp_allowlist_entry::*;
+pub use approve_custody_program::*;
+pub use assign_vault_custody::*;
+pub use begin_migration::*;
+pub use bind_admin_nft::*;
+pub use burn_lp_and_prove::*;
+pub use buy_and_lp::*;
+pub use buy_from_exit_auction::*;
+pub use cancel_emergency_drain::*;
+pub use cancel_launch_auction::*;
+pub use checkpoint_tvl::*;
+pub use claim::*;
+pub use claim_auction_bid::*;
+pub use claim_royalties::*;
+pub use claim_vested_lp::*;
+pub use close_exit_auction::*;
+pub use close_internal_balance::*;
+pub use close_order_nonce::*;
+pub use close_referral_code::*;
+pub use close_swap_cooldown::*;
+pub use close_swap_stream::*;
+pub use collect_protocol_fees::*;
+pub use commit_auction_bid::*;
+pub use compound_lp_lock::*;
+pub use configure_emergency_recovery::*;
+pub use configure_launch_cooldown::*;
+pub use configure_lp_lock::*;
+pub use configure_oracle::*;
+pub use configure_rebalance_incentive::*;
+pub use configure_stake_discount::*;
+pub use configure_strategy::*;
+pub use crank_observation::*;
+pub use create_referral_code::*;
+pub use create_token_and_pool::*;
+pub use deregister_crank_job::*;
+pub use discount_stake_transfer::*;
+pub use emergency_drain::*;
+pub use execute_swap_stream::*;
+pub use finalize_launch_auction::*;
+pub use fund_epoch::*;
+pub use get_fee_apr::*;
+pub use get_position_report::*;
+pub use get_tvl_metrics::*;
+pub use get_version::*;
+pub use guardian_pause::*;
+pub use guardian_unpause::*;
+pub use init_breaker_history::*;
+pub use init_crank_registry::*;
+pub use init_internal_balance::*;
+pub use init_internal_vault::*;
+pub use init_observations::*;
+pub use init_pool_snapshot::*;
+pub use init_protocol_config::*;
+pub use init_swap_cooldown::*;
+pub use init_tvl_history::*;
+pub use initialize_pool::*;
+pub use initialize_quote_pool::*;
+pub use internal_balance_transfer::*;
+pub use issue_token_badge::*;
+pub use lp_lock_transfer::*;
+pub use lp_virtual_price::*;
+pub use manage_strategy::*;
+pub use migrate_liquidity::*;
+pub use observe::*;
+pub use open_swap_stream::*;
+pub use propose_lp_mint_handoff::*;
+pub use propose_param_change::*;
+pub use prune_lp_allowlist_entry::*;
+pub use quote_add_liquidity::*;
+pub use quote_remove_liquidity::*;
+pub use reap_pool::*;
+pub use recall_from_strategy::*;
+pub use refresh_pool_badge::*;
+pub use register_crank_job::*;
+pub use register_pool::*;
+pub use remove_liquidity::*;
+pub use remove_lp_allowlist_entry::*;
+pub use renounce_authority::*;
+pub use report_circuit_breaker::*;
+pub use report_epoch_revenue::*;
+pub use request_emergency_drain::*;
+pub use resize_account::*;
+pub use resolve_param_proposal::*;
+pub use reveal_auction_bid::*;
+pub use revoke_custody_program::*;
+pub use revoke_token_badge::*;
+pub use schedule_sunset::*;
+pub use set_compliance_authority::*;
+pub use set_creator_quota::*;
+pub use set_creator_royalty::*;
+pub use set_fee_holiday::*;
+pub use set_fee_range::*;
+pub use set_fee_splitter::*;
+pub use set_fee_tiers::*;
+pub use set_guardian_set::*;
+pub use set_launch_quota::*;
+pub use set_lp_allowlist::*;
+pub use set_lp_lock_compounder::*;
+pub use set_max_creator_royalty::*;
+pub use set_max_partner_fee::*;
+pub use set_outflow_caps::*;
+pub use set_pause_flags::*;
+pub use set_pool_freeze::*;
+pub use set_protocol_admin::*;
+pub use set_protocol_fee::*;
+pub use set_protocol_status::*;
+pub use set_reap_grace_period::*;
+pub use set_referral_fee_share::*;
+pub use set_root::*;
+pub use settle_batch::*;
+pub use settle_internal_credits::*;
+pub use settle_signed_order::*;
+pub use simulate_swap::*;
+pub use start_exit_auction::*;
+pub use start_launch_auction::*;
+pub use swap::*;
+pub use swap_internal::*;
+pub use swap_legacy::*;
+pub use swap_route::*;
+pub use swap_with_receipt::*;
+pub use sweep_quote_dust::*;
+pub use sync_pool_decimals::*;
+pub use update_pool_fee::*;
+pub use update_pool_fee_pda::*;
+pub use update_pool_flags::*;
+pub use update_pool_params::*;
+pub use update_pool_snapshot::*;
+pub use update_referral_code::*;
+pub use update_sell_fee::*;
+pub use verify_pool_health::*;
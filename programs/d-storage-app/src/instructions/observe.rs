@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::state::{LiquidityPool, Observation, PriceObservations};
+
+#[derive(Accounts)]
+pub struct Observe<'info> {
+    #[account(
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(
+        seeds = [b"observations", pool.key().as_ref()],
+        bump
+    )]
+    pub observations: Box<Account<'info, PriceObservations>>,
+}
+
+/// Time-weighted average price, quote per token as Q64.64, over each window
+/// ending now. Fails if a window reaches back before the oldest observation.
+pub fn handler(ctx: Context<Observe>, windows_secs: Vec<u32>) -> Result<Vec<u128>> {
+    let now = Clock::get()?.unix_timestamp;
+    let current = Observation {
+        timestamp: now,
+        price_cumulative: ctx.accounts.pool.price_cumulative_at(now),
+    };
+    windows_secs
+        .iter()
+        .map(|&window_secs| {
+            ctx.accounts
+                .observations
+                .twap(window_secs, current)
+                .ok_or(error!(ExchangeError::ObservationWindowUnavailable))
+        })
+        .collect()
+}
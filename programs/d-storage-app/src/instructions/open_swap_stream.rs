@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::events::SwapStreamEvent;
+use crate::state::{LiquidityPool, ProtocolConfig, SwapStream};
+use super::swap::SwapDirection;
+
+#[derive(Accounts)]
+pub struct OpenSwapStream<'info> {
+    pub owner: Signer<'info>,
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(
+        init,
+        payer = rent_payer,
+        space = SwapStream::LEN,
+        seeds = [b"swap_stream", pool.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub swap_stream: Account<'info, SwapStream>,
+    /// Funds the account, the owner or a sponsoring dApp
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    /// Protocol-wide emergency stop status
+    #[account(seeds = [b"protocol_config"], bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+}
+
+/// Stream `amount` of the owner's internal balance through the pool at up to
+/// `rate_per_slot`, never below `min_out_per_in_x64`
+pub fn handler(
+    ctx: Context<OpenSwapStream>,
+    direction: SwapDirection,
+    amount: u64,
+    rate_per_slot: u64,
+    min_out_per_in_x64: u128,
+) -> Result<()> {
+    require!(
+        ctx.accounts.protocol_config.swaps_enabled(),
+        ExchangeError::ProtocolHalted
+    );
+    require!(amount > 0 && rate_per_slot > 0, ExchangeError::InvalidAmount);
+    
+    let swap_stream = &mut ctx.accounts.swap_stream;
+    swap_stream.owner = ctx.accounts.owner.key();
+    swap_stream.pool = ctx.accounts.pool.key();
+    swap_stream.direction = direction;
+    swap_stream.rate_per_slot = rate_per_slot;
+    swap_stream.remaining = amount;
+    swap_stream.min_out_per_in_x64 = min_out_per_in_x64;
+    swap_stream.last_slot = Clock::get()?.slot;
+    swap_stream.total_in = 0;
+    swap_stream.total_out = 0;
+    swap_stream.rent_payer = ctx.accounts.rent_payer.key();
+    
+    emit!(SwapStreamEvent {
+        pool: swap_stream.pool,
+        owner: swap_stream.owner,
+        direction,
+        amount_in: 0,
+        amount_out: 0,
+        remaining: amount,
+    });
+    
+    Ok(())
+}
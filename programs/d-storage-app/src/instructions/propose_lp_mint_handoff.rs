@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::constants::LP_MINT_HANDOFF_TIMELOCK_SECS;
+use crate::errors::ExchangeError;
+use crate::events::LpMintHandoffProposedEvent;
+use crate::state::LiquidityPool;
+
+#[derive(Accounts)]
+pub struct ProposeLpMintHandoff<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.is_admin(&admin.key(), admin_nft_account.as_deref()) @ ExchangeError::Unauthorized
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    pub admin: Signer<'info>,
+    /// Admin NFT held by the signer, required once the pool is bound to one
+    pub admin_nft_account: Option<Account<'info, TokenAccount>>,
+}
+
+/// Propose handing the LP mint authority to `successor`, typically a PDA of
+/// a newer program version. The successor accepts after the timelock.
+/// Proposing the default key cancels a pending proposal.
+pub fn handler(ctx: Context<ProposeLpMintHandoff>, successor: Pubkey) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    require!(!pool.lp_mint_migrated, ExchangeError::LpMintMigrated);
+    
+    let executable_at = if successor == Pubkey::default() {
+        0
+    } else {
+        Clock::get()?.unix_timestamp + LP_MINT_HANDOFF_TIMELOCK_SECS
+    };
+    pool.lp_mint_successor = successor;
+    pool.lp_mint_handoff_at = executable_at;
+    
+    emit!(LpMintHandoffProposedEvent {
+        pool: pool.key(),
+        successor,
+        executable_at,
+    });
+    
+    Ok(())
+}
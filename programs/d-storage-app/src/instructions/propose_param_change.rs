@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::PARAM_PROPOSAL_BOND_LAMPORTS;
+use crate::events::ParamProposalEvent;
+use crate::state::{LiquidityPool, ParamChange, ParamProposal};
+
+#[derive(Accounts)]
+pub struct ProposeParamChange<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(
+        init,
+        payer = proposer,
+        space = ParamProposal::LEN,
+        seeds = [b"param_proposal", pool.key().as_ref(), &pool.param_proposal_count.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, ParamProposal>,
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Queue a parameter change for the pool admin to approve or reject,
+/// posting a bond that is slashed on rejection
+pub fn handler(ctx: Context<ProposeParamChange>, change: ParamChange) -> Result<()> {
+    change.validate()?;
+    
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.proposer.to_account_info(),
+                to: ctx.accounts.proposal.to_account_info(),
+            },
+        ),
+        PARAM_PROPOSAL_BOND_LAMPORTS,
+    )?;
+    
+    let pool = &mut ctx.accounts.pool;
+    let id = pool.param_proposal_count;
+    pool.param_proposal_count += 1;
+    
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.pool = pool.key();
+    proposal.id = id;
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.change = change;
+    proposal.bond = PARAM_PROPOSAL_BOND_LAMPORTS;
+    proposal.created_at = Clock::get()?.unix_timestamp;
+    
+    emit!(ParamProposalEvent {
+        pool: pool.key(),
+        id,
+        proposer: proposal.proposer,
+        change,
+        bond: proposal.bond,
+    });
+    
+    Ok(())
+}
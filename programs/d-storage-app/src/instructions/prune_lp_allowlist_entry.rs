@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::events::LpAllowlistEntryEvent;
+use crate::state::{LiquidityPool, LpAllowlistEntry};
+
+#[derive(Accounts)]
+pub struct PruneLpAllowlistEntry<'info> {
+    #[account(
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [b"lp_allowlist", pool.key().as_ref(), lp_allowlist_entry.provider.as_ref()],
+        bump
+    )]
+    pub lp_allowlist_entry: Account<'info, LpAllowlistEntry>,
+    /// CHECK: Whoever paid the entry's rent, refunded on close
+    #[account(
+        mut,
+        address = lp_allowlist_entry.rent_refund_to(pool.admin.unwrap_or(lp_allowlist_entry.provider))
+            @ ExchangeError::InvalidRentPayer
+    )]
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+/// Close an expired allowlist entry. Anyone may call this; the rent goes
+/// back to whoever paid it, or for entries that predate `rent_payer` to the
+/// pool admin (the provider once the pool has no admin).
+pub fn handler(ctx: Context<PruneLpAllowlistEntry>) -> Result<()> {
+    let entry = &ctx.accounts.lp_allowlist_entry;
+    require!(
+        entry.is_expired(Clock::get()?.unix_timestamp),
+        ExchangeError::AllowlistEntryNotExpired
+    );
+    
+    emit!(LpAllowlistEntryEvent {
+        pool: ctx.accounts.pool.key(),
+        provider: entry.provider,
+        allowed: false,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::curve::deposit_amounts;
+use crate::state::LiquidityPool;
+use super::add_liquidity::{check_deposit, DepositAmounts};
+
+#[derive(Accounts)]
+pub struct QuoteAddLiquidity<'info> {
+    #[account(
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+}
+
+/// Amounts `add_liquidity` would consume and mint for the given inputs at the
+/// current reserves. Fails where the deposit would, except for the allowlist,
+/// which depends on the provider.
+pub fn handler(
+    ctx: Context<QuoteAddLiquidity>,
+    token_amount: u64,
+    sol_amount: u64,
+) -> Result<DepositAmounts> {
+    let pool = &ctx.accounts.pool;
+    check_deposit(pool, true, Clock::get()?.unix_timestamp)?;
+    
+    let (token_amount, sol_amount, lp_tokens) = deposit_amounts(
+        pool.token_reserve,
+        pool.sol_reserve,
+        pool.lp_supply,
+        token_amount,
+        sol_amount,
+    );
+    Ok(DepositAmounts {
+        token_amount,
+        sol_amount,
+        lp_tokens,
+    })
+}
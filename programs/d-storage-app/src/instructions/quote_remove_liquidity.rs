@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::curve::withdraw_amounts;
+use crate::state::LiquidityPool;
+
+#[derive(Accounts)]
+pub struct QuoteRemoveLiquidity<'info> {
+    #[account(
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RemoveLiquidityQuote {
+    /// Tokens `remove_liquidity` would pay out
+    pub token_amount: u64,
+    /// SOL, or quote tokens, `remove_liquidity` would pay out
+    pub sol_amount: u64,
+    /// LP tokens withheld as the early withdrawal penalty
+    pub penalty_lp_tokens: u64,
+}
+
+/// Amounts `remove_liquidity` would pay out for `lp_tokens` at the current
+/// reserves, after the early withdrawal penalty
+pub fn handler(ctx: Context<QuoteRemoveLiquidity>, lp_tokens: u64) -> Result<RemoveLiquidityQuote> {
+    let pool = &ctx.accounts.pool;
+    let penalty_lp_tokens = pool.lp_withdrawal_penalty(lp_tokens, Clock::get()?.unix_timestamp);
+    
+    let (token_amount, sol_amount) = withdraw_amounts(
+        pool.token_reserve,
+        pool.sol_reserve,
+        pool.lp_supply,
+        lp_tokens - penalty_lp_tokens,
+    );
+    Ok(RemoveLiquidityQuote {
+        token_amount,
+        sol_amount,
+        penalty_lp_tokens,
+    })
+}
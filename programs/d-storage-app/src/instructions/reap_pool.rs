@@ -0,0 +1,177 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, CloseAccount, Mint, Token, TokenAccount};
+
+use crate::constants::REAP_CREATOR_SHARE_BPS;
+use crate::errors::ExchangeError;
+use crate::events::PoolReapEvent;
+use crate::state::{LiquidityPool, ProtocolConfig};
+
+#[derive(Accounts)]
+pub struct ReapPool<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        has_one = token_vault @ ExchangeError::InvalidVault,
+        has_one = sol_vault @ ExchangeError::InvalidVault
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+    /// CHECK: Pool authority PDA
+    #[account(
+        seeds = [b"pool_authority", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"token_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub token_vault: Box<Account<'info, TokenAccount>>,
+    #[account(mut, address = pool.token_mint @ ExchangeError::InvalidTokenMint)]
+    pub token_mint: Box<Account<'info, Mint>>,
+    /// CHECK: SOL vault, or the quote token vault of a token-quoted pool
+    #[account(
+        mut,
+        seeds = [b"sol_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: AccountInfo<'info>,
+    /// Quote mint, required to burn leftovers in the vault of a token-quoted pool
+    #[account(mut, address = pool.quote_mint @ ExchangeError::InvalidQuoteMint)]
+    pub quote_mint: Option<Box<Account<'info, Mint>>>,
+    #[account(seeds = [b"protocol_config"], bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+    /// CHECK: Protocol admin, who acts as the treasury
+    #[account(mut, address = protocol_config.admin @ ExchangeError::Unauthorized)]
+    pub treasury: UncheckedAccount<'info>,
+    /// CHECK: Pool creator (its admin), the treasury once authority was renounced
+    #[account(
+        mut,
+        constraint = creator.key() == pool.admin.unwrap_or(protocol_config.admin) @ ExchangeError::Unauthorized
+    )]
+    pub creator: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Permissionless. The first call on an empty pool starts its grace period,
+/// a call after the grace period closes the pool and its vaults. Any
+/// deposit in between resets the clock. The empty LP mint is kept and
+/// reused if a pool is created for the token again.
+pub fn handler(ctx: Context<ReapPool>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let reap_grace_secs = ctx.accounts.protocol_config.reap_grace_secs;
+    let pool = &mut ctx.accounts.pool;
+    require!(reap_grace_secs > 0 && pool.is_empty(), ExchangeError::PoolNotReapable);
+    
+    if pool.empty_since == 0 {
+        pool.empty_since = now;
+        emit!(PoolReapEvent {
+            pool: pool.key(),
+            token_mint: pool.token_mint,
+            empty_since: now,
+            reaped: false,
+            treasury_refund: 0,
+            creator_refund: 0,
+        });
+        return Ok(());
+    }
+    require!(
+        now >= pool.empty_since + reap_grace_secs as i64,
+        ExchangeError::PoolNotReapable
+    );
+    
+    let token_mint = pool.token_mint;
+    let authority_seeds: &[&[u8]] = &[b"pool_authority", token_mint.as_ref(), &[ctx.bumps.pool_authority]];
+    let pool_info = pool.to_account_info();
+    
+    // Donations left in the vaults have no owner to return them to
+    close_token_vault(
+        &ctx.accounts.token_program,
+        &ctx.accounts.token_vault.to_account_info(),
+        ctx.accounts.token_vault.amount,
+        &ctx.accounts.token_mint.to_account_info(),
+        &ctx.accounts.pool_authority,
+        &pool_info,
+        authority_seeds,
+    )?;
+    if pool.is_native_quote() {
+        let sol_vault_seeds: &[&[u8]] = &[b"sol_vault", token_mint.as_ref(), &[ctx.bumps.sol_vault]];
+        anchor_lang::system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.sol_vault.clone(),
+                    to: pool_info.clone(),
+                },
+                &[sol_vault_seeds],
+            ),
+            ctx.accounts.sol_vault.lamports(),
+        )?;
+    } else {
+        let quote_mint = ctx.accounts.quote_mint.as_ref().ok_or(ExchangeError::InvalidQuoteMint)?;
+        let quote_amount = TokenAccount::try_deserialize(&mut &ctx.accounts.sol_vault.try_borrow_data()?[..])?.amount;
+        close_token_vault(
+            &ctx.accounts.token_program,
+            &ctx.accounts.sol_vault,
+            quote_amount,
+            &quote_mint.to_account_info(),
+            &ctx.accounts.pool_authority,
+            &pool_info,
+            authority_seeds,
+        )?;
+    }
+    
+    // Split everything the pool accounts held between creator and treasury
+    let total = pool_info.lamports();
+    let creator_refund = (total as u128 * REAP_CREATOR_SHARE_BPS as u128 / 10000) as u64;
+    **pool_info.try_borrow_mut_lamports()? -= creator_refund;
+    **ctx.accounts.creator.try_borrow_mut_lamports()? += creator_refund;
+    
+    emit!(PoolReapEvent {
+        pool: pool.key(),
+        token_mint,
+        empty_since: pool.empty_since,
+        reaped: true,
+        treasury_refund: total - creator_refund,
+        creator_refund,
+    });
+    
+    pool.close(ctx.accounts.treasury.to_account_info())
+}
+
+/// Burn whatever a vault still holds and close it into `destination`
+fn close_token_vault<'info>(
+    token_program: &Program<'info, Token>,
+    vault: &AccountInfo<'info>,
+    amount: u64,
+    mint: &AccountInfo<'info>,
+    pool_authority: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    authority_seeds: &[&[u8]],
+) -> Result<()> {
+    if amount > 0 {
+        token::burn(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                Burn {
+                    mint: mint.clone(),
+                    from: vault.clone(),
+                    authority: pool_authority.clone(),
+                },
+                &[authority_seeds],
+            ),
+            amount,
+        )?;
+    }
+    token::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        CloseAccount {
+            account: vault.clone(),
+            destination: destination.clone(),
+            authority: pool_authority.clone(),
+        },
+        &[authority_seeds],
+    ))
+}
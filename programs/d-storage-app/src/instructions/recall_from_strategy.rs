@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::errors::ExchangeError;
+use crate::state::LiquidityPool;
+use crate::strategy::StrategyCpi;
+use super::manage_strategy::redeem_collateral;
+
+#[derive(Accounts)]
+pub struct RecallFromStrategy<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        has_one = token_vault @ ExchangeError::InvalidVault
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    /// CHECK: Pool authority PDA
+    #[account(
+        seeds = [b"pool_authority", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"token_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub token_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = pool.strategy_collateral_account @ ExchangeError::InvalidStrategyAccounts
+    )]
+    pub strategy_collateral_account: Account<'info, TokenAccount>,
+    /// CHECK: Lending program recorded on the pool
+    #[account(address = pool.strategy_program @ ExchangeError::UnsupportedStrategy)]
+    pub strategy_program: AccountInfo<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Redeem lent tokens above the strategy cap back into the token vault.
+/// Permissionless, so a swap or withdrawal short of liquid tokens can be
+/// preceded by it in the same transaction.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RecallFromStrategy<'info>>,
+    collateral_amount: u64,
+) -> Result<()> {
+    let accounts = ctx.accounts;
+    require!(
+        accounts.pool.strategy_principal(collateral_amount) <= accounts.pool.strategy_excess(),
+        ExchangeError::StrategyWithinCap
+    );
+    
+    let token_mint = accounts.pool.token_mint;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"pool_authority", token_mint.as_ref(), &[ctx.bumps.pool_authority]]];
+    let token_vault = accounts.token_vault.to_account_info();
+    let cpi = StrategyCpi {
+        lending_program: &accounts.strategy_program,
+        token_vault: &token_vault,
+        collateral_account: accounts.strategy_collateral_account.as_ref(),
+        pool_authority: &accounts.pool_authority,
+        token_program: accounts.token_program.as_ref(),
+        remaining_accounts: ctx.remaining_accounts,
+        signer_seeds,
+    };
+    redeem_collateral(&mut accounts.pool, &mut accounts.token_vault, &cpi, collateral_amount, false)
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::state::LiquidityPool;
+
+#[derive(Accounts)]
+pub struct RefreshPoolBadge<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    /// CHECK: Badge PDA of the pool token, may not exist
+    #[account(
+        seeds = [b"token_badge", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub token_badge: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<RefreshPoolBadge>) -> Result<()> {
+    // Revoked badges are closed, so the badge exists iff the PDA is program-owned
+    let token_badge = &ctx.accounts.token_badge;
+    ctx.accounts.pool.badged = token_badge.owner == &crate::ID && !token_badge.data_is_empty();
+    Ok(())
+}
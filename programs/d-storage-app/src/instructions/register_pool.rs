@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::state::{LiquidityPool, PoolIndexPage, PoolRegistry};
+
+#[derive(Accounts)]
+pub struct RegisterPool<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = !pool.indexed @ ExchangeError::PoolAlreadyIndexed
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PoolRegistry::LEN,
+        seeds = [b"pool_registry"],
+        bump
+    )]
+    pub pool_registry: Account<'info, PoolRegistry>,
+    /// Index page the pool is appended to
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PoolIndexPage::LEN,
+        seeds = [b"pool_index", pool_registry.next_page().to_le_bytes().as_ref()],
+        bump
+    )]
+    pub pool_index_page: Box<Account<'info, PoolIndexPage>>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RegisterPool>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    pool.indexed = true;
+    ctx.accounts.pool_registry.append(&mut ctx.accounts.pool_index_page, pool.key())?;
+    Ok(())
+}
@@ -0,0 +1,252 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+
+use crate::curve::withdraw_amounts;
+use crate::errors::ExchangeError;
+use crate::events::LpPenaltyEvent;
+use crate::quote::QuoteVault;
+use crate::state::{DepositReceipt, LiquidityPool};
+
+/// Amounts a withdrawal paid out and the LP tokens it burned
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct WithdrawAmounts {
+    pub token_amount: u64,
+    /// SOL, or quote tokens
+    pub sol_amount: u64,
+    /// Includes any penalty LP tokens that are burned rather than paid to lockers
+    pub lp_tokens_burned: u64,
+}
+
+#[derive(Accounts)]
+pub struct RemoveLiquidity<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        has_one = token_vault @ ExchangeError::InvalidVault,
+        has_one = sol_vault @ ExchangeError::InvalidVault
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        mut,
+        associated_token::mint = pool.token_mint,
+        associated_token::authority = user
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+    /// CHECK: Pool authority PDA
+    #[account(
+        seeds = [b"pool_authority", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"token_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub token_vault: Account<'info, TokenAccount>,
+    /// CHECK: SOL vault, or the quote token vault of a token-quoted pool
+    #[account(
+        mut,
+        seeds = [b"sol_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: AccountInfo<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    #[account(mut, address = pool.lp_mint @ ExchangeError::InvalidVault)]
+    pub lp_mint: Account<'info, Mint>,
+    /// LP tokens being redeemed are burned from this account
+    #[account(
+        mut,
+        token::mint = pool.lp_mint,
+        token::authority = user
+    )]
+    pub user_lp_account: Account<'info, TokenAccount>,
+    /// Receives the quote withdrawal, required for token-quoted pools
+    #[account(
+        mut,
+        token::mint = pool.quote_mint,
+        token::authority = user
+    )]
+    pub user_quote_account: Option<Account<'info, TokenAccount>>,
+    /// Collects early-withdrawal penalties for LP lockers
+    #[account(
+        mut,
+        seeds = [b"lp_reward_vault", pool.key().as_ref()],
+        bump
+    )]
+    pub lp_reward_vault: Option<Account<'info, TokenAccount>>,
+    /// Signer's deposit receipt, reduced by the withdrawn share when passed
+    #[account(
+        mut,
+        seeds = [b"deposit_receipt", pool.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub deposit_receipt: Option<Box<Account<'info, DepositReceipt>>>,
+}
+
+pub fn handler(
+    ctx: Context<RemoveLiquidity>,
+    lp_tokens: u64,
+    min_token_amount: u64,
+    min_sol_amount: u64,
+) -> Result<WithdrawAmounts> {
+    require!(lp_tokens > 0, ExchangeError::InvalidAmount);
+    
+    // Get values before any borrows
+    let token_reserve = ctx.accounts.pool.token_reserve;
+    let sol_reserve = ctx.accounts.pool.sol_reserve;
+    let lp_supply = ctx.accounts.pool.lp_supply;
+    
+    // Early withdrawals leave a penalty share of the LP tokens behind
+    let penalty = ctx
+        .accounts
+        .pool
+        .lp_withdrawal_penalty(lp_tokens, Clock::get()?.unix_timestamp);
+    let penalty_to_lockers = penalty > 0 && ctx.accounts.pool.distribute_lp_penalty(penalty);
+    let redeemed_lp_tokens = lp_tokens - penalty;
+    
+    // Calculate amounts to withdraw
+    let (token_amount, sol_amount) =
+        withdraw_amounts(token_reserve, sol_reserve, lp_supply, redeemed_lp_tokens);
+    
+    require!(token_amount >= min_token_amount, ExchangeError::SlippageExceeded);
+    require!(
+        token_amount <= ctx.accounts.pool.liquid_token_reserve(),
+        ExchangeError::StrategyLiquidityRequired
+    );
+    require!(sol_amount >= min_sol_amount, ExchangeError::SlippageExceeded);
+    
+    // Transfer tokens from vault to user
+    if token_amount > 0 {
+        let pool_authority_bump = ctx.bumps.pool_authority;
+        let token_mint = ctx.accounts.pool.token_mint;
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.token_vault.to_account_info(),
+                    to: ctx.accounts.user_token_account.to_account_info(),
+                    authority: ctx.accounts.pool_authority.to_account_info(),
+                },
+                &[&[b"pool_authority", token_mint.as_ref(), &[pool_authority_bump]]],
+            ),
+            token_amount,
+        )?;
+    }
+    
+    // Transfer SOL (or the quote token) from vault to user
+    if sol_amount > 0 {
+        let recipient = if ctx.accounts.pool.is_native_quote() {
+            ctx.accounts.user.to_account_info()
+        } else {
+            ctx.accounts
+                .user_quote_account
+                .as_ref()
+                .ok_or(ExchangeError::InvalidQuoteAccount)?
+                .to_account_info()
+        };
+        QuoteVault {
+            pool: &ctx.accounts.pool,
+            vault: &ctx.accounts.sol_vault,
+            token_program: ctx.accounts.token_program.as_ref(),
+            system_program: ctx.accounts.system_program.as_ref(),
+        }
+        .withdraw(
+            ctx.bumps.sol_vault,
+            &ctx.accounts.pool_authority,
+            ctx.bumps.pool_authority,
+            &recipient,
+            sol_amount,
+        )?;
+    }
+    
+    // Penalties go to the lockers, or are burned for all LPs when none are locked
+    if penalty_to_lockers {
+        let lp_reward_vault = ctx
+            .accounts
+            .lp_reward_vault
+            .as_ref()
+            .ok_or(ExchangeError::LpLockVaultRequired)?;
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.user_lp_account.to_account_info(),
+                    to: lp_reward_vault.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            penalty,
+        )?;
+    }
+    // Redeemed LP tokens leave the supply along with their share of the reserves
+    let burned_lp_tokens = if penalty_to_lockers { redeemed_lp_tokens } else { lp_tokens };
+    burn_lp_tokens(
+        ctx.accounts.token_program.as_ref(),
+        ctx.accounts.lp_mint.as_ref(),
+        ctx.accounts.user_lp_account.as_ref(),
+        ctx.accounts.user.as_ref(),
+        burned_lp_tokens,
+    )?;
+    
+    if penalty > 0 {
+        emit!(LpPenaltyEvent {
+            pool: ctx.accounts.pool.key(),
+            user: ctx.accounts.user.key(),
+            penalty,
+            to_lockers: penalty_to_lockers,
+        });
+    }
+    
+    // Update pool state
+    let pool = &mut ctx.accounts.pool;
+    pool.update_price_cumulative(Clock::get()?.unix_timestamp);
+    pool.token_reserve -= token_amount;
+    pool.sol_reserve -= sol_amount;
+    pool.lp_supply -= burned_lp_tokens;
+    
+    if let Some(receipt) = &mut ctx.accounts.deposit_receipt {
+        receipt.record_withdrawal(lp_tokens);
+        receipt.updated_at = Clock::get()?.unix_timestamp;
+    }
+    
+    assert_pool_invariants!(
+        &ctx.accounts.pool,
+        Some(ctx.accounts.token_vault.as_ref()),
+        Some(&ctx.accounts.sol_vault),
+        Some(ctx.accounts.lp_mint.as_ref())
+    );
+    Ok(WithdrawAmounts {
+        token_amount,
+        sol_amount,
+        lp_tokens_burned: burned_lp_tokens,
+    })
+}
+
+/// Burn `amount` LP tokens from a holder's account, signed by the holder
+pub fn burn_lp_tokens<'info>(
+    token_program: &AccountInfo<'info>,
+    lp_mint: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    holder: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    token::burn(
+        CpiContext::new(
+            token_program.clone(),
+            Burn {
+                mint: lp_mint.clone(),
+                from: from.clone(),
+                authority: holder.clone(),
+            },
+        ),
+        amount,
+    )
+}
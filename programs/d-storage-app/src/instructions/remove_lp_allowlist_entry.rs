@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::ExchangeError;
+use crate::events::LpAllowlistEntryEvent;
+use crate::state::{LiquidityPool, LpAllowlistEntry};
+
+#[derive(Accounts)]
+pub struct RemoveLpAllowlistEntry<'info> {
+    #[account(
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.is_admin(&admin.key(), admin_nft_account.as_deref()) @ ExchangeError::Unauthorized
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [b"lp_allowlist", pool.key().as_ref(), lp_allowlist_entry.provider.as_ref()],
+        bump
+    )]
+    pub lp_allowlist_entry: Account<'info, LpAllowlistEntry>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// Admin NFT held by the signer, required once the pool is bound to one
+    pub admin_nft_account: Option<Account<'info, TokenAccount>>,
+    /// CHECK: Whoever paid the entry's rent, refunded on close
+    #[account(mut, address = lp_allowlist_entry.rent_refund_to(admin.key()) @ ExchangeError::InvalidRentPayer)]
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<RemoveLpAllowlistEntry>) -> Result<()> {
+    emit!(LpAllowlistEntryEvent {
+        pool: ctx.accounts.pool.key(),
+        provider: ctx.accounts.lp_allowlist_entry.provider,
+        allowed: false,
+    });
+    
+    Ok(())
+}
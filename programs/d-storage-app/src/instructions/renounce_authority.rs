@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::ExchangeError;
+use crate::events::AuthorityRenouncedEvent;
+use crate::state::LiquidityPool;
+
+#[derive(Accounts)]
+pub struct RenounceAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.is_admin(&admin.key(), admin_nft_account.as_deref()) @ ExchangeError::Unauthorized
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    pub admin: Signer<'info>,
+    /// Admin NFT held by the signer, required once the pool is bound to one
+    pub admin_nft_account: Option<Account<'info, TokenAccount>>,
+}
+
+pub fn handler(ctx: Context<RenounceAuthority>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    // Funds in the lending strategy can only be recalled by the admin
+    require!(pool.strategy_collateral == 0, ExchangeError::StrategyFundsDeployed);
+    
+    pool.admin = None;
+    pool.admin_nft_mint = Pubkey::default();
+    pool.drain_requested_at = 0;
+    pool.strategy_cap_bps = 0;
+    
+    emit!(AuthorityRenouncedEvent {
+        pool: pool.key(),
+        renounced_by: ctx.accounts.admin.key(),
+    });
+    
+    Ok(())
+}
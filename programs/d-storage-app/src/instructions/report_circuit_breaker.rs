@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::oracle::{oracle_breaker, read_oracle_price, record_breaker_trip};
+use crate::state::{BreakerHistory, LiquidityPool};
+
+#[derive(Accounts)]
+pub struct ReportCircuitBreaker<'info> {
+    #[account(
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.oracle != Pubkey::default() @ ExchangeError::InvalidOracle
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    /// CHECK: Price feed recorded on the pool
+    #[account(address = pool.oracle @ ExchangeError::InvalidOracle)]
+    pub oracle: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"breaker_history", pool.key().as_ref()],
+        bump
+    )]
+    pub breaker_history: Box<Account<'info, BreakerHistory>>,
+}
+
+/// Record the oracle guard the pool trips at its current reserves.
+/// Permissionless: trips that revert a swap roll back with it, so keepers
+/// report them here.
+pub fn handler(ctx: Context<ReportCircuitBreaker>) -> Result<()> {
+    let pool = &ctx.accounts.pool;
+    let price = read_oracle_price(&ctx.accounts.oracle, &pool.oracle_feed_id)?;
+    let breaker = oracle_breaker(
+        pool,
+        &price,
+        pool.token_reserve,
+        pool.sol_reserve,
+        Clock::get()?.unix_timestamp,
+    )?
+    .ok_or(ExchangeError::NoCircuitBreakerTripped)?;
+    require!(
+        record_breaker_trip(&mut ctx.accounts.breaker_history, breaker)?,
+        ExchangeError::NoCircuitBreakerTripped
+    );
+    Ok(())
+}
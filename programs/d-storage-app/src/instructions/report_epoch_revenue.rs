@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::events::EpochRevenueEvent;
+use crate::state::LiquidityPool;
+
+#[derive(Accounts)]
+pub struct ReportEpochRevenue<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+}
+
+/// Emit the pool's swap revenue since the last report and start a new
+/// period. Permissionless, allowed once per epoch.
+pub fn handler(ctx: Context<ReportEpochRevenue>) -> Result<()> {
+    let epoch = Clock::get()?.epoch;
+    let pool = &mut ctx.accounts.pool;
+    let revenue = pool.epoch_revenue.take(epoch).ok_or(ExchangeError::RevenueAlreadyReported)?;
+    
+    emit!(EpochRevenueEvent {
+        pool: pool.key(),
+        from_epoch: revenue.since_epoch,
+        epoch,
+        volume: revenue.volume,
+        lp_fees: revenue.lp_fees,
+        protocol_fees: revenue.protocol_fees,
+        creator_fees: revenue.creator_fees,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::constants::EMERGENCY_DRAIN_TIMELOCK_SECS;
+use crate::errors::ExchangeError;
+use crate::events::EmergencyDrainRequestedEvent;
+use crate::state::LiquidityPool;
+
+#[derive(Accounts)]
+pub struct RequestEmergencyDrain<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.is_admin(&admin.key(), admin_nft_account.as_deref()) @ ExchangeError::Unauthorized,
+        constraint = pool.guardian == guardian.key() @ ExchangeError::InvalidGuardian
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    pub admin: Signer<'info>,
+    pub guardian: Signer<'info>,
+    /// Admin NFT held by the signer, required once the pool is bound to one
+    pub admin_nft_account: Option<Account<'info, TokenAccount>>,
+}
+
+pub fn handler(ctx: Context<RequestEmergencyDrain>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    require!(pool.recovery_address != Pubkey::default(), ExchangeError::RecoveryNotConfigured);
+    require!(pool.drain_requested_at == 0, ExchangeError::EmergencyDrainPending);
+    
+    let now = Clock::get()?.unix_timestamp;
+    pool.drain_requested_at = now;
+    
+    emit!(EmergencyDrainRequestedEvent {
+        pool: pool.key(),
+        requested_at: now,
+        executable_at: now + EMERGENCY_DRAIN_TIMELOCK_SECS,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::ExchangeError;
+use crate::events::AccountResizedEvent;
+use crate::state::{
+    InternalBalance, LiquidityPool, LpAllowlistEntry, LpLock, PoolSnapshot, ProtocolConfig, SwapCooldown, SwapStream,
+};
+
+#[derive(Accounts)]
+pub struct ResizeAccount<'info> {
+    /// CHECK: Program account with an outdated layout, identified by its
+    /// discriminator since it may be too short to deserialize
+    #[account(mut, owner = crate::ID @ ExchangeError::InvalidAccountLayout)]
+    pub account: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(seeds = [b"protocol_config"], bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Current size of a program account, from its discriminator
+fn current_len(data: &[u8]) -> Option<usize> {
+    let discriminator = data.get(..8)?;
+    if discriminator == LiquidityPool::DISCRIMINATOR {
+        Some(LiquidityPool::LEN)
+    } else if discriminator == PoolSnapshot::DISCRIMINATOR {
+        Some(PoolSnapshot::LEN)
+    } else if discriminator == InternalBalance::DISCRIMINATOR {
+        Some(InternalBalance::LEN)
+    } else if discriminator == SwapCooldown::DISCRIMINATOR {
+        Some(SwapCooldown::LEN)
+    } else if discriminator == SwapStream::DISCRIMINATOR {
+        Some(SwapStream::LEN)
+    } else if discriminator == LpAllowlistEntry::DISCRIMINATOR {
+        Some(LpAllowlistEntry::LEN)
+    } else if discriminator == LpLock::DISCRIMINATOR {
+        Some(LpLock::LEN)
+    } else {
+        None
+    }
+}
+
+/// Bring an account to the size of its current layout. Anyone may grow an
+/// account after an upgrade appended fields, paying the extra rent. Only the
+/// protocol admin may shrink one, and receives the rent it frees.
+pub fn handler(ctx: Context<ResizeAccount>) -> Result<()> {
+    let account = ctx.accounts.account.to_account_info();
+    let old_len = account.data_len();
+    let new_len = current_len(&account.try_borrow_data()?).ok_or(ExchangeError::InvalidAccountLayout)?;
+    require!(new_len != old_len, ExchangeError::InvalidAccountLayout);
+    
+    let rent_needed = Rent::get()?.minimum_balance(new_len);
+    let lamports = account.lamports();
+    if new_len > old_len {
+        if rent_needed > lamports {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: account.clone(),
+                    },
+                ),
+                rent_needed - lamports,
+            )?;
+        }
+        // Appended fields start zeroed, their defaults
+        account.realloc(new_len, true)?;
+    } else {
+        require_keys_eq!(
+            ctx.accounts.payer.key(),
+            ctx.accounts.protocol_config.admin,
+            ExchangeError::Unauthorized
+        );
+        account.realloc(new_len, false)?;
+        let refund = lamports.saturating_sub(rent_needed);
+        **account.try_borrow_mut_lamports()? -= refund;
+        **ctx.accounts.payer.try_borrow_mut_lamports()? += refund;
+    }
+    
+    emit!(AccountResizedEvent {
+        account: account.key(),
+        old_len: old_len as u32,
+        new_len: new_len as u32,
+        payer: ctx.accounts.payer.key(),
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::ExchangeError;
+use crate::events::ParamProposalResolvedEvent;
+use crate::state::{LiquidityPool, ParamProposal, ProtocolConfig};
+
+#[derive(Accounts)]
+pub struct ResolveParamProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.is_admin(&admin.key(), admin_nft_account.as_deref()) @ ExchangeError::Unauthorized
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(
+        mut,
+        close = proposer,
+        has_one = pool,
+        has_one = proposer
+    )]
+    pub proposal: Account<'info, ParamProposal>,
+    /// CHECK: Proposer, refunded the rent and, on approval, the bond
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    /// CHECK: Protocol treasury receiving slashed bonds
+    #[account(mut, address = protocol_config.admin @ ExchangeError::Unauthorized)]
+    pub treasury: UncheckedAccount<'info>,
+    pub admin: Signer<'info>,
+    /// Admin NFT held by the signer, required once the pool is bound to one
+    pub admin_nft_account: Option<Account<'info, TokenAccount>>,
+}
+
+/// Approve (apply the change and refund the bond) or reject (slash the bond
+/// to the treasury) a queued proposal. Either way the proposal is closed.
+pub fn handler(ctx: Context<ResolveParamProposal>, approve: bool) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+    let (id, change, bond) = (proposal.id, proposal.change, proposal.bond);
+    
+    if approve {
+        let pool = &mut ctx.accounts.pool;
+        let (old_fee_rate, old_sell_fee_rate) = (pool.lp_fee_bps, pool.sell_fee_rate());
+        change.apply(pool, Clock::get()?.unix_timestamp)?;
+        // Pool admins approve proposals, so they stay within the protocol fee range
+        let protocol_config = &ctx.accounts.protocol_config;
+        require!(
+            protocol_config.fee_change_allowed(old_fee_rate, pool.lp_fee_bps, None)
+                && protocol_config.fee_change_allowed(old_sell_fee_rate, pool.sell_fee_rate(), None),
+            ExchangeError::FeeOutsideProtocolRange
+        );
+    } else {
+        // The bond sits on top of rent in the program-owned proposal account
+        **ctx.accounts.proposal.to_account_info().try_borrow_mut_lamports()? -= bond;
+        **ctx.accounts.treasury.try_borrow_mut_lamports()? += bond;
+    }
+    
+    emit!(ParamProposalResolvedEvent {
+        pool: ctx.accounts.pool.key(),
+        id,
+        approved: approve,
+        resolved_by: ctx.accounts.admin.key(),
+    });
+    
+    Ok(())
+}
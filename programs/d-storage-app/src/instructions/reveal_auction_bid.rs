@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::events::AuctionBidEvent;
+use crate::state::{AuctionBid, AuctionStatus, LaunchAuction};
+
+#[derive(Accounts)]
+pub struct RevealAuctionBid<'info> {
+    pub bidder: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"launch_auction", auction.token_mint.as_ref()],
+        bump = auction.bump
+    )]
+    pub auction: Box<Account<'info, LaunchAuction>>,
+    #[account(
+        mut,
+        seeds = [b"auction_bid", auction.key().as_ref(), bidder.key().as_ref()],
+        bump
+    )]
+    pub bid: Account<'info, AuctionBid>,
+}
+
+/// Open a sealed bid so its SOL counts towards the demand at `tick`. Bids
+/// left sealed after the reveal window are refunded in full.
+pub fn handler(ctx: Context<RevealAuctionBid>, tick: u8, salt: [u8; 32]) -> Result<()> {
+    let auction = &mut ctx.accounts.auction;
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        auction.status == AuctionStatus::Open && (auction.bid_end..auction.reveal_end).contains(&now),
+        ExchangeError::AuctionPhaseMismatch
+    );
+    
+    let bid = &mut ctx.accounts.bid;
+    require!(
+        bid.tick.is_none()
+            && (tick as usize) < LaunchAuction::MAX_TICKS
+            && AuctionBid::commitment(&auction.key(), &bid.bidder, tick, &salt) == bid.commitment,
+        ExchangeError::InvalidBidReveal
+    );
+    bid.tick = Some(tick);
+    auction.demand[tick as usize] += bid.sol_amount;
+    
+    emit!(AuctionBidEvent {
+        auction: auction.key(),
+        bidder: bid.bidder,
+        sol_amount: bid.sol_amount,
+        tick: Some(tick),
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::events::CustodyApprovalEvent;
+use crate::state::{CustodyApproval, ProtocolConfig};
+
+#[derive(Accounts)]
+pub struct RevokeCustodyProgram<'info> {
+    #[account(
+        seeds = [b"protocol_config"],
+        bump,
+        constraint = protocol_config.admin == admin.key() @ ExchangeError::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"custody_approval", custody_approval.custody_program.as_ref()],
+        bump
+    )]
+    pub custody_approval: Account<'info, CustodyApproval>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+/// Stops new pools from moving into the program's custody; vaults already
+/// handed over stay with it
+pub fn handler(ctx: Context<RevokeCustodyProgram>) -> Result<()> {
+    emit!(CustodyApprovalEvent {
+        custody_program: ctx.accounts.custody_approval.custody_program,
+        approved: false,
+        admin: ctx.accounts.admin.key(),
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::events::TokenBadgeEvent;
+use crate::state::{ProtocolConfig, TokenBadge};
+
+#[derive(Accounts)]
+pub struct RevokeTokenBadge<'info> {
+    #[account(
+        seeds = [b"protocol_config"],
+        bump,
+        constraint = protocol_config.admin == admin.key() @ ExchangeError::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"token_badge", token_badge.mint.as_ref()],
+        bump
+    )]
+    pub token_badge: Account<'info, TokenBadge>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RevokeTokenBadge>) -> Result<()> {
+    emit!(TokenBadgeEvent {
+        mint: ctx.accounts.token_badge.mint,
+        badged: false,
+        admin: ctx.accounts.admin.key(),
+    });
+    
+    Ok(())
+}
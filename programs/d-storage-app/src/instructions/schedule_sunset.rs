@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::ExchangeError;
+use crate::events::SunsetScheduledEvent;
+use crate::state::LiquidityPool;
+
+#[derive(Accounts)]
+pub struct ScheduleSunset<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.is_admin(&admin.key(), admin_nft_account.as_deref()) @ ExchangeError::Unauthorized
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    pub admin: Signer<'info>,
+    /// Admin NFT held by the signer, required once the pool is bound to one
+    pub admin_nft_account: Option<Account<'info, TokenAccount>>,
+}
+
+pub fn handler(
+    ctx: Context<ScheduleSunset>,
+    sunset_at: i64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let pool = &mut ctx.accounts.pool;
+    
+    // Once the deadline has passed the sunset is final
+    require!(!pool.is_sunset(now), ExchangeError::PoolSunset);
+    require!(sunset_at == 0 || sunset_at > now, ExchangeError::InvalidSunsetTime);
+    
+    pool.sunset_at = sunset_at;
+    
+    emit!(SunsetScheduledEvent {
+        pool: pool.key(),
+        sunset_at,
+        scheduled_by: ctx.accounts.admin.key(),
+    });
+    
+    Ok(())
+}
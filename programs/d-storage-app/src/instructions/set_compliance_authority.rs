@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::events::ComplianceAuthorityUpdateEvent;
+use crate::state::{ComplianceConfig, ProtocolConfig};
+
+#[derive(Accounts)]
+pub struct SetComplianceAuthority<'info> {
+    #[account(
+        seeds = [b"protocol_config"],
+        bump,
+        constraint = protocol_config.admin == admin.key() @ ExchangeError::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = ComplianceConfig::LEN,
+        seeds = [b"compliance_config"],
+        bump
+    )]
+    pub compliance_config: Account<'info, ComplianceConfig>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Assign the compliance role, `None` disables it. Frozen pools stay frozen
+/// until a compliance authority lifts the freeze.
+pub fn handler(ctx: Context<SetComplianceAuthority>, authority: Option<Pubkey>) -> Result<()> {
+    let compliance_config = &mut ctx.accounts.compliance_config;
+    let old_authority = compliance_config.authority;
+    compliance_config.authority = authority.unwrap_or_default();
+    compliance_config.updated_at = Clock::get()?.unix_timestamp;
+    
+    emit!(ComplianceAuthorityUpdateEvent {
+        old_authority,
+        new_authority: compliance_config.authority,
+        admin: ctx.accounts.admin.key(),
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::events::CreatorQuotaUpdateEvent;
+use crate::state::{CreatorQuota, ProtocolConfig};
+
+#[derive(Accounts)]
+#[instruction(creator: Pubkey)]
+pub struct SetCreatorQuota<'info> {
+    #[account(
+        seeds = [b"protocol_config"],
+        bump,
+        constraint = protocol_config.admin == admin.key() @ ExchangeError::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = CreatorQuota::LEN,
+        seeds = [b"creator_quota", creator.as_ref()],
+        bump
+    )]
+    pub creator_quota: Account<'info, CreatorQuota>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// `None` puts the creator back on the protocol default
+pub fn handler(ctx: Context<SetCreatorQuota>, creator: Pubkey, limit: Option<u32>) -> Result<()> {
+    let creator_quota = &mut ctx.accounts.creator_quota;
+    creator_quota.creator = creator;
+    creator_quota.limit_override = limit;
+    
+    emit!(CreatorQuotaUpdateEvent {
+        creator,
+        limit,
+        launches: creator_quota.launches,
+        admin: ctx.accounts.admin.key(),
+    });
+    
+    Ok(())
+}
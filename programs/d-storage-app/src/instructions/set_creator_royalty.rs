@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::ExchangeError;
+use crate::events::CreatorRoyaltyUpdateEvent;
+use crate::state::{LiquidityPool, ProtocolConfig, TokenRegistry};
+
+#[derive(Accounts)]
+pub struct SetCreatorRoyalty<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.is_admin(&admin.key(), admin_nft_account.as_deref()) @ ExchangeError::Unauthorized
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(seeds = [b"protocol_config"], bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    /// Only tokens with a registered creator can pay royalties
+    #[account(
+        seeds = [b"token_registry", pool.token_mint.as_ref()],
+        bump
+    )]
+    pub token_registry: Account<'info, TokenRegistry>,
+    pub admin: Signer<'info>,
+    /// Admin NFT held by the signer, required once the pool is bound to one
+    pub admin_nft_account: Option<Account<'info, TokenAccount>>,
+}
+
+pub fn handler(ctx: Context<SetCreatorRoyalty>, royalty_bps: u16) -> Result<()> {
+    require!(
+        royalty_bps <= ctx.accounts.protocol_config.max_creator_royalty_bps,
+        ExchangeError::InvalidCreatorRoyalty
+    );
+    
+    let pool = &mut ctx.accounts.pool;
+    pool.creator_royalty_bps = royalty_bps;
+    
+    emit!(CreatorRoyaltyUpdateEvent {
+        pool: pool.key(),
+        creator: ctx.accounts.token_registry.creator,
+        royalty_bps,
+        updated_by: ctx.accounts.admin.key(),
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::ExchangeError;
+use crate::events::FeeHolidayEvent;
+use crate::state::{FeeHoliday, LiquidityPool};
+
+#[derive(Accounts)]
+pub struct SetFeeHoliday<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.is_admin(&admin.key(), admin_nft_account.as_deref()) @ ExchangeError::Unauthorized
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    pub admin: Signer<'info>,
+    /// Admin NFT held by the signer, required once the pool is bound to one
+    pub admin_nft_account: Option<Account<'info, TokenAccount>>,
+}
+
+/// Schedule a fee holiday in slot `index`, or clear the slot with a default
+/// (all zero) holiday
+pub fn handler(ctx: Context<SetFeeHoliday>, index: u8, holiday: FeeHoliday) -> Result<()> {
+    let index = index as usize;
+    require!(index < LiquidityPool::MAX_FEE_HOLIDAYS, ExchangeError::InvalidFeeHoliday);
+    require!(
+        holiday == FeeHoliday::default()
+            || (holiday.start < holiday.end && holiday.end > Clock::get()?.unix_timestamp),
+        ExchangeError::InvalidFeeHoliday
+    );
+    require!(holiday.fee_rate <= 1000, ExchangeError::InvalidFeeRate);
+    
+    let pool = &mut ctx.accounts.pool;
+    pool.fee_holidays[index] = holiday;
+    
+    emit!(FeeHolidayEvent {
+        pool: pool.key(),
+        index: index as u8,
+        start: holiday.start,
+        end: holiday.end,
+        fee_rate: holiday.fee_rate,
+        updated_by: ctx.accounts.admin.key(),
+    });
+    
+    Ok(())
+}
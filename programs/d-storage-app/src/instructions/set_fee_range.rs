@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::events::FeeRangeUpdateEvent;
+use crate::state::ProtocolConfig;
+
+#[derive(Accounts)]
+pub struct SetFeeRange<'info> {
+    #[account(
+        mut,
+        seeds = [b"protocol_config"],
+        bump,
+        constraint = protocol_config.admin == admin.key() @ ExchangeError::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetFeeRange>,
+    default_fee_bps: u16,
+    min_fee_bps: u16,
+    max_fee_bps: u16,
+) -> Result<()> {
+    require!(max_fee_bps <= 1000, ExchangeError::InvalidFeeRange); // Max 10%
+    require!(min_fee_bps <= max_fee_bps, ExchangeError::InvalidFeeRange);
+    
+    let protocol_config = &mut ctx.accounts.protocol_config;
+    protocol_config.min_fee_bps = min_fee_bps;
+    protocol_config.max_fee_bps = max_fee_bps;
+    // The default applies without a co-signer, so it has to sit inside the range
+    require!(
+        default_fee_bps <= 1000 && protocol_config.fee_in_range(default_fee_bps),
+        ExchangeError::InvalidFeeRange
+    );
+    protocol_config.default_fee_bps = default_fee_bps;
+    
+    emit!(FeeRangeUpdateEvent {
+        default_fee_bps,
+        min_fee_bps,
+        max_fee_bps,
+        admin: ctx.accounts.admin.key(),
+    });
+    
+    Ok(())
+}
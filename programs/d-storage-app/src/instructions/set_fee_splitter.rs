@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::events::FeeSplitterUpdateEvent;
+use crate::state::{FeeShare, FeeSplitter, ProtocolConfig};
+
+#[derive(Accounts)]
+pub struct SetFeeSplitter<'info> {
+    #[account(
+        seeds = [b"protocol_config"],
+        bump,
+        constraint = protocol_config.admin == admin.key() @ ExchangeError::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = FeeSplitter::LEN,
+        seeds = [b"fee_splitter"],
+        bump
+    )]
+    pub fee_splitter: Account<'info, FeeSplitter>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Replace the protocol fee recipients. An empty list sends collected fees
+/// back to the protocol admin.
+pub fn handler(ctx: Context<SetFeeSplitter>, shares: Vec<FeeShare>) -> Result<()> {
+    FeeSplitter::validate(&shares)?;
+    ctx.accounts.fee_splitter.shares = shares.clone();
+    
+    emit!(FeeSplitterUpdateEvent {
+        shares,
+        admin: ctx.accounts.admin.key(),
+    });
+    
+    Ok(())
+}
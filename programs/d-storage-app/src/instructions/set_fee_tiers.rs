@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::ExchangeError;
+use crate::events::FeeTiersUpdateEvent;
+use crate::state::{FeeTier, LiquidityPool};
+
+#[derive(Accounts)]
+pub struct SetFeeTiers<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.is_admin(&admin.key(), admin_nft_account.as_deref()) @ ExchangeError::Unauthorized
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    pub admin: Signer<'info>,
+    /// Admin NFT held by the signer, required once the pool is bound to one
+    pub admin_nft_account: Option<Account<'info, TokenAccount>>,
+}
+
+/// Replace the pool's fee tiers. Trades below the first breakpoint keep
+/// paying the base LP fee.
+pub fn handler(ctx: Context<SetFeeTiers>, fee_tiers: Vec<FeeTier>) -> Result<()> {
+    require!(fee_tiers.len() <= LiquidityPool::MAX_FEE_TIERS, ExchangeError::InvalidFeeTiers);
+    let mut tiers = [FeeTier::default(); LiquidityPool::MAX_FEE_TIERS];
+    tiers[..fee_tiers.len()].copy_from_slice(&fee_tiers);
+    
+    let pool = &mut ctx.accounts.pool;
+    pool.apply_fee_tiers(tiers, Clock::get()?.unix_timestamp)?;
+    require!(pool.fees_within_cap(), ExchangeError::InvalidFeeRate);
+    
+    emit!(FeeTiersUpdateEvent {
+        pool: pool.key(),
+        fee_tiers,
+        updated_by: ctx.accounts.admin.key(),
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::events::GuardianSetUpdateEvent;
+use crate::state::ProtocolConfig;
+
+#[derive(Accounts)]
+pub struct SetGuardianSet<'info> {
+    #[account(
+        mut,
+        seeds = [b"protocol_config"],
+        bump,
+        constraint = protocol_config.admin == admin.key() @ ExchangeError::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub admin: Signer<'info>,
+}
+
+/// Replace the guardian set. Any `threshold` of the `guardians` can pause a
+/// pool; an empty set with a zero threshold disables guardian pauses.
+pub fn handler(ctx: Context<SetGuardianSet>, guardians: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    require!(
+        guardians.len() <= ProtocolConfig::MAX_GUARDIANS
+            && threshold as usize <= guardians.len()
+            && (threshold > 0 || guardians.is_empty()),
+        ExchangeError::InvalidGuardianSet
+    );
+    for (i, guardian) in guardians.iter().enumerate() {
+        require!(
+            *guardian != Pubkey::default() && !guardians[..i].contains(guardian),
+            ExchangeError::InvalidGuardianSet
+        );
+    }
+    
+    let protocol_config = &mut ctx.accounts.protocol_config;
+    protocol_config.guardians = [Pubkey::default(); ProtocolConfig::MAX_GUARDIANS];
+    protocol_config.guardians[..guardians.len()].copy_from_slice(&guardians);
+    protocol_config.guardian_threshold = threshold;
+    
+    emit!(GuardianSetUpdateEvent {
+        guardians,
+        threshold,
+        admin: ctx.accounts.admin.key(),
+    });
+    
+    Ok(())
+}
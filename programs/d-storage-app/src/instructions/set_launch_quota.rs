@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::events::LaunchQuotaUpdateEvent;
+use crate::state::ProtocolConfig;
+
+#[derive(Accounts)]
+pub struct SetLaunchQuota<'info> {
+    #[account(
+        mut,
+        seeds = [b"protocol_config"],
+        bump,
+        constraint = protocol_config.admin == admin.key() @ ExchangeError::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub admin: Signer<'info>,
+}
+
+/// Creators already above a lowered limit keep their tokens but cannot launch more
+pub fn handler(ctx: Context<SetLaunchQuota>, max_launches_per_creator: u32) -> Result<()> {
+    ctx.accounts.protocol_config.max_launches_per_creator = max_launches_per_creator;
+    
+    emit!(LaunchQuotaUpdateEvent {
+        max_launches_per_creator,
+        admin: ctx.accounts.admin.key(),
+    });
+    
+    Ok(())
+}
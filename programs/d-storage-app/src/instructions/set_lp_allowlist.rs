@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::ExchangeError;
+use crate::events::LpAllowlistUpdateEvent;
+use crate::state::{LiquidityPool, PoolFlags};
+
+#[derive(Accounts)]
+pub struct SetLpAllowlist<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.is_admin(&admin.key(), admin_nft_account.as_deref()) @ ExchangeError::Unauthorized
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    pub admin: Signer<'info>,
+    /// Admin NFT held by the signer, required once the pool is bound to one
+    pub admin_nft_account: Option<Account<'info, TokenAccount>>,
+}
+
+pub fn handler(
+    ctx: Context<SetLpAllowlist>,
+    enabled: bool,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    pool.set_features(PoolFlags::LP_ALLOWLIST, enabled);
+    
+    emit!(LpAllowlistUpdateEvent {
+        pool: pool.key(),
+        enabled,
+        updated_by: ctx.accounts.admin.key(),
+    });
+    
+    Ok(())
+}
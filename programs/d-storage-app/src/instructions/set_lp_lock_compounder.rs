@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::events::LpLockCompounderEvent;
+use crate::state::{LiquidityPool, LpLock};
+
+#[derive(Accounts)]
+pub struct SetLpLockCompounder<'info> {
+    #[account(
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(
+        mut,
+        seeds = [b"lp_lock", pool.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub lp_lock: Account<'info, LpLock>,
+    pub owner: Signer<'info>,
+}
+
+/// Authorize `compounder` to restake the lock's rewards (default revokes).
+/// It can never unlock or claim them.
+pub fn handler(ctx: Context<SetLpLockCompounder>, compounder: Pubkey) -> Result<()> {
+    ctx.accounts.lp_lock.compounder = compounder;
+    
+    emit!(LpLockCompounderEvent {
+        pool: ctx.accounts.pool.key(),
+        owner: ctx.accounts.owner.key(),
+        compounder,
+    });
+    
+    Ok(())
+}
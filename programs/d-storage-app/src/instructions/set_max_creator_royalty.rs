@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::events::MaxCreatorRoyaltyUpdateEvent;
+use crate::state::ProtocolConfig;
+
+#[derive(Accounts)]
+pub struct SetMaxCreatorRoyalty<'info> {
+    #[account(
+        mut,
+        seeds = [b"protocol_config"],
+        bump,
+        constraint = protocol_config.admin == admin.key() @ ExchangeError::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub admin: Signer<'info>,
+}
+
+/// Lowering the cap does not touch pools above it; it only limits later changes
+pub fn handler(ctx: Context<SetMaxCreatorRoyalty>, max_royalty_bps: u16) -> Result<()> {
+    require!(max_royalty_bps <= 10000, ExchangeError::InvalidCreatorRoyalty);
+    
+    ctx.accounts.protocol_config.max_creator_royalty_bps = max_royalty_bps;
+    
+    emit!(MaxCreatorRoyaltyUpdateEvent {
+        max_royalty_bps,
+        admin: ctx.accounts.admin.key(),
+    });
+    
+    Ok(())
+}
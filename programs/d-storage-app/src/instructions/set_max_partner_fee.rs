@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::events::MaxPartnerFeeUpdateEvent;
+use crate::state::ProtocolConfig;
+
+#[derive(Accounts)]
+pub struct SetMaxPartnerFee<'info> {
+    #[account(
+        mut,
+        seeds = [b"protocol_config"],
+        bump,
+        constraint = protocol_config.admin == admin.key() @ ExchangeError::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetMaxPartnerFee>, max_partner_fee_bps: u16) -> Result<()> {
+    require!(max_partner_fee_bps <= 1000, ExchangeError::PartnerFeeTooHigh); // Max 10%
+    
+    ctx.accounts.protocol_config.max_partner_fee_bps = max_partner_fee_bps;
+    
+    emit!(MaxPartnerFeeUpdateEvent {
+        max_partner_fee_bps,
+        admin: ctx.accounts.admin.key(),
+    });
+    
+    Ok(())
+}
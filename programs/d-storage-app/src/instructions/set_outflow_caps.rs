@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::ExchangeError;
+use crate::events::OutflowCapsUpdateEvent;
+use crate::state::LiquidityPool;
+
+#[derive(Accounts)]
+pub struct SetOutflowCaps<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.is_admin(&admin.key(), admin_nft_account.as_deref()) @ ExchangeError::Unauthorized
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    pub admin: Signer<'info>,
+    /// Admin NFT held by the signer, required once the pool is bound to one
+    pub admin_nft_account: Option<Account<'info, TokenAccount>>,
+}
+
+/// A `window_secs` of 0 turns the caps off. Changing the window restarts
+/// the outflow count.
+pub fn handler(
+    ctx: Context<SetOutflowCaps>,
+    window_secs: u32,
+    max_token_outflow_bps: u16,
+    max_quote_outflow_bps: u16,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    pool.outflow_caps.configure(
+        window_secs,
+        [max_token_outflow_bps, max_quote_outflow_bps],
+        Clock::get()?.unix_timestamp,
+    )?;
+    
+    emit!(OutflowCapsUpdateEvent {
+        pool: pool.key(),
+        window_secs,
+        max_token_outflow_bps,
+        max_quote_outflow_bps,
+        updated_by: ctx.accounts.admin.key(),
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::ExchangeError;
+use crate::events::PauseFlagsUpdateEvent;
+use crate::state::LiquidityPool;
+
+#[derive(Accounts)]
+pub struct SetPauseFlags<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.is_admin(&admin.key(), admin_nft_account.as_deref()) @ ExchangeError::Unauthorized
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    pub admin: Signer<'info>,
+    /// Admin NFT held by the signer, required once the pool is bound to one
+    pub admin_nft_account: Option<Account<'info, TokenAccount>>,
+}
+
+pub fn handler(
+    ctx: Context<SetPauseFlags>,
+    swaps_paused: bool,
+    deposits_paused: bool,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    // A guardian pause can only be lifted by the protocol admin
+    require!(!pool.guardian_paused, ExchangeError::GuardianPaused);
+    pool.swaps_paused = swaps_paused;
+    pool.deposits_paused = deposits_paused;
+    
+    emit!(PauseFlagsUpdateEvent {
+        pool: pool.key(),
+        swaps_paused,
+        deposits_paused,
+        updated_by: ctx.accounts.admin.key(),
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,7 @@
+use anchor_lang::prelude::*;
+
+use super::update_pool_flags::UpdatePoolFlags;
+
+pub fn handler(ctx: Context<UpdatePoolFlags>, flags: u32) -> Result<()> {
+    ctx.accounts.update(flags, true)
+}
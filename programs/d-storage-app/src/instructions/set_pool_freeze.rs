@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::events::PoolFreezeEvent;
+use crate::state::{ComplianceConfig, LiquidityPool};
+
+#[derive(Accounts)]
+pub struct SetPoolFreeze<'info> {
+    #[account(
+        seeds = [b"compliance_config"],
+        bump,
+        constraint = compliance_config.is_authority(&authority.key()) @ ExchangeError::Unauthorized
+    )]
+    pub compliance_config: Account<'info, ComplianceConfig>,
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    pub authority: Signer<'info>,
+}
+
+/// Freeze or unfreeze a pool. A frozen pool only allows withdrawals. Both
+/// directions must cite a reason code, which is recorded in the event.
+pub fn handler(ctx: Context<SetPoolFreeze>, frozen: bool, reason_code: u16) -> Result<()> {
+    require!(reason_code != 0, ExchangeError::InvalidReasonCode);
+    
+    let pool = &mut ctx.accounts.pool;
+    pool.freeze_reason = if frozen { reason_code } else { 0 };
+    
+    emit!(PoolFreezeEvent {
+        pool: pool.key(),
+        frozen,
+        reason_code,
+        authority: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::events::ProtocolAdminUpdateEvent;
+use crate::state::ProtocolConfig;
+
+#[derive(Accounts)]
+pub struct SetProtocolAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [b"protocol_config"],
+        bump,
+        constraint = protocol_config.admin == admin.key() @ ExchangeError::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetProtocolAdmin>, new_admin: Pubkey) -> Result<()> {
+    let protocol_config = &mut ctx.accounts.protocol_config;
+    let old_admin = protocol_config.admin;
+    protocol_config.admin = new_admin;
+    
+    emit!(ProtocolAdminUpdateEvent {
+        old_admin,
+        new_admin,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::events::ProtocolFeeUpdateEvent;
+use crate::state::{LiquidityPool, ProtocolConfig};
+
+#[derive(Accounts)]
+pub struct SetProtocolFee<'info> {
+    #[account(
+        seeds = [b"protocol_config"],
+        bump,
+        constraint = protocol_config.admin == admin.key() @ ExchangeError::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    pub admin: Signer<'info>,
+}
+
+/// Set the fee the protocol takes on top of the pool's LP fee. Only the
+/// protocol admin can change it; the pool admin controls the LP fee.
+pub fn handler(ctx: Context<SetProtocolFee>, protocol_fee_bps: u16) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let old_protocol_fee_bps = pool.protocol_fee_bps;
+    pool.protocol_fee_bps = protocol_fee_bps;
+    require!(pool.fees_within_cap(), ExchangeError::InvalidFeeRate);
+    
+    emit!(ProtocolFeeUpdateEvent {
+        pool: pool.key(),
+        old_protocol_fee_bps,
+        new_protocol_fee_bps: protocol_fee_bps,
+        updated_by: ctx.accounts.admin.key(),
+    });
+    
+    Ok(())
+}
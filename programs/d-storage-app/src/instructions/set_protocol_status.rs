@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::events::ProtocolStatusEvent;
+use crate::state::{ProtocolConfig, ProtocolStatus};
+
+#[derive(Accounts)]
+pub struct SetProtocolStatus<'info> {
+    #[account(
+        mut,
+        seeds = [b"protocol_config"],
+        bump,
+        constraint = protocol_config.admin == admin.key() @ ExchangeError::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub admin: Signer<'info>,
+}
+
+/// Halting blocks swaps, deposits and pool creation everywhere. Recovery
+/// re-enables swaps first and deposits in a separate step.
+pub fn handler(ctx: Context<SetProtocolStatus>, status: ProtocolStatus) -> Result<()> {
+    let protocol_config = &mut ctx.accounts.protocol_config;
+    let previous = protocol_config.status;
+    require!(
+        previous.can_transition_to(status),
+        ExchangeError::InvalidProtocolStatusTransition
+    );
+    protocol_config.status = status;
+    
+    emit!(ProtocolStatusEvent {
+        previous,
+        status,
+        admin: ctx.accounts.admin.key(),
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::events::ReapGracePeriodUpdateEvent;
+use crate::state::ProtocolConfig;
+
+#[derive(Accounts)]
+pub struct SetReapGracePeriod<'info> {
+    #[account(
+        mut,
+        seeds = [b"protocol_config"],
+        bump,
+        constraint = protocol_config.admin == admin.key() @ ExchangeError::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetReapGracePeriod>, reap_grace_secs: u32) -> Result<()> {
+    ctx.accounts.protocol_config.reap_grace_secs = reap_grace_secs;
+    
+    emit!(ReapGracePeriodUpdateEvent {
+        reap_grace_secs,
+        admin: ctx.accounts.admin.key(),
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::ExchangeError;
+use crate::events::ReferralFeeShareUpdateEvent;
+use crate::state::LiquidityPool;
+
+#[derive(Accounts)]
+pub struct SetReferralFeeShare<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.is_admin(&admin.key(), admin_nft_account.as_deref()) @ ExchangeError::Unauthorized
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    pub admin: Signer<'info>,
+    /// Admin NFT held by the signer, required once the pool is bound to one
+    pub admin_nft_account: Option<Account<'info, TokenAccount>>,
+}
+
+pub fn handler(
+    ctx: Context<SetReferralFeeShare>,
+    share_bps: u16,
+) -> Result<()> {
+    require!(share_bps <= 10000, ExchangeError::InvalidReferralShare);
+    
+    let pool = &mut ctx.accounts.pool;
+    pool.referral_fee_share_bps = share_bps;
+    
+    emit!(ReferralFeeShareUpdateEvent {
+        pool: pool.key(),
+        share_bps,
+        updated_by: ctx.accounts.admin.key(),
+    });
+    
+    Ok(())
+}
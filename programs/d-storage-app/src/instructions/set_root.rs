@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::events::RewardRootSetEvent;
+use crate::state::RewardEpoch;
+
+#[derive(Accounts)]
+pub struct SetRoot<'info> {
+    #[account(
+        mut,
+        seeds = [b"reward_epoch", reward_epoch.pool.as_ref(), reward_epoch.epoch.to_le_bytes().as_ref()],
+        bump = reward_epoch.bump,
+        constraint = reward_epoch.funder == funder.key() @ ExchangeError::Unauthorized
+    )]
+    pub reward_epoch: Account<'info, RewardEpoch>,
+    pub funder: Signer<'info>,
+}
+
+/// Publish the Merkle root of the epoch's (claimant, amount) snapshot. The
+/// root is final once set, so claims made against it stay valid.
+pub fn handler(ctx: Context<SetRoot>, merkle_root: [u8; 32]) -> Result<()> {
+    let reward_epoch = &mut ctx.accounts.reward_epoch;
+    require!(!reward_epoch.has_root(), ExchangeError::RewardRootAlreadySet);
+    require!(merkle_root != [0; 32], ExchangeError::InvalidRewardProof);
+    reward_epoch.merkle_root = merkle_root;
+    
+    emit!(RewardRootSetEvent {
+        pool: reward_epoch.pool,
+        epoch: reward_epoch.epoch,
+        merkle_root,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::errors::ExchangeError;
+use crate::events::InternalBalanceEvent;
+use crate::internal_vault::InternalVault;
+use crate::state::{InternalBalance, ProtocolConfig};
+
+#[derive(Accounts)]
+pub struct SettleBatch<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    /// CHECK: Internal vault PDA, holds SOL balances
+    #[account(
+        mut,
+        seeds = [b"internal_vault"],
+        bump
+    )]
+    pub internal_vault: AccountInfo<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    /// Protocol-wide emergency stop status
+    #[account(seeds = [b"protocol_config"], bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+}
+
+impl<'info> SettleBatch<'info> {
+    /// Pay out the whole internal balance in one transfer. `vault` is the
+    /// internal token vault of the mint (the internal vault itself for SOL) and
+    /// `destination` the user's token account (the user's wallet for SOL).
+    fn settle(
+        &self,
+        internal_vault_bump: u8,
+        balance_info: &'info AccountInfo<'info>,
+        vault: &AccountInfo<'info>,
+        destination: &'info AccountInfo<'info>,
+    ) -> Result<()> {
+        let mut balance = Account::<InternalBalance>::try_from(balance_info)?;
+        require_keys_eq!(balance.owner, self.user.key(), ExchangeError::InvalidSettlementAccounts);
+        
+        let token_vault = if balance.is_native() {
+            require_keys_eq!(vault.key(), self.internal_vault.key(), ExchangeError::InvalidSettlementAccounts);
+            require_keys_eq!(destination.key(), self.user.key(), ExchangeError::InvalidSettlementAccounts);
+            None
+        } else {
+            let (expected_vault, _) =
+                Pubkey::find_program_address(&[b"internal_vault", balance.mint.as_ref()], &crate::ID);
+            require_keys_eq!(vault.key(), expected_vault, ExchangeError::InvalidSettlementAccounts);
+            let destination_account = Account::<TokenAccount>::try_from(destination)?;
+            require!(
+                destination_account.mint == balance.mint && destination_account.owner == self.user.key(),
+                ExchangeError::InvalidSettlementAccounts
+            );
+            Some(vault)
+        };
+        
+        let amount = balance.amount;
+        if amount == 0 {
+            return Ok(());
+        }
+        balance.debit(amount)?;
+        
+        InternalVault {
+            vault: &self.internal_vault,
+            bump: internal_vault_bump,
+            token_program: self.token_program.as_ref(),
+            system_program: self.system_program.as_ref(),
+        }
+        .pay(token_vault, destination, amount)?;
+        balance.exit(&crate::ID)?;
+        
+        emit!(InternalBalanceEvent {
+            owner: balance.owner,
+            mint: balance.mint,
+            amount,
+            deposit: false,
+            balance: balance.amount,
+        });
+        
+        Ok(())
+    }
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, SettleBatch<'info>>) -> Result<()> {
+    // Halted settlements fall back to `withdraw_internal_balance`, one mint at a time
+    require!(
+        ctx.accounts.protocol_config.swaps_enabled(),
+        ExchangeError::ProtocolHalted
+    );
+    let remaining_accounts = ctx.remaining_accounts;
+    require!(
+        !remaining_accounts.is_empty() && remaining_accounts.len().is_multiple_of(3),
+        ExchangeError::InvalidSettlementAccounts
+    );
+    
+    // Fills accumulate in the internal balances; each mint settles with one transfer
+    for accounts in remaining_accounts.chunks(3) {
+        ctx.accounts.settle(ctx.bumps.internal_vault, &accounts[0], &accounts[1], &accounts[2])?;
+    }
+    
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::errors::ExchangeError;
+use crate::events::InternalCreditsSettledEvent;
+use crate::internal_vault::InternalVault;
+use crate::state::LiquidityPool;
+use super::swap_internal::internal_quote_vault;
+
+#[derive(Accounts)]
+pub struct SettleInternalCredits<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        has_one = token_vault @ ExchangeError::InvalidVault,
+        has_one = sol_vault @ ExchangeError::InvalidVault
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(
+        mut,
+        seeds = [b"token_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub token_vault: Account<'info, TokenAccount>,
+    /// CHECK: SOL vault, or the quote token vault of a token-quoted pool
+    #[account(
+        mut,
+        seeds = [b"sol_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: AccountInfo<'info>,
+    /// CHECK: Internal vault PDA, holds SOL balances
+    #[account(
+        mut,
+        seeds = [b"internal_vault"],
+        bump
+    )]
+    pub internal_vault: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"internal_vault", pool.token_mint.as_ref()],
+        bump
+    )]
+    pub internal_token_vault: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    /// Internal vault for the quote mint, required for token-quoted pools
+    #[account(
+        mut,
+        seeds = [b"internal_vault", pool.quote_mint.as_ref()],
+        bump
+    )]
+    pub internal_quote_vault: Option<Account<'info, TokenAccount>>,
+}
+
+/// Move the pool assets left in the internal vaults by internal swaps and
+/// deposits into the pool vaults. Permissionless, so wallet withdrawals,
+/// fee claims and drains can be preceded by it in the same transaction.
+pub fn handler(ctx: Context<SettleInternalCredits>) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let pool = &accounts.pool;
+    let token_amount = pool.internal_token_credit;
+    let quote_amount = pool.internal_quote_credit;
+    require!(token_amount > 0 || quote_amount > 0, ExchangeError::InvalidAmount);
+    
+    let internal_vault = InternalVault {
+        vault: &accounts.internal_vault,
+        bump: ctx.bumps.internal_vault,
+        token_program: accounts.token_program.as_ref(),
+        system_program: accounts.system_program.as_ref(),
+    };
+    internal_vault.pay(
+        Some(accounts.internal_token_vault.as_ref()),
+        accounts.token_vault.as_ref(),
+        token_amount,
+    )?;
+    internal_vault.pay(
+        internal_quote_vault(pool, accounts.internal_quote_vault.as_ref())?,
+        &accounts.sol_vault,
+        quote_amount,
+    )?;
+    
+    let pool = &mut ctx.accounts.pool;
+    pool.internal_token_credit = 0;
+    pool.internal_quote_credit = 0;
+    
+    emit!(InternalCreditsSettledEvent {
+        pool: pool.key(),
+        token_amount,
+        quote_amount,
+    });
+    
+    assert_pool_invariants!(
+        &ctx.accounts.pool,
+        Some(ctx.accounts.token_vault.as_ref()),
+        Some(&ctx.accounts.sol_vault),
+        None
+    );
+    Ok(())
+}
@@ -0,0 +1,234 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::errors::ExchangeError;
+use crate::events::{SignedOrderFilledEvent, SwapEvent};
+use crate::internal_vault::InternalVault;
+use crate::oracle::{check_oracle, rebalance_discount_bps, record_breaker_trip};
+use crate::signed_order::{verify_ed25519_signature, SignedOrder};
+use crate::state::{BreakerHistory, InternalBalance, LiquidityPool, OrderNonce, PoolFlags, ProtocolConfig, SwapCooldown};
+use super::swap::{
+    check_opposing_swaps, price_swap, reserves_after_swap, set_aside_fees, swap_price_impact, throttle_outflow, SwapDirection, SwapResult,
+};
+use super::swap_internal::{internal_quote_vault, InternalSwapSettlement};
+
+#[derive(Accounts)]
+#[instruction(order: SignedOrder)]
+pub struct SettleSignedOrder<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.lp_fee_bps <= 1000 @ ExchangeError::InvalidFeeRate,
+        has_one = token_vault @ ExchangeError::InvalidVault,
+        has_one = sol_vault @ ExchangeError::InvalidVault
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+    /// Third party executing the order and paying for the nonce account
+    #[account(mut)]
+    pub filler: Signer<'info>,
+    /// CHECK: Order owner, authenticated by the Ed25519 signature
+    #[account(address = order.owner @ ExchangeError::InvalidOrder)]
+    pub owner: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = filler,
+        space = OrderNonce::LEN,
+        seeds = [b"order_nonce", owner.key().as_ref(), order.nonce.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub order_nonce: Account<'info, OrderNonce>,
+    /// CHECK: Pool authority PDA
+    #[account(
+        seeds = [b"pool_authority", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"token_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub token_vault: Box<Account<'info, TokenAccount>>,
+    /// CHECK: SOL vault, or the quote token vault of a token-quoted pool
+    #[account(
+        mut,
+        seeds = [b"sol_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"internal_balance", owner.key().as_ref(), pool.token_mint.as_ref()],
+        bump
+    )]
+    pub token_balance: Box<Account<'info, InternalBalance>>,
+    #[account(
+        mut,
+        seeds = [b"internal_balance", owner.key().as_ref(), pool.quote_mint.as_ref()],
+        bump
+    )]
+    pub quote_balance: Box<Account<'info, InternalBalance>>,
+    /// CHECK: Internal vault PDA, holds SOL balances
+    #[account(
+        mut,
+        seeds = [b"internal_vault"],
+        bump
+    )]
+    pub internal_vault: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"internal_vault", pool.token_mint.as_ref()],
+        bump
+    )]
+    pub internal_token_vault: Box<Account<'info, TokenAccount>>,
+    /// CHECK: Instructions sysvar holding the Ed25519 signature check
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    /// Internal vault for the quote mint, required for token-quoted pools
+    #[account(
+        mut,
+        seeds = [b"internal_vault", pool.quote_mint.as_ref()],
+        bump
+    )]
+    pub internal_quote_vault: Option<Box<Account<'info, TokenAccount>>>,
+    /// CHECK: Price feed recorded on the pool, required when the oracle guard is on
+    #[account(address = pool.oracle @ ExchangeError::InvalidOracle)]
+    pub oracle: Option<UncheckedAccount<'info>>,
+    /// Owner's cooldown tracker, required during the launch window
+    #[account(
+        mut,
+        seeds = [b"swap_cooldown", pool.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub swap_cooldown: Option<Account<'info, SwapCooldown>>,
+    /// Circuit breaker history, records skipped oracle guards when passed
+    #[account(
+        mut,
+        seeds = [b"breaker_history", pool.key().as_ref()],
+        bump
+    )]
+    pub breaker_history: Option<Box<Account<'info, BreakerHistory>>>,
+    /// Protocol-wide emergency stop status
+    #[account(seeds = [b"protocol_config"], bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+}
+
+/// Fill an order against the owner's internal balances. The order nonce
+/// account can only be created once, so each order fills at most once.
+pub fn handler(ctx: Context<SettleSignedOrder>, order: SignedOrder) -> Result<SwapResult> {
+    let accounts = ctx.accounts;
+    require!(
+        accounts.protocol_config.swaps_enabled(),
+        ExchangeError::ProtocolHalted
+    );
+    require!(accounts.pool.features_enabled(PoolFlags::INTERNAL_BALANCES), ExchangeError::FeatureDisabled);
+    check_opposing_swaps(&accounts.pool, Some(&accounts.instructions))?;
+    let now = Clock::get()?.unix_timestamp;
+    require_keys_eq!(order.pool, accounts.pool.key(), ExchangeError::InvalidOrder);
+    require!(now <= order.expiry, ExchangeError::OrderExpired);
+    verify_ed25519_signature(&accounts.instructions, &order.owner, &order.try_to_vec()?)?;
+    
+    let fee_discount_bps =
+        rebalance_discount_bps(&accounts.pool, accounts.oracle.as_deref(), order.direction, now)?;
+    let (amount_out, lp_fee, protocol_fee) = price_swap(
+        &accounts.pool,
+        accounts.swap_cooldown.as_deref_mut(),
+        now,
+        order.direction,
+        order.amount_in,
+        order.min_amount_out,
+        fee_discount_bps,
+    )?;
+    let price_impact_bps =
+        swap_price_impact(&accounts.pool, order.direction, order.amount_in - lp_fee - protocol_fee, amount_out);
+    let set_aside = set_aside_fees(&mut accounts.pool, order.direction, order.amount_in, lp_fee, protocol_fee, now);
+    throttle_outflow(&mut accounts.pool, order.direction, amount_out, now)?;
+    let (new_token_reserve, new_sol_reserve) =
+        reserves_after_swap(&accounts.pool, order.direction, order.amount_in - set_aside, amount_out);
+    let breaker = check_oracle(&accounts.pool, accounts.oracle.as_deref(), new_token_reserve, new_sol_reserve, now)?;
+    if let (Some(breaker), Some(history)) = (breaker, &mut accounts.breaker_history) {
+        record_breaker_trip(history, breaker)?;
+    }
+    
+    match order.direction {
+        SwapDirection::TokenToSol => {
+            accounts.token_balance.debit(order.amount_in)?;
+            accounts.quote_balance.credit(amount_out)?;
+        }
+        SwapDirection::SolToToken => {
+            accounts.quote_balance.debit(order.amount_in)?;
+            accounts.token_balance.credit(amount_out)?;
+        }
+    }
+    let from_vault = accounts.pool.book_internal_swap(
+        matches!(order.direction, SwapDirection::TokenToSol),
+        order.amount_in,
+        amount_out,
+    )?;
+    InternalSwapSettlement {
+        pool: &accounts.pool,
+        pool_authority: &accounts.pool_authority,
+        pool_authority_bump: ctx.bumps.pool_authority,
+        token_vault: accounts.token_vault.as_ref().as_ref(),
+        sol_vault: &accounts.sol_vault,
+        sol_vault_bump: ctx.bumps.sol_vault,
+        internal_vault: InternalVault {
+            vault: &accounts.internal_vault,
+            bump: ctx.bumps.internal_vault,
+            token_program: accounts.token_program.as_ref(),
+            system_program: accounts.system_program.as_ref(),
+        },
+        internal_token_vault: accounts.internal_token_vault.as_ref().as_ref(),
+        internal_quote_vault: internal_quote_vault(
+            &accounts.pool,
+            accounts.internal_quote_vault.as_deref(),
+        )?,
+    }
+    .settle(order.direction, from_vault)?;
+    
+    let pool = &mut accounts.pool;
+    pool.update_price_cumulative(now);
+    pool.token_reserve = new_token_reserve;
+    pool.sol_reserve = new_sol_reserve;
+    
+    let order_nonce = &mut accounts.order_nonce;
+    order_nonce.owner = order.owner;
+    order_nonce.nonce = order.nonce;
+    order_nonce.filled_at = now;
+    order_nonce.expiry = order.expiry;
+    order_nonce.rent_payer = accounts.filler.key();
+    
+    let (token_in, token_out) = match order.direction {
+        SwapDirection::TokenToSol => (pool.token_mint, pool.quote_mint), // default = SOL out
+        SwapDirection::SolToToken => (pool.quote_mint, pool.token_mint), // default = SOL in
+    };
+    emit!(SwapEvent {
+        user: order.owner,
+        token_in,
+        token_out,
+        amount_in: order.amount_in,
+        amount_out,
+        recipient: order.owner,
+        price_impact_bps,
+    });
+    emit!(SignedOrderFilledEvent {
+        owner: order.owner,
+        filler: accounts.filler.key(),
+        pool: pool.key(),
+        nonce: order.nonce,
+        amount_in: order.amount_in,
+        amount_out,
+    });
+    
+    assert_pool_invariants!(pool, Some(accounts.token_vault.as_ref().as_ref()), Some(&accounts.sol_vault), None);
+    Ok(SwapResult {
+        amount_in: order.amount_in,
+        amount_out,
+        fee: lp_fee + protocol_fee,
+        price_after_x64: pool.spot_price_x64(),
+    })
+}
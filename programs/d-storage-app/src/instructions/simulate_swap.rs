@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::oracle::{check_oracle, rebalance_discount_bps};
+use crate::state::{LiquidityPool, SwapCooldown};
+use super::swap::{price_swap, reserves_after_swap, set_aside_fees, swap_price_impact, throttle_outflow, SwapDirection};
+
+#[derive(Accounts)]
+pub struct SimulateSwap<'info> {
+    #[account(
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.lp_fee_bps <= 1000 @ ExchangeError::InvalidFeeRate
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    /// CHECK: Price feed recorded on the pool, required when the oracle guard is on
+    #[account(address = pool.oracle @ ExchangeError::InvalidOracle)]
+    pub oracle: Option<UncheckedAccount<'info>>,
+    /// Cooldown tracker of the wallet that would swap, checked but not updated
+    #[account(has_one = pool)]
+    pub swap_cooldown: Option<Account<'info, SwapCooldown>>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SwapQuote {
+    pub amount_out: u64,
+    /// LP fee charged on the input, referral share included
+    pub lp_fee: u64,
+    pub protocol_fee: u64,
+    pub price_impact_bps: u16,
+}
+
+/// Dry run of `swap`: the same pricing, cooldown and oracle checks, without
+/// transfers or state writes. The result is returned as return data.
+pub fn handler(
+    ctx: Context<SimulateSwap>,
+    direction: SwapDirection,
+    amount_in: u64,
+    min_amount_out: u64,
+) -> Result<SwapQuote> {
+    let accounts = &ctx.accounts;
+    let now = Clock::get()?.unix_timestamp;
+    let mut swap_cooldown = accounts.swap_cooldown.as_ref().map(|account| (**account).clone());
+    let fee_discount_bps = rebalance_discount_bps(&accounts.pool, accounts.oracle.as_deref(), direction, now)?;
+    let (amount_out, lp_fee, protocol_fee) = price_swap(
+        &accounts.pool,
+        swap_cooldown.as_mut(),
+        now,
+        direction,
+        amount_in,
+        min_amount_out,
+        fee_discount_bps,
+    )?;
+    let price_impact_bps =
+        swap_price_impact(&accounts.pool, direction, amount_in - lp_fee - protocol_fee, amount_out);
+    
+    // Set-aside fees only touch a copy of the pool
+    let mut pool = (*accounts.pool).clone();
+    let set_aside = set_aside_fees(&mut pool, direction, amount_in, lp_fee, protocol_fee, now);
+    throttle_outflow(&mut pool, direction, amount_out, now)?;
+    let (new_token_reserve, new_sol_reserve) =
+        reserves_after_swap(&pool, direction, amount_in - set_aside, amount_out);
+    check_oracle(&accounts.pool, accounts.oracle.as_deref(), new_token_reserve, new_sol_reserve, now)?;
+    
+    Ok(SwapQuote {
+        amount_out,
+        lp_fee,
+        protocol_fee,
+        price_impact_bps,
+    })
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+
+use crate::errors::ExchangeError;
+use super::discount_stake_transfer::DiscountStakeTransfer;
+
+pub fn handler(ctx: Context<DiscountStakeTransfer>, amount: u64) -> Result<()> {
+    require!(
+        ctx.accounts.protocol_config.deposits_enabled(),
+        ExchangeError::ProtocolHalted
+    );
+    let now = Clock::get()?.unix_timestamp;
+    let withdrawn = ctx.accounts.stake_position.withdraw_unlocked(now);
+    ctx.accounts.pay_from_vault(ctx.bumps.stake_discount_config, withdrawn)?;
+    
+    if amount > 0 {
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.user_stake_account.to_account_info(),
+                    to: ctx.accounts.stake_vault.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+    }
+    
+    let cooldown_secs = ctx.accounts.stake_discount_config.cooldown_secs;
+    let stake_position = &mut ctx.accounts.stake_position;
+    stake_position.owner = ctx.accounts.user.key();
+    stake_position.stake(amount, now, cooldown_secs);
+    
+    ctx.accounts.emit_event(amount, true, withdrawn, now);
+    
+    Ok(())
+}
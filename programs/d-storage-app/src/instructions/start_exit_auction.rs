@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::errors::ExchangeError;
+use crate::events::ExitAuctionEvent;
+use crate::state::{ExitAuction, LiquidityPool};
+
+#[derive(Accounts)]
+pub struct StartExitAuction<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    
+    pub pool: Box<Account<'info, LiquidityPool>>,
+    
+    #[account(
+        init,
+        payer = seller,
+        space = ExitAuction::LEN,
+        seeds = [b"exit_auction", pool.key().as_ref(), seller.key().as_ref()],
+        bump
+    )]
+    pub exit_auction: Box<Account<'info, ExitAuction>>,
+    
+    /// Escrow of the LP tokens on sale
+    #[account(
+        init,
+        payer = seller,
+        token::mint = lp_mint,
+        token::authority = exit_auction,
+        seeds = [b"exit_auction_vault", exit_auction.key().as_ref()],
+        bump
+    )]
+    pub exit_auction_vault: Box<Account<'info, TokenAccount>>,
+    
+    #[account(address = pool.lp_mint)]
+    pub lp_mint: Box<Account<'info, Mint>>,
+    
+    #[account(
+        mut,
+        token::mint = pool.lp_mint,
+        token::authority = seller
+    )]
+    pub seller_lp_account: Box<Account<'info, TokenAccount>>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<StartExitAuction>,
+    lp_amount: u64,
+    start_price_x64: u128,
+    end_price_x64: u128,
+    duration_secs: i64,
+) -> Result<()> {
+    require!(lp_amount > 0, ExchangeError::InvalidAmount);
+    require!(
+        end_price_x64 > 0 && start_price_x64 >= end_price_x64 && duration_secs > 0,
+        ExchangeError::InvalidExitAuction
+    );
+    
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.seller_lp_account.to_account_info(),
+                to: ctx.accounts.exit_auction_vault.to_account_info(),
+                authority: ctx.accounts.seller.to_account_info(),
+            },
+        ),
+        lp_amount,
+    )?;
+    
+    let now = Clock::get()?.unix_timestamp;
+    let exit_auction = &mut ctx.accounts.exit_auction;
+    exit_auction.seller = ctx.accounts.seller.key();
+    exit_auction.pool = ctx.accounts.pool.key();
+    exit_auction.remaining = lp_amount;
+    exit_auction.sold = 0;
+    exit_auction.proceeds = 0;
+    exit_auction.start_price_x64 = start_price_x64;
+    exit_auction.end_price_x64 = end_price_x64;
+    exit_auction.start_time = now;
+    exit_auction.end_time = now + duration_secs;
+    exit_auction.bump = ctx.bumps.exit_auction;
+    
+    emit!(ExitAuctionEvent {
+        pool: exit_auction.pool,
+        seller: exit_auction.seller,
+        buyer: None,
+        lp_amount,
+        price_x64: start_price_x64,
+        lamports: 0,
+        remaining: lp_amount,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,156 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::errors::ExchangeError;
+use crate::events::LaunchAuctionStartedEvent;
+use crate::state::{AuctionStatus, CreatorQuota, CurveType, LaunchAuction, ProtocolConfig};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct LaunchAuctionParams {
+    /// Tokens sold to the bidders at the clearing price
+    pub supply_for_sale: u64,
+    /// Tokens seeding the pool next to the raised SOL
+    pub pool_token_amount: u64,
+    /// Lowest accepted price in lamports per token unit, Q64.64
+    pub reserve_price_x64: u128,
+    /// Price step between bid ticks
+    pub tick_spacing_bps: u16,
+    pub bid_duration_secs: i64,
+    pub reveal_duration_secs: i64,
+    pub fee_rate: u16,
+    pub curve_type: CurveType,
+}
+
+#[derive(Accounts)]
+pub struct StartLaunchAuction<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    pub token_mint: Box<Account<'info, Mint>>,
+    
+    #[account(
+        init,
+        payer = creator,
+        space = LaunchAuction::LEN,
+        seeds = [b"launch_auction", token_mint.key().as_ref()],
+        bump
+    )]
+    pub auction: Box<Account<'info, LaunchAuction>>,
+    
+    /// Inventory sold in the auction and reserved for the pool
+    #[account(
+        init,
+        payer = creator,
+        token::mint = token_mint,
+        token::authority = auction,
+        seeds = [b"launch_auction_vault", token_mint.key().as_ref()],
+        bump
+    )]
+    pub auction_vault: Box<Account<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = creator
+    )]
+    pub creator_token_account: Box<Account<'info, TokenAccount>>,
+    
+    /// CHECK: Pool PDA of the mint, the auction seeds it so it must not exist yet
+    #[account(
+        seeds = [b"pool", token_mint.key().as_ref()],
+        bump,
+        constraint = pool.data_is_empty() @ ExchangeError::InvalidAuctionParams
+    )]
+    pub pool: UncheckedAccount<'info>,
+    
+    #[account(seeds = [b"protocol_config"], bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+    
+    /// Launches made by the creator, checked against their quota
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = CreatorQuota::LEN,
+        seeds = [b"creator_quota", creator.key().as_ref()],
+        bump
+    )]
+    pub creator_quota: Box<Account<'info, CreatorQuota>>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    
+    /// Protocol admin co-signing a fee outside the protocol range
+    pub protocol_admin: Option<Signer<'info>>,
+}
+
+pub fn handler(ctx: Context<StartLaunchAuction>, mut params: LaunchAuctionParams) -> Result<()> {
+    params.fee_rate = ctx.accounts.protocol_config.resolve_fee(params.fee_rate);
+    require!(params.fee_rate <= 1000, ExchangeError::InvalidFeeRate); // Max 10%
+    require!(
+        ctx.accounts
+            .protocol_config
+            .fee_allowed(params.fee_rate, ctx.accounts.protocol_admin.as_ref().map(|admin| admin.key())),
+        ExchangeError::FeeOutsideProtocolRange
+    );
+    require!(ctx.accounts.protocol_config.deposits_enabled(), ExchangeError::ProtocolHalted);
+    
+    let creator_quota = &mut ctx.accounts.creator_quota;
+    creator_quota.creator = ctx.accounts.creator.key();
+    require!(
+        creator_quota.record_launch(ctx.accounts.protocol_config.max_launches_per_creator),
+        ExchangeError::CreatorQuotaExceeded
+    );
+    
+    let now = Clock::get()?.unix_timestamp;
+    let auction = &mut ctx.accounts.auction;
+    auction.creator = ctx.accounts.creator.key();
+    auction.token_mint = ctx.accounts.token_mint.key();
+    auction.supply_for_sale = params.supply_for_sale;
+    auction.pool_token_amount = params.pool_token_amount;
+    auction.reserve_price_x64 = params.reserve_price_x64;
+    auction.tick_spacing_bps = params.tick_spacing_bps;
+    auction.bid_end = now + params.bid_duration_secs;
+    auction.reveal_end = auction.bid_end + params.reveal_duration_secs;
+    auction.fee_rate = params.fee_rate;
+    auction.curve_type = params.curve_type;
+    auction.status = AuctionStatus::Open;
+    auction.bump = ctx.bumps.auction;
+    require!(
+        params.supply_for_sale > 0
+            && params.pool_token_amount > 0
+            && params.tick_spacing_bps > 0
+            && params.bid_duration_secs > 0
+            && params.reveal_duration_secs > 0
+            && auction.has_valid_prices(),
+        ExchangeError::InvalidAuctionParams
+    );
+    
+    let inventory = params
+        .supply_for_sale
+        .checked_add(params.pool_token_amount)
+        .ok_or(ExchangeError::MathOverflow)?;
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.creator_token_account.to_account_info(),
+                to: ctx.accounts.auction_vault.to_account_info(),
+                authority: ctx.accounts.creator.to_account_info(),
+            },
+        ),
+        inventory,
+    )?;
+    
+    emit!(LaunchAuctionStartedEvent {
+        auction: auction.key(),
+        token_mint: auction.token_mint,
+        creator: auction.creator,
+        supply_for_sale: params.supply_for_sale,
+        pool_token_amount: params.pool_token_amount,
+        reserve_price_x64: params.reserve_price_x64,
+        bid_end: auction.bid_end,
+        reveal_end: auction.reveal_end,
+    });
+    
+    Ok(())
+}
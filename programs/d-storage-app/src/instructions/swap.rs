@@ -0,0 +1,1042 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_lang::solana_program::sysvar;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, CloseAccount, Mint, SyncNative, Token, TokenAccount, Transfer};
+
+use crate::curve::{price_impact_bps, swap_output};
+use crate::errors::ExchangeError;
+use crate::events::{PartnerFeePaidEvent, ReferralPaidEvent, SwapEvent};
+use crate::oracle::{check_oracle, rebalance_discount_bps, record_breaker_trip};
+use crate::quote::QuoteVault;
+use crate::sandwich_guard::check_sandwich;
+use crate::state::{
+    BreakerHistory, CurveType, LiquidityPool, Observation, OutflowCaps, PoolFlags, PoolSnapshot, PriceObservations, ProtocolConfig,
+    ReferralCode, StakeDiscountConfig, StakePosition, SwapCooldown,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SwapDirection {
+    /// Sell tokens for SOL
+    TokenToSol,
+    /// Buy tokens with SOL
+    SolToToken,
+}
+
+/// Optional limits and charges of a swap
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SwapOptions {
+    /// Fail when the price impact exceeds this
+    pub max_price_impact_bps: Option<u16>,
+    /// Integrator surcharge taken out of the output
+    pub partner_fee_bps: u16,
+    /// Position of the swap in a route
+    pub hop: u8,
+    /// LP fee rate the earlier hops of a route charged, taken off the LP fee
+    /// rate of later hops so the route pays the highest rate once
+    pub routed_fee_bps: u16,
+}
+
+/// Outcome of a swap, returned as return data by every swap entrypoint so
+/// CPI callers can deserialize it instead of parsing raw bytes
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SwapResult {
+    pub amount_in: u64,
+    /// Output received, net of any partner fee
+    pub amount_out: u64,
+    /// LP and protocol fees charged on the input, referral share included
+    pub fee: u64,
+    /// Pool spot price after the swap, quote per token as Q64.64
+    pub price_after_x64: u128,
+}
+
+#[derive(Accounts)]
+pub struct Swap<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.lp_fee_bps <= 1000 @ ExchangeError::InvalidFeeRate,
+        has_one = token_vault @ ExchangeError::InvalidVault,
+        has_one = sol_vault @ ExchangeError::InvalidVault
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    /// Created on the fly for buyers who have never held the token
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = token_mint,
+        associated_token::authority = user
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+    /// CHECK: Pool authority PDA
+    #[account(
+        seeds = [b"pool_authority", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"token_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub token_vault: Account<'info, TokenAccount>,
+    /// CHECK: SOL vault, or the quote token vault of a token-quoted pool
+    #[account(
+        mut,
+        seeds = [b"sol_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: AccountInfo<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    #[account(address = pool.token_mint @ ExchangeError::InvalidTokenMint)]
+    pub token_mint: Account<'info, Mint>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    // Optional accounts are kept last so older clients can omit them
+    /// Optional wSOL account: receives SOL output wrapped, or is unwrapped
+    /// (closed into the user) to fund SOL input
+    #[account(
+        mut,
+        token::authority = user,
+        constraint = user_wsol_account.mint == token::spl_token::native_mint::ID @ ExchangeError::InvalidWsolAccount
+    )]
+    pub user_wsol_account: Option<Account<'info, TokenAccount>>,
+    /// CHECK: Price feed recorded on the pool, required when the oracle guard is on
+    #[account(address = pool.oracle @ ExchangeError::InvalidOracle)]
+    pub oracle: Option<UncheckedAccount<'info>>,
+    /// Optional referral code, its recipient receives a share of the fee
+    pub referral_code: Option<Account<'info, ReferralCode>>,
+    /// CHECK: Recipient of SOL referral fees, checked against the code
+    #[account(mut)]
+    pub referrer: Option<UncheckedAccount<'info>>,
+    /// Recipient's token account for token referral fees
+    #[account(mut, token::mint = pool.token_mint)]
+    pub referrer_token_account: Option<Account<'info, TokenAccount>>,
+    /// Per-wallet cooldown tracker, required during the launch window
+    #[account(
+        mut,
+        seeds = [b"swap_cooldown", pool.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub swap_cooldown: Option<Account<'info, SwapCooldown>>,
+    /// User's quote token account, required for token-quoted pools
+    #[account(
+        mut,
+        token::mint = pool.quote_mint,
+        token::authority = user
+    )]
+    pub user_quote_account: Option<Account<'info, TokenAccount>>,
+    /// Recipient's quote token account for quote referral fees
+    #[account(mut, token::mint = pool.quote_mint)]
+    pub referrer_quote_account: Option<Account<'info, TokenAccount>>,
+    /// Token account receiving the output instead of the signer ("swap and
+    /// send"): token, quote token or wSOL depending on the direction
+    #[account(mut)]
+    pub recipient_token_account: Option<Account<'info, TokenAccount>>,
+    /// CHECK: Wallet receiving native SOL output instead of the signer
+    #[account(mut)]
+    pub recipient: Option<UncheckedAccount<'info>>,
+    /// Token or quote token account the signer is an approved delegate of,
+    /// used as the input instead of the signer's own account
+    #[account(
+        mut,
+        constraint = delegated_source_account.delegate == COption::Some(user.key()) @ ExchangeError::InvalidDelegateAccount
+    )]
+    pub delegated_source_account: Option<Account<'info, TokenAccount>>,
+    /// Price observation buffer, sampled by the swap when passed
+    #[account(
+        mut,
+        seeds = [b"observations", pool.key().as_ref()],
+        bump
+    )]
+    pub observations: Option<Box<Account<'info, PriceObservations>>>,
+    /// Pool snapshot, refreshed by the swap when passed along with the
+    /// observation buffer
+    #[account(
+        mut,
+        seeds = [b"pool_snapshot", pool.key().as_ref()],
+        bump
+    )]
+    pub pool_snapshot: Option<Box<Account<'info, PoolSnapshot>>>,
+    /// CHECK: Integrator wallet receiving a native SOL partner fee
+    #[account(mut)]
+    pub partner: Option<UncheckedAccount<'info>>,
+    /// Integrator token or quote token account receiving the partner fee
+    #[account(mut)]
+    pub partner_fee_account: Option<Account<'info, TokenAccount>>,
+    /// Circuit breaker history, records skipped oracle guards when passed
+    #[account(
+        mut,
+        seeds = [b"breaker_history", pool.key().as_ref()],
+        bump
+    )]
+    pub breaker_history: Option<Box<Account<'info, BreakerHistory>>>,
+    /// Stake tiers, discount the LP fee along with the signer's stake position
+    #[account(seeds = [b"stake_discount_config"], bump)]
+    pub stake_discount_config: Option<Box<Account<'info, StakeDiscountConfig>>>,
+    #[account(
+        seeds = [b"stake_position", user.key().as_ref()],
+        bump
+    )]
+    pub stake_position: Option<Box<Account<'info, StakePosition>>>,
+    /// CHECK: Instructions sysvar, required when the pool guards against sandwiches
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: Option<UncheckedAccount<'info>>,
+    /// Protocol-wide emergency stop status
+    #[account(seeds = [b"protocol_config"], bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+}
+
+impl<'info> Swap<'info> {
+    /// Single math path shared by every swap entrypoint
+    pub fn swap(
+        &mut self,
+        bumps: &SwapBumps,
+        direction: SwapDirection,
+        amount_in: u64,
+        min_amount_out: u64,
+        options: SwapOptions,
+    ) -> Result<SwapResult> {
+        require!(
+            self.protocol_config.swaps_enabled(),
+            ExchangeError::ProtocolHalted
+        );
+        self.check_pool_flags(&options)?;
+        let now = Clock::get()?.unix_timestamp;
+        // Later hops of a route trade value that already paid an LP fee
+        let fee_discount_bps = if options.hop > 0 {
+            options.routed_fee_bps
+        } else {
+            self.fee_discount_bps(direction, now)?
+        };
+        let (amount_out, lp_fee, protocol_fee) = price_swap(
+            &self.pool,
+            self.swap_cooldown.as_deref_mut(),
+            now,
+            direction,
+            amount_in,
+            min_amount_out,
+            fee_discount_bps,
+        )?;
+        let price_impact_bps =
+            swap_price_impact(&self.pool, direction, amount_in - lp_fee - protocol_fee, amount_out);
+        if let Some(max_price_impact_bps) = options.max_price_impact_bps {
+            require!(price_impact_bps <= max_price_impact_bps, ExchangeError::PriceImpactTooHigh);
+        }
+        
+        // Integrator surcharge comes out of the output, within the protocol cap
+        require!(
+            options.partner_fee_bps
+                <= self.protocol_config.max_partner_fee_bps,
+            ExchangeError::PartnerFeeTooHigh
+        );
+        let partner_fee = (amount_out as u128 * options.partner_fee_bps as u128 / 10000) as u64;
+        let pool_amount_out = amount_out;
+        let amount_out = amount_out - partner_fee;
+        require!(amount_out >= min_amount_out, ExchangeError::SlippageExceeded);
+        
+        // Referrers receive their share of the LP fee directly from the user
+        let referral_fee = self.referral_fee(direction, lp_fee)?;
+        let amount_in_to_vault = amount_in - referral_fee;
+        let set_aside = set_aside_fees(&mut self.pool, direction, amount_in, lp_fee - referral_fee, protocol_fee, now);
+        throttle_outflow(&mut self.pool, direction, pool_amount_out, now)?;
+        
+        let (new_token_reserve, new_sol_reserve) =
+            reserves_after_swap(&self.pool, direction, amount_in_to_vault - set_aside, pool_amount_out);
+        let breaker = check_oracle(&self.pool, self.oracle.as_deref(), new_token_reserve, new_sol_reserve, now)?;
+        if let (Some(breaker), Some(history)) = (breaker, &mut self.breaker_history) {
+            record_breaker_trip(history, breaker)?;
+        }
+        
+        self.transfer_in(direction, amount_in_to_vault)?;
+        self.pay_referral(direction, referral_fee)?;
+        self.transfer_out(bumps, direction, amount_out)?;
+        self.pay_partner(bumps, direction, partner_fee)?;
+        
+        let pool = &mut self.pool;
+        pool.update_price_cumulative(now);
+        pool.token_reserve = new_token_reserve;
+        pool.sol_reserve = new_sol_reserve;
+        if let Some(observations) = &mut self.observations {
+            observations.record(Observation {
+                timestamp: now,
+                price_cumulative: pool.price_cumulative,
+            });
+        }
+        if let Some(pool_snapshot) = &mut self.pool_snapshot {
+            let observations = self.observations.as_ref().ok_or(ExchangeError::ObservationsRequired)?;
+            pool_snapshot.refresh(pool, observations, now, Clock::get()?.slot);
+        }
+        
+        let (token_in, token_out) = match direction {
+            SwapDirection::TokenToSol => (pool.token_mint, pool.quote_mint), // default = SOL out
+            SwapDirection::SolToToken => (pool.quote_mint, pool.token_mint), // default = SOL in
+        };
+        emit!(SwapEvent {
+            user: self.user.key(),
+            token_in,
+            token_out,
+            amount_in,
+            amount_out,
+            recipient: self.recipient_key(),
+            price_impact_bps,
+        });
+        
+        assert_pool_invariants!(&self.pool, Some(self.token_vault.as_ref()), Some(&self.sol_vault), None);
+        Ok(SwapResult {
+            amount_in,
+            amount_out,
+            fee: lp_fee + protocol_fee,
+            price_after_x64: self.pool.spot_price_x64(),
+        })
+    }
+
+    /// Reject optional features the pool has turned off, and sandwiches when
+    /// the pool guards against them
+    fn check_pool_flags(&self, options: &SwapOptions) -> Result<()> {
+        let used = [
+            (PoolFlags::WSOL, self.user_wsol_account.is_some()),
+            (PoolFlags::REFERRALS, self.referral_code.is_some()),
+            (PoolFlags::PARTNER_FEES, options.partner_fee_bps > 0),
+            (PoolFlags::SWAP_AND_SEND, self.recipient.is_some() || self.recipient_token_account.is_some()),
+            (PoolFlags::DELEGATED_SWAPS, self.delegated_source_account.is_some()),
+            (PoolFlags::ROUTING, options.hop > 0),
+        ]
+        .into_iter()
+        .filter(|(_, used)| *used)
+        .fold(0, |features, (feature, _)| features | feature);
+        require!(self.pool.features_enabled(used), ExchangeError::FeatureDisabled);
+        check_opposing_swaps(&self.pool, self.instructions.as_deref())
+    }
+
+    /// LP fee discount of the signer's stake tier
+    /// LP fee rate a first hop of `amount_in` charges, discounts included
+    pub fn first_hop_fee_rate(&self, direction: SwapDirection, amount_in: u64, now: i64) -> Result<u16> {
+        Ok(lp_fee_rate(&self.pool, direction, amount_in, now, self.fee_discount_bps(direction, now)?))
+    }
+    
+    /// Rebalance and stake discounts off the LP fee rate
+    fn fee_discount_bps(&self, direction: SwapDirection, now: i64) -> Result<u16> {
+        Ok(rebalance_discount_bps(&self.pool, self.oracle.as_deref(), direction, now)?
+            .saturating_add(self.stake_discount_bps(now)))
+    }
+    
+    fn stake_discount_bps(&self, now: i64) -> u16 {
+        match (&self.stake_discount_config, &self.stake_position) {
+            (Some(config), Some(position)) => config.discount_bps(position.effective_stake(now)),
+            _ => 0,
+        }
+    }
+
+    /// Referral share of `fee`, after checking the payout account matches the code
+    fn referral_fee(&self, direction: SwapDirection, fee: u64) -> Result<u64> {
+        let Some(code) = &self.referral_code else {
+            return Ok(0);
+        };
+        let recipient = match direction {
+            SwapDirection::TokenToSol => self
+                .referrer_token_account
+                .as_ref()
+                .map(|account| account.owner),
+            SwapDirection::SolToToken if self.pool.is_native_quote() => {
+                self.referrer.as_ref().map(|account| account.key())
+            }
+            SwapDirection::SolToToken => self
+                .referrer_quote_account
+                .as_ref()
+                .map(|account| account.owner),
+        };
+        require!(recipient == Some(code.recipient), ExchangeError::InvalidReferralAccount);
+        Ok(self.pool.referral_fee(fee))
+    }
+
+    /// Move the user's input into the matching vault
+    fn transfer_in(&self, direction: SwapDirection, amount: u64) -> Result<()> {
+        match direction {
+            SwapDirection::TokenToSol => token::transfer(
+                CpiContext::new(
+                    self.token_program.to_account_info(),
+                    Transfer {
+                        from: self.input_token_account(direction)?,
+                        to: self.token_vault.to_account_info(),
+                        authority: self.user.to_account_info(),
+                    },
+                ),
+                amount,
+            ),
+            SwapDirection::SolToToken if self.delegated_source_account.is_some() => {
+                self.quote_vault().deposit(
+                    self.user.as_ref(),
+                    Some(&self.input_token_account(direction)?),
+                    amount,
+                )
+            }
+            SwapDirection::SolToToken => {
+                // Unwrap the user's wSOL account into the signer before paying in SOL
+                if let Some(wsol_account) = &self.user_wsol_account {
+                    token::close_account(CpiContext::new(
+                        self.token_program.to_account_info(),
+                        CloseAccount {
+                            account: wsol_account.to_account_info(),
+                            destination: self.user.to_account_info(),
+                            authority: self.user.to_account_info(),
+                        },
+                    ))?;
+                }
+                self.quote_vault().deposit(
+                    self.user.as_ref(),
+                    self.user_quote_account.as_ref().map(|account| account.as_ref()),
+                    amount,
+                )
+            }
+        }
+    }
+
+    /// Token account the input is paid from: the delegated source account if
+    /// one was passed, otherwise the signer's own token or quote account
+    fn input_token_account(&self, direction: SwapDirection) -> Result<AccountInfo<'info>> {
+        let input_mint = match direction {
+            SwapDirection::TokenToSol => self.pool.token_mint,
+            SwapDirection::SolToToken => self.pool.quote_mint,
+        };
+        if let Some(account) = &self.delegated_source_account {
+            // Native SOL cannot be delegated, so the quote side needs a token pool
+            require!(
+                input_mint != Pubkey::default() && account.mint == input_mint,
+                ExchangeError::InvalidDelegateAccount
+            );
+            return Ok(account.to_account_info());
+        }
+        match direction {
+            SwapDirection::TokenToSol => Ok(self.user_token_account.to_account_info()),
+            SwapDirection::SolToToken => self
+                .user_quote_account
+                .as_ref()
+                .map(|account| account.to_account_info())
+                .ok_or(error!(ExchangeError::InvalidQuoteAccount)),
+        }
+    }
+
+    fn quote_vault(&self) -> QuoteVault<'_, 'info> {
+        QuoteVault {
+            pool: &self.pool,
+            vault: &self.sol_vault,
+            token_program: self.token_program.as_ref(),
+            system_program: self.system_program.as_ref(),
+        }
+    }
+
+    /// Pay the referral fee in the input asset
+    fn pay_referral(&self, direction: SwapDirection, amount: u64) -> Result<()> {
+        let Some(code) = &self.referral_code else {
+            return Ok(());
+        };
+        if amount == 0 {
+            return Ok(());
+        }
+        
+        let mint = match direction {
+            SwapDirection::TokenToSol => {
+                let referrer_token_account = self
+                    .referrer_token_account
+                    .as_ref()
+                    .ok_or(ExchangeError::InvalidReferralAccount)?;
+                token::transfer(
+                    CpiContext::new(
+                        self.token_program.to_account_info(),
+                        Transfer {
+                            from: self.input_token_account(direction)?,
+                            to: referrer_token_account.to_account_info(),
+                            authority: self.user.to_account_info(),
+                        },
+                    ),
+                    amount,
+                )?;
+                self.pool.token_mint
+            }
+            SwapDirection::SolToToken if !self.pool.is_native_quote() => {
+                let referrer_quote_account = self
+                    .referrer_quote_account
+                    .as_ref()
+                    .ok_or(ExchangeError::InvalidReferralAccount)?;
+                token::transfer(
+                    CpiContext::new(
+                        self.token_program.to_account_info(),
+                        Transfer {
+                            from: self.input_token_account(direction)?,
+                            to: referrer_quote_account.to_account_info(),
+                            authority: self.user.to_account_info(),
+                        },
+                    ),
+                    amount,
+                )?;
+                self.pool.quote_mint
+            }
+            SwapDirection::SolToToken => {
+                let referrer = self.referrer.as_ref().ok_or(ExchangeError::InvalidReferralAccount)?;
+                anchor_lang::system_program::transfer(
+                    CpiContext::new(
+                        self.system_program.to_account_info(),
+                        anchor_lang::system_program::Transfer {
+                            from: self.user.to_account_info(),
+                            to: referrer.to_account_info(),
+                        },
+                    ),
+                    amount,
+                )?;
+                Pubkey::default() // SOL
+            }
+        };
+        
+        emit!(ReferralPaidEvent {
+            pool: self.pool.key(),
+            code: code.code.clone(),
+            recipient: code.recipient,
+            mint,
+            amount,
+        });
+        
+        Ok(())
+    }
+
+    /// Pay the partner fee in the output asset, straight from the vault
+    fn pay_partner(&self, bumps: &SwapBumps, direction: SwapDirection, amount: u64) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        
+        let partner = match (direction, &self.partner_fee_account) {
+            (SwapDirection::TokenToSol, None) if self.pool.is_native_quote() => self
+                .partner
+                .as_ref()
+                .ok_or(ExchangeError::InvalidPartnerAccount)?
+                .to_account_info(),
+            (_, Some(account)) if account.mint == self.output_mint(direction) => account.to_account_info(),
+            _ => return err!(ExchangeError::InvalidPartnerAccount),
+        };
+        let recipient = match &self.partner_fee_account {
+            Some(account) => account.owner,
+            None => partner.key(),
+        };
+        match direction {
+            SwapDirection::TokenToSol => self.quote_vault().withdraw(
+                bumps.sol_vault,
+                &self.pool_authority,
+                bumps.pool_authority,
+                &partner,
+                amount,
+            )?,
+            SwapDirection::SolToToken => {
+                let token_mint = self.pool.token_mint;
+                token::transfer(
+                    CpiContext::new_with_signer(
+                        self.token_program.to_account_info(),
+                        Transfer {
+                            from: self.token_vault.to_account_info(),
+                            to: partner,
+                            authority: self.pool_authority.to_account_info(),
+                        },
+                        &[&[b"pool_authority", token_mint.as_ref(), &[bumps.pool_authority]]],
+                    ),
+                    amount,
+                )?
+            }
+        }
+        
+        emit!(PartnerFeePaidEvent {
+            pool: self.pool.key(),
+            recipient,
+            mint: self.output_mint(direction),
+            amount,
+        });
+        
+        Ok(())
+    }
+
+    /// Output mint of a swap in `direction`, default for native SOL
+    fn output_mint(&self, direction: SwapDirection) -> Pubkey {
+        match direction {
+            SwapDirection::TokenToSol => self.pool.quote_mint,
+            SwapDirection::SolToToken => self.pool.token_mint,
+        }
+    }
+
+    /// Wallet credited with the output, the signer unless a recipient was passed
+    fn recipient_key(&self) -> Pubkey {
+        if let Some(account) = &self.recipient_token_account {
+            account.owner
+        } else if let Some(recipient) = &self.recipient {
+            recipient.key()
+        } else {
+            self.user.key()
+        }
+    }
+
+    /// Pay the output from the matching vault to the user or the recipient
+    fn transfer_out(&self, bumps: &SwapBumps, direction: SwapDirection, amount: u64) -> Result<()> {
+        let token_mint = self.pool.token_mint;
+        let output_mint = match self.output_mint(direction) {
+            mint if mint == Pubkey::default() => token::spl_token::native_mint::ID,
+            mint => mint,
+        };
+        if let Some(account) = &self.recipient_token_account {
+            require_keys_eq!(account.mint, output_mint, ExchangeError::InvalidRecipient);
+        }
+        
+        match direction {
+            SwapDirection::TokenToSol if !self.pool.is_native_quote() => {
+                let quote_recipient = match &self.recipient_token_account {
+                    Some(account) => account,
+                    None => self
+                        .user_quote_account
+                        .as_ref()
+                        .ok_or(ExchangeError::InvalidQuoteAccount)?,
+                };
+                self.quote_vault().withdraw(
+                    bumps.sol_vault,
+                    &self.pool_authority,
+                    bumps.pool_authority,
+                    quote_recipient.as_ref(),
+                    amount,
+                )
+            }
+            SwapDirection::TokenToSol => {
+                // Wrap into a wSOL account if one was passed
+                let wsol_account = self
+                    .recipient_token_account
+                    .as_ref()
+                    .or(self.user_wsol_account.as_ref().filter(|_| self.recipient.is_none()));
+                let sol_recipient = match (wsol_account, &self.recipient) {
+                    (Some(wsol_account), _) => wsol_account.to_account_info(),
+                    (None, Some(recipient)) => recipient.to_account_info(),
+                    (None, None) => self.user.to_account_info(),
+                };
+                anchor_lang::system_program::transfer(
+                    CpiContext::new_with_signer(
+                        self.system_program.to_account_info(),
+                        anchor_lang::system_program::Transfer {
+                            from: self.sol_vault.to_account_info(),
+                            to: sol_recipient,
+                        },
+                        &[&[b"sol_vault", token_mint.as_ref(), &[bumps.sol_vault]]],
+                    ),
+                    amount,
+                )?;
+                
+                if let Some(wsol_account) = wsol_account {
+                    token::sync_native(CpiContext::new(
+                        self.token_program.to_account_info(),
+                        SyncNative {
+                            account: wsol_account.to_account_info(),
+                        },
+                    ))?;
+                }
+                Ok(())
+            }
+            SwapDirection::SolToToken => token::transfer(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    Transfer {
+                        from: self.token_vault.to_account_info(),
+                        to: self
+                            .recipient_token_account
+                            .as_ref()
+                            .unwrap_or(&self.user_token_account)
+                            .to_account_info(),
+                        authority: self.pool_authority.to_account_info(),
+                    },
+                    &[&[b"pool_authority", token_mint.as_ref(), &[bumps.pool_authority]]],
+                ),
+                amount,
+            ),
+        }
+    }
+}
+
+pub fn handler(
+    ctx: Context<Swap>,
+    direction: SwapDirection,
+    amount_in: u64,
+    min_amount_out: u64,
+) -> Result<SwapResult> {
+    ctx.accounts.swap(&ctx.bumps, direction, amount_in, min_amount_out, SwapOptions::default())
+}
+
+/// Swap that also fails when the price impact exceeds `max_price_impact_bps`
+pub fn handler_with_impact_limit(
+    ctx: Context<Swap>,
+    direction: SwapDirection,
+    amount_in: u64,
+    min_amount_out: u64,
+    max_price_impact_bps: u16,
+) -> Result<SwapResult> {
+    require!(max_price_impact_bps <= 10000, ExchangeError::InvalidPriceImpactLimit);
+    ctx.accounts.swap(
+        &ctx.bumps,
+        direction,
+        amount_in,
+        min_amount_out,
+        SwapOptions {
+            max_price_impact_bps: Some(max_price_impact_bps),
+            ..Default::default()
+        },
+    )
+}
+
+/// Swap paying an integrator `partner_fee_bps` of the output. The user's
+/// minimum applies to what is left after the fee.
+pub fn handler_with_partner_fee(
+    ctx: Context<Swap>,
+    direction: SwapDirection,
+    amount_in: u64,
+    min_amount_out: u64,
+    partner_fee_bps: u16,
+) -> Result<SwapResult> {
+    ctx.accounts.swap(
+        &ctx.bumps,
+        direction,
+        amount_in,
+        min_amount_out,
+        SwapOptions {
+            partner_fee_bps,
+            ..Default::default()
+        },
+    )
+}
+
+/// Checks and curve pricing shared by every swap path, `fee_discount_bps`
+/// coming off the LP fee rate. Returns the amount out and the LP and
+/// protocol fees charged on `amount_in`.
+pub fn price_swap(
+    pool: &LiquidityPool,
+    swap_cooldown: Option<&mut SwapCooldown>,
+    now: i64,
+    direction: SwapDirection,
+    amount_in: u64,
+    min_amount_out: u64,
+    fee_discount_bps: u16,
+) -> Result<(u64, u64, u64)> {
+    require!(!pool.is_sunset(now), ExchangeError::PoolSunset);
+    require!(!pool.is_frozen(), ExchangeError::PoolFrozen);
+    require!(!pool.swaps_paused, ExchangeError::SwapsPaused);
+    enforce_swap_cooldown(pool, swap_cooldown, now)?;
+    
+    let (reserve_in, reserve_out) = match direction {
+        SwapDirection::TokenToSol => (pool.token_reserve, pool.sol_reserve),
+        SwapDirection::SolToToken => (pool.sol_reserve, pool.token_reserve),
+    };
+    let lp_fee_bps = lp_fee_rate(pool, direction, amount_in, now, fee_discount_bps) as u64;
+    let fee_bps = lp_fee_bps + pool.protocol_fee_bps as u64;
+    
+    // Validate fee rate (defensive programming)
+    require!(fee_bps <= 1000, ExchangeError::InvalidFeeRate);
+    
+    // Apply fee, then price the remainder on the pool curve
+    let amount_in_after_fee = (amount_in as u128 * (10000 - fee_bps) as u128 / 10000) as u64;
+    require!(
+        pool.curve_type != CurveType::ConstantSum || pool.decimals_recorded,
+        ExchangeError::PoolDecimalsUnknown
+    );
+    let (token_decimals, quote_decimals) = pool.unit_decimals();
+    let decimals = match direction {
+        SwapDirection::TokenToSol => (token_decimals, quote_decimals),
+        SwapDirection::SolToToken => (quote_decimals, token_decimals),
+    };
+    let amount_out = swap_output(pool.curve_type, reserve_in, reserve_out, amount_in_after_fee, decimals)?;
+    
+    require!(amount_out >= min_amount_out, ExchangeError::SlippageExceeded);
+    require!(amount_out < reserve_out, ExchangeError::InsufficientLiquidity);
+    
+    let fee = amount_in - amount_in_after_fee;
+    let protocol_fee = if fee_bps == 0 {
+        0
+    } else {
+        (fee as u128 * pool.protocol_fee_bps as u128 / fee_bps as u128) as u64
+    };
+    Ok((amount_out, fee - protocol_fee, protocol_fee))
+}
+
+/// LP fee rate of a swap of `amount_in`: the pool's rate for the direction
+/// and trade size, less any fee holiday and `fee_discount_bps`
+pub fn lp_fee_rate(
+    pool: &LiquidityPool,
+    direction: SwapDirection,
+    amount_in: u64,
+    now: i64,
+    fee_discount_bps: u16,
+) -> u16 {
+    // Fee tiers are keyed on the trade size in quote, sells valued at spot
+    let (lp_fee_bps, trade_size) = match direction {
+        SwapDirection::TokenToSol => (pool.sell_fee_rate(), pool.token_value_in_quote(amount_in)),
+        SwapDirection::SolToToken => (pool.lp_fee_bps, amount_in),
+    };
+    let lp_fee_bps = pool.tiered_fee_rate(lp_fee_bps, trade_size);
+    pool.discounted_fee_rate(lp_fee_bps, now).saturating_sub(fee_discount_bps)
+}
+
+/// Price impact in bps of a swap priced against the pool's current reserves,
+/// `amount_in_after_fee` being the input left once all fees are taken
+pub fn swap_price_impact(
+    pool: &LiquidityPool,
+    direction: SwapDirection,
+    amount_in_after_fee: u64,
+    amount_out: u64,
+) -> u16 {
+    let (reserve_in, reserve_out) = match direction {
+        SwapDirection::TokenToSol => (pool.token_reserve, pool.sol_reserve),
+        SwapDirection::SolToToken => (pool.sol_reserve, pool.token_reserve),
+    };
+    price_impact_bps(pool.curve_type, reserve_in, reserve_out, amount_in_after_fee, amount_out)
+}
+
+/// Set the protocol fee and the creator royalty on `lp_fee` aside in the
+/// input vault, outside the reserves, and record what LPs keep in the fee
+/// metrics and the trade in the epoch revenue. Returns the total set aside.
+pub fn set_aside_fees(
+    pool: &mut LiquidityPool,
+    direction: SwapDirection,
+    amount_in: u64,
+    lp_fee: u64,
+    protocol_fee: u64,
+    now: i64,
+) -> u64 {
+    let royalty = pool.creator_royalty(lp_fee);
+    let in_quote = |amount: u64| match direction {
+        SwapDirection::TokenToSol => pool.token_value_in_quote(amount),
+        SwapDirection::SolToToken => amount,
+    };
+    let (volume, lp_fee_in_quote, protocol_fee_in_quote, royalty_in_quote) =
+        (in_quote(amount_in), in_quote(lp_fee - royalty), in_quote(protocol_fee), in_quote(royalty));
+    match direction {
+        SwapDirection::TokenToSol => {
+            pool.royalty_owed_token += royalty;
+            pool.protocol_fees_token += protocol_fee;
+        }
+        SwapDirection::SolToToken => {
+            pool.royalty_owed_quote += royalty;
+            pool.protocol_fees_quote += protocol_fee;
+        }
+    }
+    pool.fee_metrics.record(now, lp_fee_in_quote);
+    pool.epoch_revenue.record(volume, lp_fee_in_quote, protocol_fee_in_quote, royalty_in_quote);
+    royalty + protocol_fee
+}
+
+/// Reject sandwiches of `pool` when it turns off opposing swaps, which
+/// makes the instructions sysvar required
+pub fn check_opposing_swaps(pool: &Account<LiquidityPool>, instructions: Option<&AccountInfo>) -> Result<()> {
+    if !pool.features_enabled(PoolFlags::OPPOSING_SWAPS) {
+        let instructions = instructions.ok_or(ExchangeError::InstructionsSysvarRequired)?;
+        check_sandwich(instructions, &pool.key())?;
+    }
+    Ok(())
+}
+
+/// Count `amount_out` against the pool's outflow cap for the side it leaves.
+/// Buys needing tokens lent to the strategy fail until the lent tokens
+/// above the cap are recalled with `recall_from_strategy`.
+pub fn throttle_outflow(
+    pool: &mut LiquidityPool,
+    direction: SwapDirection,
+    amount_out: u64,
+    now: i64,
+) -> Result<()> {
+    let (side, reserve) = match direction {
+        SwapDirection::TokenToSol => (OutflowCaps::QUOTE, pool.sol_reserve),
+        SwapDirection::SolToToken => {
+            require!(
+                amount_out <= pool.liquid_token_reserve(),
+                ExchangeError::StrategyLiquidityRequired
+            );
+            (OutflowCaps::TOKEN, pool.token_reserve)
+        }
+    };
+    require!(
+        pool.outflow_caps.record(side, amount_out, reserve, now),
+        ExchangeError::OutflowCapExceeded
+    );
+    Ok(())
+}
+
+/// (token, SOL) reserves once `amount_in` has entered and `amount_out` left the pool
+pub fn reserves_after_swap(
+    pool: &LiquidityPool,
+    direction: SwapDirection,
+    amount_in: u64,
+    amount_out: u64,
+) -> (u64, u64) {
+    match direction {
+        SwapDirection::TokenToSol => (pool.token_reserve + amount_in, pool.sol_reserve - amount_out),
+        SwapDirection::SolToToken => (pool.token_reserve - amount_out, pool.sol_reserve + amount_in),
+    }
+}
+
+/// During the launch window, require the wallet's cooldown tracker and make
+/// sure enough slots have passed since its previous swap
+fn enforce_swap_cooldown(
+    pool: &LiquidityPool,
+    swap_cooldown: Option<&mut SwapCooldown>,
+    now: i64,
+) -> Result<()> {
+    if !pool.in_launch_window(now) {
+        return Ok(());
+    }
+    let swap_cooldown = swap_cooldown.ok_or(ExchangeError::SwapCooldownRequired)?;
+    let slot = Clock::get()?.slot;
+    require!(
+        swap_cooldown.last_swap_slot == 0
+            || slot >= swap_cooldown.last_swap_slot + pool.launch_cooldown_slots,
+        ExchangeError::SwapCooldownActive
+    );
+    swap_cooldown.last_swap_slot = slot;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use anchor_lang::solana_program::program_pack::Pack;
+    use anchor_lang::solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+    use anchor_lang::system_program;
+    use anchor_spl::associated_token::get_associated_token_address;
+    use anchor_spl::token::spl_token;
+
+    use crate::state::ProtocolStatus;
+    use super::*;
+
+    fn account_info(key: Pubkey, owner: Pubkey, data: Vec<u8>, is_signer: bool, executable: bool) -> AccountInfo<'static> {
+        AccountInfo::new(
+            Box::leak(Box::new(key)),
+            is_signer,
+            true,
+            Box::leak(Box::new(1_000_000_000)),
+            Box::leak(data.into_boxed_slice()),
+            Box::leak(Box::new(owner)),
+            executable,
+            0,
+        )
+    }
+
+    fn anchor_data<T: AccountSerialize>(account: &T, len: usize) -> Vec<u8> {
+        let mut data = Vec::with_capacity(len);
+        account.try_serialize(&mut data).unwrap();
+        data.resize(len, 0);
+        data
+    }
+
+    fn token_data(mint: Pubkey, owner: Pubkey) -> Vec<u8> {
+        let account = spl_token::state::Account {
+            mint,
+            owner,
+            amount: 1_000_000,
+            state: spl_token::state::AccountState::Initialized,
+            ..Default::default()
+        };
+        let mut data = vec![0; spl_token::state::Account::LEN];
+        account.pack_into_slice(&mut data);
+        data
+    }
+
+    /// Accounts of a swap on a live pool, without the trailing optional
+    /// accounts and the protocol config
+    fn swap_accounts() -> Vec<AccountInfo<'static>> {
+        let pda = |seeds: &[&[u8]]| Pubkey::find_program_address(seeds, &crate::ID).0;
+        let token_mint = Pubkey::new_unique();
+        let user = Pubkey::new_unique();
+        let pool_authority = pda(&[b"pool_authority", token_mint.as_ref()]);
+        let pool = LiquidityPool {
+            token_mint,
+            token_vault: pda(&[b"token_vault", token_mint.as_ref()]),
+            sol_vault: pda(&[b"sol_vault", token_mint.as_ref()]),
+            token_reserve: 1_000_000,
+            sol_reserve: 1_000_000,
+            is_initialized: true,
+            ..Default::default()
+        };
+        let mut mint_data = vec![0; spl_token::state::Mint::LEN];
+        spl_token::state::Mint {
+            mint_authority: COption::Some(user),
+            decimals: 6,
+            is_initialized: true,
+            ..Default::default()
+        }
+        .pack_into_slice(&mut mint_data);
+        let program = |id: Pubkey| account_info(id, Pubkey::default(), vec![], false, true);
+        vec![
+            account_info(pda(&[b"pool", token_mint.as_ref()]), crate::ID, anchor_data(&pool, LiquidityPool::LEN), false, false),
+            account_info(user, system_program::ID, vec![], true, false),
+            account_info(get_associated_token_address(&user, &token_mint), spl_token::ID, token_data(token_mint, user), false, false),
+            account_info(pool_authority, system_program::ID, vec![], false, false),
+            account_info(pool.token_vault, spl_token::ID, token_data(token_mint, pool_authority), false, false),
+            account_info(pool.sol_vault, system_program::ID, vec![], false, false),
+            program(spl_token::ID),
+            program(system_program::ID),
+            account_info(token_mint, spl_token::ID, mint_data, false, false),
+            program(anchor_spl::associated_token::ID),
+        ]
+    }
+
+    /// Serves the rent sysvar `init_if_needed` reads while validating
+    struct RentStub;
+
+    impl SyscallStubs for RentStub {
+        fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+            // SAFETY: the runtime passes a pointer to a `Rent` to fill in
+            unsafe { var_addr.cast::<Rent>().write_unaligned(Rent::default()) };
+            0
+        }
+    }
+
+    fn try_swap_accounts(accounts: Vec<AccountInfo<'static>>) -> Result<(Swap<'static>, SwapBumps)> {
+        let mut accounts: &'static [AccountInfo<'static>] = Box::leak(accounts.into_boxed_slice());
+        set_syscall_stubs(Box::new(RentStub));
+        let mut bumps = SwapBumps::default();
+        let swap = Swap::try_accounts(&crate::ID, &mut accounts, &[], &mut bumps, &mut BTreeSet::new())?;
+        Ok((swap, bumps))
+    }
+
+    #[test]
+    fn halted_protocol_rejects_swaps_without_the_config() {
+        // Leaving the config out no longer counts as a live protocol
+        assert_eq!(
+            try_swap_accounts(swap_accounts()).err().unwrap(),
+            ErrorCode::AccountNotEnoughKeys.into()
+        );
+        
+        // Passed along, a halted config stops the swap
+        let config = ProtocolConfig {
+            status: ProtocolStatus::Halted,
+            ..Default::default()
+        };
+        let config_key = Pubkey::find_program_address(&[b"protocol_config"], &crate::ID).0;
+        let mut accounts = swap_accounts();
+        accounts.extend((0..19).map(|_| account_info(crate::ID, Pubkey::default(), vec![], false, true)));
+        accounts.push(account_info(config_key, crate::ID, anchor_data(&config, ProtocolConfig::LEN), false, false));
+        let (mut swap, bumps) = try_swap_accounts(accounts).unwrap();
+        assert_eq!(
+            swap.swap(&bumps, SwapDirection::SolToToken, 1_000, 0, SwapOptions::default()).unwrap_err(),
+            ExchangeError::ProtocolHalted.into()
+        );
+    }
+    
+    #[test]
+    fn routed_fee_never_drops_below_the_destination_pool_fee() {
+        let pool = |lp_fee_bps: u16| LiquidityPool {
+            token_reserve: 1_000_000,
+            sol_reserve: 1_000_000,
+            lp_fee_bps,
+            ..Default::default()
+        };
+        for (first_fee_bps, second_fee_bps) in [(30, 100), (100, 30), (30, 30), (0, 250), (250, 0)] {
+            let (first, second) = (pool(first_fee_bps), pool(second_fee_bps));
+            let routed_fee_bps = lp_fee_rate(&first, SwapDirection::TokenToSol, 10_000, 0, 0);
+            let second_hop_bps = lp_fee_rate(&second, SwapDirection::SolToToken, 10_000, 0, routed_fee_bps);
+            assert_eq!(second_hop_bps, second_fee_bps.saturating_sub(first_fee_bps));
+            assert!(routed_fee_bps + second_hop_bps >= second_fee_bps);
+        }
+    }
+}
@@ -0,0 +1,288 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::ExchangeError;
+use crate::events::SwapEvent;
+use crate::internal_vault::InternalVault;
+use crate::oracle::{check_oracle, rebalance_discount_bps, record_breaker_trip};
+use crate::quote::QuoteVault;
+use crate::state::{BreakerHistory, InternalBalance, LiquidityPool, PoolFlags, ProtocolConfig, SwapCooldown};
+use super::swap::{
+    check_opposing_swaps, price_swap, reserves_after_swap, set_aside_fees, swap_price_impact, throttle_outflow, SwapDirection, SwapResult,
+};
+
+#[derive(Accounts)]
+pub struct SwapInternal<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.lp_fee_bps <= 1000 @ ExchangeError::InvalidFeeRate,
+        has_one = token_vault @ ExchangeError::InvalidVault,
+        has_one = sol_vault @ ExchangeError::InvalidVault
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    /// CHECK: Pool authority PDA
+    #[account(
+        seeds = [b"pool_authority", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"token_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub token_vault: Account<'info, TokenAccount>,
+    /// CHECK: SOL vault, or the quote token vault of a token-quoted pool
+    #[account(
+        mut,
+        seeds = [b"sol_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"internal_balance", user.key().as_ref(), pool.token_mint.as_ref()],
+        bump
+    )]
+    pub token_balance: Account<'info, InternalBalance>,
+    #[account(
+        mut,
+        seeds = [b"internal_balance", user.key().as_ref(), pool.quote_mint.as_ref()],
+        bump
+    )]
+    pub quote_balance: Account<'info, InternalBalance>,
+    /// CHECK: Internal vault PDA, holds SOL balances
+    #[account(
+        mut,
+        seeds = [b"internal_vault"],
+        bump
+    )]
+    pub internal_vault: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"internal_vault", pool.token_mint.as_ref()],
+        bump
+    )]
+    pub internal_token_vault: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    /// Internal vault for the quote mint, required for token-quoted pools
+    #[account(
+        mut,
+        seeds = [b"internal_vault", pool.quote_mint.as_ref()],
+        bump
+    )]
+    pub internal_quote_vault: Option<Account<'info, TokenAccount>>,
+    /// CHECK: Price feed recorded on the pool, required when the oracle guard is on
+    #[account(address = pool.oracle @ ExchangeError::InvalidOracle)]
+    pub oracle: Option<UncheckedAccount<'info>>,
+    /// Per-wallet cooldown tracker, required during the launch window
+    #[account(
+        mut,
+        seeds = [b"swap_cooldown", pool.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub swap_cooldown: Option<Account<'info, SwapCooldown>>,
+    /// Circuit breaker history, records skipped oracle guards when passed
+    #[account(
+        mut,
+        seeds = [b"breaker_history", pool.key().as_ref()],
+        bump
+    )]
+    pub breaker_history: Option<Box<Account<'info, BreakerHistory>>>,
+    /// Protocol-wide emergency stop status
+    #[account(seeds = [b"protocol_config"], bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+    /// CHECK: Instructions sysvar, required when the pool guards against sandwiches
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> SwapInternal<'info> {
+    /// Swap against the user's internal balances. The pool keeps the input in
+    /// the internal vaults as credit, so assets only move when the output is
+    /// more than the pool's credit on that side.
+    pub fn swap(
+        &mut self,
+        bumps: &SwapInternalBumps,
+        direction: SwapDirection,
+        amount_in: u64,
+        min_amount_out: u64,
+    ) -> Result<SwapResult> {
+        require!(
+            self.protocol_config.swaps_enabled(),
+            ExchangeError::ProtocolHalted
+        );
+        require!(self.pool.features_enabled(PoolFlags::INTERNAL_BALANCES), ExchangeError::FeatureDisabled);
+        check_opposing_swaps(&self.pool, self.instructions.as_deref())?;
+        let now = Clock::get()?.unix_timestamp;
+        let fee_discount_bps = rebalance_discount_bps(&self.pool, self.oracle.as_deref(), direction, now)?;
+        let (amount_out, lp_fee, protocol_fee) = price_swap(
+            &self.pool,
+            self.swap_cooldown.as_deref_mut(),
+            now,
+            direction,
+            amount_in,
+            min_amount_out,
+            fee_discount_bps,
+        )?;
+        let price_impact_bps =
+            swap_price_impact(&self.pool, direction, amount_in - lp_fee - protocol_fee, amount_out);
+        
+        let set_aside = set_aside_fees(&mut self.pool, direction, amount_in, lp_fee, protocol_fee, now);
+        throttle_outflow(&mut self.pool, direction, amount_out, now)?;
+        let (new_token_reserve, new_sol_reserve) =
+            reserves_after_swap(&self.pool, direction, amount_in - set_aside, amount_out);
+        let breaker = check_oracle(&self.pool, self.oracle.as_deref(), new_token_reserve, new_sol_reserve, now)?;
+        if let (Some(breaker), Some(history)) = (breaker, &mut self.breaker_history) {
+            record_breaker_trip(history, breaker)?;
+        }
+        
+        match direction {
+            SwapDirection::TokenToSol => {
+                self.token_balance.debit(amount_in)?;
+                self.quote_balance.credit(amount_out)?;
+            }
+            SwapDirection::SolToToken => {
+                self.quote_balance.debit(amount_in)?;
+                self.token_balance.credit(amount_out)?;
+            }
+        }
+        let from_vault = self.pool.book_internal_swap(
+            matches!(direction, SwapDirection::TokenToSol),
+            amount_in,
+            amount_out,
+        )?;
+        self.settlement(bumps)?.settle(direction, from_vault)?;
+        
+        let pool = &mut self.pool;
+        pool.update_price_cumulative(now);
+        pool.token_reserve = new_token_reserve;
+        pool.sol_reserve = new_sol_reserve;
+        
+        let (token_in, token_out) = match direction {
+            SwapDirection::TokenToSol => (pool.token_mint, pool.quote_mint), // default = SOL out
+            SwapDirection::SolToToken => (pool.quote_mint, pool.token_mint), // default = SOL in
+        };
+        emit!(SwapEvent {
+            user: self.user.key(),
+            token_in,
+            token_out,
+            amount_in,
+            amount_out,
+            recipient: self.user.key(),
+            price_impact_bps,
+        });
+        
+        assert_pool_invariants!(pool, Some(self.token_vault.as_ref()), Some(&self.sol_vault), None);
+        Ok(SwapResult {
+            amount_in,
+            amount_out,
+            fee: lp_fee + protocol_fee,
+            price_after_x64: pool.spot_price_x64(),
+        })
+    }
+
+    /// Pool and internal vault accounts moved by the swap
+    fn settlement(&self, bumps: &SwapInternalBumps) -> Result<InternalSwapSettlement<'_, 'info>> {
+        Ok(InternalSwapSettlement {
+            pool: &self.pool,
+            pool_authority: &self.pool_authority,
+            pool_authority_bump: bumps.pool_authority,
+            token_vault: self.token_vault.as_ref(),
+            sol_vault: &self.sol_vault,
+            sol_vault_bump: bumps.sol_vault,
+            internal_vault: InternalVault {
+                vault: &self.internal_vault,
+                bump: bumps.internal_vault,
+                token_program: self.token_program.as_ref(),
+                system_program: self.system_program.as_ref(),
+            },
+            internal_token_vault: self.internal_token_vault.as_ref(),
+            internal_quote_vault: internal_quote_vault(&self.pool, self.internal_quote_vault.as_ref())?,
+        })
+    }
+}
+
+/// Asset movements of a swap against internal balances once it is booked on
+/// the pool's internal credit: only the output the credit did not cover moves
+/// from the pool vault into the internal vaults
+pub struct InternalSwapSettlement<'a, 'info> {
+    pub pool: &'a LiquidityPool,
+    pub pool_authority: &'a AccountInfo<'info>,
+    pub pool_authority_bump: u8,
+    pub token_vault: &'a AccountInfo<'info>,
+    pub sol_vault: &'a AccountInfo<'info>,
+    pub sol_vault_bump: u8,
+    pub internal_vault: InternalVault<'a, 'info>,
+    pub internal_token_vault: &'a AccountInfo<'info>,
+    /// Internal vault of the quote mint, `None` for SOL-quoted pools
+    pub internal_quote_vault: Option<&'a AccountInfo<'info>>,
+}
+
+impl InternalSwapSettlement<'_, '_> {
+    /// Move `amount_out` of the swap output from the pool vault into the
+    /// internal vault of that side
+    pub fn settle(&self, direction: SwapDirection, amount_out: u64) -> Result<()> {
+        if amount_out == 0 {
+            return Ok(());
+        }
+        let quote_vault = QuoteVault {
+            pool: self.pool,
+            vault: self.sol_vault,
+            token_program: self.internal_vault.token_program,
+            system_program: self.internal_vault.system_program,
+        };
+        
+        match direction {
+            SwapDirection::TokenToSol => quote_vault.withdraw(
+                self.sol_vault_bump,
+                self.pool_authority,
+                self.pool_authority_bump,
+                self.internal_quote_vault.unwrap_or(self.internal_vault.vault),
+                amount_out,
+            ),
+            SwapDirection::SolToToken => {
+                let token_mint = self.pool.token_mint;
+                token::transfer(
+                    CpiContext::new_with_signer(
+                        self.internal_vault.token_program.clone(),
+                        Transfer {
+                            from: self.token_vault.clone(),
+                            to: self.internal_token_vault.clone(),
+                            authority: self.pool_authority.clone(),
+                        },
+                        &[&[b"pool_authority", token_mint.as_ref(), &[self.pool_authority_bump]]],
+                    ),
+                    amount_out,
+                )
+            }
+        }
+    }
+}
+
+/// Internal quote vault to settle against, required for token-quoted pools
+pub fn internal_quote_vault<'a, 'info>(
+    pool: &LiquidityPool,
+    account: Option<&'a Account<'info, TokenAccount>>,
+) -> Result<Option<&'a AccountInfo<'info>>> {
+    if pool.is_native_quote() {
+        return Ok(None);
+    }
+    let account = account.ok_or(ExchangeError::InvalidInternalVault)?;
+    Ok(Some(account.as_ref()))
+}
+
+pub fn handler(
+    ctx: Context<SwapInternal>,
+    direction: SwapDirection,
+    amount_in: u64,
+    min_amount_out: u64,
+) -> Result<SwapResult> {
+    ctx.accounts.swap(&ctx.bumps, direction, amount_in, min_amount_out)
+}
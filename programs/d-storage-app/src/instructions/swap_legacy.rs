@@ -0,0 +1,261 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::ExchangeError;
+use crate::events::{DeprecatedEntrypointEvent, SwapEvent};
+use crate::oracle::check_oracle;
+use crate::quote::QuoteVault;
+use crate::state::{LiquidityPool, PoolFlags, ProtocolConfig};
+use super::swap::{price_swap, reserves_after_swap, set_aside_fees, swap_price_impact, throttle_outflow, SwapDirection};
+
+/// Accounts of the original `swap_token_to_sol`, plus the protocol config so the
+/// emergency stop applies
+#[derive(Accounts)]
+pub struct SwapTokenToSol<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.lp_fee_bps <= 1000 @ ExchangeError::InvalidFeeRate
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        mut,
+        associated_token::mint = pool.token_mint,
+        associated_token::authority = user
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+    /// CHECK: Pool authority PDA
+    #[account(
+        seeds = [b"pool_authority", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"token_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub token_vault: Account<'info, TokenAccount>,
+    /// CHECK: SOL vault
+    #[account(
+        mut,
+        seeds = [b"sol_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: AccountInfo<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    /// Protocol-wide emergency stop status
+    #[account(seeds = [b"protocol_config"], bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+}
+
+/// Accounts of the original `swap_sol_to_token`, plus the protocol config so the
+/// emergency stop applies
+#[derive(Accounts)]
+pub struct SwapSolToToken<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.lp_fee_bps <= 1000 @ ExchangeError::InvalidFeeRate
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        mut,
+        associated_token::mint = pool.token_mint,
+        associated_token::authority = user
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+    /// CHECK: Pool authority PDA
+    #[account(
+        seeds = [b"pool_authority", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"token_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub token_vault: Account<'info, TokenAccount>,
+    /// CHECK: SOL vault
+    #[account(
+        mut,
+        seeds = [b"sol_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: AccountInfo<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    /// Protocol-wide emergency stop status
+    #[account(seeds = [b"protocol_config"], bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+}
+
+/// A swap over the original account list, shared by both legacy entrypoints
+struct LegacySwap<'a, 'info> {
+    pool: &'a mut Account<'info, LiquidityPool>,
+    user: &'a Signer<'info>,
+    user_token_account: &'a Account<'info, TokenAccount>,
+    pool_authority: &'a AccountInfo<'info>,
+    pool_authority_bump: u8,
+    token_vault: &'a Account<'info, TokenAccount>,
+    sol_vault: &'a AccountInfo<'info>,
+    sol_vault_bump: u8,
+    token_program: &'a Program<'info, Token>,
+    system_program: &'a Program<'info, System>,
+    protocol_config: &'a ProtocolConfig,
+}
+
+impl LegacySwap<'_, '_> {
+    /// Price and settle the swap with the same math as `swap`. The original
+    /// accounts carry no oracle, cooldown tracker or instructions sysvar, so
+    /// pools that need one of them reject legacy swaps.
+    fn swap(
+        self,
+        entrypoint: &str,
+        direction: SwapDirection,
+        amount_in: u64,
+        min_amount_out: u64,
+    ) -> Result<()> {
+        require!(self.protocol_config.swaps_enabled(), ExchangeError::ProtocolHalted);
+        require!(self.pool.is_native_quote(), ExchangeError::NativeQuoteOnly);
+        require!(
+            self.pool.features_enabled(PoolFlags::OPPOSING_SWAPS),
+            ExchangeError::InstructionsSysvarRequired
+        );
+        emit!(DeprecatedEntrypointEvent {
+            entrypoint: entrypoint.to_string(),
+            replacement: "swap".to_string(),
+            caller: self.user.key(),
+        });
+        
+        let now = Clock::get()?.unix_timestamp;
+        let (amount_out, lp_fee, protocol_fee) =
+            price_swap(self.pool, None, now, direction, amount_in, min_amount_out, 0)?;
+        let price_impact_bps =
+            swap_price_impact(self.pool, direction, amount_in - lp_fee - protocol_fee, amount_out);
+        let set_aside = set_aside_fees(self.pool, direction, amount_in, lp_fee, protocol_fee, now);
+        throttle_outflow(self.pool, direction, amount_out, now)?;
+        let (new_token_reserve, new_sol_reserve) =
+            reserves_after_swap(self.pool, direction, amount_in - set_aside, amount_out);
+        check_oracle(self.pool, None, new_token_reserve, new_sol_reserve, now)?;
+        
+        let token_mint = self.pool.token_mint;
+        let sol_vault = QuoteVault {
+            pool: self.pool,
+            vault: self.sol_vault,
+            token_program: self.token_program.as_ref(),
+            system_program: self.system_program.as_ref(),
+        };
+        match direction {
+            SwapDirection::TokenToSol => {
+                token::transfer(
+                    CpiContext::new(
+                        self.token_program.to_account_info(),
+                        Transfer {
+                            from: self.user_token_account.to_account_info(),
+                            to: self.token_vault.to_account_info(),
+                            authority: self.user.to_account_info(),
+                        },
+                    ),
+                    amount_in,
+                )?;
+                sol_vault.withdraw(
+                    self.sol_vault_bump,
+                    self.pool_authority,
+                    self.pool_authority_bump,
+                    self.user.as_ref(),
+                    amount_out,
+                )?;
+            }
+            SwapDirection::SolToToken => {
+                sol_vault.deposit(self.user.as_ref(), None, amount_in)?;
+                token::transfer(
+                    CpiContext::new_with_signer(
+                        self.token_program.to_account_info(),
+                        Transfer {
+                            from: self.token_vault.to_account_info(),
+                            to: self.user_token_account.to_account_info(),
+                            authority: self.pool_authority.to_account_info(),
+                        },
+                        &[&[b"pool_authority", token_mint.as_ref(), &[self.pool_authority_bump]]],
+                    ),
+                    amount_out,
+                )?;
+            }
+        }
+        
+        let pool = self.pool;
+        pool.update_price_cumulative(now);
+        pool.token_reserve = new_token_reserve;
+        pool.sol_reserve = new_sol_reserve;
+        
+        let (token_in, token_out) = match direction {
+            SwapDirection::TokenToSol => (pool.token_mint, pool.quote_mint), // default = SOL out
+            SwapDirection::SolToToken => (pool.quote_mint, pool.token_mint), // default = SOL in
+        };
+        emit!(SwapEvent {
+            user: self.user.key(),
+            token_in,
+            token_out,
+            amount_in,
+            amount_out,
+            recipient: self.user.key(),
+            price_impact_bps,
+        });
+        
+        assert_pool_invariants!(pool, Some(self.token_vault.as_ref()), Some(self.sol_vault), None);
+        Ok(())
+    }
+}
+
+pub fn handler_token_to_sol(
+    ctx: Context<SwapTokenToSol>,
+    token_amount: u64,
+    min_sol_amount: u64,
+) -> Result<()> {
+    let accounts = ctx.accounts;
+    LegacySwap {
+        pool: &mut accounts.pool,
+        user: &accounts.user,
+        user_token_account: &accounts.user_token_account,
+        pool_authority: &accounts.pool_authority,
+        pool_authority_bump: ctx.bumps.pool_authority,
+        token_vault: &accounts.token_vault,
+        sol_vault: &accounts.sol_vault,
+        sol_vault_bump: ctx.bumps.sol_vault,
+        token_program: &accounts.token_program,
+        system_program: &accounts.system_program,
+        protocol_config: &accounts.protocol_config,
+    }
+    .swap("swap_token_to_sol", SwapDirection::TokenToSol, token_amount, min_sol_amount)
+}
+
+pub fn handler_sol_to_token(
+    ctx: Context<SwapSolToToken>,
+    sol_amount: u64,
+    min_token_amount: u64,
+) -> Result<()> {
+    let accounts = ctx.accounts;
+    LegacySwap {
+        pool: &mut accounts.pool,
+        user: &accounts.user,
+        user_token_account: &accounts.user_token_account,
+        pool_authority: &accounts.pool_authority,
+        pool_authority_bump: ctx.bumps.pool_authority,
+        token_vault: &accounts.token_vault,
+        sol_vault: &accounts.sol_vault,
+        sol_vault_bump: ctx.bumps.sol_vault,
+        token_program: &accounts.token_program,
+        system_program: &accounts.system_program,
+        protocol_config: &accounts.protocol_config,
+    }
+    .swap("swap_sol_to_token", SwapDirection::SolToToken, sol_amount, min_token_amount)
+}
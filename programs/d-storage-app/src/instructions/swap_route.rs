@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::state::PoolFlags;
+use super::swap::*;
+
+/// Two-hop route through the quote asset: sell the first pool's token, then
+/// buy the second pool's token with the proceeds
+#[derive(Accounts)]
+pub struct SwapRoute<'info> {
+    #[account(
+        constraint = first.pool.token_mint != second.pool.token_mint @ ExchangeError::InvalidRoute,
+        constraint = first.pool.quote_mint == second.pool.quote_mint @ ExchangeError::InvalidRoute,
+        constraint = first.user.key() == second.user.key() @ ExchangeError::InvalidRoute
+    )]
+    pub first: Swap<'info>,
+    pub second: Swap<'info>,
+}
+
+/// Route `amount_in` of the first token into the second token. The second
+/// hop spends exactly what the first paid out, so it only charges the part
+/// of its LP fee rate above the first hop's: the route pays the higher of
+/// the two rates once. The result reports the fee of the first hop, in the
+/// input token.
+pub fn handler(ctx: Context<SwapRoute>, amount_in: u64, min_amount_out: u64) -> Result<SwapResult> {
+    // The intermediate asset has to come back to the signer
+    let first = &ctx.accounts.first;
+    require!(
+        first.recipient.is_none() && first.recipient_token_account.is_none() && first.user_wsol_account.is_none(),
+        ExchangeError::InvalidRoute
+    );
+    require!(
+        first.pool.features_enabled(PoolFlags::ROUTING),
+        ExchangeError::FeatureDisabled
+    );
+    
+    let routed_fee_bps = ctx.accounts.first.first_hop_fee_rate(
+        SwapDirection::TokenToSol,
+        amount_in,
+        Clock::get()?.unix_timestamp,
+    )?;
+    let first = ctx.accounts.first.swap(
+        &ctx.bumps.first,
+        SwapDirection::TokenToSol,
+        amount_in,
+        0,
+        SwapOptions::default(),
+    )?;
+    let second = ctx.accounts.second.swap(
+        &ctx.bumps.second,
+        SwapDirection::SolToToken,
+        first.amount_out,
+        min_amount_out,
+        SwapOptions {
+            hop: 1,
+            routed_fee_bps,
+            ..Default::default()
+        },
+    )?;
+    Ok(SwapResult {
+        amount_in,
+        fee: first.fee,
+        ..second
+    })
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_SWAP_CHUNKS;
+use crate::errors::ExchangeError;
+use super::swap::*;
+
+/// Fill `amount_in` as `n_chunks` consecutive swaps, each against the pool
+/// state the previous one left. Every chunk emits its own swap event and
+/// counts toward the outflow caps on its own; the caller's minimum applies
+/// to the total. Unwrapping wSOL input is not supported, as the first chunk
+/// would close the account.
+pub fn handler(
+    ctx: Context<Swap>,
+    direction: SwapDirection,
+    amount_in: u64,
+    n_chunks: u8,
+    min_total_out: u64,
+) -> Result<SwapResult> {
+    require!(
+        n_chunks > 0 && n_chunks <= MAX_SWAP_CHUNKS && amount_in >= n_chunks as u64,
+        ExchangeError::InvalidChunkCount
+    );
+    require!(
+        direction == SwapDirection::TokenToSol || ctx.accounts.user_wsol_account.is_none(),
+        ExchangeError::InvalidWsolAccount
+    );
+    
+    let chunk = amount_in / n_chunks as u64;
+    let mut total = SwapResult {
+        amount_in: 0,
+        amount_out: 0,
+        fee: 0,
+        price_after_x64: 0,
+    };
+    for i in 0..n_chunks {
+        // The last chunk picks up the rounding remainder
+        let chunk_in = if i + 1 == n_chunks { amount_in - total.amount_in } else { chunk };
+        let result = ctx
+            .accounts
+            .swap(&ctx.bumps, direction, chunk_in, 0, SwapOptions::default())?;
+        total = SwapResult {
+            amount_in: total.amount_in + result.amount_in,
+            amount_out: total.amount_out + result.amount_out,
+            fee: total.fee + result.fee,
+            price_after_x64: result.price_after_x64,
+        };
+    }
+    require!(total.amount_out >= min_total_out, ExchangeError::SlippageExceeded);
+    
+    Ok(total)
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+use crate::state::TradeReceipt;
+use super::swap::*;
+
+#[derive(Accounts)]
+#[instruction(receipt_id: u64)]
+pub struct SwapWithReceipt<'info> {
+    pub swap: Swap<'info>,
+    #[account(
+        init,
+        payer = swap.user,
+        space = TradeReceipt::LEN,
+        seeds = [b"trade_receipt", swap.user.key().as_ref(), receipt_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub trade_receipt: Box<Account<'info, TradeReceipt>>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Swap and write a receipt PDA for it, paid by the signer
+pub fn handler(
+    ctx: Context<SwapWithReceipt>,
+    receipt_id: u64,
+    direction: SwapDirection,
+    amount_in: u64,
+    min_amount_out: u64,
+) -> Result<SwapResult> {
+    let result = ctx
+        .accounts
+        .swap
+        .swap(&ctx.bumps.swap, direction, amount_in, min_amount_out, SwapOptions::default())?;
+    let amount_out = result.amount_out;
+    
+    let pool = &ctx.accounts.swap.pool;
+    let user = ctx.accounts.swap.user.key();
+    let (token_in, token_out, quote_amount, token_amount) = match direction {
+        SwapDirection::TokenToSol => (pool.token_mint, pool.quote_mint, amount_out, amount_in),
+        SwapDirection::SolToToken => (pool.quote_mint, pool.token_mint, amount_in, amount_out),
+    };
+    let params_hash = hashv(&[
+        pool.key().as_ref(),
+        user.as_ref(),
+        &receipt_id.to_le_bytes(),
+        &[direction as u8],
+        &amount_in.to_le_bytes(),
+        &min_amount_out.to_le_bytes(),
+    ])
+    .to_bytes();
+    
+    let clock = Clock::get()?;
+    let receipt = &mut ctx.accounts.trade_receipt;
+    receipt.user = user;
+    receipt.pool = pool.key();
+    receipt.receipt_id = receipt_id;
+    receipt.params_hash = params_hash;
+    receipt.token_in = token_in;
+    receipt.token_out = token_out;
+    receipt.amount_in = amount_in;
+    receipt.amount_out = amount_out;
+    receipt.price_x64 = ((quote_amount as u128) << 64)
+        .checked_div(token_amount as u128)
+        .unwrap_or(0);
+    receipt.slot = clock.slot;
+    receipt.timestamp = clock.unix_timestamp;
+    Ok(result)
+}
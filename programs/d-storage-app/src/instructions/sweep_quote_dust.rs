@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::constants::MAX_QUOTE_DUST;
+use crate::errors::ExchangeError;
+use crate::events::QuoteDustSweptEvent;
+use crate::quote::QuoteVault;
+use crate::state::{LiquidityPool, ProtocolConfig};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DustDestination {
+    /// Paid to the protocol admin, who acts as the treasury
+    Treasury,
+    /// Added to the quote reserve, accruing to LPs
+    Reserves,
+}
+
+#[derive(Accounts)]
+pub struct SweepQuoteDust<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        has_one = sol_vault @ ExchangeError::InvalidVault
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump,
+        constraint = protocol_config.admin == admin.key() @ ExchangeError::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// CHECK: Pool authority PDA
+    #[account(
+        seeds = [b"pool_authority", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: AccountInfo<'info>,
+    /// CHECK: SOL vault, or the quote token vault of a token-quoted pool
+    #[account(
+        mut,
+        seeds = [b"sol_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: AccountInfo<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    /// Treasury quote account, required to sweep a token-quoted pool to the treasury
+    #[account(mut, token::mint = pool.quote_mint, token::authority = admin)]
+    pub treasury_quote_account: Option<Account<'info, TokenAccount>>,
+}
+
+/// Reconcile the quote vault with the pool's accounting by sweeping the
+/// unaccounted surplus left by rounding. Larger surpluses are not dust and
+/// are left for the admin to look into.
+pub fn handler(ctx: Context<SweepQuoteDust>, destination: DustDestination) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let pool = &accounts.pool;
+    let sol_vault = &accounts.sol_vault;
+    let (balance, expected) = if pool.is_native_quote() {
+        (sol_vault.lamports(), pool.expected_quote_balance() + Rent::get()?.minimum_balance(0))
+    } else {
+        let quote_vault = TokenAccount::try_deserialize(&mut &sol_vault.try_borrow_data()?[..])?;
+        (quote_vault.amount, pool.expected_quote_balance())
+    };
+    let dust = balance.saturating_sub(expected);
+    require!(dust > 0 && dust <= MAX_QUOTE_DUST, ExchangeError::InvalidDustAmount);
+    
+    if destination == DustDestination::Treasury {
+        let treasury = if pool.is_native_quote() {
+            accounts.admin.to_account_info()
+        } else {
+            accounts
+                .treasury_quote_account
+                .as_ref()
+                .ok_or(ExchangeError::InvalidQuoteAccount)?
+                .to_account_info()
+        };
+        QuoteVault {
+            pool,
+            vault: sol_vault,
+            token_program: accounts.token_program.as_ref(),
+            system_program: accounts.system_program.as_ref(),
+        }
+        .withdraw(
+            ctx.bumps.sol_vault,
+            &accounts.pool_authority,
+            ctx.bumps.pool_authority,
+            &treasury,
+            dust,
+        )?;
+    }
+    
+    let pool = &mut ctx.accounts.pool;
+    if destination == DustDestination::Reserves {
+        pool.update_price_cumulative(Clock::get()?.unix_timestamp);
+        pool.sol_reserve += dust;
+    }
+    
+    emit!(QuoteDustSweptEvent {
+        pool: pool.key(),
+        amount: dust,
+        destination,
+        sol_reserve: pool.sol_reserve,
+    });
+    
+    assert_pool_invariants!(&ctx.accounts.pool, None, Some(&ctx.accounts.sol_vault), None);
+    Ok(())
+}
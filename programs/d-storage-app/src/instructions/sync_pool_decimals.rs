@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::errors::ExchangeError;
+use crate::events::PoolDecimalsSyncedEvent;
+use crate::state::LiquidityPool;
+
+#[derive(Accounts)]
+pub struct SyncPoolDecimals<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(address = pool.token_mint)]
+    pub token_mint: Account<'info, Mint>,
+    /// Required for token-quoted pools
+    #[account(address = pool.quote_mint @ ExchangeError::InvalidQuoteMint)]
+    pub quote_mint: Option<Account<'info, Mint>>,
+}
+
+/// Record the mint decimals on a pool created before pools kept them.
+/// Permissionless, the values are read off the mints.
+pub fn handler(ctx: Context<SyncPoolDecimals>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    pool.token_decimals = ctx.accounts.token_mint.decimals;
+    pool.quote_decimals = if pool.is_native_quote() {
+        9
+    } else {
+        ctx.accounts.quote_mint.as_ref().ok_or(ExchangeError::InvalidQuoteMint)?.decimals
+    };
+    pool.decimals_recorded = true;
+    
+    emit!(PoolDecimalsSyncedEvent {
+        pool: pool.key(),
+        token_decimals: pool.token_decimals,
+        quote_decimals: pool.quote_decimals,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use super::lp_lock_transfer::LpLockTransfer;
+
+pub fn handler(ctx: Context<LpLockTransfer>, amount: u64) -> Result<()> {
+    require!(
+        Clock::get()?.unix_timestamp >= ctx.accounts.pool.lp_penalty_until,
+        ExchangeError::LpLockActive
+    );
+    let reward = ctx.accounts.claim_reward(ctx.bumps.pool_authority)?;
+    
+    let reward_per_share = ctx.accounts.pool.lp_lock_reward_per_share;
+    let lp_lock = &mut ctx.accounts.lp_lock;
+    lp_lock.amount = lp_lock
+        .amount
+        .checked_sub(amount)
+        .ok_or(ExchangeError::InsufficientLiquidity)?;
+    lp_lock.sync_reward_debt(reward_per_share);
+    ctx.accounts.pool.lp_locked_total -= amount;
+    
+    let accounts = &ctx.accounts;
+    accounts.pay_from_vault(&accounts.lp_lock_vault, ctx.bumps.pool_authority, amount)?;
+    accounts.emit_event(amount, false, reward);
+    
+    Ok(())
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+use super::discount_stake_transfer::DiscountStakeTransfer;
+
+pub fn handler(ctx: Context<DiscountStakeTransfer>, amount: u64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let cooldown_secs = ctx.accounts.stake_discount_config.cooldown_secs;
+    let stake_position = &mut ctx.accounts.stake_position;
+    let withdrawn = stake_position.withdraw_unlocked(now);
+    if amount > 0 {
+        stake_position.unstake(amount, now, cooldown_secs)?;
+    }
+    
+    ctx.accounts.pay_from_vault(ctx.bumps.stake_discount_config, withdrawn)?;
+    ctx.accounts.emit_event(amount, false, withdrawn, now);
+    
+    Ok(())
+}
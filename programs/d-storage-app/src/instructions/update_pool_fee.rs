@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::ExchangeError;
+use crate::events::FeeUpdateEvent;
+use crate::state::{LiquidityPool, ProtocolConfig};
+
+#[derive(Accounts)]
+pub struct UpdatePoolFee<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    /// CHECK: Pool authority PDA
+    #[account(
+        seeds = [b"pool_authority", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool_authority.key() == pool.pool_authority @ ExchangeError::Unauthorized
+    )]
+    pub pool_authority: AccountInfo<'info>,
+    #[account(constraint = pool.is_admin(&admin.key(), admin_nft_account.as_deref()) @ ExchangeError::Unauthorized)]
+    pub admin: Signer<'info>,
+    /// Admin NFT held by the signer, required once the pool is bound to one
+    pub admin_nft_account: Option<Account<'info, TokenAccount>>,
+    /// Protocol fee range, fees outside it need the protocol admin. Without
+    /// it only the 10% cap applies.
+    #[account(seeds = [b"protocol_config"], bump)]
+    pub protocol_config: Option<Box<Account<'info, ProtocolConfig>>>,
+    /// Protocol admin co-signing a fee outside the protocol range
+    pub protocol_admin: Option<Signer<'info>>,
+}
+
+pub fn handler(
+    ctx: Context<UpdatePoolFee>,
+    new_fee_rate: u16,
+) -> Result<()> {
+    let protocol_admin = ctx.accounts.protocol_admin.as_ref().map(|admin| admin.key());
+    set_lp_fee(
+        &mut ctx.accounts.pool,
+        ctx.accounts.protocol_config.as_deref().map(|config| &**config),
+        protocol_admin,
+        ctx.accounts.pool_authority.key(),
+        new_fee_rate,
+    )
+}
+
+/// Change the pool's LP fee within the 10% cap and, when `protocol_config`
+/// is passed, the protocol fee range unless `protocol_admin` co-signed
+pub fn set_lp_fee(
+    pool: &mut Account<LiquidityPool>,
+    protocol_config: Option<&ProtocolConfig>,
+    protocol_admin: Option<Pubkey>,
+    updated_by: Pubkey,
+    new_fee_rate: u16,
+) -> Result<()> {
+    require!(new_fee_rate <= 1000, ExchangeError::InvalidFeeRate); // Max 10%
+    
+    let old_fee_rate = pool.lp_fee_bps;
+    require!(
+        protocol_config.is_none_or(|config| config.fee_change_allowed(old_fee_rate, new_fee_rate, protocol_admin)),
+        ExchangeError::FeeOutsideProtocolRange
+    );
+    pool.apply_fee_change(new_fee_rate, Clock::get()?.unix_timestamp)?;
+    require!(pool.fees_within_cap(), ExchangeError::InvalidFeeRate);
+    
+    emit!(FeeUpdateEvent {
+        pool: pool.key(),
+        old_fee_rate,
+        new_fee_rate,
+        updated_by,
+    });
+    
+    Ok(())
+}
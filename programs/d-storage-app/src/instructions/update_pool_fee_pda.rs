@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::ExchangeError;
+use crate::state::{LiquidityPool, ProtocolConfig};
+use super::update_pool_fee::set_lp_fee;
+
+#[derive(Accounts)]
+pub struct UpdatePoolFeePda<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    /// CHECK: Pool authority PDA
+    #[account(
+        seeds = [b"pool_authority", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool_authority.key() == pool.pool_authority @ ExchangeError::Unauthorized
+    )]
+    pub pool_authority: AccountInfo<'info>,
+    #[account(constraint = pool.is_admin(&admin.key(), admin_nft_account.as_deref()) @ ExchangeError::Unauthorized)]
+    pub admin: Signer<'info>,
+    /// Admin NFT held by the signer, required once the pool is bound to one
+    pub admin_nft_account: Option<Account<'info, TokenAccount>>,
+    /// Protocol fee range, fees outside it need the protocol admin. Without
+    /// it only the 10% cap applies.
+    #[account(seeds = [b"protocol_config"], bump)]
+    pub protocol_config: Option<Box<Account<'info, ProtocolConfig>>>,
+    /// Protocol admin co-signing a fee outside the protocol range
+    pub protocol_admin: Option<Signer<'info>>,
+}
+
+pub fn handler(
+    ctx: Context<UpdatePoolFeePda>,
+    new_fee_rate: u16,
+) -> Result<()> {
+    let protocol_admin = ctx.accounts.protocol_admin.as_ref().map(|admin| admin.key());
+    set_lp_fee(
+        &mut ctx.accounts.pool,
+        ctx.accounts.protocol_config.as_deref().map(|config| &**config),
+        protocol_admin,
+        ctx.accounts.pool_authority.key(),
+        new_fee_rate,
+    )
+}
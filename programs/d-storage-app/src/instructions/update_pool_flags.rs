@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::ExchangeError;
+use crate::events::PoolFlagsUpdateEvent;
+use crate::state::{LiquidityPool, PoolFlags};
+
+#[derive(Accounts)]
+pub struct UpdatePoolFlags<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.is_admin(&admin.key(), admin_nft_account.as_deref()) @ ExchangeError::Unauthorized
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    pub admin: Signer<'info>,
+    /// Admin NFT held by the signer, required once the pool is bound to one
+    pub admin_nft_account: Option<Account<'info, TokenAccount>>,
+}
+
+impl<'info> UpdatePoolFlags<'info> {
+    /// Turn the features in `flags` (`PoolFlags` bits) on or off
+    pub fn update(&mut self, flags: u32, enabled: bool) -> Result<()> {
+        require!(flags != 0 && flags & !PoolFlags::ALL == 0, ExchangeError::InvalidPoolFlags);
+        
+        let pool = &mut self.pool;
+        let old_flags = pool.enabled_features();
+        pool.set_features(flags, enabled);
+        
+        emit!(PoolFlagsUpdateEvent {
+            pool: pool.key(),
+            old_flags,
+            new_flags: pool.enabled_features(),
+            updated_by: self.admin.key(),
+        });
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+
+use crate::errors::ExchangeError;
+use crate::events::PoolParamsUpdateEvent;
+use crate::state::{LiquidityPool, ProtocolConfig};
+
+use super::configure_oracle::OracleParams;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct OutflowCapParams {
+    /// Window length, 0 turns the caps off
+    pub window_secs: u32,
+    pub max_token_outflow_bps: u16,
+    pub max_quote_outflow_bps: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct PauseFlags {
+    pub swaps_paused: bool,
+    pub deposits_paused: bool,
+}
+
+/// Pool settings to change in one go. `None` leaves a setting as it is.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct PoolParams {
+    pub lp_fee_bps: Option<u16>,
+    /// `Some(None)` drops the sell fee override
+    pub sell_fee_bps: Option<Option<u16>>,
+    pub referral_fee_share_bps: Option<u16>,
+    pub outflow_caps: Option<OutflowCapParams>,
+    pub pause_flags: Option<PauseFlags>,
+    /// Needs the `token_mint` and `oracle` accounts
+    pub oracle: Option<OracleParams>,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePoolParams<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.is_admin(&admin.key(), admin_nft_account.as_deref()) @ ExchangeError::Unauthorized
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    pub admin: Signer<'info>,
+    /// Protocol fee range, fees outside it need the protocol admin
+    #[account(seeds = [b"protocol_config"], bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+    /// Protocol admin co-signing a fee outside the protocol range
+    pub protocol_admin: Option<Signer<'info>>,
+    /// Required with an oracle config, for the token decimals
+    #[account(address = pool.token_mint)]
+    pub token_mint: Option<Account<'info, Mint>>,
+    /// CHECK: Parsed and validated as a Pyth price update when an oracle config is given
+    pub oracle: Option<UncheckedAccount<'info>>,
+    /// Admin NFT held by the signer, required once the pool is bound to one
+    pub admin_nft_account: Option<Account<'info, TokenAccount>>,
+}
+
+/// Applies every given setting with the same checks as its standalone
+/// instruction. Any failing check rejects the whole update.
+pub fn handler(
+    ctx: Context<UpdatePoolParams>,
+    params: PoolParams,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let protocol_config = &ctx.accounts.protocol_config;
+    let protocol_admin = ctx.accounts.protocol_admin.as_ref().map(|admin| admin.key());
+    let pool = &mut ctx.accounts.pool;
+    
+    if let Some(new_fee_rate) = params.lp_fee_bps {
+        require!(new_fee_rate <= 1000, ExchangeError::InvalidFeeRate);
+        require!(
+            protocol_config.fee_change_allowed(pool.lp_fee_bps, new_fee_rate, protocol_admin),
+            ExchangeError::FeeOutsideProtocolRange
+        );
+        pool.apply_fee_change(new_fee_rate, now)?;
+    }
+    if let Some(new_sell_fee_rate) = params.sell_fee_bps {
+        require!(
+            new_sell_fee_rate.is_none_or(|rate| rate <= 1000),
+            ExchangeError::InvalidFeeRate
+        );
+        let old_sell_fee_rate = pool.sell_fee_rate();
+        require!(
+            new_sell_fee_rate.is_none_or(|rate| {
+                protocol_config.fee_change_allowed(old_sell_fee_rate, rate, protocol_admin)
+            }),
+            ExchangeError::FeeOutsideProtocolRange
+        );
+        pool.apply_sell_fee_change(new_sell_fee_rate, now)?;
+    }
+    require!(pool.fees_within_cap(), ExchangeError::InvalidFeeRate);
+    
+    if let Some(share_bps) = params.referral_fee_share_bps {
+        require!(share_bps <= 10000, ExchangeError::InvalidReferralShare);
+        pool.referral_fee_share_bps = share_bps;
+    }
+    if let Some(caps) = params.outflow_caps {
+        pool.outflow_caps.configure(
+            caps.window_secs,
+            [caps.max_token_outflow_bps, caps.max_quote_outflow_bps],
+            now,
+        )?;
+    }
+    if let Some(flags) = params.pause_flags {
+        // A guardian pause can only be lifted by the protocol admin
+        require!(!pool.guardian_paused, ExchangeError::GuardianPaused);
+        pool.swaps_paused = flags.swaps_paused;
+        pool.deposits_paused = flags.deposits_paused;
+    }
+    if let Some(oracle_params) = params.oracle {
+        let (Some(token_mint), Some(oracle)) = (&ctx.accounts.token_mint, &ctx.accounts.oracle) else {
+            return err!(ExchangeError::InvalidOracleConfig);
+        };
+        oracle_params.apply(pool, oracle, token_mint.decimals)?;
+    }
+    
+    emit!(PoolParamsUpdateEvent {
+        pool: pool.key(),
+        params,
+        oracle: pool.oracle,
+        updated_by: ctx.accounts.admin.key(),
+    });
+    
+    Ok(())
+}
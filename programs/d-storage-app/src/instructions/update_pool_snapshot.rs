@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{CrankJobKind, CrankRegistry, LiquidityPool, PoolSnapshot, PriceObservations};
+
+#[derive(Accounts)]
+pub struct UpdatePoolSnapshot<'info> {
+    #[account(
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(
+        seeds = [b"observations", pool.key().as_ref()],
+        bump
+    )]
+    pub observations: Box<Account<'info, PriceObservations>>,
+    #[account(
+        mut,
+        seeds = [b"pool_snapshot", pool.key().as_ref()],
+        bump
+    )]
+    pub pool_snapshot: Account<'info, PoolSnapshot>,
+    /// Crank registry, the job is rescheduled when passed
+    #[account(
+        mut,
+        seeds = [b"crank_registry"],
+        bump
+    )]
+    pub crank_registry: Option<Box<Account<'info, CrankRegistry>>>,
+}
+
+/// Permissionless crank copying the pool's current state into its snapshot
+pub fn handler(ctx: Context<UpdatePoolSnapshot>) -> Result<()> {
+    let clock = Clock::get()?;
+    ctx.accounts.pool_snapshot.refresh(
+        &ctx.accounts.pool,
+        &ctx.accounts.observations,
+        clock.unix_timestamp,
+        clock.slot,
+    );
+    if let Some(crank_registry) = &mut ctx.accounts.crank_registry {
+        crank_registry.reschedule(CrankJobKind::PoolSnapshot, ctx.accounts.pool.key(), clock.unix_timestamp);
+    }
+    Ok(())
+}
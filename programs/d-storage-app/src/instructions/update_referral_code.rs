@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::events::ReferralCodeEvent;
+use crate::state::ReferralCode;
+
+#[derive(Accounts)]
+pub struct UpdateReferralCode<'info> {
+    #[account(
+        mut,
+        seeds = [b"referral", referral_code.code.as_bytes()],
+        bump,
+        constraint = referral_code.owner == owner.key() @ ExchangeError::Unauthorized
+    )]
+    pub referral_code: Account<'info, ReferralCode>,
+    pub owner: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateReferralCode>,
+    recipient: Pubkey,
+) -> Result<()> {
+    let referral_code = &mut ctx.accounts.referral_code;
+    referral_code.recipient = recipient;
+    
+    emit!(ReferralCodeEvent {
+        code: referral_code.code.clone(),
+        recipient,
+        owner: referral_code.owner,
+        closed: false,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::ExchangeError;
+use crate::events::SellFeeUpdateEvent;
+use crate::state::{LiquidityPool, ProtocolConfig};
+
+#[derive(Accounts)]
+pub struct UpdateSellFee<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        constraint = pool.is_admin(&admin.key(), admin_nft_account.as_deref()) @ ExchangeError::Unauthorized
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    pub admin: Signer<'info>,
+    /// Admin NFT held by the signer, required once the pool is bound to one
+    pub admin_nft_account: Option<Account<'info, TokenAccount>>,
+    /// Protocol fee range, fees outside it need the protocol admin. Without
+    /// it only the 10% cap applies.
+    #[account(seeds = [b"protocol_config"], bump)]
+    pub protocol_config: Option<Box<Account<'info, ProtocolConfig>>>,
+    /// Protocol admin co-signing a fee outside the protocol range
+    pub protocol_admin: Option<Signer<'info>>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateSellFee>,
+    new_sell_fee_rate: Option<u16>,
+) -> Result<()> {
+    // Max 10%, like the pool fee rate
+    require!(
+        new_sell_fee_rate.is_none_or(|rate| rate <= 1000),
+        ExchangeError::InvalidFeeRate
+    );
+    
+    let pool = &mut ctx.accounts.pool;
+    let old_sell_fee_rate = pool.sell_fee_rate();
+    let protocol_admin = ctx.accounts.protocol_admin.as_ref().map(|admin| admin.key());
+    require!(
+        new_sell_fee_rate.zip(ctx.accounts.protocol_config.as_ref()).is_none_or(|(rate, config)| {
+            config.fee_change_allowed(old_sell_fee_rate, rate, protocol_admin)
+        }),
+        ExchangeError::FeeOutsideProtocolRange
+    );
+    pool.apply_sell_fee_change(new_sell_fee_rate, Clock::get()?.unix_timestamp)?;
+    require!(pool.fees_within_cap(), ExchangeError::InvalidFeeRate);
+    
+    emit!(SellFeeUpdateEvent {
+        pool: pool.key(),
+        old_sell_fee_rate,
+        new_sell_fee_rate: pool.sell_fee_rate(),
+        updated_by: ctx.accounts.admin.key(),
+    });
+    
+    Ok(())
+}
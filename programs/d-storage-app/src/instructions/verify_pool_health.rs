@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+
+use crate::errors::ExchangeError;
+use crate::events::HealthReport;
+use crate::state::LiquidityPool;
+
+#[derive(Accounts)]
+pub struct VerifyPoolHealth<'info> {
+    #[account(
+        seeds = [b"pool", pool.token_mint.key().as_ref()],
+        bump,
+        has_one = token_vault @ ExchangeError::InvalidVault,
+        has_one = sol_vault @ ExchangeError::InvalidVault
+    )]
+    pub pool: Account<'info, LiquidityPool>,
+    #[account(
+        seeds = [b"token_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub token_vault: Account<'info, TokenAccount>,
+    /// CHECK: SOL vault, or the quote token vault of a token-quoted pool
+    #[account(
+        seeds = [b"sol_vault", pool.token_mint.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: AccountInfo<'info>,
+    #[account(address = pool.lp_mint @ ExchangeError::InvalidVault)]
+    pub lp_mint: Account<'info, Mint>,
+}
+
+pub fn handler(ctx: Context<VerifyPoolHealth>) -> Result<()> {
+    let pool = &ctx.accounts.pool;
+    let sol_vault = &ctx.accounts.sol_vault;
+    
+    // SOL vaults keep their rent exemption on top of the expected balance
+    let (sol_vault_balance, expected_sol_vault_balance) = if pool.is_native_quote() {
+        (
+            sol_vault.lamports(),
+            pool.expected_quote_balance() + Rent::get()?.minimum_balance(0),
+        )
+    } else {
+        let quote_vault = TokenAccount::try_deserialize(&mut &sol_vault.try_borrow_data()?[..])?;
+        (quote_vault.amount, pool.expected_quote_balance())
+    };
+    let token_vault_balance = ctx.accounts.token_vault.amount;
+    let expected_token_vault_balance = pool.expected_token_vault_balance();
+    
+    let token_discrepancy = discrepancy(token_vault_balance, expected_token_vault_balance);
+    let sol_discrepancy = discrepancy(sol_vault_balance, expected_sol_vault_balance);
+    
+    // Surpluses (donations, rounding dust) are harmless, shortfalls are not.
+    // LP shares are tracked on the pool; the mint supply is reported as is.
+    emit!(HealthReport {
+        pool: pool.key(),
+        token_vault_balance,
+        expected_token_vault_balance,
+        token_discrepancy,
+        sol_vault_balance,
+        expected_sol_vault_balance,
+        sol_discrepancy,
+        lp_supply: pool.lp_supply,
+        lp_mint_supply: ctx.accounts.lp_mint.supply,
+        healthy: token_discrepancy >= 0 && sol_discrepancy >= 0,
+    });
+    
+    Ok(())
+}
+
+/// Actual minus expected balance, saturated to the `i64` range
+fn discrepancy(actual: u64, expected: u64) -> i64 {
+    (actual as i128 - expected as i128).clamp(i64::MIN as i128, i64::MAX as i128) as i64
+}
@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+use super::manage_strategy::ManageStrategy;
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ManageStrategy<'info>>,
+    collateral_amount: u64,
+) -> Result<()> {
+    let pool_authority_bump = ctx.bumps.pool_authority;
+    ctx.accounts.redeem(ctx.remaining_accounts, pool_authority_bump, collateral_amount, false)
+}
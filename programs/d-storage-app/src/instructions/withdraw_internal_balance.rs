@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::events::InternalBalanceEvent;
+use super::internal_balance_transfer::InternalBalanceTransfer;
+
+pub fn handler(ctx: Context<InternalBalanceTransfer>, amount: u64) -> Result<()> {
+    ctx.accounts.internal_balance.debit(amount)?;
+    
+    let accounts = &ctx.accounts;
+    let internal_vault = accounts.internal_vault(ctx.bumps.internal_vault);
+    match accounts.token_accounts()? {
+        None => internal_vault.pay(None, accounts.user.as_ref(), amount)?,
+        Some((internal_token_vault, user_token_account)) => {
+            internal_vault.pay(Some(&internal_token_vault), &user_token_account, amount)?
+        }
+    }
+    
+    let internal_balance = &accounts.internal_balance;
+    emit!(InternalBalanceEvent {
+        owner: internal_balance.owner,
+        mint: internal_balance.mint,
+        amount,
+        deposit: false,
+        balance: internal_balance.amount,
+    });
+    
+    Ok(())
+}
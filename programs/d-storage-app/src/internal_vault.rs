@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+
+/// Protocol vault backing internal balances. The `internal_vault` PDA holds
+/// SOL balances itself and owns one internal token vault per mint.
+pub struct InternalVault<'a, 'info> {
+    pub vault: &'a AccountInfo<'info>,
+    pub bump: u8,
+    pub token_program: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl<'info> InternalVault<'_, 'info> {
+    /// Pay `amount` out of the vault: SOL when `token_vault` is `None`,
+    /// otherwise tokens from that internal token vault
+    pub fn pay(
+        &self,
+        token_vault: Option<&AccountInfo<'info>>,
+        recipient: &AccountInfo<'info>,
+        amount: u64,
+    ) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        let signer_seeds: &[&[&[u8]]] = &[&[b"internal_vault", &[self.bump]]];
+        match token_vault {
+            None => anchor_lang::system_program::transfer(
+                CpiContext::new_with_signer(
+                    self.system_program.clone(),
+                    anchor_lang::system_program::Transfer {
+                        from: self.vault.clone(),
+                        to: recipient.clone(),
+                    },
+                    signer_seeds,
+                ),
+                amount,
+            ),
+            Some(token_vault) => token::transfer(
+                CpiContext::new_with_signer(
+                    self.token_program.clone(),
+                    Transfer {
+                        from: token_vault.clone(),
+                        to: recipient.clone(),
+                        authority: self.vault.clone(),
+                    },
+                    signer_seeds,
+                ),
+                amount,
+            ),
+        }
+    }
+}
@@ -0,0 +1,74 @@
+//! Pool accounting invariants, checked at the end of the instructions that
+//! move pool assets when built with the `invariant-checks` feature (tests
+//! and local validators). Mainnet builds leave the feature off and the
+//! checks compile away.
+
+#[cfg(feature = "invariant-checks")]
+use anchor_lang::prelude::*;
+#[cfg(feature = "invariant-checks")]
+use anchor_spl::token::{Mint, TokenAccount};
+
+#[cfg(feature = "invariant-checks")]
+use crate::errors::ExchangeError;
+#[cfg(feature = "invariant-checks")]
+use crate::state::LiquidityPool;
+
+/// Check the vaults hold at least what the pool accounts for, and that the
+/// LP mint supply matches the pool's LP supply. Balances are read from the
+/// account data, so transfers made earlier in the instruction are seen.
+/// Instructions that only touch one side may skip the other side's vault.
+#[cfg(feature = "invariant-checks")]
+pub fn check_pool_invariants(
+    pool: &LiquidityPool,
+    token_vault: Option<&AccountInfo>,
+    quote_vault: Option<&AccountInfo>,
+    lp_mint: Option<&AccountInfo>,
+) -> Result<()> {
+    if let Some(token_vault) = token_vault {
+        let token_balance = TokenAccount::try_deserialize(&mut &token_vault.try_borrow_data()?[..])?.amount;
+        if token_balance < pool.expected_token_vault_balance() {
+            msg!(
+                "Invariant: token vault holds {} < {} accounted",
+                token_balance,
+                pool.expected_token_vault_balance()
+            );
+            return err!(ExchangeError::InvariantViolation);
+        }
+    }
+    
+    if let Some(quote_vault) = quote_vault {
+        let quote_balance = if pool.is_native_quote() {
+            quote_vault.lamports().saturating_sub(Rent::get()?.minimum_balance(0))
+        } else {
+            TokenAccount::try_deserialize(&mut &quote_vault.try_borrow_data()?[..])?.amount
+        };
+        if quote_balance < pool.expected_quote_balance() {
+            msg!(
+                "Invariant: quote vault holds {} < {} accounted",
+                quote_balance,
+                pool.expected_quote_balance()
+            );
+            return err!(ExchangeError::InvariantViolation);
+        }
+    }
+    
+    // Once migrated, the LP mint is no longer the pool's to account for
+    if let Some(lp_mint) = lp_mint.filter(|_| !pool.lp_mint_migrated) {
+        let lp_mint_supply = Mint::try_deserialize(&mut &lp_mint.try_borrow_data()?[..])?.supply;
+        if lp_mint_supply != pool.lp_supply {
+            msg!("Invariant: LP mint supply {} != pool LP supply {}", lp_mint_supply, pool.lp_supply);
+            return err!(ExchangeError::InvariantViolation);
+        }
+    }
+    Ok(())
+}
+
+/// Run `check_pool_invariants` in `invariant-checks` builds, expanding to
+/// nothing otherwise
+#[macro_export]
+macro_rules! assert_pool_invariants {
+    ($pool:expr, $token_vault:expr, $quote_vault:expr, $lp_mint:expr $(,)?) => {
+        #[cfg(feature = "invariant-checks")]
+        $crate::invariants::check_pool_invariants($pool, $token_vault, $quote_vault, $lp_mint)?;
+    };
+}
@@ -0,0 +1,66 @@
+//! Byte offsets of the `LiquidityPool` fields clients filter pools on, for
+//! `getProgramAccounts` memcmp filters. Offsets include the 8-byte account
+//! discriminator. Only the leading fields have fixed offsets: Borsh encodes
+//! `Option` in 1 or 1 + N bytes, so everything after `admin` moves.
+
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::state::{CurveType, LiquidityPool};
+
+pub const POOL_DISCRIMINATOR_OFFSET: usize = 0;
+pub const POOL_TOKEN_MINT_OFFSET: usize = 8;
+pub const POOL_CURVE_TYPE_OFFSET: usize = POOL_TOKEN_MINT_OFFSET + 32;
+pub const POOL_FLAGS_OFFSET: usize = POOL_CURVE_TYPE_OFFSET + 1;
+
+/// A memcmp filter: the bytes expected at an offset
+pub type MemcmpFilter = (usize, Vec<u8>);
+
+/// Matches every pool account
+pub fn pool_filter() -> MemcmpFilter {
+    (POOL_DISCRIMINATOR_OFFSET, LiquidityPool::DISCRIMINATOR.to_vec())
+}
+
+pub fn token_mint_filter(token_mint: &Pubkey) -> MemcmpFilter {
+    (POOL_TOKEN_MINT_OFFSET, token_mint.to_bytes().to_vec())
+}
+
+pub fn curve_type_filter(curve_type: CurveType) -> MemcmpFilter {
+    (POOL_CURVE_TYPE_OFFSET, vec![curve_type as u8])
+}
+
+/// Matches pools whose `flags` field is exactly `flags`, which for pools
+/// created since the bits mean turned on includes `PoolFlags::ENABLED_BITS`
+pub fn flags_filter(flags: u32) -> MemcmpFilter {
+    (POOL_FLAGS_OFFSET, flags.to_le_bytes().to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::PoolFlags;
+
+    fn matches(data: &[u8], (offset, bytes): &MemcmpFilter) -> bool {
+        data.get(*offset..offset + bytes.len()) == Some(&bytes[..])
+    }
+
+    #[test]
+    fn filters_match_serialized_pool() {
+        let pool = LiquidityPool {
+            token_mint: Pubkey::new_unique(),
+            curve_type: CurveType::ConstantSum,
+            flags: PoolFlags::ENABLED_BITS | PoolFlags::WSOL | PoolFlags::ROUTING,
+            admin: Some(Pubkey::new_unique()),
+            ..Default::default()
+        };
+        let mut data = Vec::new();
+        pool.try_serialize(&mut data).unwrap();
+        
+        assert!(matches(&data, &pool_filter()));
+        assert!(matches(&data, &token_mint_filter(&pool.token_mint)));
+        assert!(matches(&data, &curve_type_filter(CurveType::ConstantSum)));
+        assert!(matches(&data, &flags_filter(pool.flags)));
+        assert!(!matches(&data, &token_mint_filter(&Pubkey::new_unique())));
+        assert!(!matches(&data, &curve_type_filter(CurveType::ConstantProduct)));
+    }
+}
@@ -1,6 +1,28 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer, Mint};
-use anchor_spl::associated_token::AssociatedToken;
+
+pub mod constants;
+pub mod curve;
+pub mod errors;
+pub mod events;
+// Before `instructions`, which use its macro
+#[macro_use]
+pub mod invariants;
+pub mod instructions;
+pub mod internal_vault;
+pub mod layout;
+pub mod merkle;
+pub mod oracle;
+pub mod quote;
+pub mod sandwich_guard;
+pub mod signed_order;
+pub mod state;
+pub mod strategy;
+
+pub use errors::*;
+pub use events::*;
+pub use instructions::*;
+pub use signed_order::SignedOrder;
+pub use state::*;
 
 declare_id!("HWHCbmSEp3V56MM7oVGYmdVLaFupSUUr9kpbfj2zAAuq");
 
@@ -8,363 +30,230 @@ declare_id!("HWHCbmSEp3V56MM7oVGYmdVLaFupSUUr9kpbfj2zAAuq");
 pub mod flexible_token_exchange {
     use super::*;
 
-    /// Initialize a new liquidity pool for any token-SOL exchange
+    /// Initialize a new liquidity pool for any token-SOL exchange. The payer,
+    /// the creator or a sponsoring launchpad, funds the account rent. The initial
+    /// LP supply is returned as return data, as for the other pool creations.
+    /// A fee rate of 0 picks the protocol default fee.
     pub fn initialize_pool(
         ctx: Context<InitializePool>,
         initial_token_amount: u64,
         initial_sol_amount: u64,
         fee_rate: u16,
-    ) -> Result<()> {
-        // Validate fee rate
-        require!(fee_rate <= 1000, ExchangeError::InvalidFeeRate); // Max 10%
-        
-        // Create SOL vault as System Program owned account
-        let sol_vault_bump = ctx.bumps.sol_vault;
-        let token_mint = ctx.accounts.token_mint.key();
-        let rent_exemption = Rent::get()?.minimum_balance(0);
-        
-        anchor_lang::system_program::create_account(
-            CpiContext::new_with_signer(
-                ctx.accounts.system_program.to_account_info(),
-                anchor_lang::system_program::CreateAccount {
-                    from: ctx.accounts.authority.to_account_info(),
-                    to: ctx.accounts.sol_vault.to_account_info(),
-                },
-                &[&[b"sol_vault", token_mint.as_ref(), &[sol_vault_bump]]],
-            ),
-            rent_exemption + initial_sol_amount,
-            0,
-            &anchor_lang::system_program::ID,
-        )?;
-        
-        let pool = &mut ctx.accounts.pool;
-        pool.token_mint = ctx.accounts.token_mint.key();
-        pool.token_vault = ctx.accounts.token_vault.key();
-        pool.sol_vault = ctx.accounts.sol_vault.key();
-        pool.lp_mint = ctx.accounts.lp_mint.key();
-        pool.token_reserve = initial_token_amount;
-        pool.sol_reserve = initial_sol_amount;
-        pool.lp_supply = 0;
-        pool.fee_rate = fee_rate;
-        pool.pool_authority = ctx.accounts.pool_authority.key();
-        pool.is_initialized = true;
-        pool.created_at = Clock::get()?.unix_timestamp;
-        
-        // Transfer initial liquidity
-        if initial_token_amount > 0 {
-            token::transfer(
-                CpiContext::new(
-                    ctx.accounts.token_program.to_account_info(),
-                    Transfer {
-                        from: ctx.accounts.authority_token_account.to_account_info(),
-                        to: ctx.accounts.token_vault.to_account_info(),
-                        authority: ctx.accounts.authority.to_account_info(),
-                    },
-                ),
-                initial_token_amount,
-            )?;
-        }
-
-        // Mint initial LP tokens (geometric mean of reserves)
-        let initial_lp_tokens = (initial_token_amount as f64 * initial_sol_amount as f64).sqrt() as u64;
-        pool.lp_supply = initial_lp_tokens;
-
-        Ok(())
-    }
-
-    /// Add liquidity to the pool
+        curve_type: CurveType,
+    ) -> Result<u64> {
+        instructions::initialize_pool::handler(
+            ctx,
+            initial_token_amount,
+            initial_sol_amount,
+            fee_rate,
+            curve_type,
+        )
+    }
+
+    /// Initialize a pool quoted in an SPL token (e.g. USDC) instead of SOL.
+    /// The quote side reuses the SOL-named accounts and fields of the pool.
+    pub fn initialize_quote_pool(
+        ctx: Context<InitializeQuotePool>,
+        initial_token_amount: u64,
+        initial_quote_amount: u64,
+        fee_rate: u16,
+        curve_type: CurveType,
+    ) -> Result<u64> {
+        instructions::initialize_quote_pool::handler(
+            ctx,
+            initial_token_amount,
+            initial_quote_amount,
+            fee_rate,
+            curve_type,
+        )
+    }
+
+    /// Launchpad flow in one transaction: create the mint, mint the supply,
+    /// register the token, initialize the pool and seed its liquidity. The
+    /// seeded LP is minted to the creator, vests linearly to them (cliff +
+    /// duration) or is burned into the pool's burn vault. Returns the LP
+    /// minted straight to the creator. The payer, the creator or a sponsoring
+    /// launchpad, funds the account rent. Each
+    /// launch counts against the creator's quota.
+    pub fn create_token_and_pool(
+        ctx: Context<CreateTokenAndPool>,
+        params: LaunchParams,
+    ) -> Result<u64> {
+        instructions::create_token_and_pool::handler(ctx, params)
+    }
+
+    /// Add liquidity to the pool. The amounts actually used are returned as
+    /// return data.
     pub fn add_liquidity(
         ctx: Context<AddLiquidity>,
         token_amount: u64,
         sol_amount: u64,
         min_lp_tokens: u64,
-    ) -> Result<()> {
-        let pool = &mut ctx.accounts.pool;
-        
-        // Calculate optimal amounts based on current ratio
-        let token_reserve = pool.token_reserve;
-        let sol_reserve = pool.sol_reserve;
-        
-        let optimal_sol_amount = if token_reserve == 0 {
-            sol_amount
-        } else {
-            (token_amount * sol_reserve) / token_reserve
-        };
-        
-        let optimal_token_amount = if sol_reserve == 0 {
-            token_amount
-        } else {
-            (sol_amount * token_reserve) / sol_reserve
-        };
-        
-        let final_token_amount = std::cmp::min(token_amount, optimal_token_amount);
-        let final_sol_amount = std::cmp::min(sol_amount, optimal_sol_amount);
-        
-        // Calculate LP tokens to mint
-        let lp_tokens = if pool.lp_supply == 0 {
-            (final_token_amount as f64 * final_sol_amount as f64).sqrt() as u64
-        } else {
-            std::cmp::min(
-                (final_token_amount * pool.lp_supply) / token_reserve,
-                (final_sol_amount * pool.lp_supply) / sol_reserve,
-            )
-        };
-        
-        require!(lp_tokens >= min_lp_tokens, ExchangeError::SlippageExceeded);
-        
-        // Transfer tokens to vault
-        if final_token_amount > 0 {
-            token::transfer(
-                CpiContext::new(
-                    ctx.accounts.token_program.to_account_info(),
-                    Transfer {
-                        from: ctx.accounts.user_token_account.to_account_info(),
-                        to: ctx.accounts.token_vault.to_account_info(),
-                        authority: ctx.accounts.user.to_account_info(),
-                    },
-                ),
-                final_token_amount,
-            )?;
-        }
-        
-        if final_sol_amount > 0 {
-            anchor_lang::system_program::transfer(
-                CpiContext::new(
-                    ctx.accounts.system_program.to_account_info(),
-                    anchor_lang::system_program::Transfer {
-                        from: ctx.accounts.user.to_account_info(),
-                        to: ctx.accounts.sol_vault.to_account_info(),
-                    },
-                ),
-                final_sol_amount,
-            )?;
-        }
-        
-        // Update pool reserves
-        pool.token_reserve += final_token_amount;
-        pool.sol_reserve += final_sol_amount;
-        pool.lp_supply += lp_tokens;
-        
-        Ok(())
-    }
-
-    /// Swap tokens for SOL
+    ) -> Result<DepositAmounts> {
+        instructions::add_liquidity::handler(ctx, token_amount, sol_amount, min_lp_tokens)
+    }
+
+    /// Snapshot the user's token and wSOL (or quote token) balances ahead of
+    /// a withdrawal from another AMM, starting a `migrate_liquidity`
+    pub fn begin_migration(ctx: Context<BeginMigration>) -> Result<()> {
+        instructions::begin_migration::handler(ctx)
+    }
+
+    /// Deposit tokens and SOL withdrawn from another AMM since
+    /// `begin_migration` in the same transaction, up to what the withdrawal
+    /// paid into the snapshotted accounts. Only the AMM's instructions and
+    /// pool creation may run in between, so fresh pools are created by placing
+    /// `initialize_pool` before this instruction; existing pools are topped up.
+    pub fn migrate_liquidity(
+        ctx: Context<MigrateLiquidity>,
+        token_amount: u64,
+        sol_amount: u64,
+        min_lp_tokens: u64,
+    ) -> Result<DepositAmounts> {
+        instructions::migrate_liquidity::handler(ctx, token_amount, sol_amount, min_lp_tokens)
+    }
+
+    /// Swap in either direction using the pool curve. The output goes to the
+    /// signer unless a recipient account is passed. Like every swap
+    /// entrypoint, a `SwapResult` is returned as return data.
+    pub fn swap(
+        ctx: Context<Swap>,
+        direction: SwapDirection,
+        amount_in: u64,
+        min_amount_out: u64,
+    ) -> Result<SwapResult> {
+        instructions::swap::handler(ctx, direction, amount_in, min_amount_out)
+    }
+
+    /// Swap paying an integrator (wallet, bot) a surcharge out of the output,
+    /// capped by the protocol
+    pub fn swap_with_partner_fee(
+        ctx: Context<Swap>,
+        direction: SwapDirection,
+        amount_in: u64,
+        min_amount_out: u64,
+        partner_fee_bps: u16,
+    ) -> Result<SwapResult> {
+        instructions::swap::handler_with_partner_fee(
+            ctx,
+            direction,
+            amount_in,
+            min_amount_out,
+            partner_fee_bps,
+        )
+    }
+
+    /// Swap and record it in a receipt PDA seeded by `receipt_id`, paid by
+    /// the signer, for accounting and compliance integrations
+    pub fn swap_with_receipt(
+        ctx: Context<SwapWithReceipt>,
+        receipt_id: u64,
+        direction: SwapDirection,
+        amount_in: u64,
+        min_amount_out: u64,
+    ) -> Result<SwapResult> {
+        instructions::swap_with_receipt::handler(ctx, receipt_id, direction, amount_in, min_amount_out)
+    }
+
+    /// Swap with an additional cap on price impact, for wallets that warn
+    /// on impact rather than on a minimum output
+    pub fn swap_with_impact_limit(
+        ctx: Context<Swap>,
+        direction: SwapDirection,
+        amount_in: u64,
+        min_amount_out: u64,
+        max_price_impact_bps: u16,
+    ) -> Result<SwapResult> {
+        instructions::swap::handler_with_impact_limit(
+            ctx,
+            direction,
+            amount_in,
+            min_amount_out,
+            max_price_impact_bps,
+        )
+    }
+
+    /// Sell one token and buy another with the proceeds through two pools
+    /// sharing the quote asset. The second hop charges only the part of its
+    /// LP fee rate above the first hop's, so routed trades pay the higher
+    /// rate once instead of both.
+    pub fn swap_route(
+        ctx: Context<SwapRoute>,
+        amount_in: u64,
+        min_amount_out: u64,
+    ) -> Result<SwapResult> {
+        instructions::swap_route::handler(ctx, amount_in, min_amount_out)
+    }
+
+    /// Fill a large trade as `n_chunks` consecutive swaps, each with its own
+    /// event and outflow cap check; `min_total_out` applies to the total
+    pub fn swap_split(
+        ctx: Context<Swap>,
+        direction: SwapDirection,
+        amount_in: u64,
+        n_chunks: u8,
+        min_total_out: u64,
+    ) -> Result<SwapResult> {
+        instructions::swap_split::handler(ctx, direction, amount_in, n_chunks, min_total_out)
+    }
+
+    /// Swap tokens for SOL (legacy entrypoint, use `swap`). Kept for at least
+    /// one release with its original accounts plus the protocol config, and
+    /// emits a deprecation event on every call.
     pub fn swap_token_to_sol(
         ctx: Context<SwapTokenToSol>,
         token_amount: u64,
         min_sol_amount: u64,
     ) -> Result<()> {
-        // Get values before any borrows
-        let token_mint = ctx.accounts.pool.token_mint;
-        let pool = &mut ctx.accounts.pool;
-        
-        // Validate fee rate (defensive programming)
-        require!(pool.fee_rate <= 1000, ExchangeError::InvalidFeeRate);
-        
-        // Calculate SOL output using constant product formula (x * y = k)
-        let token_reserve = pool.token_reserve;
-        let sol_reserve = pool.sol_reserve;
-        
-        // Apply fee (0.3%)
-        let token_amount_after_fee = token_amount * (10000 - pool.fee_rate as u64) / 10000;
-        
-        // Calculate output: sol_out = (sol_reserve * token_in) / (token_reserve + token_in)
-        let sol_amount_out = (sol_reserve * token_amount_after_fee) / (token_reserve + token_amount_after_fee);
-        
-        require!(sol_amount_out >= min_sol_amount, ExchangeError::SlippageExceeded);
-        require!(sol_amount_out < sol_reserve, ExchangeError::InsufficientLiquidity);
-        
-        // Transfer tokens from user to vault
-        token::transfer(
-            CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.user_token_account.to_account_info(),
-                    to: ctx.accounts.token_vault.to_account_info(),
-                    authority: ctx.accounts.user.to_account_info(),
-                },
-            ),
-            token_amount,
-        )?;
-        
-        // Transfer SOL from vault to user
-        let sol_vault_bump = ctx.bumps.sol_vault;
-        anchor_lang::system_program::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.system_program.to_account_info(),
-                anchor_lang::system_program::Transfer {
-                    from: ctx.accounts.sol_vault.to_account_info(),
-                    to: ctx.accounts.user.to_account_info(),
-                },
-                &[&[b"sol_vault", token_mint.as_ref(), &[sol_vault_bump]]],
-            ),
-            sol_amount_out,
-        )?;
-        
-        // Update reserves
-        pool.token_reserve += token_amount;
-        pool.sol_reserve -= sol_amount_out;
-        
-        emit!(SwapEvent {
-            user: ctx.accounts.user.key(),
-            token_in: pool.token_mint,
-            token_out: Pubkey::default(), // SOL
-            amount_in: token_amount,
-            amount_out: sol_amount_out,
-        });
-        
-        Ok(())
-    }
-
-    /// Swap SOL for tokens
+        instructions::swap_legacy::handler_token_to_sol(ctx, token_amount, min_sol_amount)
+    }
+
+    /// Swap SOL for tokens (legacy entrypoint, use `swap`). Kept for at least
+    /// one release with its original accounts plus the protocol config, and
+    /// emits a deprecation event on every call.
     pub fn swap_sol_to_token(
         ctx: Context<SwapSolToToken>,
         sol_amount: u64,
         min_token_amount: u64,
     ) -> Result<()> {
-        // Get values before any borrows
-        let token_reserve = ctx.accounts.pool.token_reserve;
-        let sol_reserve = ctx.accounts.pool.sol_reserve;
-        let fee_rate = ctx.accounts.pool.fee_rate;
-        
-        // Validate fee rate (defensive programming)
-        require!(fee_rate <= 1000, ExchangeError::InvalidFeeRate);
-        
-        // Apply fee
-        let sol_amount_after_fee = sol_amount * (10000 - fee_rate as u64) / 10000;
-        
-        // Calculate output: token_out = (token_reserve * sol_in) / (sol_reserve + sol_in)
-        let token_amount_out = (token_reserve * sol_amount_after_fee) / (sol_reserve + sol_amount_after_fee);
-        
-        require!(token_amount_out >= min_token_amount, ExchangeError::SlippageExceeded);
-        require!(token_amount_out < token_reserve, ExchangeError::InsufficientLiquidity);
-        
-        // Transfer tokens from vault to user
-        let pool_authority_bump = ctx.bumps.pool_authority;
-        let token_mint = ctx.accounts.pool.token_mint;
-        token::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.token_vault.to_account_info(),
-                    to: ctx.accounts.user_token_account.to_account_info(),
-                    authority: ctx.accounts.pool_authority.to_account_info(),
-                },
-                &[&[b"pool_authority", token_mint.as_ref(), &[pool_authority_bump]]],
-            ),
-            token_amount_out,
-        )?;
-        
-        // Transfer SOL from user to vault
-        anchor_lang::system_program::transfer(
-            CpiContext::new(
-                ctx.accounts.system_program.to_account_info(),
-                anchor_lang::system_program::Transfer {
-                    from: ctx.accounts.user.to_account_info(),
-                    to: ctx.accounts.sol_vault.to_account_info(),
-                },
-            ),
-            sol_amount,
-        )?;
-        
-        // Update reserves
-        let pool = &mut ctx.accounts.pool;
-        pool.sol_reserve += sol_amount;
-        pool.token_reserve -= token_amount_out;
-        
-        emit!(SwapEvent {
-            user: ctx.accounts.user.key(),
-            token_in: Pubkey::default(), // SOL
-            token_out: token_mint,
-            amount_in: sol_amount,
-            amount_out: token_amount_out,
-        });
-        
-        Ok(())
-    }
-
-    /// Remove liquidity from the pool
+        instructions::swap_legacy::handler_sol_to_token(ctx, sol_amount, min_token_amount)
+    }
+
+    /// Remove liquidity from the pool. The amounts paid out and the LP tokens
+    /// burned are returned as return data.
     pub fn remove_liquidity(
         ctx: Context<RemoveLiquidity>,
         lp_tokens: u64,
         min_token_amount: u64,
         min_sol_amount: u64,
-    ) -> Result<()> {
-        // Get values before any borrows
-        let pool_bump = ctx.bumps.pool;
-        let token_reserve = ctx.accounts.pool.token_reserve;
-        let sol_reserve = ctx.accounts.pool.sol_reserve;
-        let lp_supply = ctx.accounts.pool.lp_supply;
-        
-        // Calculate amounts to withdraw
-        let token_amount = (token_reserve * lp_tokens) / lp_supply;
-        let sol_amount = (sol_reserve * lp_tokens) / lp_supply;
-        
-        require!(token_amount >= min_token_amount, ExchangeError::SlippageExceeded);
-        require!(sol_amount >= min_sol_amount, ExchangeError::SlippageExceeded);
-        
-        // Transfer tokens from vault to user
-        if token_amount > 0 {
-            let pool_authority_bump = ctx.bumps.pool_authority;
-            let token_mint = ctx.accounts.pool.token_mint;
-            token::transfer(
-                CpiContext::new_with_signer(
-                    ctx.accounts.token_program.to_account_info(),
-                    Transfer {
-                        from: ctx.accounts.token_vault.to_account_info(),
-                        to: ctx.accounts.user_token_account.to_account_info(),
-                        authority: ctx.accounts.pool_authority.to_account_info(),
-                    },
-                    &[&[b"pool_authority", token_mint.as_ref(), &[pool_authority_bump]]],
-                ),
-                token_amount,
-            )?;
-        }
-        
-        // Transfer SOL from vault to user
-        if sol_amount > 0 {
-            let token_mint = ctx.accounts.pool.token_mint;
-            anchor_lang::system_program::transfer(
-                CpiContext::new_with_signer(
-                    ctx.accounts.system_program.to_account_info(),
-                    anchor_lang::system_program::Transfer {
-                        from: ctx.accounts.sol_vault.to_account_info(),
-                        to: ctx.accounts.user.to_account_info(),
-                    },
-                    &[&[b"sol_vault", token_mint.as_ref(), &[ctx.bumps.sol_vault]]],
-                ),
-                sol_amount,
-            )?;
-        }
-        
-        // Update pool state
-        let pool = &mut ctx.accounts.pool;
-        pool.token_reserve -= token_amount;
-        pool.sol_reserve -= sol_amount;
-        pool.lp_supply -= lp_tokens;
-        
-        Ok(())
-    }
-
-    /// Update pool fee rate (only pool authority can call this)
+    ) -> Result<WithdrawAmounts> {
+        instructions::remove_liquidity::handler(ctx, lp_tokens, min_token_amount, min_sol_amount)
+    }
+
+    /// Update the pool's LP fee rate (only the pool admin can call this)
     pub fn update_pool_fee(
         ctx: Context<UpdatePoolFee>,
         new_fee_rate: u16,
     ) -> Result<()> {
-        require!(new_fee_rate <= 1000, ExchangeError::InvalidFeeRate); // Max 10%
-        
-        let pool = &mut ctx.accounts.pool;
-        let old_fee_rate = pool.fee_rate;
-        pool.fee_rate = new_fee_rate;
-        
-        emit!(FeeUpdateEvent {
-            pool: pool.key(),
-            old_fee_rate,
-            new_fee_rate,
-            updated_by: ctx.accounts.pool_authority.key(),
-        });
-        
-        Ok(())
+        instructions::update_pool_fee::handler(ctx, new_fee_rate)
+    }
+
+    /// Charge sells a different fee rate than buys, or pass `None` to use
+    /// the pool fee rate for both
+    pub fn update_sell_fee(
+        ctx: Context<UpdateSellFee>,
+        new_sell_fee_rate: Option<u16>,
+    ) -> Result<()> {
+        instructions::update_sell_fee::handler(ctx, new_sell_fee_rate)
+    }
+
+    /// Schedule a window of reduced or zero swap fees, or clear one
+    pub fn set_fee_holiday(
+        ctx: Context<SetFeeHoliday>,
+        index: u8,
+        holiday: FeeHoliday,
+    ) -> Result<()> {
+        instructions::set_fee_holiday::handler(ctx, index, holiday)
     }
 
     /// Update pool fee rate using PDA authority (for automated fixes)
@@ -372,342 +261,875 @@ pub mod flexible_token_exchange {
         ctx: Context<UpdatePoolFeePda>,
         new_fee_rate: u16,
     ) -> Result<()> {
-        require!(new_fee_rate <= 1000, ExchangeError::InvalidFeeRate); // Max 10%
-        
-        let pool = &mut ctx.accounts.pool;
-        let old_fee_rate = pool.fee_rate;
-        pool.fee_rate = new_fee_rate;
-        
-        emit!(FeeUpdateEvent {
-            pool: pool.key(),
-            old_fee_rate,
-            new_fee_rate,
-            updated_by: ctx.accounts.pool_authority.key(),
-        });
-        
-        Ok(())
+        instructions::update_pool_fee_pda::handler(ctx, new_fee_rate)
     }
-}
 
-// Account structures
-#[account]
-pub struct TokenRegistry {
-    pub token_mint: Pubkey,
-    pub name: String,
-    pub symbol: String,
-    pub decimals: u8,
-    pub metadata_uri: String,
-    pub creator: Pubkey,
-    pub total_supply: u64,
-    pub is_active: bool,
-    pub created_at: i64,
-}
+    /// Schedule (or cancel with 0) the pool sunset. After the deadline the pool
+    /// rejects deposits and swaps, but withdrawals keep working.
+    pub fn schedule_sunset(
+        ctx: Context<ScheduleSunset>,
+        sunset_at: i64,
+    ) -> Result<()> {
+        instructions::schedule_sunset::handler(ctx, sunset_at)
+    }
 
-#[account]
-pub struct LiquidityPool {
-    pub token_mint: Pubkey,        // Configurable token mint
-    pub token_vault: Pubkey,       // Token vault account
-    pub sol_vault: Pubkey,         // SOL vault account
-    pub lp_mint: Pubkey,          // LP token mint
-    pub token_reserve: u64,        // Current token reserves
-    pub sol_reserve: u64,          // Current SOL reserves
-    pub lp_supply: u64,           // Total LP tokens issued
-    pub fee_rate: u16,            // Fee rate in basis points (e.g., 30 = 0.3%)
-    pub pool_authority: Pubkey,    // Pool authority PDA
-    pub is_initialized: bool,      // Pool initialization status
-    pub created_at: i64,          // Pool creation timestamp
-}
+    /// Enable or disable the liquidity provider allowlist (swaps stay public)
+    pub fn set_lp_allowlist(
+        ctx: Context<SetLpAllowlist>,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::set_lp_allowlist::handler(ctx, enabled)
+    }
 
-// Context structures
-#[derive(Accounts)]
-pub struct InitializePool<'info> {
-    /// CHECK: Token mint account - must be a valid SPL token mint
-    #[account(
-        constraint = token_mint.mint_authority.is_some() @ ExchangeError::InvalidTokenMint
-    )]
-    pub token_mint: Account<'info, Mint>,
-    
-    #[account(
-        init,
-        payer = authority,
-        space = 8 + 32 + 32 + 32 + 32 + 32 + 8 + 8 + 8 + 2 + 32 + 1 + 8,
-        seeds = [b"pool", token_mint.key().as_ref()],
-        bump
-    )]
-    pub pool: Account<'info, LiquidityPool>,
-    
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    
-    /// CHECK: Pool authority PDA
-    #[account(
-        seeds = [b"pool_authority", token_mint.key().as_ref()],
-        bump
-    )]
-    pub pool_authority: AccountInfo<'info>,
-    
-    #[account(
-        init,
-        payer = authority,
-        token::mint = token_mint,
-        token::authority = pool_authority,
-        seeds = [b"token_vault", token_mint.key().as_ref()],
-        bump
-    )]
-    pub token_vault: Account<'info, TokenAccount>,
-    
-    /// CHECK: SOL vault - will be created as System Program owned account
-    #[account(
-        mut,
-        seeds = [b"sol_vault", token_mint.key().as_ref()],
-        bump
-    )]
-    pub sol_vault: AccountInfo<'info>,
-    
-    #[account(
-        init,
-        payer = authority,
-        mint::decimals = 6,
-        mint::authority = pool_authority,
-        seeds = [b"lp_mint", token_mint.key().as_ref()],
-        bump
-    )]
-    pub lp_mint: Account<'info, Mint>,
-    
-    #[account(
-        mut,
-        associated_token::mint = token_mint,
-        associated_token::authority = authority
-    )]
-    pub authority_token_account: Account<'info, TokenAccount>,
-    
-    pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
-    pub rent: Sysvar<'info, Rent>,
-}
+    /// Allow a provider to add liquidity to an allowlisted pool, until
+    /// `expires_at` (0 = no expiry)
+    pub fn add_lp_allowlist_entry(
+        ctx: Context<AddLpAllowlistEntry>,
+        provider: Pubkey,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::add_lp_allowlist_entry::handler(ctx, provider, expires_at)
+    }
 
-#[derive(Accounts)]
-pub struct AddLiquidity<'info> {
-    #[account(
-        mut,
-        seeds = [b"pool", pool.token_mint.key().as_ref()],
-        bump
-    )]
-    pub pool: Account<'info, LiquidityPool>,
-    #[account(mut)]
-    pub user: Signer<'info>,
-    #[account(
-        mut,
-        associated_token::mint = pool.token_mint,
-        associated_token::authority = user
-    )]
-    pub user_token_account: Account<'info, TokenAccount>,
-    #[account(
-        mut,
-        seeds = [b"token_vault", pool.token_mint.key().as_ref()],
-        bump
-    )]
-    pub token_vault: Account<'info, TokenAccount>,
-    /// CHECK: SOL vault
-    #[account(
-        mut,
-        seeds = [b"sol_vault", pool.token_mint.key().as_ref()],
-        bump
-    )]
-    pub sol_vault: AccountInfo<'info>,
-    pub token_program: Program<'info, Token>,
-    pub system_program: Program<'info, System>,
-}
+    /// Revoke a provider's allowlist entry and refund its rent to whoever paid it
+    pub fn remove_lp_allowlist_entry(ctx: Context<RemoveLpAllowlistEntry>) -> Result<()> {
+        instructions::remove_lp_allowlist_entry::handler(ctx)
+    }
 
-#[derive(Accounts)]
-pub struct SwapTokenToSol<'info> {
-    #[account(
-        mut,
-        seeds = [b"pool", pool.token_mint.key().as_ref()],
-        bump,
-        constraint = pool.fee_rate <= 1000 @ ExchangeError::InvalidFeeRate
-    )]
-    pub pool: Account<'info, LiquidityPool>,
-    #[account(mut)]
-    pub user: Signer<'info>,
-    #[account(
-        mut,
-        associated_token::mint = pool.token_mint,
-        associated_token::authority = user
-    )]
-    pub user_token_account: Account<'info, TokenAccount>,
-    /// CHECK: Pool authority PDA
-    #[account(
-        seeds = [b"pool_authority", pool.token_mint.key().as_ref()],
-        bump
-    )]
-    pub pool_authority: AccountInfo<'info>,
-    #[account(
-        mut,
-        seeds = [b"token_vault", pool.token_mint.key().as_ref()],
-        bump
-    )]
-    pub token_vault: Account<'info, TokenAccount>,
-    /// CHECK: SOL vault
-    #[account(
-        mut,
-        seeds = [b"sol_vault", pool.token_mint.key().as_ref()],
-        bump
-    )]
-    pub sol_vault: AccountInfo<'info>,
-    pub token_program: Program<'info, Token>,
-    pub system_program: Program<'info, System>,
-}
+    /// Close an expired allowlist entry, refunding its rent (permissionless)
+    pub fn prune_lp_allowlist_entry(ctx: Context<PruneLpAllowlistEntry>) -> Result<()> {
+        instructions::prune_lp_allowlist_entry::handler(ctx)
+    }
 
-#[derive(Accounts)]
-pub struct SwapSolToToken<'info> {
-    #[account(
-        mut,
-        seeds = [b"pool", pool.token_mint.key().as_ref()],
-        bump,
-        constraint = pool.fee_rate <= 1000 @ ExchangeError::InvalidFeeRate
-    )]
-    pub pool: Account<'info, LiquidityPool>,
-    #[account(mut)]
-    pub user: Signer<'info>,
-    #[account(
-        mut,
-        associated_token::mint = pool.token_mint,
-        associated_token::authority = user
-    )]
-    pub user_token_account: Account<'info, TokenAccount>,
-    /// CHECK: Pool authority PDA
-    #[account(
-        seeds = [b"pool_authority", pool.token_mint.key().as_ref()],
-        bump
-    )]
-    pub pool_authority: AccountInfo<'info>,
-    #[account(
-        mut,
-        seeds = [b"token_vault", pool.token_mint.key().as_ref()],
-        bump
-    )]
-    pub token_vault: Account<'info, TokenAccount>,
-    /// CHECK: SOL vault
-    #[account(
-        mut,
-        seeds = [b"sol_vault", pool.token_mint.key().as_ref()],
-        bump
-    )]
-    pub sol_vault: AccountInfo<'info>,
-    pub token_program: Program<'info, Token>,
-    pub system_program: Program<'info, System>,
-}
+    /// Configure the lending strategy that may borrow idle token reserves,
+    /// capped at half the reserve. A cap of 0 disables new deployments.
+    pub fn configure_strategy(
+        ctx: Context<ConfigureStrategy>,
+        strategy_program: Pubkey,
+        cap_bps: u16,
+    ) -> Result<()> {
+        instructions::configure_strategy::handler(ctx, strategy_program, cap_bps)
+    }
 
-#[derive(Accounts)]
-pub struct RemoveLiquidity<'info> {
-    #[account(
-        mut,
-        seeds = [b"pool", pool.token_mint.key().as_ref()],
-        bump
-    )]
-    pub pool: Account<'info, LiquidityPool>,
-    #[account(mut)]
-    pub user: Signer<'info>,
-    #[account(
-        mut,
-        associated_token::mint = pool.token_mint,
-        associated_token::authority = user
-    )]
-    pub user_token_account: Account<'info, TokenAccount>,
-    /// CHECK: Pool authority PDA
-    #[account(
-        seeds = [b"pool_authority", pool.token_mint.key().as_ref()],
-        bump
-    )]
-    pub pool_authority: AccountInfo<'info>,
-    #[account(
-        mut,
-        seeds = [b"token_vault", pool.token_mint.key().as_ref()],
-        bump
-    )]
-    pub token_vault: Account<'info, TokenAccount>,
-    /// CHECK: SOL vault
-    #[account(
-        mut,
-        seeds = [b"sol_vault", pool.token_mint.key().as_ref()],
-        bump
-    )]
-    pub sol_vault: AccountInfo<'info>,
-    pub token_program: Program<'info, Token>,
-    pub system_program: Program<'info, System>,
-}
+    /// Supply idle vault tokens to the lending strategy, up to the configured
+    /// cap. Lent tokens above the cap can be recalled by anyone.
+    pub fn deploy_to_strategy<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ManageStrategy<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::deploy_to_strategy::handler(ctx, amount)
+    }
 
+    /// Redeem strategy collateral back into the token vault. Interest earned on
+    /// the redeemed share is added to the token reserve as LP yield, and a
+    /// shortfall is taken out of it.
+    pub fn withdraw_from_strategy<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ManageStrategy<'info>>,
+        collateral_amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_from_strategy::handler(ctx, collateral_amount)
+    }
 
+    /// Redeem lent tokens above the strategy cap, left there once swaps and
+    /// withdrawals shrank the reserve or the cap was lowered. Anyone can
+    /// call it ahead of a swap or withdrawal that needs the tokens.
+    pub fn recall_from_strategy<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RecallFromStrategy<'info>>,
+        collateral_amount: u64,
+    ) -> Result<()> {
+        instructions::recall_from_strategy::handler(ctx, collateral_amount)
+    }
 
-#[derive(Accounts)]
-pub struct UpdatePoolFee<'info> {
-    #[account(
-        mut,
-        seeds = [b"pool", pool.token_mint.key().as_ref()],
-        bump
-    )]
-    pub pool: Account<'info, LiquidityPool>,
-    /// CHECK: Pool authority PDA
-    #[account(
-        seeds = [b"pool_authority", pool.token_mint.key().as_ref()],
-        bump,
-        constraint = pool_authority.key() == pool.pool_authority @ ExchangeError::Unauthorized
-    )]
-    pub pool_authority: AccountInfo<'info>,
-}
+    /// Break-glass exit: redeem all strategy collateral and disable the strategy
+    pub fn emergency_derisk<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ManageStrategy<'info>>,
+    ) -> Result<()> {
+        instructions::emergency_derisk::handler(ctx)
+    }
 
-#[derive(Accounts)]
-pub struct UpdatePoolFeePda<'info> {
-    #[account(
-        mut,
-        seeds = [b"pool", pool.token_mint.key().as_ref()],
-        bump
-    )]
-    pub pool: Account<'info, LiquidityPool>,
-    /// CHECK: Pool authority PDA
-    #[account(
-        seeds = [b"pool_authority", pool.token_mint.key().as_ref()],
-        bump,
-        constraint = pool_authority.key() == pool.pool_authority @ ExchangeError::Unauthorized
-    )]
-    pub pool_authority: AccountInfo<'info>,
-}
+    /// Configure the oracle guard against the Pyth feed `feed_id`, read from
+    /// fully verified price updates only. Swaps that would move the pool price
+    /// more than `max_deviation_bps` away from the feed are rejected; a
+    /// deviation of 0 disables the guard. When the feed is stale or its confidence interval
+    /// is too wide, swaps either revert or fall back to pure curve pricing.
+    pub fn configure_oracle(
+        ctx: Context<ConfigureOracle>,
+        feed_id: [u8; 32],
+        max_deviation_bps: u16,
+        max_confidence_bps: u16,
+        max_staleness_secs: i64,
+        fallback_to_curve: bool,
+    ) -> Result<()> {
+        instructions::configure_oracle::handler(
+            ctx,
+            feed_id,
+            max_deviation_bps,
+            max_confidence_bps,
+            max_staleness_secs,
+            fallback_to_curve,
+        )
+    }
 
+    /// Register the recovery address and guardian used by the emergency drain.
+    /// Changing either needs the current guardian, or the protocol admin, to
+    /// co-sign with the pool admin.
+    pub fn configure_emergency_recovery(
+        ctx: Context<ConfigureEmergencyRecovery>,
+        recovery_address: Pubkey,
+        guardian: Pubkey,
+    ) -> Result<()> {
+        instructions::configure_emergency_recovery::handler(ctx, recovery_address, guardian)
+    }
 
+    /// Start the emergency drain timelock (admin and guardian must both sign)
+    pub fn request_emergency_drain(ctx: Context<RequestEmergencyDrain>) -> Result<()> {
+        instructions::request_emergency_drain::handler(ctx)
+    }
 
-// Events
-#[event]
-pub struct SwapEvent {
-    pub user: Pubkey,
-    pub token_in: Pubkey,
-    pub token_out: Pubkey,
-    pub amount_in: u64,
-    pub amount_out: u64,
-}
+    /// Cancel a pending emergency drain (admin or guardian)
+    pub fn cancel_emergency_drain(ctx: Context<CancelEmergencyDrain>) -> Result<()> {
+        instructions::cancel_emergency_drain::handler(ctx)
+    }
 
-#[event]
-pub struct FeeUpdateEvent {
-    pub pool: Pubkey,
-    pub old_fee_rate: u16,
-    pub new_fee_rate: u16,
-    pub updated_by: Pubkey,
-}
+    /// Break-glass: after the timelock, move the vault funds (less owed
+    /// royalties and protocol fees) to the recovery address and sunset the
+    /// pool. Internal credits must be settled first. Only for use after a
+    /// confirmed exploit.
+    pub fn emergency_drain(ctx: Context<EmergencyDrain>) -> Result<()> {
+        instructions::emergency_drain::handler(ctx)
+    }
+
+    /// Set the share of the swap fee paid to referrers, in basis points of the fee
+    pub fn set_referral_fee_share(
+        ctx: Context<SetReferralFeeShare>,
+        share_bps: u16,
+    ) -> Result<()> {
+        instructions::set_referral_fee_share::handler(ctx, share_bps)
+    }
+
+    /// Register a short human-readable referral code (e.g. `?ref=CODE`)
+    pub fn create_referral_code(
+        ctx: Context<CreateReferralCode>,
+        code: String,
+        recipient: Pubkey,
+    ) -> Result<()> {
+        instructions::create_referral_code::handler(ctx, code, recipient)
+    }
+
+    /// Point a referral code at a new payout address
+    pub fn update_referral_code(
+        ctx: Context<UpdateReferralCode>,
+        recipient: Pubkey,
+    ) -> Result<()> {
+        instructions::update_referral_code::handler(ctx, recipient)
+    }
+
+    /// Release a referral code and refund its rent to the owner
+    pub fn close_referral_code(ctx: Context<CloseReferralCode>) -> Result<()> {
+        instructions::close_referral_code::handler(ctx)
+    }
+
+    /// Permanently give up admin rights, freezing every pool parameter
+    pub fn renounce_authority(ctx: Context<RenounceAuthority>) -> Result<()> {
+        instructions::renounce_authority::handler(ctx)
+    }
+
+    /// Pause swaps and deposits independently. Withdrawals are never paused so
+    /// liquidity providers can always exit.
+    pub fn set_pause_flags(
+        ctx: Context<SetPauseFlags>,
+        swaps_paused: bool,
+        deposits_paused: bool,
+    ) -> Result<()> {
+        instructions::set_pause_flags::handler(ctx, swaps_paused, deposits_paused)
+    }
+
+    /// Configure the anti-bot launch window: until `launch_window_end` each
+    /// wallet must wait `cooldown_slots` slots between swaps
+    pub fn configure_launch_cooldown(
+        ctx: Context<ConfigureLaunchCooldown>,
+        cooldown_slots: u64,
+        launch_window_end: i64,
+    ) -> Result<()> {
+        instructions::configure_launch_cooldown::handler(ctx, cooldown_slots, launch_window_end)
+    }
+
+    /// Create the per-wallet cooldown tracker required to swap during launch
+    pub fn init_swap_cooldown(ctx: Context<InitSwapCooldown>) -> Result<()> {
+        instructions::init_swap_cooldown::handler(ctx)
+    }
+
+    /// Close a cooldown tracker once the launch window is over
+    pub fn close_swap_cooldown(ctx: Context<CloseSwapCooldown>) -> Result<()> {
+        instructions::close_swap_cooldown::handler(ctx)
+    }
+
+    /// Create the internal vault's token account for a mint
+    pub fn init_internal_vault(ctx: Context<InitInternalVault>) -> Result<()> {
+        instructions::init_internal_vault::handler(ctx)
+    }
+
+    /// Open an internal balance for a mint (default pubkey for SOL)
+    pub fn init_internal_balance(
+        ctx: Context<InitInternalBalance>,
+        mint: Pubkey,
+    ) -> Result<()> {
+        instructions::init_internal_balance::handler(ctx, mint)
+    }
+
+    /// Move assets from the wallet into the user's internal balance
+    pub fn deposit_internal_balance(
+        ctx: Context<InternalBalanceTransfer>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::deposit_internal_balance::handler(ctx, amount)
+    }
+
+    /// Move assets from the user's internal balance back to the wallet
+    pub fn withdraw_internal_balance(
+        ctx: Context<InternalBalanceTransfer>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_internal_balance::handler(ctx, amount)
+    }
+
+    /// Close an empty internal balance and refund its rent
+    pub fn close_internal_balance(ctx: Context<CloseInternalBalance>) -> Result<()> {
+        instructions::close_internal_balance::handler(ctx)
+    }
+
+    /// Swap against internal balances instead of wallet token accounts. The
+    /// pool keeps its side in the internal vaults until `settle_internal_credits`.
+    pub fn swap_internal(
+        ctx: Context<SwapInternal>,
+        direction: SwapDirection,
+        amount_in: u64,
+        min_amount_out: u64,
+    ) -> Result<SwapResult> {
+        instructions::swap_internal::handler(ctx, direction, amount_in, min_amount_out)
+    }
+
+    /// Add liquidity funded from internal balances, left in the internal
+    /// vaults as pool credit until `settle_internal_credits`
+    pub fn add_liquidity_internal(
+        ctx: Context<AddLiquidityInternal>,
+        token_amount: u64,
+        sol_amount: u64,
+        min_lp_tokens: u64,
+    ) -> Result<DepositAmounts> {
+        instructions::add_liquidity_internal::handler(ctx, token_amount, sol_amount, min_lp_tokens)
+    }
+
+    /// Move the pool assets held in the internal vaults into the pool vaults
+    pub fn settle_internal_credits(ctx: Context<SettleInternalCredits>) -> Result<()> {
+        instructions::settle_internal_credits::handler(ctx)
+    }
+
+    /// Settle a market maker's activity: pay out each internal balance listed
+    /// in the remaining accounts with one transfer per mint. Remaining accounts
+    /// are (internal balance, vault, destination) triples; for SOL the vault is
+    /// the internal vault and the destination the signer's wallet.
+    pub fn settle_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SettleBatch<'info>>,
+    ) -> Result<()> {
+        instructions::settle_batch::handler(ctx)
+    }
+
+    /// Fill a user's off-chain signed swap order against their internal
+    /// balances. The transaction must verify the owner's signature over the
+    /// borsh-encoded order in an Ed25519 instruction placed right before this one.
+    pub fn settle_signed_order(
+        ctx: Context<SettleSignedOrder>,
+        order: SignedOrder,
+    ) -> Result<SwapResult> {
+        instructions::settle_signed_order::handler(ctx, order)
+    }
+
+    /// Close the nonce of an expired signed order, refunding its rent to the
+    /// filler (permissionless)
+    pub fn close_order_nonce(ctx: Context<CloseOrderNonce>) -> Result<()> {
+        instructions::close_order_nonce::handler(ctx)
+    }
+
+    /// Create the protocol config (only the program upgrade authority)
+    pub fn init_protocol_config(
+        ctx: Context<InitProtocolConfig>,
+        admin: Pubkey,
+    ) -> Result<()> {
+        instructions::init_protocol_config::handler(ctx, admin)
+    }
+
+    /// Hand protocol admin rights to a new address
+    pub fn set_protocol_admin(
+        ctx: Context<SetProtocolAdmin>,
+        new_admin: Pubkey,
+    ) -> Result<()> {
+        instructions::set_protocol_admin::handler(ctx, new_admin)
+    }
+
+    /// Mark a vetted token mint with a protocol badge
+    pub fn issue_token_badge(ctx: Context<IssueTokenBadge>) -> Result<()> {
+        instructions::issue_token_badge::handler(ctx)
+    }
+
+    /// Revoke a token badge and refund its rent to the protocol admin
+    pub fn revoke_token_badge(ctx: Context<RevokeTokenBadge>) -> Result<()> {
+        instructions::revoke_token_badge::handler(ctx)
+    }
+
+    /// Sync the pool's badge flag after a badge is issued or revoked
+    pub fn refresh_pool_badge(ctx: Context<RefreshPoolBadge>) -> Result<()> {
+        instructions::refresh_pool_badge::handler(ctx)
+    }
+
+    /// List a pool created before the pool index existed (anyone can pay)
+    pub fn register_pool(ctx: Context<RegisterPool>) -> Result<()> {
+        instructions::register_pool::handler(ctx)
+    }
+
+    /// Program version and enabled feature bits, returned as return data
+    pub fn get_version(ctx: Context<GetVersion>) -> Result<ProgramVersion> {
+        instructions::get_version::handler(ctx)
+    }
+
+    /// Permissionless health check: compare vault balances with the recorded
+    /// reserves and emit a `HealthReport` for monitoring bots
+    pub fn verify_pool_health(ctx: Context<VerifyPoolHealth>) -> Result<()> {
+        instructions::verify_pool_health::handler(ctx)
+    }
+
+    /// Configure the early-withdrawal penalty. Until `penalty_until`, removing
+    /// liquidity forfeits `penalty_bps` of the LP tokens to addresses that keep
+    /// their LP locked (or burns them when nothing is locked).
+    pub fn configure_lp_lock(
+        ctx: Context<ConfigureLpLock>,
+        penalty_bps: u16,
+        penalty_until: i64,
+    ) -> Result<()> {
+        instructions::configure_lp_lock::handler(ctx, penalty_bps, penalty_until)
+    }
+
+    /// Lock LP tokens to share withdrawal penalties; `amount` 0 only claims
+    pub fn lock_lp(
+        ctx: Context<LpLockTransfer>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::lock_lp::handler(ctx, amount)
+    }
+
+    /// Unlock LP tokens once the penalty period is over, claiming rewards
+    pub fn unlock_lp(
+        ctx: Context<LpLockTransfer>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::unlock_lp::handler(ctx, amount)
+    }
+
+    /// Let `compounder` restake this lock's rewards (default revokes)
+    pub fn set_lp_lock_compounder(ctx: Context<SetLpLockCompounder>, compounder: Pubkey) -> Result<()> {
+        instructions::set_lp_lock_compounder::handler(ctx, compounder)
+    }
+
+    /// Restake a lock's pending rewards, called by its compounder
+    pub fn compound_lp_lock(ctx: Context<CompoundLpLock>) -> Result<u64> {
+        instructions::compound_lp_lock::handler(ctx)
+    }
+
+    /// Release the creator's vested share of the initial LP
+    pub fn claim_vested_lp(ctx: Context<ClaimVestedLp>) -> Result<u64> {
+        instructions::claim_vested_lp::handler(ctx)
+    }
+
+    /// Burn LP tokens for good and record the burn (amount, share of supply,
+    /// time) in a per-wallet PDA that rug-check tools can read
+    pub fn burn_lp_and_prove(
+        ctx: Context<BurnLpAndProve>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::burn_lp_and_prove::handler(ctx, amount)
+    }
+
+    /// Bind admin rights to an NFT so they move with it, or unbind them
+    /// back to the signer's key when no NFT is passed
+    pub fn bind_admin_nft(ctx: Context<BindAdminNft>) -> Result<()> {
+        instructions::bind_admin_nft::handler(ctx)
+    }
+
+    /// Cap the share of swap fees pools may pay to token creators
+    pub fn set_max_creator_royalty(ctx: Context<SetMaxCreatorRoyalty>, max_royalty_bps: u16) -> Result<()> {
+        instructions::set_max_creator_royalty::handler(ctx, max_royalty_bps)
+    }
+
+    /// Default number of tokens each creator may launch, 0 for no limit
+    pub fn set_launch_quota(ctx: Context<SetLaunchQuota>, max_launches_per_creator: u32) -> Result<()> {
+        instructions::set_launch_quota::handler(ctx, max_launches_per_creator)
+    }
+
+    /// Cap the surcharge integrators may add to swaps, 0 disables partner fees
+    pub fn set_max_partner_fee(ctx: Context<SetMaxPartnerFee>, max_partner_fee_bps: u16) -> Result<()> {
+        instructions::set_max_partner_fee::handler(ctx, max_partner_fee_bps)
+    }
+
+    /// Set the fee of pools created with a fee rate of 0 and the range pool
+    /// fees must stay within unless the protocol admin co-signs (max 0 = the
+    /// default cap)
+    pub fn set_fee_range(
+        ctx: Context<SetFeeRange>,
+        default_fee_bps: u16,
+        min_fee_bps: u16,
+        max_fee_bps: u16,
+    ) -> Result<()> {
+        instructions::set_fee_range::handler(ctx, default_fee_bps, min_fee_bps, max_fee_bps)
+    }
+
+    /// Override the launch limit of one creator, `None` restores the default
+    pub fn set_creator_quota(
+        ctx: Context<SetCreatorQuota>,
+        creator: Pubkey,
+        limit: Option<u32>,
+    ) -> Result<()> {
+        instructions::set_creator_quota::handler(ctx, creator, limit)
+    }
+
+    /// Pay the registered token creator a share of each swap fee
+    pub fn set_creator_royalty(ctx: Context<SetCreatorRoyalty>, royalty_bps: u16) -> Result<()> {
+        instructions::set_creator_royalty::handler(ctx, royalty_bps)
+    }
+
+    /// Withdraw the creator royalties accrued by a pool
+    pub fn claim_royalties(ctx: Context<ClaimRoyalties>) -> Result<()> {
+        instructions::claim_royalties::handler(ctx)
+    }
+
+    /// Set the protocol fee charged on top of a pool's LP fee
+    pub fn set_protocol_fee(ctx: Context<SetProtocolFee>, protocol_fee_bps: u16) -> Result<()> {
+        instructions::set_protocol_fee::handler(ctx, protocol_fee_bps)
+    }
+
+    /// Withdraw the protocol fees accrued by a pool, split between the fee
+    /// splitter's recipients when one is passed
+    pub fn collect_protocol_fees<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CollectProtocolFees<'info>>,
+    ) -> Result<()> {
+        instructions::collect_protocol_fees::handler(ctx)
+    }
+
+    /// Deposit reward tokens for a pool's reward epoch
+    pub fn fund_epoch(ctx: Context<FundEpoch>, epoch: u64, amount: u64) -> Result<()> {
+        instructions::fund_epoch::handler(ctx, epoch, amount)
+    }
+
+    /// Publish the Merkle root of an epoch's reward snapshot
+    pub fn set_root(ctx: Context<SetRoot>, merkle_root: [u8; 32]) -> Result<()> {
+        instructions::set_root::handler(ctx, merkle_root)
+    }
+
+    /// Claim epoch rewards with a Merkle proof of the claimant's share
+    pub fn claim(ctx: Context<Claim>, amount: u64, proof: Vec<[u8; 32]>) -> Result<u64> {
+        instructions::claim::handler(ctx, amount, proof)
+    }
+
+    /// Create the price observation buffer backing `observe`
+    pub fn init_observations(ctx: Context<InitObservations>) -> Result<()> {
+        instructions::init_observations::handler(ctx)
+    }
+
+    /// TWAPs over the given windows, returned as return data
+    pub fn observe(ctx: Context<Observe>, windows_secs: Vec<u32>) -> Result<Vec<u128>> {
+        instructions::observe::handler(ctx, windows_secs)
+    }
+
+    /// Token/SOL amounts and LP minted by `add_liquidity` for the given
+    /// inputs, returned as return data
+    pub fn quote_add_liquidity(
+        ctx: Context<QuoteAddLiquidity>,
+        token_amount: u64,
+        sol_amount: u64,
+    ) -> Result<DepositAmounts> {
+        instructions::quote_add_liquidity::handler(ctx, token_amount, sol_amount)
+    }
+
+    /// Token/SOL paid out by `remove_liquidity` for `lp_tokens`, and the
+    /// early withdrawal penalty, returned as return data
+    pub fn quote_remove_liquidity(
+        ctx: Context<QuoteRemoveLiquidity>,
+        lp_tokens: u64,
+    ) -> Result<RemoveLiquidityQuote> {
+        instructions::quote_remove_liquidity::handler(ctx, lp_tokens)
+    }
+
+    /// Record a price observation without a swap, paying the caller a small
+    /// bounty. Allowed once per observation interval.
+    pub fn crank_observation(ctx: Context<CrankObservation>) -> Result<()> {
+        instructions::crank_observation::handler(ctx)
+    }
+
+    /// LP virtual price (quote value per LP unit, Q64.64), returned as
+    /// return data and emitted as an event
+    pub fn lp_virtual_price(ctx: Context<LpVirtualPrice>) -> Result<u128> {
+        instructions::lp_virtual_price::handler(ctx)
+    }
+
+    /// Create the pool snapshot read by lending protocols
+    pub fn init_pool_snapshot(ctx: Context<InitPoolSnapshot>) -> Result<()> {
+        instructions::init_pool_snapshot::handler(ctx)
+    }
+
+    /// Refresh the pool snapshot from the current reserves and TWAP, with the
+    /// LP fair value priced at that TWAP
+    pub fn update_pool_snapshot(ctx: Context<UpdatePoolSnapshot>) -> Result<()> {
+        instructions::update_pool_snapshot::handler(ctx)
+    }
+
+    /// Propose handing the LP mint authority to a successor program
+    pub fn propose_lp_mint_handoff(ctx: Context<ProposeLpMintHandoff>, successor: Pubkey) -> Result<()> {
+        instructions::propose_lp_mint_handoff::handler(ctx, successor)
+    }
+
+    /// Successor accepts the LP mint authority after the timelock
+    pub fn accept_lp_mint_handoff(ctx: Context<AcceptLpMintHandoff>) -> Result<()> {
+        instructions::accept_lp_mint_handoff::handler(ctx)
+    }
+
+    /// Queue a bonded pool parameter change for the admin to resolve
+    pub fn propose_param_change(ctx: Context<ProposeParamChange>, change: ParamChange) -> Result<()> {
+        instructions::propose_param_change::handler(ctx, change)
+    }
+
+    /// Approve or reject a queued parameter proposal
+    pub fn resolve_param_proposal(ctx: Context<ResolveParamProposal>, approve: bool) -> Result<()> {
+        instructions::resolve_param_proposal::handler(ctx, approve)
+    }
+
+    /// Set the LP fee schedule by trade size; pass no tiers to clear it
+    pub fn set_fee_tiers(ctx: Context<SetFeeTiers>, fee_tiers: Vec<FeeTier>) -> Result<()> {
+        instructions::set_fee_tiers::handler(ctx, fee_tiers)
+    }
+
+    /// Discount the LP fee on swaps that move the pool back toward the oracle price
+    pub fn configure_rebalance_incentive(
+        ctx: Context<ConfigureRebalanceIncentive>,
+        discount_bps: u16,
+        min_deviation_bps: u16,
+    ) -> Result<()> {
+        instructions::configure_rebalance_incentive::handler(ctx, discount_bps, min_deviation_bps)
+    }
+
+    /// Replace the M-of-N guardian set allowed to pause pools
+    pub fn set_guardian_set(ctx: Context<SetGuardianSet>, guardians: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        instructions::set_guardian_set::handler(ctx, guardians, threshold)
+    }
+
+    /// Pause a pool with a quorum of guardian signatures (remaining accounts)
+    pub fn guardian_pause(ctx: Context<GuardianPause>) -> Result<()> {
+        instructions::guardian_pause::handler(ctx)
+    }
+
+    /// Lift a guardian pause (protocol admin)
+    pub fn guardian_unpause(ctx: Context<GuardianUnpause>) -> Result<()> {
+        instructions::guardian_unpause::handler(ctx)
+    }
+
+    /// Approve a custody program to hold pool token vaults (protocol admin)
+    pub fn approve_custody_program(ctx: Context<ApproveCustodyProgram>, custody_program: Pubkey) -> Result<()> {
+        instructions::approve_custody_program::handler(ctx, custody_program)
+    }
+
+    /// Withdraw a custody program's approval (protocol admin)
+    pub fn revoke_custody_program(ctx: Context<RevokeCustodyProgram>) -> Result<()> {
+        instructions::revoke_custody_program::handler(ctx)
+    }
+
+    /// Hand the token vault to an approved custody program, keeping the pool
+    /// authority as delegate for settlement
+    pub fn assign_vault_custody(ctx: Context<AssignVaultCustody>) -> Result<()> {
+        instructions::assign_vault_custody::handler(ctx)
+    }
+
+    /// Dry run of `swap` returning the output, fees and price impact as
+    /// return data, for preflighting routes via CPI
+    pub fn simulate_swap(
+        ctx: Context<SimulateSwap>,
+        direction: SwapDirection,
+        amount_in: u64,
+        min_amount_out: u64,
+    ) -> Result<SwapQuote> {
+        instructions::simulate_swap::handler(ctx, direction, amount_in, min_amount_out)
+    }
+
+    /// Create the rolling daily TVL history of a pool
+    pub fn init_tvl_history(ctx: Context<InitTvlHistory>) -> Result<()> {
+        instructions::init_tvl_history::handler(ctx)
+    }
+
+    /// Write today's TVL checkpoint, permissionless once per day
+    pub fn checkpoint_tvl(ctx: Context<CheckpointTvl>) -> Result<()> {
+        instructions::checkpoint_tvl::handler(ctx)
+    }
+
+    /// APR and impermanent loss over the last `window_days`, from the TVL history
+    pub fn get_tvl_metrics(ctx: Context<GetTvlMetrics>, window_days: u16) -> Result<TvlMetrics> {
+        instructions::get_tvl_metrics::handler(ctx, window_days)
+    }
+
+    /// Program-wide emergency stop: halt everything but withdrawals, then
+    /// re-enable swaps and finally deposits
+    pub fn set_protocol_status(ctx: Context<SetProtocolStatus>, status: ProtocolStatus) -> Result<()> {
+        instructions::set_protocol_status::handler(ctx, status)
+    }
 
-// Error codes
-#[error_code]
-pub enum ExchangeError {
-    #[msg("Slippage tolerance exceeded")]
-    SlippageExceeded,
-    #[msg("Insufficient liquidity in the pool")]
-    InsufficientLiquidity,
-    #[msg("Invalid token mint")]
-    InvalidTokenMint,
-    #[msg("Pool not initialized")]
-    PoolNotInitialized,
-    #[msg("Unauthorized access")]
-    Unauthorized,
-    #[msg("Invalid fee rate - must be between 0 and 1000 basis points (10%)")]
-    InvalidFeeRate,
+    /// Sweep rounding dust in the quote vault to the treasury or into the reserve
+    pub fn sweep_quote_dust(ctx: Context<SweepQuoteDust>, destination: DustDestination) -> Result<()> {
+        instructions::sweep_quote_dust::handler(ctx, destination)
+    }
+
+    /// Rolling 24h and 7d LP fee totals and the APR they imply
+    pub fn get_fee_apr(ctx: Context<GetFeeApr>) -> Result<FeeApr> {
+        instructions::get_fee_apr::handler(ctx)
+    }
+
+    /// Create the registry keepers read pending crank jobs from
+    pub fn init_crank_registry(ctx: Context<InitCrankRegistry>) -> Result<()> {
+        instructions::init_crank_registry::handler(ctx)
+    }
+
+    /// List one of the pool's crank jobs in the registry
+    pub fn register_crank_job(ctx: Context<RegisterCrankJob>, kind: CrankJobKind) -> Result<()> {
+        instructions::register_crank_job::handler(ctx, kind)
+    }
+
+    /// Remove one of the pool's crank jobs from the registry
+    pub fn deregister_crank_job(ctx: Context<DeregisterCrankJob>, kind: CrankJobKind) -> Result<()> {
+        instructions::deregister_crank_job::handler(ctx, kind)
+    }
+
+    /// Create the ring buffer recording a pool's circuit breaker trips
+    pub fn init_breaker_history(ctx: Context<InitBreakerHistory>) -> Result<()> {
+        instructions::init_breaker_history::handler(ctx)
+    }
+
+    /// Record an oracle guard the pool currently trips, permissionless
+    pub fn report_circuit_breaker(ctx: Context<ReportCircuitBreaker>) -> Result<()> {
+        instructions::report_circuit_breaker::handler(ctx)
+    }
+
+    /// One-click LP from SOL only: buy tokens at a bounded average price and
+    /// deposit them with the remaining SOL. Returns the amounts deposited.
+    pub fn buy_and_lp(
+        ctx: Context<BuyAndLp>,
+        sol_amount: u64,
+        max_price_x64: u128,
+        min_lp_tokens: u64,
+    ) -> Result<DepositAmounts> {
+        instructions::buy_and_lp::handler(ctx, sol_amount, max_price_x64, min_lp_tokens)
+    }
+
+    /// Start a sealed batch auction selling part of a token's supply at one
+    /// uniform price. The clearing SOL and the reserved inventory seed the pool.
+    pub fn start_launch_auction(
+        ctx: Context<StartLaunchAuction>,
+        params: LaunchAuctionParams,
+    ) -> Result<()> {
+        instructions::start_launch_auction::handler(ctx, params)
+    }
+
+    /// Escrow SOL behind a sealed bid during the bidding window
+    pub fn commit_auction_bid(
+        ctx: Context<CommitAuctionBid>,
+        commitment: [u8; 32],
+        sol_amount: u64,
+    ) -> Result<()> {
+        instructions::commit_auction_bid::handler(ctx, commitment, sol_amount)
+    }
+
+    /// Reveal the price tick of a sealed bid during the reveal window
+    pub fn reveal_auction_bid(
+        ctx: Context<RevealAuctionBid>,
+        tick: u8,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        instructions::reveal_auction_bid::handler(ctx, tick, salt)
+    }
+
+    /// Clear a launch auction after the reveal window and seed its pool. The
+    /// seeded LP is minted into the pool's burn vault, locking it for good.
+    pub fn finalize_launch_auction(ctx: Context<FinalizeLaunchAuction>) -> Result<()> {
+        instructions::finalize_launch_auction::handler(ctx)
+    }
+
+    /// Cancel a launch auction that cannot seed its pool, refunding every bid
+    pub fn cancel_launch_auction(ctx: Context<CancelLaunchAuction>) -> Result<()> {
+        instructions::cancel_launch_auction::handler(ctx)
+    }
+
+    /// Claim the tokens and SOL refund of a settled auction bid
+    pub fn claim_auction_bid(ctx: Context<ClaimAuctionBid>) -> Result<u64> {
+        instructions::claim_auction_bid::handler(ctx)
+    }
+
+    /// Escrow LP tokens in a Dutch auction whose price falls linearly from
+    /// `start_price_x64` to `end_price_x64` over `duration_secs`, letting a
+    /// large LP exit without dumping through the curve
+    pub fn start_exit_auction(
+        ctx: Context<StartExitAuction>,
+        lp_amount: u64,
+        start_price_x64: u128,
+        end_price_x64: u128,
+        duration_secs: i64,
+    ) -> Result<()> {
+        instructions::start_exit_auction::handler(
+            ctx,
+            lp_amount,
+            start_price_x64,
+            end_price_x64,
+            duration_secs,
+        )
+    }
+
+    /// Buy LP tokens from an exit auction at its current asking price
+    pub fn buy_from_exit_auction(
+        ctx: Context<BuyFromExitAuction>,
+        lp_amount: u64,
+        max_price_x64: u128,
+    ) -> Result<u64> {
+        instructions::buy_from_exit_auction::handler(ctx, lp_amount, max_price_x64)
+    }
+
+    /// Close an exit auction, returning the unsold LP tokens to the seller
+    pub fn close_exit_auction(ctx: Context<CloseExitAuction>) -> Result<()> {
+        instructions::close_exit_auction::handler(ctx)
+    }
+
+    /// Open a stream converting an internal balance through the pool at up to
+    /// `rate_per_slot`, a continuous alternative to discrete DCA tranches
+    pub fn open_swap_stream(
+        ctx: Context<OpenSwapStream>,
+        direction: SwapDirection,
+        amount: u64,
+        rate_per_slot: u64,
+        min_out_per_in_x64: u128,
+    ) -> Result<()> {
+        instructions::open_swap_stream::handler(ctx, direction, amount, rate_per_slot, min_out_per_in_x64)
+    }
+
+    /// Execute the input a swap stream accrued since it last ran. Permissionless.
+    pub fn execute_swap_stream(ctx: Context<ExecuteSwapStream>) -> Result<SwapResult> {
+        instructions::execute_swap_stream::handler(ctx)
+    }
+
+    /// Stop a swap stream and reclaim its rent
+    pub fn close_swap_stream(ctx: Context<CloseSwapStream>) -> Result<()> {
+        instructions::close_swap_stream::handler(ctx)
+    }
+
+    /// Cap the share of each reserve that may leave through swaps per sliding
+    /// window, a last-resort throttle on drains. `window_secs` of 0 disables it.
+    pub fn set_outflow_caps(
+        ctx: Context<SetOutflowCaps>,
+        window_secs: u32,
+        max_token_outflow_bps: u16,
+        max_quote_outflow_bps: u16,
+    ) -> Result<()> {
+        instructions::set_outflow_caps::handler(ctx, window_secs, max_token_outflow_bps, max_quote_outflow_bps)
+    }
+
+    /// Assign the compliance authority, or disable the role with `None`
+    pub fn set_compliance_authority(ctx: Context<SetComplianceAuthority>, authority: Option<Pubkey>) -> Result<()> {
+        instructions::set_compliance_authority::handler(ctx, authority)
+    }
+
+    /// Freeze a pool for a legal takedown, or lift the freeze. Frozen pools
+    /// only allow withdrawals and every change cites a reason code.
+    pub fn set_pool_freeze(ctx: Context<SetPoolFreeze>, frozen: bool, reason_code: u16) -> Result<()> {
+        instructions::set_pool_freeze::handler(ctx, frozen, reason_code)
+    }
+
+    /// Resize a pool or pool snapshot to its current layout after an
+    /// upgrade, topping up rent when it grows (anyone) or refunding it when
+    /// it shrinks (protocol admin)
+    pub fn resize_account(ctx: Context<ResizeAccount>) -> Result<()> {
+        instructions::resize_account::handler(ctx)
+    }
+
+    /// Record the token and quote mint decimals on a pool created before
+    /// pools kept them, so its constant sum curve can price whole units.
+    /// Permissionless
+    pub fn sync_pool_decimals(ctx: Context<SyncPoolDecimals>) -> Result<()> {
+        instructions::sync_pool_decimals::handler(ctx)
+    }
+
+    /// Set how long a pool must stay empty before `reap_pool` may close it,
+    /// 0 disables reaping
+    pub fn set_reap_grace_period(ctx: Context<SetReapGracePeriod>, reap_grace_secs: u32) -> Result<()> {
+        instructions::set_reap_grace_period::handler(ctx, reap_grace_secs)
+    }
+
+    /// Close a pool with no liquidity left once it has been empty for the
+    /// grace period, splitting the rent between its creator and the treasury.
+    /// The first call only starts the grace period.
+    pub fn reap_pool(ctx: Context<ReapPool>) -> Result<()> {
+        instructions::reap_pool::handler(ctx)
+    }
+
+    /// Change several pool settings (fees, outflow caps, pause flags, oracle
+    /// config) at once, so the pool never sits half-configured
+    pub fn update_pool_params(
+        ctx: Context<UpdatePoolParams>,
+        params: PoolParams,
+    ) -> Result<()> {
+        instructions::update_pool_params::handler(ctx, params)
+    }
+
+    /// Set the recipients and bps shares protocol fees are split between
+    pub fn set_fee_splitter(ctx: Context<SetFeeSplitter>, shares: Vec<FeeShare>) -> Result<()> {
+        instructions::set_fee_splitter::handler(ctx, shares)
+    }
+
+    /// Configure the protocol token stake tiers that discount the LP fee
+    pub fn configure_stake_discount(
+        ctx: Context<ConfigureStakeDiscount>,
+        cooldown_secs: u32,
+        tiers: Vec<StakeTier>,
+    ) -> Result<()> {
+        instructions::configure_stake_discount::handler(ctx, cooldown_secs, tiers)
+    }
+
+    /// Stake protocol tokens toward a fee discount tier, counting after the
+    /// cooldown; also withdraws unstaked tokens whose cooldown is over
+    pub fn stake_for_discount(
+        ctx: Context<DiscountStakeTransfer>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::stake_for_discount::handler(ctx, amount)
+    }
+
+    /// Start the cooldown on staked tokens, withdrawing any whose cooldown is
+    /// over; `amount` 0 only withdraws
+    pub fn unstake_for_discount(
+        ctx: Context<DiscountStakeTransfer>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::unstake_for_discount::handler(ctx, amount)
+    }
+
+    /// Impermanent loss and P&L of a provider's position, from the deposit
+    /// receipt `add_liquidity` writes when passed one
+    pub fn get_position_report(ctx: Context<GetPositionReport>) -> Result<PositionReport> {
+        instructions::get_position_report::handler(ctx)
+    }
+
+    /// Turn on optional features (`PoolFlags` bits) for a pool
+    pub fn set_pool_flags(ctx: Context<UpdatePoolFlags>, flags: u32) -> Result<()> {
+        instructions::set_pool_flags::handler(ctx, flags)
+    }
+
+    /// Turn off optional features (`PoolFlags` bits) for a pool
+    pub fn clear_pool_flags(ctx: Context<UpdatePoolFlags>, flags: u32) -> Result<()> {
+        instructions::clear_pool_flags::handler(ctx, flags)
+    }
+
+    /// Emit the pool's revenue since the last report, once per epoch (permissionless crank)
+    pub fn report_epoch_revenue(ctx: Context<ReportEpochRevenue>) -> Result<()> {
+        instructions::report_epoch_revenue::handler(ctx)
+    }
 }
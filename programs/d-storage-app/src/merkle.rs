@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+/// Domain prefixes keep a leaf from being passed off as an inner node
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+/// Leaf committing `claimant` to `amount`
+pub fn reward_leaf(claimant: &Pubkey, amount: u64) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, claimant.as_ref(), &amount.to_le_bytes()]).to_bytes()
+}
+
+/// Parent of two nodes. Children are sorted, so proofs need no left/right flags.
+pub fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[NODE_PREFIX, left, right]).to_bytes()
+}
+
+/// Whether `proof` leads from `leaf` to `root`
+pub fn verify_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    proof.iter().fold(leaf, |node, sibling| hash_pair(&node, sibling)) == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn proofs_verify_against_the_root() {
+        let claimants: Vec<(Pubkey, u64)> = (1..=3).map(|i| (Pubkey::new_unique(), i * 100)).collect();
+        let leaves: Vec<[u8; 32]> = claimants
+            .iter()
+            .map(|(claimant, amount)| reward_leaf(claimant, *amount))
+            .collect();
+        // Three leaves: the odd one is paired with the root of the other two
+        let pair = hash_pair(&leaves[0], &leaves[1]);
+        let root = hash_pair(&pair, &leaves[2]);
+        
+        assert!(verify_proof(&[leaves[1], leaves[2]], &root, leaves[0]));
+        assert!(verify_proof(&[leaves[0], leaves[2]], &root, leaves[1]));
+        assert!(verify_proof(&[pair], &root, leaves[2]));
+        
+        // Wrong amount, wrong claimant or a truncated proof all fail
+        let (claimant, amount) = claimants[0];
+        assert!(!verify_proof(&[leaves[1], leaves[2]], &root, reward_leaf(&claimant, amount + 1)));
+        assert!(!verify_proof(&[leaves[1], leaves[2]], &root, reward_leaf(&claimants[1].0, amount)));
+        assert!(!verify_proof(&[leaves[1]], &root, leaves[0]));
+    }
+}
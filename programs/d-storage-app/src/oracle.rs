@@ -0,0 +1,291 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use crate::events::{CircuitBreakerEvent, OracleDegradedEvent};
+use crate::instructions::swap::SwapDirection;
+use crate::state::{BreakerHistory, BreakerKind, BreakerTrip, LiquidityPool, PoolFlags};
+
+/// Pyth pull-oracle receiver that owns `PriceUpdateV2` accounts
+pub const PYTH_RECEIVER_PROGRAM_ID: Pubkey = pubkey!("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ");
+/// Pyth push oracle, whose sponsored feeds are `PriceUpdateV2` accounts too
+pub const PYTH_PUSH_ORACLE_PROGRAM_ID: Pubkey = pubkey!("pythWSnswVUd12oZpeFP8e9CVaEqJg25g1Vtc2biRsT");
+const PRICE_UPDATE_V2_DISCRIMINATOR: [u8; 8] = [34, 241, 35, 99, 157, 126, 244, 205];
+
+/// Decimals used when comparing pool and oracle prices
+const PRICE_DECIMALS: u32 = 9;
+
+/// Price of one whole token in the quote asset as reported by the feed
+pub struct OraclePrice {
+    pub price: i64,
+    pub confidence: u64,
+    pub exponent: i32,
+    pub publish_time: i64,
+}
+
+impl OraclePrice {
+    /// Confidence interval relative to the price, in basis points
+    pub fn confidence_bps(&self) -> u64 {
+        (self.confidence as u128 * 10000 / self.price.max(1) as u128) as u64
+    }
+
+    /// Quote base units (lamports for SOL) per whole token, scaled by `PRICE_DECIMALS`
+    pub fn quote_units_per_token(&self, quote_decimals: u8) -> Option<u128> {
+        let price = u128::try_from(self.price).ok()?;
+        let exponent = quote_decimals as i32 + PRICE_DECIMALS as i32 + self.exponent;
+        if exponent >= 0 {
+            price.checked_mul(10u128.checked_pow(exponent as u32)?)
+        } else {
+            Some(price / 10u128.checked_pow(exponent.unsigned_abs())?)
+        }
+    }
+}
+
+/// Quote base units per whole token implied by the reserves, scaled by `PRICE_DECIMALS`
+pub fn pool_price(token_reserve: u64, sol_reserve: u64, token_decimals: u8) -> Option<u128> {
+    (sol_reserve as u128)
+        .checked_mul(10u128.checked_pow(token_decimals as u32 + PRICE_DECIMALS)?)?
+        .checked_div(token_reserve as u128)
+}
+
+/// Read a fully verified Pyth `PriceUpdateV2` account for `feed_id`
+pub fn read_oracle_price(oracle: &AccountInfo, feed_id: &[u8; 32]) -> Result<OraclePrice> {
+    require!(
+        [PYTH_RECEIVER_PROGRAM_ID, PYTH_PUSH_ORACLE_PROGRAM_ID].contains(oracle.owner),
+        ExchangeError::InvalidOracle
+    );
+    parse_price_update(&oracle.try_borrow_data()?, feed_id)
+}
+
+/// Decode `PriceUpdateV2` account data published for `feed_id`
+pub fn parse_price_update(data: &[u8], feed_id: &[u8; 32]) -> Result<OraclePrice> {
+    require!(
+        data.len() >= 8 && data[..8] == PRICE_UPDATE_V2_DISCRIMINATOR,
+        ExchangeError::InvalidOracle
+    );
+    
+    // Skip discriminator and write authority. Only `Full` verification
+    // (variant 1, no payload) is accepted, so the feed id follows at 41.
+    require!(data.get(40) == Some(&1), ExchangeError::InvalidOracle);
+    require!(data.get(41..73) == Some(&feed_id[..]), ExchangeError::InvalidOracle);
+    let message = data.get(73..73 + 28).ok_or(ExchangeError::InvalidOracle)?;
+    let read_i64 = |offset: usize| i64::from_le_bytes(message[offset..offset + 8].try_into().unwrap());
+    
+    Ok(OraclePrice {
+        price: read_i64(0),
+        confidence: u64::from_le_bytes(message[8..16].try_into().unwrap()),
+        exponent: i32::from_le_bytes(message[16..20].try_into().unwrap()),
+        publish_time: read_i64(20),
+    })
+}
+
+/// First oracle guard tripped by the pool priced at the given reserves, with
+/// its magnitude: feed age, confidence or deviation, in that order
+pub fn oracle_breaker(
+    pool: &LiquidityPool,
+    price: &OraclePrice,
+    token_reserve: u64,
+    sol_reserve: u64,
+    now: i64,
+) -> Result<Option<(BreakerKind, u64)>> {
+    let age = now - price.publish_time;
+    if age > pool.oracle_max_staleness {
+        return Ok(Some((BreakerKind::OracleStale, age as u64)));
+    }
+    let confidence_bps = price.confidence_bps();
+    if confidence_bps > pool.oracle_max_confidence_bps as u64 {
+        return Ok(Some((BreakerKind::OracleConfidence, confidence_bps)));
+    }
+    
+    let oracle_price = price
+        .quote_units_per_token(pool.quote_decimals)
+        .ok_or(ExchangeError::MathOverflow)?;
+    let pool_price = pool_price(token_reserve, sol_reserve, pool.token_decimals)
+        .ok_or(ExchangeError::MathOverflow)?;
+    let deviation_bps = oracle_price.abs_diff(pool_price) * 10000 / oracle_price.max(1);
+    if deviation_bps > pool.oracle_max_deviation_bps as u128 {
+        return Ok(Some((BreakerKind::PriceDeviation, deviation_bps.min(u64::MAX as u128) as u64)));
+    }
+    Ok(None)
+}
+
+/// Reject post-swap reserves whose price strays too far from the oracle.
+/// Returns the degraded-feed guard that was skipped under the curve
+/// fallback, if any, so callers can record it.
+pub fn check_oracle(
+    pool: &Account<LiquidityPool>,
+    oracle: Option<&AccountInfo>,
+    token_reserve: u64,
+    sol_reserve: u64,
+    now: i64,
+) -> Result<Option<(BreakerKind, u64)>> {
+    if pool.oracle == Pubkey::default() || !pool.features_enabled(PoolFlags::ORACLE_GUARD) {
+        return Ok(None);
+    }
+    let oracle = oracle.ok_or(ExchangeError::InvalidOracle)?;
+    let price = read_oracle_price(oracle, &pool.oracle_feed_id)?;
+    
+    match oracle_breaker(pool, &price, token_reserve, sol_reserve, now)? {
+        Some((BreakerKind::PriceDeviation, _)) => err!(ExchangeError::OraclePriceDeviation),
+        Some(breaker) => {
+            require!(pool.oracle_fallback_to_curve, ExchangeError::OracleDegraded);
+            emit!(OracleDegradedEvent {
+                pool: pool.key(),
+                publish_time: price.publish_time,
+                confidence_bps: price.confidence_bps(),
+            });
+            Ok(Some(breaker))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Write a trip to the pool's breaker history and announce it
+pub fn record_breaker_trip(
+    history: &mut BreakerHistory,
+    (kind, magnitude): (BreakerKind, u64),
+) -> Result<bool> {
+    let clock = Clock::get()?;
+    let trip = BreakerTrip {
+        slot: clock.slot,
+        timestamp: clock.unix_timestamp,
+        kind,
+        magnitude,
+    };
+    if !history.record(trip) {
+        return Ok(false);
+    }
+    
+    emit!(CircuitBreakerEvent {
+        pool: history.pool,
+        kind,
+        magnitude,
+        slot: trip.slot,
+        trip_count: history.trip_count,
+    });
+    
+    Ok(true)
+}
+
+/// LP fee discount for a swap in `direction`: the pool's rebalance discount
+/// when the swap moves a pool price that has drifted at least
+/// `rebalance_min_deviation_bps` away from a healthy oracle price back toward
+/// it, zero otherwise
+pub fn rebalance_discount_bps(
+    pool: &LiquidityPool,
+    oracle: Option<&AccountInfo>,
+    direction: SwapDirection,
+    now: i64,
+) -> Result<u16> {
+    if pool.rebalance_discount_bps == 0
+        || pool.oracle == Pubkey::default()
+        || !pool.features_enabled(PoolFlags::ORACLE_GUARD)
+    {
+        return Ok(0);
+    }
+    let Some(oracle) = oracle else {
+        return Ok(0);
+    };
+    let price = read_oracle_price(oracle, &pool.oracle_feed_id)?;
+    if now - price.publish_time > pool.oracle_max_staleness
+        || price.confidence_bps() > pool.oracle_max_confidence_bps as u64
+    {
+        return Ok(0);
+    }
+    let (Some(oracle_price), Some(pool_price)) = (
+        price.quote_units_per_token(pool.quote_decimals),
+        pool_price(pool.token_reserve, pool.sol_reserve, pool.token_decimals),
+    ) else {
+        return Ok(0);
+    };
+    
+    let deviation_bps = oracle_price.abs_diff(pool_price) * 10000 / oracle_price.max(1);
+    // Selling tokens lowers the pool price, buying raises it
+    let toward_oracle = match direction {
+        SwapDirection::TokenToSol => pool_price > oracle_price,
+        SwapDirection::SolToToken => pool_price < oracle_price,
+    };
+    if toward_oracle && deviation_bps >= pool.rebalance_min_deviation_bps as u128 {
+        Ok(pool.rebalance_discount_bps)
+    } else {
+        Ok(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn price(price: i64, confidence: u64, exponent: i32) -> OraclePrice {
+        OraclePrice {
+            price,
+            confidence,
+            exponent,
+            publish_time: 0,
+        }
+    }
+
+    #[test]
+    fn oracle_and_pool_prices_share_a_scale() {
+        // 0.005 SOL per token, 1000 tokens (6 decimals) against 5 SOL
+        let oracle = price(500_000, 0, -8).quote_units_per_token(9).unwrap();
+        let pool = pool_price(1_000_000_000, 5_000_000_000, 6).unwrap();
+        assert_eq!(oracle, 5_000_000_000_000_000);
+        assert_eq!(oracle, pool);
+    }
+
+    #[test]
+    fn usdc_quoted_prices_share_a_scale() {
+        // 0.25 USDC per token, 400 tokens (9 decimals) against 100 USDC
+        let oracle = price(25_000_000, 0, -8).quote_units_per_token(6).unwrap();
+        let pool = pool_price(400_000_000_000, 100_000_000, 9).unwrap();
+        assert_eq!(oracle, 250_000_000_000_000);
+        assert_eq!(oracle, pool);
+    }
+
+    #[test]
+    fn large_exponents_are_scaled_down() {
+        assert_eq!(price(5, 0, -20).quote_units_per_token(9), Some(0));
+        assert_eq!(price(12_345, 0, -21).quote_units_per_token(9), Some(12));
+    }
+
+    #[test]
+    fn negative_prices_are_rejected() {
+        assert_eq!(price(-1, 0, -8).quote_units_per_token(9), None);
+    }
+
+    #[test]
+    fn confidence_is_relative_to_price() {
+        assert_eq!(price(500_000, 5_000, -8).confidence_bps(), 100);
+        assert_eq!(price(0, 5, -8).confidence_bps(), 50_000);
+    }
+
+    fn price_update(level: &[u8], feed_id: [u8; 32], price: i64) -> Vec<u8> {
+        let mut data = PRICE_UPDATE_V2_DISCRIMINATOR.to_vec();
+        data.extend([0; 32]);
+        data.extend(level);
+        data.extend(feed_id);
+        data.extend(price.to_le_bytes());
+        data.extend(7u64.to_le_bytes());
+        data.extend((-8i32).to_le_bytes());
+        data.extend(1_700_000_000i64.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn price_updates_must_be_fully_verified_for_the_feed() {
+        let feed_id = [7; 32];
+        let price = parse_price_update(&price_update(&[1], feed_id, 500_000), &feed_id).unwrap();
+        assert_eq!(price.price, 500_000);
+        assert_eq!(price.confidence, 7);
+        assert_eq!(price.exponent, -8);
+        assert_eq!(price.publish_time, 1_700_000_000);
+        
+        assert!(parse_price_update(&price_update(&[1], [8; 32], 500_000), &feed_id).is_err());
+        assert!(parse_price_update(&price_update(&[0, 3], feed_id, 500_000), &feed_id).is_err());
+    }
+
+    #[test]
+    fn empty_pool_has_no_price() {
+        assert_eq!(pool_price(0, 1_000, 6), None);
+    }
+}
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+
+use crate::errors::ExchangeError;
+use crate::state::LiquidityPool;
+
+/// Quote side of a pool: native SOL held by the system-owned `sol_vault`, or
+/// a quote token held by the token account at the same address
+pub struct QuoteVault<'a, 'info> {
+    pub pool: &'a LiquidityPool,
+    pub vault: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl<'info> QuoteVault<'_, 'info> {
+    /// Pay `amount` into the vault from the user's wallet (SOL) or from the
+    /// user's quote token account
+    pub fn deposit(
+        &self,
+        user: &AccountInfo<'info>,
+        user_quote_account: Option<&AccountInfo<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        if self.pool.is_native_quote() {
+            return anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    self.system_program.clone(),
+                    anchor_lang::system_program::Transfer {
+                        from: user.clone(),
+                        to: self.vault.clone(),
+                    },
+                ),
+                amount,
+            );
+        }
+        
+        let user_quote_account = user_quote_account.ok_or(ExchangeError::InvalidQuoteAccount)?;
+        token::transfer(
+            CpiContext::new(
+                self.token_program.clone(),
+                Transfer {
+                    from: user_quote_account.clone(),
+                    to: self.vault.clone(),
+                    authority: user.clone(),
+                },
+            ),
+            amount,
+        )
+    }
+
+    /// Pay `amount` out of the vault to a wallet (SOL) or a quote token account.
+    /// SOL vaults sign for themselves, token vaults through the pool authority.
+    pub fn withdraw(
+        &self,
+        vault_bump: u8,
+        pool_authority: &AccountInfo<'info>,
+        pool_authority_bump: u8,
+        recipient: &AccountInfo<'info>,
+        amount: u64,
+    ) -> Result<()> {
+        let token_mint = self.pool.token_mint;
+        if self.pool.is_native_quote() {
+            return anchor_lang::system_program::transfer(
+                CpiContext::new_with_signer(
+                    self.system_program.clone(),
+                    anchor_lang::system_program::Transfer {
+                        from: self.vault.clone(),
+                        to: recipient.clone(),
+                    },
+                    &[&[b"sol_vault", token_mint.as_ref(), &[vault_bump]]],
+                ),
+                amount,
+            );
+        }
+        
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.clone(),
+                Transfer {
+                    from: self.vault.clone(),
+                    to: recipient.clone(),
+                    authority: pool_authority.clone(),
+                },
+                &[&[b"pool_authority", token_mint.as_ref(), &[pool_authority_bump]]],
+            ),
+            amount,
+        )
+    }
+}
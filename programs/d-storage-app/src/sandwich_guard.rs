@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::sysvar;
+use anchor_lang::Discriminator;
+
+use crate::errors::ExchangeError;
+use crate::instruction;
+use crate::instructions::swap::SwapDirection;
+
+/// A swap against the guarded pool found in the transaction
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct PoolSwap {
+    signer: Pubkey,
+    direction: SwapDirection,
+}
+
+/// Read a top-level swap of this program against `pool`. Every swap
+/// entrypoint takes the pool first and the signer second; a route sells
+/// into its first pool and buys from its second.
+fn decode_pool_swap(ix: &Instruction, pool: &Pubkey) -> Option<PoolSwap> {
+    if ix.program_id != crate::ID || ix.accounts.len() < 2 || ix.data.len() < 8 {
+        return None;
+    }
+    let (discriminator, args) = ix.data.split_at(8);
+    let direction_arg = |offset: usize| {
+        args.get(offset..offset + 1)
+            .and_then(|mut data| SwapDirection::deserialize(&mut data).ok())
+    };
+    let direction = if discriminator == instruction::SwapRoute::DISCRIMINATOR {
+        if ix.accounts[0].pubkey == *pool {
+            SwapDirection::TokenToSol
+        } else if ix.accounts.iter().any(|meta| meta.pubkey == *pool) {
+            SwapDirection::SolToToken
+        } else {
+            return None;
+        }
+    } else {
+        if ix.accounts[0].pubkey != *pool {
+            return None;
+        }
+        if discriminator == instruction::SwapTokenToSol::DISCRIMINATOR {
+            SwapDirection::TokenToSol
+        } else if discriminator == instruction::SwapSolToToken::DISCRIMINATOR {
+            SwapDirection::SolToToken
+        } else if discriminator == instruction::SwapWithReceipt::DISCRIMINATOR {
+            direction_arg(8)?
+        } else if [
+            instruction::Swap::DISCRIMINATOR,
+            instruction::SwapWithPartnerFee::DISCRIMINATOR,
+            instruction::SwapWithImpactLimit::DISCRIMINATOR,
+            instruction::SwapInternal::DISCRIMINATOR,
+            instruction::SwapSplit::DISCRIMINATOR,
+        ]
+        .contains(&discriminator)
+        {
+            direction_arg(0)?
+        } else {
+            return None;
+        }
+    };
+    Some(PoolSwap {
+        signer: ix.accounts[1].pubkey,
+        direction,
+    })
+}
+
+/// Swaps in both directions from more than one signer: someone trading
+/// around someone else
+fn is_sandwich(swaps: &[PoolSwap]) -> bool {
+    let both_directions = swaps.iter().any(|swap| swap.direction == SwapDirection::TokenToSol)
+        && swaps.iter().any(|swap| swap.direction == SwapDirection::SolToToken);
+    both_directions && swaps.iter().any(|swap| swap.signer != swaps[0].signer)
+}
+
+/// Reject the transaction when its top-level instructions swap `pool` in
+/// opposing directions from different signers. Swaps made through CPI do
+/// not show up in the instructions sysvar.
+pub fn check_sandwich(instructions: &AccountInfo, pool: &Pubkey) -> Result<()> {
+    let mut swaps = Vec::new();
+    let mut index = 0;
+    while let Ok(ix) = sysvar::instructions::load_instruction_at_checked(index, instructions) {
+        swaps.extend(decode_pool_swap(&ix, pool));
+        index += 1;
+    }
+    require!(!is_sandwich(&swaps), ExchangeError::SandwichDetected);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::instruction::AccountMeta;
+
+    fn swap_ix(data: Vec<u8>, pool: Pubkey, signer: Pubkey) -> Instruction {
+        Instruction {
+            program_id: crate::ID,
+            accounts: vec![AccountMeta::new(pool, false), AccountMeta::new(signer, true)],
+            data,
+        }
+    }
+
+    #[test]
+    fn decodes_swap_entrypoints() {
+        let pool = Pubkey::new_unique();
+        let signer = Pubkey::new_unique();
+        let mut data = instruction::Swap::DISCRIMINATOR.to_vec();
+        data.push(1);
+        assert_eq!(
+            decode_pool_swap(&swap_ix(data.clone(), pool, signer), &pool),
+            Some(PoolSwap {
+                signer,
+                direction: SwapDirection::SolToToken,
+            })
+        );
+        assert_eq!(decode_pool_swap(&swap_ix(data, Pubkey::new_unique(), signer), &pool), None);
+        
+        let mut data = instruction::SwapWithReceipt::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&7u64.to_le_bytes());
+        data.push(0);
+        assert_eq!(
+            decode_pool_swap(&swap_ix(data, pool, signer), &pool).map(|swap| swap.direction),
+            Some(SwapDirection::TokenToSol)
+        );
+        
+        let data = instruction::GetVersion::DISCRIMINATOR.to_vec();
+        assert_eq!(decode_pool_swap(&swap_ix(data, pool, signer), &pool), None);
+    }
+
+    #[test]
+    fn flags_opposing_swaps_from_different_signers() {
+        let (victim, attacker) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let swap = |signer, direction| PoolSwap { signer, direction };
+        let front_run = swap(attacker, SwapDirection::SolToToken);
+        let victim_buy = swap(victim, SwapDirection::SolToToken);
+        let back_run = swap(attacker, SwapDirection::TokenToSol);
+        
+        assert!(is_sandwich(&[front_run, victim_buy, back_run]));
+        // One signer round-tripping, or several signers trading one way, is fine
+        assert!(!is_sandwich(&[victim_buy, swap(victim, SwapDirection::TokenToSol)]));
+        assert!(!is_sandwich(&[front_run, victim_buy]));
+        assert!(!is_sandwich(&[]));
+    }
+}
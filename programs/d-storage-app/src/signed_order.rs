@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{ed25519_program, sysvar};
+
+use crate::errors::ExchangeError;
+use crate::instructions::swap::SwapDirection;
+
+/// Size of the header before the first `Ed25519SignatureOffsets` entry
+const ED25519_HEADER_LEN: usize = 2;
+/// Size of one `Ed25519SignatureOffsets` entry
+const ED25519_OFFSETS_LEN: usize = 14;
+/// Instruction index meaning "data is in the Ed25519 instruction itself"
+const ED25519_CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+/// Swap intent signed off-chain by the owner of the internal balances it
+/// trades. The borsh encoding is the signed message.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SignedOrder {
+    pub owner: Pubkey,
+    pub pool: Pubkey,
+    pub direction: SwapDirection,
+    pub amount_in: u64,
+    pub min_amount_out: u64,
+    /// Unix timestamp after which the order can no longer be filled
+    pub expiry: i64,
+    /// Per-owner nonce, each value can be filled once
+    pub nonce: u64,
+}
+
+/// Public key and message of a single-signature Ed25519 program instruction
+/// that carries all of its data inline
+pub fn ed25519_signed_message(data: &[u8]) -> Option<(&[u8], &[u8])> {
+    if data.len() < ED25519_HEADER_LEN + ED25519_OFFSETS_LEN || data[0] != 1 {
+        return None;
+    }
+    let offsets = &data[ED25519_HEADER_LEN..ED25519_HEADER_LEN + ED25519_OFFSETS_LEN];
+    let read = |i: usize| u16::from_le_bytes([offsets[i], offsets[i + 1]]);
+    let (signature_ix, public_key_offset, public_key_ix) = (read(2), read(4) as usize, read(6));
+    let (message_offset, message_len, message_ix) = (read(8) as usize, read(10) as usize, read(12));
+    
+    // Data in other instructions is not verified against what we read here
+    if [signature_ix, public_key_ix, message_ix] != [ED25519_CURRENT_INSTRUCTION; 3] {
+        return None;
+    }
+    let public_key = data.get(public_key_offset..public_key_offset + 32)?;
+    let message = data.get(message_offset..message_offset + message_len)?;
+    Some((public_key, message))
+}
+
+/// Require the instruction right before this one to be an Ed25519 program
+/// instruction verifying `signer`'s signature over `message`
+pub fn verify_ed25519_signature(instructions: &AccountInfo, signer: &Pubkey, message: &[u8]) -> Result<()> {
+    let current_index = sysvar::instructions::load_current_index_checked(instructions)?;
+    require!(current_index > 0, ExchangeError::InvalidOrderSignature);
+    let ix = sysvar::instructions::load_instruction_at_checked(current_index as usize - 1, instructions)?;
+    require_keys_eq!(ix.program_id, ed25519_program::ID, ExchangeError::InvalidOrderSignature);
+    
+    let (public_key, signed_message) =
+        ed25519_signed_message(&ix.data).ok_or(ExchangeError::InvalidOrderSignature)?;
+    require!(
+        public_key == signer.as_ref() && signed_message == message,
+        ExchangeError::InvalidOrderSignature
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Ed25519 instruction data laid out the way the web3.js helper builds it
+    fn ed25519_data(public_key: &[u8; 32], message: &[u8], instruction_index: u16) -> Vec<u8> {
+        let public_key_offset = (ED25519_HEADER_LEN + ED25519_OFFSETS_LEN) as u16;
+        let signature_offset = public_key_offset + 32;
+        let message_offset = signature_offset + 64;
+        let mut data = vec![1, 0];
+        for value in [
+            signature_offset,
+            instruction_index,
+            public_key_offset,
+            instruction_index,
+            message_offset,
+            message.len() as u16,
+            instruction_index,
+        ] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.extend_from_slice(public_key);
+        data.extend_from_slice(&[0; 64]);
+        data.extend_from_slice(message);
+        data
+    }
+
+    #[test]
+    fn reads_inline_public_key_and_message() {
+        let data = ed25519_data(&[7; 32], b"order", ED25519_CURRENT_INSTRUCTION);
+        let (public_key, message) = ed25519_signed_message(&data).unwrap();
+        assert_eq!(public_key, &[7; 32]);
+        assert_eq!(message, b"order");
+    }
+
+    #[test]
+    fn rejects_data_from_other_instructions() {
+        let data = ed25519_data(&[7; 32], b"order", 0);
+        assert!(ed25519_signed_message(&data).is_none());
+    }
+
+    #[test]
+    fn rejects_multiple_or_truncated_signatures() {
+        let mut data = ed25519_data(&[7; 32], b"order", ED25519_CURRENT_INSTRUCTION);
+        data[0] = 2;
+        assert!(ed25519_signed_message(&data).is_none());
+        
+        let data = ed25519_data(&[7; 32], b"order", ED25519_CURRENT_INSTRUCTION);
+        assert!(ed25519_signed_message(&data[..data.len() - 1]).is_none());
+    }
+}
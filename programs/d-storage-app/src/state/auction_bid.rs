@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+#[account]
+pub struct AuctionBid {
+    pub auction: Pubkey,           // Launch auction the bid is placed in
+    pub bidder: Pubkey,
+    pub commitment: [u8; 32],      // Hash of the auction, bidder, price tick and salt
+    pub sol_amount: u64,           // SOL deposited with the bid
+    pub tick: Option<u8>,          // Price tick, set once revealed
+}
+
+impl AuctionBid {
+    /// Account size including the discriminator
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 2;
+
+    /// Commitment a sealed bid at `tick` is placed with
+    pub fn commitment(auction: &Pubkey, bidder: &Pubkey, tick: u8, salt: &[u8; 32]) -> [u8; 32] {
+        hashv(&[auction.as_ref(), bidder.as_ref(), &[tick], salt]).to_bytes()
+    }
+}
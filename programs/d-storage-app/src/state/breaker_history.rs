@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BreakerKind {
+    /// Pool price strayed from the oracle by more than the allowed deviation,
+    /// magnitude in bps
+    PriceDeviation,
+    /// Oracle update older than the allowed staleness, magnitude in seconds
+    OracleStale,
+    /// Oracle confidence interval too wide, magnitude in bps of the price
+    OracleConfidence,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct BreakerTrip {
+    pub slot: u64,
+    pub timestamp: i64,
+    pub kind: BreakerKind,
+    pub magnitude: u64,
+}
+
+impl BreakerTrip {
+    pub const LEN: usize = 8 + 8 + 1 + 8;
+}
+
+/// Ring buffer of the latest circuit breaker trips of a pool, for auditing
+/// how often its guards fire
+#[account]
+pub struct BreakerHistory {
+    pub pool: Pubkey,              // Pool whose guards are recorded
+    pub trip_count: u64,           // Trips recorded since creation
+    pub next_index: u16,           // Slot the next trip is written to
+    pub trips: Vec<BreakerTrip>,   // Grows to MAX_TRIPS, then wraps
+}
+
+impl BreakerHistory {
+    pub const MAX_TRIPS: usize = 32;
+
+    /// Account size including the discriminator
+    pub const LEN: usize = 8 + 32 + 8 + 2 + 4 + BreakerTrip::LEN * Self::MAX_TRIPS;
+
+    pub fn latest(&self) -> Option<&BreakerTrip> {
+        let len = self.trips.len();
+        if len == 0 {
+            return None;
+        }
+        self.trips.get((self.next_index as usize + len - 1) % len)
+    }
+
+    /// Record a trip unless the same breaker already tripped in this slot.
+    /// Returns whether it was written.
+    pub fn record(&mut self, trip: BreakerTrip) -> bool {
+        if self
+            .latest()
+            .is_some_and(|latest| latest.slot == trip.slot && latest.kind == trip.kind)
+        {
+            return false;
+        }
+        let index = self.next_index as usize;
+        if index < self.trips.len() {
+            self.trips[index] = trip;
+        } else {
+            self.trips.push(trip);
+        }
+        self.next_index = ((index + 1) % Self::MAX_TRIPS) as u16;
+        self.trip_count += 1;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trip(slot: u64, kind: BreakerKind) -> BreakerTrip {
+        BreakerTrip {
+            slot,
+            timestamp: 0,
+            kind,
+            magnitude: 0,
+        }
+    }
+
+    #[test]
+    fn records_once_per_slot_and_kind_and_wraps() {
+        let mut history = BreakerHistory {
+            pool: Pubkey::default(),
+            trip_count: 0,
+            next_index: 0,
+            trips: Vec::new(),
+        };
+        assert!(history.record(trip(1, BreakerKind::OracleStale)));
+        assert!(!history.record(trip(1, BreakerKind::OracleStale)));
+        assert!(history.record(trip(1, BreakerKind::PriceDeviation)));
+        for slot in 2..2 + BreakerHistory::MAX_TRIPS as u64 {
+            assert!(history.record(trip(slot, BreakerKind::OracleConfidence)));
+        }
+        assert_eq!(history.trips.len(), BreakerHistory::MAX_TRIPS);
+        assert_eq!(history.trip_count, 2 + BreakerHistory::MAX_TRIPS as u64);
+        assert_eq!(history.latest().unwrap().slot, 1 + BreakerHistory::MAX_TRIPS as u64);
+        
+        let data = history.try_to_vec().unwrap();
+        assert_eq!(8 + data.len(), BreakerHistory::LEN);
+    }
+}
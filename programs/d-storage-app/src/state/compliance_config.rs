@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+/// Compliance role, kept beside the protocol config since that account has
+/// no room left for another key
+#[account]
+pub struct ComplianceConfig {
+    pub authority: Pubkey,         // May freeze pools for legal takedowns (default = role disabled)
+    pub updated_at: i64,          // Last time the role was assigned
+}
+
+impl ComplianceConfig {
+    /// Account size including the discriminator
+    pub const LEN: usize = 8 + 32 + 8;
+
+    pub fn is_authority(&self, key: &Pubkey) -> bool {
+        self.authority != Pubkey::default() && self.authority == *key
+    }
+}
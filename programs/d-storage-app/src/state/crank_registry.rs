@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use super::{PriceObservations, TvlHistory};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CrankJobKind {
+    /// `crank_observation`
+    PriceObservation,
+    /// `checkpoint_tvl`
+    TvlCheckpoint,
+    /// `update_pool_snapshot`
+    PoolSnapshot,
+}
+
+impl CrankJobKind {
+    /// Earliest time the job can run again after running at `now`
+    pub fn next_due(self, now: i64) -> i64 {
+        match self {
+            CrankJobKind::PriceObservation | CrankJobKind::PoolSnapshot => {
+                now + PriceObservations::MIN_INTERVAL_SECS
+            }
+            CrankJobKind::TvlCheckpoint => {
+                (now.div_euclid(TvlHistory::INTERVAL_SECS) + 1) * TvlHistory::INTERVAL_SECS
+            }
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CrankJob {
+    pub kind: CrankJobKind,
+    pub pool: Pubkey,
+    pub due_at: i64,
+}
+
+impl CrankJob {
+    pub const LEN: usize = 1 + 32 + 8;
+}
+
+/// Jobs keepers can run, kept in one account so a single fetch finds
+/// everything due. The crank instructions reschedule their job when the
+/// registry is passed to them.
+#[account]
+pub struct CrankRegistry {
+    pub jobs: Vec<CrankJob>,       // Registered jobs, unordered
+}
+
+impl CrankRegistry {
+    pub const MAX_JOBS: usize = 128;
+
+    /// Account size including the discriminator, sized for a full registry
+    pub const LEN: usize = 8 + 4 + CrankJob::LEN * Self::MAX_JOBS;
+
+    fn position(&self, kind: CrankJobKind, pool: Pubkey) -> Option<usize> {
+        self.jobs.iter().position(|job| job.kind == kind && job.pool == pool)
+    }
+
+    /// Add a job that is due right away
+    pub fn register(&mut self, kind: CrankJobKind, pool: Pubkey, now: i64) -> Result<()> {
+        require!(self.position(kind, pool).is_none(), ExchangeError::CrankJobExists);
+        require!(self.jobs.len() < Self::MAX_JOBS, ExchangeError::CrankRegistryFull);
+        self.jobs.push(CrankJob { kind, pool, due_at: now });
+        Ok(())
+    }
+
+    pub fn deregister(&mut self, kind: CrankJobKind, pool: Pubkey) -> Result<()> {
+        let index = self.position(kind, pool).ok_or(ExchangeError::CrankJobNotFound)?;
+        self.jobs.swap_remove(index);
+        Ok(())
+    }
+
+    /// Push a job back after it ran at `now`. Unregistered jobs are ignored.
+    pub fn reschedule(&mut self, kind: CrankJobKind, pool: Pubkey, now: i64) {
+        if let Some(index) = self.position(kind, pool) {
+            self.jobs[index].due_at = kind.next_due(now);
+        }
+    }
+
+    pub fn due(&self, now: i64) -> impl Iterator<Item = &CrankJob> {
+        self.jobs.iter().filter(move |job| job.due_at <= now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn len_fits_full_registry() {
+        let registry = CrankRegistry {
+            jobs: vec![
+                CrankJob {
+                    kind: CrankJobKind::PoolSnapshot,
+                    pool: Pubkey::default(),
+                    due_at: 0,
+                };
+                CrankRegistry::MAX_JOBS
+            ],
+        };
+        let data = registry.try_to_vec().unwrap();
+        assert_eq!(8 + data.len(), CrankRegistry::LEN);
+    }
+
+    #[test]
+    fn jobs_come_due_after_their_interval() {
+        let day = TvlHistory::INTERVAL_SECS;
+        let pool = Pubkey::new_unique();
+        let mut registry = CrankRegistry { jobs: Vec::new() };
+        registry.register(CrankJobKind::PriceObservation, pool, 0).unwrap();
+        registry.register(CrankJobKind::TvlCheckpoint, pool, 0).unwrap();
+        assert!(registry.register(CrankJobKind::TvlCheckpoint, pool, 0).is_err());
+        assert_eq!(registry.due(0).count(), 2);
+        
+        registry.reschedule(CrankJobKind::TvlCheckpoint, pool, day + 10);
+        registry.reschedule(CrankJobKind::PriceObservation, pool, day + 10);
+        assert_eq!(registry.due(day + 10).count(), 0);
+        assert_eq!(registry.due(day + 10 + PriceObservations::MIN_INTERVAL_SECS).count(), 1);
+        assert_eq!(registry.due(2 * day).count(), 2);
+        
+        registry.deregister(CrankJobKind::PriceObservation, pool).unwrap();
+        assert!(registry.deregister(CrankJobKind::PriceObservation, pool).is_err());
+        assert_eq!(registry.jobs.len(), 1);
+    }
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct CreatorQuota {
+    pub creator: Pubkey,           // Token creator the quota applies to
+    pub launches: u32,            // Tokens registered by the creator so far
+    pub limit_override: Option<u32>, // Admin-set limit replacing the protocol default
+}
+
+impl CreatorQuota {
+    /// Account size including the discriminator
+    pub const LEN: usize = 8 + 32 + 4 + 1 + 4;
+
+    /// Launches the creator may make, `default_limit` of 0 means unlimited
+    pub fn limit(&self, default_limit: u32) -> Option<u32> {
+        match self.limit_override {
+            Some(limit) => Some(limit),
+            None if default_limit == 0 => None,
+            None => Some(default_limit),
+        }
+    }
+
+    /// Count one more launch if the creator is still within their limit
+    pub fn record_launch(&mut self, default_limit: u32) -> bool {
+        if self.limit(default_limit).is_some_and(|limit| self.launches >= limit) {
+            return false;
+        }
+        self.launches += 1;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn override_replaces_default_limit() {
+        let mut quota = CreatorQuota {
+            creator: Pubkey::new_unique(),
+            launches: 0,
+            limit_override: None,
+        };
+        assert!(quota.record_launch(0));
+        assert!(!quota.record_launch(1));
+        
+        quota.limit_override = Some(3);
+        assert!(quota.record_launch(1));
+        assert!(quota.record_launch(1));
+        assert!(!quota.record_launch(1));
+        assert_eq!(quota.launches, 3);
+        
+        quota.limit_override = Some(0);
+        assert!(!quota.record_launch(0));
+    }
+}
@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct CustodyApproval {
+    pub custody_program: Pubkey,   // Program allowed to hold pool token vaults
+    pub approved_by: Pubkey,       // Protocol admin that approved it
+    pub approved_at: i64,         // Approval timestamp
+}
+
+impl CustodyApproval {
+    /// Account size including the discriminator
+    pub const LEN: usize = 8 + 32 + 32 + 8;
+}
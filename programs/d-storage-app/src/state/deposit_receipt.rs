@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+
+use crate::curve::withdraw_amounts;
+use super::LiquidityPool;
+
+/// Value of a receipt's position at the current reserve ratio, all in quote
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PositionReport {
+    /// Tokens and quote the covered LP tokens redeem for now
+    pub token_amount: u64,
+    pub quote_amount: u64,
+    /// Deposited amounts valued at the entry price
+    pub entry_value: u64,
+    /// Deposited amounts valued at the current price, had they been held
+    pub hold_value: u64,
+    /// Redeemable amounts valued at the current price, fees included
+    pub lp_value: u64,
+    /// `hold_value - lp_value`, net of the fees earned
+    pub impermanent_loss: i64,
+    /// `lp_value - entry_value`
+    pub net_pnl: i64,
+}
+
+/// A provider's deposits into one pool, kept on request so the position's
+/// impermanent loss and P&L can be worked out at withdrawal time
+#[account]
+pub struct DepositReceipt {
+    pub owner: Pubkey,
+    pub pool: Pubkey,
+    pub lp_amount: u64,            // LP tokens minted to the owner, less those withdrawn
+    pub token_deposited: u64,      // Tokens behind `lp_amount`
+    pub quote_deposited: u64,      // SOL or quote tokens behind `lp_amount`
+    pub entry_token_value: u64,    // Deposited tokens valued in quote at each deposit
+    pub updated_at: i64,
+}
+
+impl DepositReceipt {
+    /// Account size including the discriminator
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 8 + 8 + 8;
+
+    /// Token-weighted quote per token paid at deposit, as Q64.64
+    pub fn entry_price_x64(&self) -> u128 {
+        if self.token_deposited == 0 {
+            return 0;
+        }
+        ((self.entry_token_value as u128) << 64) / self.token_deposited as u128
+    }
+
+    /// Add a deposit, `token_value` being its tokens valued in quote at the
+    /// pool's price at the time
+    pub fn record_deposit(&mut self, token_amount: u64, quote_amount: u64, lp_tokens: u64, token_value: u64) {
+        self.token_deposited += token_amount;
+        self.quote_deposited += quote_amount;
+        self.entry_token_value += token_value;
+        self.lp_amount += lp_tokens;
+    }
+
+    /// Drop the share of the deposits behind `lp_tokens` withdrawn LP tokens
+    pub fn record_withdrawal(&mut self, lp_tokens: u64) {
+        let remaining = self.lp_amount.saturating_sub(lp_tokens);
+        let scale = |amount: u64| {
+            if self.lp_amount == 0 {
+                return 0;
+            }
+            (amount as u128 * remaining as u128 / self.lp_amount as u128) as u64
+        };
+        self.token_deposited = scale(self.token_deposited);
+        self.quote_deposited = scale(self.quote_deposited);
+        self.entry_token_value = scale(self.entry_token_value);
+        self.lp_amount = remaining;
+    }
+
+    pub fn report(&self, pool: &LiquidityPool) -> PositionReport {
+        let (token_amount, quote_amount) =
+            withdraw_amounts(pool.token_reserve, pool.sol_reserve, pool.lp_supply, self.lp_amount);
+        let entry_value = self.quote_deposited.saturating_add(self.entry_token_value);
+        let hold_value = self
+            .quote_deposited
+            .saturating_add(pool.token_value_in_quote(self.token_deposited));
+        let lp_value = quote_amount.saturating_add(pool.token_value_in_quote(token_amount));
+        PositionReport {
+            token_amount,
+            quote_amount,
+            entry_value,
+            hold_value,
+            lp_value,
+            impermanent_loss: (hold_value as i128 - lp_value as i128) as i64,
+            net_pnl: (lp_value as i128 - entry_value as i128) as i64,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn receipt() -> DepositReceipt {
+        DepositReceipt {
+            owner: Pubkey::default(),
+            pool: Pubkey::default(),
+            lp_amount: 0,
+            token_deposited: 0,
+            quote_deposited: 0,
+            entry_token_value: 0,
+            updated_at: 0,
+        }
+    }
+
+    #[test]
+    fn len_matches_serialized_size() {
+        assert_eq!(8 + receipt().try_to_vec().unwrap().len(), DepositReceipt::LEN);
+    }
+
+    #[test]
+    fn price_move_shows_impermanent_loss() {
+        let mut receipt = receipt();
+        receipt.record_deposit(1_000, 1_000, 1_000, 1_000);
+        
+        // Price went 1 -> 4 along x * y = 1_000_000
+        let pool = LiquidityPool {
+            token_reserve: 500,
+            sol_reserve: 2_000,
+            lp_supply: 1_000,
+            ..Default::default()
+        };
+        let report = receipt.report(&pool);
+        assert_eq!(report.entry_value, 2_000);
+        assert_eq!(report.hold_value, 5_000);
+        assert_eq!(report.lp_value, 4_000);
+        assert_eq!(report.impermanent_loss, 1_000);
+        assert_eq!(report.net_pnl, 2_000);
+        
+        receipt.record_withdrawal(250);
+        assert_eq!((receipt.lp_amount, receipt.token_deposited, receipt.quote_deposited), (750, 750, 750));
+        assert_eq!(receipt.entry_price_x64(), 1 << 64);
+    }
+
+    #[test]
+    fn entry_price_is_token_weighted() {
+        let mut receipt = receipt();
+        receipt.record_deposit(100, 100, 100, 100);
+        receipt.record_deposit(300, 900, 300, 900);
+        assert_eq!(receipt.entry_price_x64(), (5 << 64) / 2);
+    }
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+/// Dutch auction unwinding an LP position: the escrowed LP tokens are offered
+/// at a price falling linearly from `start_price_x64` to `end_price_x64`, so a
+/// large holder exits to buyers instead of through the curve
+#[account]
+pub struct ExitAuction {
+    pub seller: Pubkey,            // LP holder receiving the proceeds
+    pub pool: Pubkey,
+    pub remaining: u64,            // LP tokens still escrowed for sale
+    pub sold: u64,
+    pub proceeds: u64,             // Lamports paid to the seller so far
+    pub start_price_x64: u128,     // Lamports per LP token unit, Q64.64
+    pub end_price_x64: u128,       // Floor the price rests at after `end_time`
+    pub start_time: i64,
+    pub end_time: i64,
+    pub bump: u8,
+}
+
+impl ExitAuction {
+    /// Account size including the discriminator
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 8 + 16 + 16 + 8 + 8 + 1;
+
+    /// Asking price at `now`
+    pub fn price_at(&self, now: i64) -> u128 {
+        if now <= self.start_time {
+            return self.start_price_x64;
+        }
+        if now >= self.end_time {
+            return self.end_price_x64;
+        }
+        let elapsed = (now - self.start_time) as u128;
+        let duration = (self.end_time - self.start_time) as u128;
+        let drop = self.start_price_x64 - self.end_price_x64;
+        // Split the product so large Q64.64 prices cannot overflow
+        let decay = drop / duration * elapsed + drop % duration * elapsed / duration;
+        self.start_price_x64 - decay
+    }
+
+    /// Lamports owed for `lp_amount` at `price_x64`, rounded up for the seller
+    pub fn cost(lp_amount: u64, price_x64: u128) -> Option<u64> {
+        let whole = (lp_amount as u128).checked_mul(price_x64 >> 64)?;
+        let fraction = lp_amount as u128 * (price_x64 & u64::MAX as u128);
+        let cost = whole.checked_add(fraction.div_ceil(1 << 64))?;
+        u64::try_from(cost).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn price_decays_linearly_to_the_floor() {
+        let auction = ExitAuction {
+            seller: Pubkey::new_unique(),
+            pool: Pubkey::new_unique(),
+            remaining: 1_000,
+            sold: 0,
+            proceeds: 0,
+            start_price_x64: 10 << 64,
+            end_price_x64: 2 << 64,
+            start_time: 100,
+            end_time: 500,
+            bump: 0,
+        };
+        assert_eq!(auction.price_at(0), 10 << 64);
+        assert_eq!(auction.price_at(300), 6 << 64);
+        assert_eq!(auction.price_at(400), 4 << 64);
+        assert_eq!(auction.price_at(10_000), 2 << 64);
+        
+        assert_eq!(ExitAuction::cost(3, 6 << 64), Some(18));
+        assert_eq!(ExitAuction::cost(3, (1 << 64) / 2), Some(2));
+        assert_eq!(ExitAuction::cost(1, u128::MAX), None);
+    }
+}
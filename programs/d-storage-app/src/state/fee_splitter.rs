@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct FeeShare {
+    pub recipient: Pubkey,
+    pub share_bps: u16,
+}
+
+impl FeeShare {
+    pub const LEN: usize = 32 + 2;
+}
+
+/// Recipients of the collected protocol fees (treasury, insurance fund,
+/// buyback, team...). Empty pays everything to the protocol admin.
+#[account]
+pub struct FeeSplitter {
+    pub shares: Vec<FeeShare>,     // Shares add up to 10000 bps
+}
+
+impl FeeSplitter {
+    pub const MAX_RECIPIENTS: usize = 8;
+
+    /// Account size including the discriminator, sized for a full splitter
+    pub const LEN: usize = 8 + 4 + FeeShare::LEN * Self::MAX_RECIPIENTS;
+
+    pub fn validate(shares: &[FeeShare]) -> Result<()> {
+        require!(shares.len() <= Self::MAX_RECIPIENTS, ExchangeError::InvalidFeeSplit);
+        let total: u32 = shares.iter().map(|share| share.share_bps as u32).sum();
+        require!(shares.is_empty() || total == 10000, ExchangeError::InvalidFeeSplit);
+        for (i, share) in shares.iter().enumerate() {
+            require!(
+                share.share_bps > 0 && shares[..i].iter().all(|other| other.recipient != share.recipient),
+                ExchangeError::InvalidFeeSplit
+            );
+        }
+        Ok(())
+    }
+
+    /// Each recipient's cut of `amount`, in share order. Rounding dust goes
+    /// to the first recipient.
+    pub fn split(&self, amount: u64) -> Vec<u64> {
+        let mut cuts: Vec<u64> = self
+            .shares
+            .iter()
+            .map(|share| (amount as u128 * share.share_bps as u128 / 10000) as u64)
+            .collect();
+        let dust = amount - cuts.iter().sum::<u64>();
+        if let Some(first) = cuts.first_mut() {
+            *first += dust;
+        }
+        cuts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn share(share_bps: u16) -> FeeShare {
+        FeeShare {
+            recipient: Pubkey::new_unique(),
+            share_bps,
+        }
+    }
+
+    #[test]
+    fn len_fits_full_splitter() {
+        let splitter = FeeSplitter {
+            shares: vec![share(1250); FeeSplitter::MAX_RECIPIENTS],
+        };
+        let data = splitter.try_to_vec().unwrap();
+        assert_eq!(8 + data.len(), FeeSplitter::LEN);
+    }
+
+    #[test]
+    fn shares_must_cover_the_whole_fee() {
+        assert!(FeeSplitter::validate(&[]).is_ok());
+        assert!(FeeSplitter::validate(&[share(6000), share(4000)]).is_ok());
+        assert!(FeeSplitter::validate(&[share(6000), share(3000)]).is_err());
+        assert!(FeeSplitter::validate(&[share(10000), share(0)]).is_err());
+        
+        let duplicate = share(5000);
+        assert!(FeeSplitter::validate(&[duplicate, duplicate]).is_err());
+    }
+
+    #[test]
+    fn split_pays_out_the_full_amount() {
+        let splitter = FeeSplitter {
+            shares: vec![share(5000), share(3333), share(1667)],
+        };
+        let cuts = splitter.split(1001);
+        assert_eq!(cuts, vec![502, 333, 166]);
+        assert_eq!(cuts.iter().sum::<u64>(), 1001);
+    }
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+
+#[account]
+pub struct InternalBalance {
+    pub owner: Pubkey,             // Wallet the balance belongs to
+    pub mint: Pubkey,              // Asset mint (default = native SOL)
+    pub amount: u64,               // Amount held for the owner in the internal vault
+    pub rent_payer: Pubkey,        // Refunded on close (default = the owner)
+}
+
+impl InternalBalance {
+    /// Account size including the discriminator
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 32;
+
+    /// Account the rent goes back to on close
+    pub fn rent_refund_to(&self) -> Pubkey {
+        if self.rent_payer == Pubkey::default() {
+            self.owner
+        } else {
+            self.rent_payer
+        }
+    }
+
+    /// Whether the balance is held in native SOL
+    pub fn is_native(&self) -> bool {
+        self.mint == Pubkey::default()
+    }
+
+    /// Add `amount` to the balance
+    pub fn credit(&mut self, amount: u64) -> Result<()> {
+        self.amount = self.amount.checked_add(amount).ok_or(ExchangeError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Remove `amount` from the balance, failing if it is not available
+    pub fn debit(&mut self, amount: u64) -> Result<()> {
+        self.amount = self
+            .amount
+            .checked_sub(amount)
+            .ok_or(ExchangeError::InsufficientInternalBalance)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,230 @@
+use anchor_lang::prelude::*;
+
+use super::CurveType;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum AuctionStatus {
+    /// Taking sealed bids, then reveals
+    #[default]
+    Open,
+    /// Clearing price set and the pool seeded, bids can be claimed
+    Cleared,
+    /// No demand or the pool could not be seeded, every bid is refunded
+    Cancelled,
+}
+
+/// Sealed batch auction selling a fixed supply at one uniform price. Bids
+/// commit to a price tick during the bidding window and reveal it after;
+/// revealed demand is aggregated per tick so clearing never walks the bids.
+#[account]
+pub struct LaunchAuction {
+    pub creator: Pubkey,
+    pub token_mint: Pubkey,
+    pub supply_for_sale: u64,      // Tokens sold to the bidders
+    pub pool_token_amount: u64,    // Tokens seeding the pool next to the raised SOL
+    pub reserve_price_x64: u128,   // Price of tick 0 in lamports per token unit, Q64.64
+    pub tick_spacing_bps: u16,     // Price step between ticks
+    pub bid_end: i64,
+    pub reveal_end: i64,
+    pub fee_rate: u16,             // LP fee of the seeded pool
+    pub curve_type: CurveType,
+    pub status: AuctionStatus,
+    pub committed_sol: u64,        // All bid deposits, revealed or not
+    pub demand: [u64; 32],         // Revealed SOL per price tick
+    pub clearing_tick: u8,
+    pub clearing_price_x64: u128,
+    pub marginal_sol: u64,         // SOL filled at the clearing tick, shared pro rata
+    pub marginal_tokens: u64,      // Tokens allocated to the clearing tick
+    pub cleared_sol: u64,          // SOL raised, moved into the pool
+    pub tokens_sold: u64,
+    pub bump: u8,
+}
+
+impl LaunchAuction {
+    pub const MAX_TICKS: usize = 32;
+    /// Account size including the discriminator
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 16 + 2 + 8 + 8 + 2 + 1 + 1 + 8
+        + 8 * Self::MAX_TICKS + 1 + 16 + 8 + 8 + 8 + 8 + 1;
+
+    /// Price of `tick`, growing geometrically by the tick spacing
+    pub fn tick_price_x64(&self, tick: usize) -> Option<u128> {
+        let mut price = self.reserve_price_x64;
+        for _ in 0..tick {
+            price = price.checked_mul(10_000 + self.tick_spacing_bps as u128)? / 10_000;
+        }
+        Some(price)
+    }
+
+    /// Whether every tick prices the whole supply at one lamport or more
+    /// without overflowing
+    pub fn has_valid_prices(&self) -> bool {
+        let top = Self::MAX_TICKS - 1;
+        self.tick_price_x64(0)
+            .and_then(|price| quote_for(self.supply_for_sale, price))
+            .is_some_and(|capacity| capacity > 0)
+            && self
+                .tick_price_x64(top)
+                .and_then(|price| quote_for(self.supply_for_sale, price))
+                .is_some_and(|capacity| capacity <= u64::MAX as u128)
+    }
+
+    /// Whether any bid was revealed
+    pub fn has_demand(&self) -> bool {
+        self.demand.iter().any(|&sol| sol > 0)
+    }
+
+    /// Set the clearing price: the highest tick at which the bids at or above
+    /// it take the whole supply, bids at that tick filled pro rata. When the
+    /// auction is undersubscribed it clears at the reserve price and the
+    /// unsold supply goes to the pool. Returns the SOL raised.
+    pub fn clear(&mut self) -> Option<u64> {
+        let mut above = 0u64;
+        let mut clearing = None;
+        for t in (0..Self::MAX_TICKS).rev() {
+            let capacity = quote_for(self.supply_for_sale, self.tick_price_x64(t)?)?;
+            if above > 0 && above as u128 > capacity {
+                // Bids above already exceed the supply at this price but fell
+                // short at the next tick up: clear there without selling out
+                let marginal_sol = self.demand[t + 1];
+                clearing = Some((t + 1, above - marginal_sol, marginal_sol));
+                break;
+            }
+            if (above + self.demand[t]) as u128 >= capacity {
+                clearing = Some((t, above, (capacity - above as u128) as u64));
+                break;
+            }
+            above += self.demand[t];
+        }
+        let (tick, above, marginal_sol) =
+            clearing.unwrap_or((0, above - self.demand[0], self.demand[0]));
+        
+        let price = self.tick_price_x64(tick)?;
+        self.clearing_tick = tick as u8;
+        self.clearing_price_x64 = price;
+        self.marginal_sol = marginal_sol;
+        self.marginal_tokens = tokens_for(marginal_sol, price) as u64;
+        self.cleared_sol = above + marginal_sol;
+        self.tokens_sold = (tokens_for(above, price) as u64 + self.marginal_tokens)
+            .min(self.supply_for_sale);
+        Some(self.cleared_sol)
+    }
+
+    /// Tokens and SOL refund owed to a bid of `sol_amount` revealed at `tick`
+    pub fn allocation(&self, sol_amount: u64, tick: Option<u8>) -> (u64, u64) {
+        let Some(tick) = tick.filter(|_| self.status == AuctionStatus::Cleared) else {
+            return (0, sol_amount);
+        };
+        if tick < self.clearing_tick {
+            return (0, sol_amount);
+        }
+        if tick > self.clearing_tick {
+            return (tokens_for(sol_amount, self.clearing_price_x64) as u64, 0);
+        }
+        let demand = self.demand[tick as usize] as u128;
+        if demand == 0 {
+            return (0, sol_amount);
+        }
+        let tokens = sol_amount as u128 * self.marginal_tokens as u128 / demand;
+        let refund = sol_amount as u128 * (demand - self.marginal_sol as u128) / demand;
+        (tokens as u64, refund as u64)
+    }
+
+    /// Tokens left in the auction vault for the pool once bids are served
+    pub fn pool_tokens(&self) -> u64 {
+        self.pool_token_amount + self.supply_for_sale - self.tokens_sold
+    }
+}
+
+/// Lamports buying `tokens` at `price_x64`, rounded down
+fn quote_for(tokens: u64, price_x64: u128) -> Option<u128> {
+    let whole = (tokens as u128).checked_mul(price_x64 >> 64)?;
+    whole.checked_add((tokens as u128 * (price_x64 & u64::MAX as u128)) >> 64)
+}
+
+/// Tokens bought by `lamports` at `price_x64`, rounded down
+fn tokens_for(lamports: u64, price_x64: u128) -> u128 {
+    ((lamports as u128) << 64) / price_x64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn auction(demand: &[(usize, u64)]) -> LaunchAuction {
+        let mut auction = LaunchAuction {
+            creator: Pubkey::new_unique(),
+            token_mint: Pubkey::new_unique(),
+            supply_for_sale: 1_000,
+            pool_token_amount: 500,
+            reserve_price_x64: 1 << 64,
+            tick_spacing_bps: 10_000,
+            bid_end: 0,
+            reveal_end: 0,
+            fee_rate: 30,
+            curve_type: CurveType::ConstantProduct,
+            status: AuctionStatus::Open,
+            committed_sol: 0,
+            demand: [0; 32],
+            clearing_tick: 0,
+            clearing_price_x64: 0,
+            marginal_sol: 0,
+            marginal_tokens: 0,
+            cleared_sol: 0,
+            tokens_sold: 0,
+            bump: 0,
+        };
+        for &(tick, sol) in demand {
+            auction.demand[tick] += sol;
+            auction.committed_sol += sol;
+        }
+        auction
+    }
+
+    #[test]
+    fn clears_at_uniform_price_with_pro_rata_marginal_tick() {
+        // Ticks double the price: 1, 2, 4 lamports per token
+        let mut auction = auction(&[(2, 2_000), (1, 1_000), (0, 500)]);
+        assert_eq!(auction.clear(), Some(2_000));
+        assert_eq!(auction.clearing_tick, 1);
+        assert_eq!(auction.tokens_sold, 1_000);
+        
+        auction.status = AuctionStatus::Cleared;
+        assert_eq!(auction.allocation(2_000, Some(2)), (1_000, 0));
+        assert_eq!(auction.allocation(1_000, Some(1)), (0, 1_000));
+        assert_eq!(auction.allocation(500, Some(0)), (0, 500));
+        assert_eq!(auction.allocation(500, None), (0, 500));
+        assert_eq!(auction.pool_tokens(), 500);
+        
+        let mut auction = self::auction(&[(2, 1_000), (1, 1_000)]);
+        assert_eq!(auction.clear(), Some(2_000));
+        auction.status = AuctionStatus::Cleared;
+        assert_eq!(auction.allocation(1_000, Some(2)), (500, 0));
+        // The two bids at the clearing tick share the remaining 500 tokens
+        assert_eq!(auction.allocation(600, Some(1)), (300, 0));
+        assert_eq!(auction.allocation(400, Some(1)), (200, 0));
+    }
+
+    #[test]
+    fn undersubscribed_auction_clears_at_reserve_price() {
+        let mut auction = auction(&[(3, 800), (0, 100)]);
+        assert_eq!(auction.clear(), Some(900));
+        assert_eq!(auction.clearing_tick, 0);
+        assert_eq!(auction.tokens_sold, 900);
+        assert_eq!(auction.pool_tokens(), 600);
+        
+        let mut empty = self::auction(&[]);
+        assert_eq!(empty.clear(), Some(0));
+    }
+
+    #[test]
+    fn gap_between_ticks_clears_without_selling_out() {
+        // 6_000 lamports exceed the supply at tick 2 (4_000) but not at tick 3 (8_000)
+        let mut auction = auction(&[(3, 6_000), (0, 100)]);
+        assert_eq!(auction.clear(), Some(6_000));
+        assert_eq!(auction.clearing_tick, 3);
+        assert_eq!(auction.tokens_sold, 750);
+        auction.status = AuctionStatus::Cleared;
+        assert_eq!(auction.allocation(6_000, Some(3)), (750, 0));
+        assert_eq!(auction.allocation(100, Some(0)), (0, 100));
+    }
+}
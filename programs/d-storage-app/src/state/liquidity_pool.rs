@@ -0,0 +1,1118 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::constants::{FEE_CHANGE_WINDOW_SECS, LP_LOCK_REWARD_PRECISION, MAX_FEE_CHANGE_PER_WINDOW_BPS};
+use crate::curve::{constant_product, constant_sum};
+use crate::errors::ExchangeError;
+
+#[account]
+#[derive(Default)]
+pub struct LiquidityPool {
+    // `token_mint`, `curve_type` and `flags` lead at fixed offsets for
+    // memcmp filters, see `crate::layout`
+    pub token_mint: Pubkey,        // Configurable token mint
+    pub curve_type: CurveType,     // Pricing curve used for swaps
+    pub flags: u32,               // Optional features turned on for the pool, see `PoolFlags`
+    pub token_vault: Pubkey,       // Token vault account
+    pub sol_vault: Pubkey,         // SOL vault account (quote token vault for token-quoted pools)
+    pub lp_mint: Pubkey,          // LP token mint
+    pub token_reserve: u64,        // Current token reserves
+    pub sol_reserve: u64,          // Current SOL (or quote token) reserves
+    pub lp_supply: u64,           // Total LP tokens issued
+    pub lp_fee_bps: u16,          // Fee kept by LPs in basis points (e.g., 30 = 0.3%)
+    pub pool_authority: Pubkey,    // Pool authority PDA
+    pub is_initialized: bool,      // Pool initialization status
+    pub created_at: i64,          // Pool creation timestamp
+    pub admin: Option<Pubkey>,     // Pool creator allowed to manage the pool (None = renounced)
+    pub sunset_at: i64,           // Sunset deadline (0 = not scheduled)
+    pub lp_allowlist_enabled: bool, // Mirrors `PoolFlags::LP_ALLOWLIST` for existing readers
+    pub strategy_program: Pubkey,  // Lending program idle tokens may be supplied to
+    pub strategy_collateral_account: Pubkey, // Pool-owned account holding strategy collateral
+    pub strategy_cap_bps: u16,    // Max share of token reserve deployable (0 = disabled)
+    pub strategy_deployed: u64,   // Token principal currently supplied to the strategy
+    pub strategy_collateral: u64, // Collateral tokens received for the principal
+    pub strategy_yield: u64,      // Total interest returned to the pool
+    pub oracle: Pubkey,            // TOKEN/SOL price feed (default = guard disabled)
+    pub token_decimals: u8,       // Token mint decimals, used to compare prices
+    pub oracle_max_deviation_bps: u16, // Max pool price deviation from the feed
+    pub oracle_max_confidence_bps: u16, // Max confidence interval relative to price
+    pub oracle_max_staleness: i64, // Max feed age in seconds
+    pub oracle_fallback_to_curve: bool, // Skip the guard instead of reverting when degraded
+    pub recovery_address: Pubkey,  // Destination of an emergency drain
+    pub guardian: Pubkey,          // Secondary signer required for an emergency drain
+    pub drain_requested_at: i64,  // Pending emergency drain start (0 = none)
+    pub referral_fee_share_bps: u16, // Share of the swap fee paid to referrers
+    pub fee_window_start: i64,    // Start of the current fee change window
+    pub fee_window_base_rate: u16, // Fee rate at the start of the window
+    pub swaps_paused: bool,        // Swaps halted (e.g. oracle outage)
+    pub deposits_paused: bool,     // New liquidity halted, exits still allowed
+    pub launch_cooldown_slots: u64, // Slots a wallet must wait between swaps at launch
+    pub launch_window_end: i64,   // End of the launch cooldown period
+    pub quote_mint: Pubkey,        // Quote token mint (default = native SOL)
+    pub quote_decimals: u8,       // Quote asset decimals (9 for SOL)
+    pub badged: bool,              // Token mint carries a protocol-issued badge
+    pub indexed: bool,             // Pool is listed in the on-chain pool index
+    pub lp_penalty_bps: u16,      // Penalty on LP withdrawals before `lp_penalty_until`
+    pub lp_penalty_until: i64,    // End of the early-withdrawal penalty period
+    pub lp_locked_total: u64,     // LP tokens held in the lock vault
+    pub lp_lock_reward_per_share: u128, // Penalty LP tokens per locked LP token, scaled
+    pub admin_nft_mint: Pubkey,    // NFT whose holder is the admin (default = admin key only)
+    pub creator_royalty_bps: u16, // Share of the swap fee owed to the token creator
+    pub royalty_owed_token: u64,  // Creator royalties held in the token vault
+    pub royalty_owed_quote: u64,  // Creator royalties held in the SOL/quote vault
+    pub sell_fee_override: Option<u16>, // Fee rate on sells (None = `lp_fee_bps` for both sides)
+    pub fee_window_base_sell_rate: u16, // Sell fee rate at the start of the window
+    pub fee_holidays: [FeeHoliday; LiquidityPool::MAX_FEE_HOLIDAYS], // Scheduled reduced-fee windows
+    pub protocol_fee_bps: u16,    // Fee taken by the protocol on top of the LP fee
+    pub protocol_fees_token: u64, // Uncollected protocol fees held in the token vault
+    pub protocol_fees_quote: u64, // Uncollected protocol fees held in the SOL/quote vault
+    pub price_cumulative: u128,   // Sum of spot price (Q64.64) x seconds, wraps on overflow
+    pub price_last_updated: i64,  // Timestamp `price_cumulative` was last advanced to
+    pub lp_mint_successor: Pubkey, // Proposed new LP mint authority (default = none)
+    pub lp_mint_handoff_at: i64,  // Earliest time the successor may accept the handoff
+    pub lp_mint_migrated: bool,    // LP mint authority handed off, deposits closed here
+    pub param_proposal_count: u64, // Parameter proposals queued so far, seeds the next id
+    pub fee_tiers: [FeeTier; LiquidityPool::MAX_FEE_TIERS], // LP fee by trade size, ascending
+    pub rebalance_discount_bps: u16, // LP fee discount on swaps moving the price toward the oracle
+    pub rebalance_min_deviation_bps: u16, // Oracle deviation needed before the discount applies
+    pub guardian_paused: bool,     // Paused by a guardian quorum, only the protocol admin unpauses
+    pub custody_authority: Pubkey, // External custody owning the token vault (default = pool authority)
+    pub fee_metrics: FeeMetrics,   // Rolling LP fee totals in quote terms
+    pub outflow_caps: OutflowCaps, // Per-direction reserve outflow throttle
+    pub freeze_reason: u16,       // Compliance freeze reason code (0 = not frozen)
+    pub empty_since: i64,         // When `reap_pool` first saw the pool empty (0 = not marked)
+    pub epoch_revenue: EpochRevenue, // Swap revenue since the last `report_epoch_revenue`
+    pub oracle_feed_id: [u8; 32], // Pyth feed id the `oracle` account must carry
+    pub internal_token_credit: u64, // Pool tokens left in the internal vaults by internal swaps
+    pub internal_quote_credit: u64, // Pool quote left in the internal vaults by internal swaps
+    pub decimals_recorded: bool,   // `token_decimals` and `quote_decimals` hold the mints' decimals
+}
+
+impl LiquidityPool {
+    pub const MAX_FEE_HOLIDAYS: usize = 4;
+    pub const MAX_FEE_TIERS: usize = 4;
+
+    /// Account size including the discriminator
+    pub const LEN: usize = 8 + 32 + 1 + 4 + 32 + 32 + 32 + 32 + 8 + 8 + 8 + 2 + 32 + 1 + 8 + 33 + 8 + 1 + 32 + 32 + 2 + 8 + 8 + 8 + 32 + 1 + 2 + 2 + 8 + 1 + 32 + 32 + 8 + 2 + 8 + 2 + 1 + 1 + 8 + 8 + 32 + 1 + 1 + 1 + 2 + 8 + 8 + 16 + 32 + 2 + 8 + 8 + 3 + 2 + FeeHoliday::LEN * Self::MAX_FEE_HOLIDAYS + 2 + 8 + 8 + 16 + 8 + 32 + 8 + 1 + 8 + FeeTier::LEN * Self::MAX_FEE_TIERS + 2 + 2 + 1 + 32 + FeeMetrics::LEN + OutflowCaps::LEN + 2 + 8 + EpochRevenue::LEN + 32 + 8 + 8 + 1;
+
+    /// Frozen by the compliance authority, only withdrawals are allowed
+    pub fn is_frozen(&self) -> bool {
+        self.freeze_reason != 0
+    }
+
+    /// Features turned on (`PoolFlags` bits). Pools created while a set bit
+    /// turned a feature off are read the old way round, with the oracle guard
+    /// and allowlist taken from their own settings.
+    pub fn enabled_features(&self) -> u32 {
+        if self.flags & PoolFlags::ENABLED_BITS != 0 {
+            return self.flags & PoolFlags::ALL;
+        }
+        let mut features = PoolFlags::DEFAULT & !self.flags;
+        if self.oracle != Pubkey::default() {
+            features |= PoolFlags::ORACLE_GUARD;
+        }
+        if self.lp_allowlist_enabled {
+            features |= PoolFlags::LP_ALLOWLIST;
+        }
+        features
+    }
+
+    /// All of `features` (`PoolFlags` bits) are turned on
+    pub fn features_enabled(&self, features: u32) -> bool {
+        self.enabled_features() & features == features
+    }
+
+    /// Turn `features` (`PoolFlags` bits) on or off, moving a pool still on
+    /// the old bit meaning over to the new one
+    pub fn set_features(&mut self, features: u32, enabled: bool) {
+        let current = self.enabled_features();
+        let updated = if enabled { current | features } else { current & !features };
+        self.flags = updated | PoolFlags::ENABLED_BITS;
+        self.lp_allowlist_enabled = updated & PoolFlags::LP_ALLOWLIST != 0;
+    }
+
+    /// No liquidity, fees or lent-out tokens left, nothing to lose by closing it
+    pub fn is_empty(&self) -> bool {
+        self.lp_supply == 0
+            && self.token_reserve == 0
+            && self.sol_reserve == 0
+            && self.strategy_deployed == 0
+            && self.internal_token_credit == 0
+            && self.internal_quote_credit == 0
+            && self.royalty_owed_token == 0
+            && self.royalty_owed_quote == 0
+            && self.protocol_fees_token == 0
+            && self.protocol_fees_quote == 0
+    }
+
+    /// Whether the pool has passed its scheduled sunset deadline
+    pub fn is_sunset(&self, now: i64) -> bool {
+        self.sunset_at != 0 && now >= self.sunset_at
+    }
+
+    /// Change the fee rate, limiting the net change per 24h window so a fee
+    /// spike cannot be slipped in right before a large swap
+    pub fn apply_fee_change(&mut self, new_fee_rate: u16, now: i64) -> Result<()> {
+        self.roll_fee_window(now);
+        require!(
+            new_fee_rate.abs_diff(self.fee_window_base_rate) <= MAX_FEE_CHANGE_PER_WINDOW_BPS,
+            ExchangeError::FeeChangeTooLarge
+        );
+        self.lp_fee_bps = new_fee_rate;
+        Ok(())
+    }
+
+    /// Set or clear a separate sell fee, limited like `apply_fee_change`
+    pub fn apply_sell_fee_change(&mut self, sell_fee_override: Option<u16>, now: i64) -> Result<()> {
+        self.roll_fee_window(now);
+        let new_sell_fee_rate = sell_fee_override.unwrap_or(self.lp_fee_bps);
+        require!(
+            new_sell_fee_rate.abs_diff(self.fee_window_base_sell_rate) <= MAX_FEE_CHANGE_PER_WINDOW_BPS,
+            ExchangeError::FeeChangeTooLarge
+        );
+        self.sell_fee_override = sell_fee_override;
+        Ok(())
+    }
+
+    /// Replace the fee tiers. Used tiers come first with strictly ascending
+    /// breakpoints, and each rate is limited like `apply_fee_change` so
+    /// tiers cannot be used to spike the fee on large trades.
+    pub fn apply_fee_tiers(&mut self, fee_tiers: [FeeTier; Self::MAX_FEE_TIERS], now: i64) -> Result<()> {
+        self.roll_fee_window(now);
+        let used = fee_tiers.iter().take_while(|tier| !tier.is_unused()).count();
+        require!(
+            fee_tiers[used..].iter().all(FeeTier::is_unused)
+                && fee_tiers[..used]
+                    .windows(2)
+                    .all(|pair| pair[0].min_trade_size < pair[1].min_trade_size),
+            ExchangeError::InvalidFeeTiers
+        );
+        require!(
+            fee_tiers[..used].iter().all(|tier| tier.fee_rate <= 1000),
+            ExchangeError::InvalidFeeRate
+        );
+        require!(
+            fee_tiers[..used].iter().all(|tier| {
+                tier.fee_rate.abs_diff(self.fee_window_base_rate) <= MAX_FEE_CHANGE_PER_WINDOW_BPS
+            }),
+            ExchangeError::FeeChangeTooLarge
+        );
+        self.fee_tiers = fee_tiers;
+        Ok(())
+    }
+
+    fn roll_fee_window(&mut self, now: i64) {
+        if now >= self.fee_window_start + FEE_CHANGE_WINDOW_SECS {
+            self.fee_window_start = now;
+            self.fee_window_base_rate = self.lp_fee_bps;
+            self.fee_window_base_sell_rate = self.sell_fee_rate();
+        }
+    }
+
+    /// Fee rate on token-to-quote swaps; buys always pay `lp_fee_bps`
+    pub fn sell_fee_rate(&self) -> u16 {
+        self.sell_fee_override.unwrap_or(self.lp_fee_bps)
+    }
+
+    /// Whether the LP fee on either side plus the protocol fee stays within 10%
+    pub fn fees_within_cap(&self) -> bool {
+        let max_tier_rate = self.fee_tiers.iter().map(|tier| tier.fee_rate).max().unwrap_or(0);
+        self.lp_fee_bps.max(self.sell_fee_rate()).max(max_tier_rate) as u32 + self.protocol_fee_bps as u32
+            <= 1000
+    }
+
+    /// LP fee rate for a trade worth `trade_size` quote: the rate of the
+    /// highest tier the trade reaches, or `fee_rate` below the first tier
+    pub fn tiered_fee_rate(&self, fee_rate: u16, trade_size: u64) -> u16 {
+        self.fee_tiers
+            .iter()
+            .take_while(|tier| !tier.is_unused())
+            .filter(|tier| tier.min_trade_size <= trade_size)
+            .last()
+            .map_or(fee_rate, |tier| tier.fee_rate)
+    }
+
+    /// LP fee rate `fee_rate` reduced by any fee holiday running at `now`.
+    /// Holidays never raise a fee and leave the protocol fee alone.
+    pub fn discounted_fee_rate(&self, fee_rate: u16, now: i64) -> u16 {
+        self.fee_holidays
+            .iter()
+            .filter(|holiday| holiday.is_active(now))
+            .map(|holiday| holiday.fee_rate)
+            .fold(fee_rate, u16::min)
+    }
+
+    /// Whether per-wallet swap cooldowns are being enforced
+    pub fn in_launch_window(&self, now: i64) -> bool {
+        self.launch_cooldown_slots > 0 && now < self.launch_window_end
+    }
+
+    /// Whether the quote side is native SOL rather than a quote token
+    pub fn is_native_quote(&self) -> bool {
+        self.quote_mint == Pubkey::default()
+    }
+
+    /// Referrer's cut of a swap fee
+    pub fn referral_fee(&self, fee: u64) -> u64 {
+        (fee as u128 * self.referral_fee_share_bps as u128 / 10000) as u64
+    }
+
+    /// Creator's cut of the part of a swap fee left after the referral
+    pub fn creator_royalty(&self, fee: u64) -> u64 {
+        (fee as u128 * self.creator_royalty_bps as u128 / 10000) as u64
+    }
+
+    /// Penalty charged on withdrawing `lp_tokens` at `now`
+    pub fn lp_withdrawal_penalty(&self, lp_tokens: u64, now: i64) -> u64 {
+        if now >= self.lp_penalty_until {
+            return 0;
+        }
+        (lp_tokens as u128 * self.lp_penalty_bps as u128 / 10000) as u64
+    }
+
+    /// Credit penalty LP tokens to lockers. Returns false when nothing is
+    /// locked and the caller should burn the penalty instead.
+    pub fn distribute_lp_penalty(&mut self, penalty: u64) -> bool {
+        if self.lp_locked_total == 0 {
+            return false;
+        }
+        self.lp_lock_reward_per_share +=
+            penalty as u128 * LP_LOCK_REWARD_PRECISION / self.lp_locked_total as u128;
+        true
+    }
+
+    /// Whether `signer` holds admin rights. Once the pool is bound to an admin
+    /// NFT the rights follow the NFT: the signer must hold it in `admin_nft_account`.
+    pub fn is_admin(&self, signer: &Pubkey, admin_nft_account: Option<&TokenAccount>) -> bool {
+        if self.admin_nft_mint == Pubkey::default() {
+            return self.admin == Some(*signer);
+        }
+        self.admin.is_some()
+            && admin_nft_account.is_some_and(|account| {
+                account.mint == self.admin_nft_mint && account.owner == *signer && account.amount == 1
+            })
+    }
+
+    /// Quote per token as a Q64.64 fixed-point number, zero for an empty pool
+    pub fn spot_price_x64(&self) -> u128 {
+        if self.token_reserve == 0 {
+            return 0;
+        }
+        ((self.sol_reserve as u128) << 64) / self.token_reserve as u128
+    }
+
+    /// Value of `amount` tokens in quote at the current reserve ratio
+    pub fn token_value_in_quote(&self, amount: u64) -> u64 {
+        if self.token_reserve == 0 {
+            return 0;
+        }
+        let value = amount as u128 * self.sol_reserve as u128 / self.token_reserve as u128;
+        value.min(u64::MAX as u128) as u64
+    }
+
+    /// Token and quote decimals a constant sum pool scales by to trade whole
+    /// units one to one. Pools that never recorded them count base units.
+    pub fn unit_decimals(&self) -> (u8, u8) {
+        if self.decimals_recorded {
+            (self.token_decimals, self.quote_decimals)
+        } else {
+            (0, 0)
+        }
+    }
+
+    /// Quote per token at a constant sum pool's fixed price, as Q64.64
+    pub fn constant_sum_price_x64(&self) -> u128 {
+        let (token_decimals, quote_decimals) = self.unit_decimals();
+        constant_sum::convert(1 << 64, token_decimals, quote_decimals).unwrap_or(u128::MAX)
+    }
+
+    /// Both reserves in quote terms, the token reserve valued at the curve's spot price
+    pub fn reserves_value_in_quote(&self) -> u128 {
+        let token_value = match self.curve_type {
+            CurveType::ConstantProduct => self.sol_reserve as u128,
+            CurveType::ConstantSum => {
+                let (token_decimals, quote_decimals) = self.unit_decimals();
+                constant_sum::convert(self.token_reserve as u128, token_decimals, quote_decimals).unwrap_or(u128::MAX)
+            }
+        };
+        token_value.saturating_add(self.sol_reserve as u128)
+    }
+
+    /// Underlying value of one LP base unit in quote terms, as Q64.64: the
+    /// quote reserve plus the token reserve valued at the curve's spot price,
+    /// divided by the LP supply. Zero before the first deposit.
+    pub fn lp_virtual_price_x64(&self) -> u128 {
+        if self.lp_supply == 0 {
+            return 0;
+        }
+        let value = self.reserves_value_in_quote();
+        let lp_supply = self.lp_supply as u128;
+        let fraction = ((value % lp_supply) << 64) / lp_supply;
+        (value / lp_supply)
+            .checked_mul(1 << 64)
+            .map_or(u128::MAX, |whole| whole + fraction)
+    }
+
+    /// Value of one LP base unit in quote terms as Q64.64, with the token
+    /// priced at `price_x64` (e.g. a TWAP) through the curve invariant instead
+    /// of the spot reserves, so it cannot be moved by swapping within a
+    /// transaction. Zero before the first deposit or without a price.
+    pub fn lp_fair_value_x64(&self, price_x64: u128) -> u128 {
+        if self.lp_supply == 0 || price_x64 == 0 {
+            return 0;
+        }
+        let value_x32 = match self.curve_type {
+            CurveType::ConstantProduct => {
+                constant_product::fair_value_x32(self.token_reserve, self.sol_reserve, price_x64)
+            }
+            // Swaps move a constant sum pool at a fixed price, its value stays put
+            CurveType::ConstantSum => self.reserves_value_in_quote().checked_mul(1 << 32),
+        };
+        let Some(value_x32) = value_x32 else {
+            return u128::MAX;
+        };
+        let lp_supply = self.lp_supply as u128;
+        let fraction = ((value_x32 % lp_supply) << 32) / lp_supply;
+        (value_x32 / lp_supply)
+            .checked_mul(1 << 32)
+            .map_or(u128::MAX, |whole| whole + fraction)
+    }
+
+    /// Price accumulator extrapolated to `now` at the current spot price
+    pub fn price_cumulative_at(&self, now: i64) -> u128 {
+        if self.price_last_updated == 0 || now <= self.price_last_updated {
+            return self.price_cumulative;
+        }
+        let elapsed = (now - self.price_last_updated) as u128;
+        self.price_cumulative
+            .wrapping_add(self.spot_price_x64().wrapping_mul(elapsed))
+    }
+
+    /// Advance the price accumulator to `now`. Call before changing the reserves.
+    pub fn update_price_cumulative(&mut self, now: i64) {
+        self.price_cumulative = self.price_cumulative_at(now);
+        self.price_last_updated = self.price_last_updated.max(now);
+    }
+
+    /// Token principal the strategy may hold: `strategy_cap_bps` of the reserve
+    pub fn strategy_cap(&self) -> u64 {
+        (self.token_reserve as u128 * self.strategy_cap_bps as u128 / 10000) as u64
+    }
+
+    /// Lent principal above the cap, after outflows shrank the reserve or the
+    /// cap was lowered. Anyone may recall it.
+    pub fn strategy_excess(&self) -> u64 {
+        self.strategy_deployed.saturating_sub(self.strategy_cap())
+    }
+
+    /// Principal released by redeeming `collateral_amount`, pro-rata
+    pub fn strategy_principal(&self, collateral_amount: u64) -> u64 {
+        if self.strategy_collateral == 0 {
+            return 0;
+        }
+        (self.strategy_deployed as u128 * collateral_amount as u128 / self.strategy_collateral as u128) as u64
+    }
+
+    /// Token reserve held in the vault rather than lent to the strategy
+    pub fn liquid_token_reserve(&self) -> u64 {
+        self.token_reserve.saturating_sub(self.strategy_deployed)
+    }
+
+    /// Tokens the token vault should hold: the reserve minus what is lent out,
+    /// plus unclaimed creator royalties and protocol fees, minus the pool
+    /// tokens still held in the internal vaults
+    pub fn expected_token_vault_balance(&self) -> u64 {
+        (self.liquid_token_reserve() + self.royalty_owed_token + self.protocol_fees_token)
+            .saturating_sub(self.internal_token_credit)
+    }
+
+    /// Quote the SOL/quote vault should hold, excluding SOL vault rent and the
+    /// pool quote still held in the internal vaults
+    pub fn expected_quote_balance(&self) -> u64 {
+        (self.sol_reserve + self.royalty_owed_quote + self.protocol_fees_quote)
+            .saturating_sub(self.internal_quote_credit)
+    }
+
+    /// Book a swap against internal balances without moving assets: the input
+    /// stays in the internal vaults as pool credit, and the output is paid out
+    /// of the pool's credit on that side first. Returns the part of the output
+    /// the pool vault still has to move into the internal vaults.
+    pub fn book_internal_swap(&mut self, token_in: bool, amount_in: u64, amount_out: u64) -> Result<u64> {
+        let (credit_in, credit_out) = if token_in {
+            (&mut self.internal_token_credit, &mut self.internal_quote_credit)
+        } else {
+            (&mut self.internal_quote_credit, &mut self.internal_token_credit)
+        };
+        *credit_in = credit_in.checked_add(amount_in).ok_or(ExchangeError::MathOverflow)?;
+        let from_credit = amount_out.min(*credit_out);
+        *credit_out -= from_credit;
+        Ok(amount_out - from_credit)
+    }
+}
+
+/// Window during which swaps pay an LP fee of at most `fee_rate`. Unused slots are zeroed.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct FeeHoliday {
+    pub start: i64,
+    pub end: i64,
+    pub fee_rate: u16,
+}
+
+impl FeeHoliday {
+    pub const LEN: usize = 8 + 8 + 2;
+
+    pub fn is_active(&self, now: i64) -> bool {
+        self.start <= now && now < self.end
+    }
+}
+
+/// Bits of `LiquidityPool::flags`. A set bit turns the feature on for the
+/// pool; new pools start with `DEFAULT`.
+pub struct PoolFlags;
+
+impl PoolFlags {
+    /// Wrapping and unwrapping through a wSOL account
+    pub const WSOL: u32 = 1 << 0;
+    pub const REFERRALS: u32 = 1 << 1;
+    pub const PARTNER_FEES: u32 = 1 << 2;
+    /// Sending the output to another wallet
+    pub const SWAP_AND_SEND: u32 = 1 << 3;
+    /// Swapping from an account the signer is a delegate of
+    pub const DELEGATED_SWAPS: u32 = 1 << 4;
+    /// Being a hop of `swap_route`
+    pub const ROUTING: u32 = 1 << 5;
+    /// Swaps, signed orders, streams and deposits settled in internal balances
+    pub const INTERNAL_BALANCES: u32 = 1 << 6;
+    /// Swaps in both directions from different signers within one
+    /// transaction, the sandwich pattern. Turning this off makes swaps
+    /// require the instructions sysvar.
+    pub const OPPOSING_SWAPS: u32 = 1 << 7;
+    /// Oracle price checks on swaps, once `configure_oracle` set a feed
+    pub const ORACLE_GUARD: u32 = 1 << 8;
+    /// Only allowlisted providers may add liquidity
+    pub const LP_ALLOWLIST: u32 = 1 << 9;
+
+    pub const ALL: u32 = (1 << 10) - 1;
+    /// Everything but the oracle guard and the allowlist
+    pub const DEFAULT: u32 = (1 << 8) - 1;
+    /// Marks pools whose bits mean turned on. Older pools stored the
+    /// features turned off instead, see `LiquidityPool::enabled_features`.
+    pub const ENABLED_BITS: u32 = 1 << 31;
+}
+
+/// LP fee rate for trades worth at least `min_trade_size` quote. Unused slots are zeroed.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct FeeTier {
+    pub min_trade_size: u64,
+    pub fee_rate: u16,
+}
+
+impl FeeTier {
+    pub const LEN: usize = 8 + 2;
+
+    pub fn is_unused(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// LP fees earned, in quote, bucketed so the last 24h and 7d totals can be
+/// read without indexing swaps. Buckets are cleared lazily as time moves on.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct FeeMetrics {
+    pub last_updated: i64,
+    pub recent: [u64; FeeMetrics::RECENT_BUCKETS], // 4h buckets covering 24h
+    pub daily: [u64; FeeMetrics::DAILY_BUCKETS],   // 1d buckets covering 7d
+}
+
+impl FeeMetrics {
+    pub const RECENT_BUCKETS: usize = 6;
+    pub const RECENT_BUCKET_SECS: i64 = 4 * 60 * 60;
+    pub const DAILY_BUCKETS: usize = 7;
+    pub const DAILY_BUCKET_SECS: i64 = 24 * 60 * 60;
+    pub const LEN: usize = 8 + 8 * Self::RECENT_BUCKETS + 8 * Self::DAILY_BUCKETS;
+
+    /// Clear the buckets that fell out of the windows since the last update
+    fn roll(&mut self, now: i64) {
+        if now <= self.last_updated {
+            return;
+        }
+        for (buckets, bucket_secs) in [
+            (&mut self.recent[..], Self::RECENT_BUCKET_SECS),
+            (&mut self.daily[..], Self::DAILY_BUCKET_SECS),
+        ] {
+            let len = buckets.len() as i64;
+            let (last, current) = (self.last_updated / bucket_secs, now / bucket_secs);
+            for bucket in (last + 1..=current).take(buckets.len()) {
+                buckets[bucket.rem_euclid(len) as usize] = 0;
+            }
+        }
+        self.last_updated = now;
+    }
+
+    pub fn record(&mut self, now: i64, fee: u64) {
+        self.roll(now);
+        let recent = (now / Self::RECENT_BUCKET_SECS).rem_euclid(Self::RECENT_BUCKETS as i64) as usize;
+        let daily = (now / Self::DAILY_BUCKET_SECS).rem_euclid(Self::DAILY_BUCKETS as i64) as usize;
+        self.recent[recent] = self.recent[recent].saturating_add(fee);
+        self.daily[daily] = self.daily[daily].saturating_add(fee);
+    }
+
+    /// (24h, 7d) fee totals as of `now`. The current bucket is partial, so
+    /// the windows span between one bucket less and their full length.
+    pub fn totals(&self, now: i64) -> (u64, u64) {
+        let mut metrics = *self;
+        metrics.roll(now);
+        let sum = |buckets: &[u64]| buckets.iter().fold(0u64, |total, &fee| total.saturating_add(fee));
+        (sum(&metrics.recent), sum(&metrics.daily))
+    }
+}
+
+/// Share of a reserve allowed to leave the pool through swaps per window, a
+/// last-resort throttle on drains. The window slides: the previous window's
+/// outflow counts in proportion to how much of it still overlaps.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct OutflowCaps {
+    pub window_secs: u32,          // Window length (0 = caps disabled)
+    pub max_outflow_bps: [u16; 2], // Cap on the token and quote reserve (0 = uncapped)
+    pub window_start: i64,
+    pub previous: [u64; 2],        // Token and quote outflow of the previous window
+    pub current: [u64; 2],         // Token and quote outflow of the current window
+}
+
+impl OutflowCaps {
+    pub const TOKEN: usize = 0;
+    pub const QUOTE: usize = 1;
+    pub const LEN: usize = 4 + 2 * 2 + 8 + 8 * 2 + 8 * 2;
+
+    /// Set the window and caps, restarting the count when the window changes
+    pub fn configure(&mut self, window_secs: u32, max_outflow_bps: [u16; 2], now: i64) -> Result<()> {
+        require!(
+            max_outflow_bps.iter().all(|&bps| bps <= 10000),
+            ExchangeError::InvalidOutflowCaps
+        );
+        if self.window_secs != window_secs {
+            *self = OutflowCaps {
+                window_secs,
+                window_start: now,
+                ..OutflowCaps::default()
+            };
+        }
+        self.max_outflow_bps = max_outflow_bps;
+        Ok(())
+    }
+
+    fn roll(&mut self, now: i64) {
+        let window = self.window_secs as i64;
+        if now >= self.window_start + 2 * window {
+            self.previous = [0; 2];
+            self.current = [0; 2];
+            self.window_start = now - now.rem_euclid(window);
+        } else if now >= self.window_start + window {
+            self.previous = self.current;
+            self.current = [0; 2];
+            self.window_start += window;
+        }
+    }
+
+    /// Outflow of `side` over the last window length
+    pub fn outflow(&self, side: usize, now: i64) -> u64 {
+        if self.window_secs == 0 {
+            return 0;
+        }
+        let mut caps = *self;
+        caps.roll(now);
+        let window = caps.window_secs as u128;
+        let overlap = window.saturating_sub((now - caps.window_start) as u128);
+        let previous = caps.previous[side] as u128 * overlap / window;
+        caps.current[side].saturating_add(previous as u64)
+    }
+
+    /// Record `amount` leaving the `side` reserve, currently `reserve`.
+    /// Returns false if it would take the window over the cap.
+    pub fn record(&mut self, side: usize, amount: u64, reserve: u64, now: i64) -> bool {
+        if self.window_secs == 0 || self.max_outflow_bps[side] == 0 {
+            return true;
+        }
+        self.roll(now);
+        let outflow = self.outflow(side, now);
+        // Measure the cap against the reserve as it stood before the window's outflow
+        let limit = (reserve as u128 + outflow as u128) * self.max_outflow_bps[side] as u128 / 10000;
+        if outflow as u128 + amount as u128 > limit {
+            return false;
+        }
+        self.current[side] += amount;
+        true
+    }
+}
+
+/// Swap volume and fees, in quote, accumulated between two revenue reports
+/// so dashboards can read one event per pool and epoch instead of every swap
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct EpochRevenue {
+    pub since_epoch: u64,          // Epoch of the last report (0 = since creation)
+    pub volume: u64,
+    pub lp_fees: u64,
+    pub protocol_fees: u64,
+    pub creator_fees: u64,
+}
+
+impl EpochRevenue {
+    pub const LEN: usize = 8 * 5;
+
+    pub fn record(&mut self, volume: u64, lp_fee: u64, protocol_fee: u64, creator_fee: u64) {
+        self.volume = self.volume.saturating_add(volume);
+        self.lp_fees = self.lp_fees.saturating_add(lp_fee);
+        self.protocol_fees = self.protocol_fees.saturating_add(protocol_fee);
+        self.creator_fees = self.creator_fees.saturating_add(creator_fee);
+    }
+
+    /// Close the period at `epoch`, returning its totals. None if it was
+    /// already closed this epoch.
+    pub fn take(&mut self, epoch: u64) -> Option<EpochRevenue> {
+        if epoch <= self.since_epoch {
+            return None;
+        }
+        Some(std::mem::replace(
+            self,
+            EpochRevenue {
+                since_epoch: epoch,
+                ..Default::default()
+            },
+        ))
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum CurveType {
+    /// x * y = k, the default for volatile pairs
+    #[default]
+    ConstantProduct,
+    /// 1:1 pricing with zero slippage until one side depletes (pegged wrappers)
+    ConstantSum,
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::solana_program::program_pack::Pack;
+    use anchor_spl::token::spl_token;
+
+    use super::*;
+
+    #[test]
+    fn len_fits_serialized_pool() {
+        let pool = LiquidityPool {
+            admin: Some(Pubkey::new_unique()),
+            ..Default::default()
+        };
+        let mut data = Vec::new();
+        pool.try_serialize(&mut data).unwrap();
+        assert!(data.len() <= LiquidityPool::LEN);
+    }
+
+    #[test]
+    fn sunset_starts_at_deadline() {
+        let mut pool = LiquidityPool::default();
+        assert!(!pool.is_sunset(i64::MAX));
+        
+        pool.sunset_at = 100;
+        assert!(!pool.is_sunset(99));
+        assert!(pool.is_sunset(100));
+    }
+
+    #[test]
+    fn fee_changes_are_limited_per_window() {
+        let mut pool = LiquidityPool {
+            lp_fee_bps: 30,
+            ..Default::default()
+        };
+        let start = FEE_CHANGE_WINDOW_SECS;
+        
+        pool.apply_fee_change(80, start).unwrap();
+        assert_eq!(pool.lp_fee_bps, 80);
+        
+        // The limit applies to the net change since the window started
+        assert!(pool.apply_fee_change(81, start + 1).is_err());
+        pool.apply_fee_change(0, start + 1).unwrap();
+        assert!(pool.apply_fee_change(81, start + FEE_CHANGE_WINDOW_SECS - 1).is_err());
+        
+        // A new window starts from the current rate
+        pool.apply_fee_change(50, start + FEE_CHANGE_WINDOW_SECS).unwrap();
+        assert_eq!(pool.fee_window_base_rate, 0);
+    }
+
+    #[test]
+    fn sell_fee_defaults_to_lp_fee_and_is_limited() {
+        let mut pool = LiquidityPool {
+            lp_fee_bps: 30,
+            ..Default::default()
+        };
+        let start = FEE_CHANGE_WINDOW_SECS;
+        assert_eq!(pool.sell_fee_rate(), 30);
+        
+        pool.apply_sell_fee_change(Some(80), start).unwrap();
+        assert_eq!(pool.sell_fee_rate(), 80);
+        assert_eq!(pool.lp_fee_bps, 30);
+        assert!(pool.apply_sell_fee_change(Some(81), start + 1).is_err());
+        
+        // Buy fee changes no longer move the sell fee
+        pool.apply_fee_change(0, start + 1).unwrap();
+        assert_eq!(pool.sell_fee_rate(), 80);
+        
+        // Clearing the override falls back to the buy rate, within the limit
+        assert!(pool.apply_sell_fee_change(None, start + FEE_CHANGE_WINDOW_SECS).is_err());
+        pool.apply_sell_fee_change(Some(30), start + FEE_CHANGE_WINDOW_SECS).unwrap();
+        pool.apply_sell_fee_change(None, start + 2 * FEE_CHANGE_WINDOW_SECS).unwrap();
+        assert_eq!(pool.sell_fee_rate(), 0);
+    }
+
+    #[test]
+    fn protocol_fee_counts_towards_fee_cap() {
+        let mut pool = LiquidityPool {
+            lp_fee_bps: 900,
+            protocol_fee_bps: 100,
+            ..Default::default()
+        };
+        assert!(pool.fees_within_cap());
+        
+        pool.sell_fee_override = Some(901);
+        assert!(!pool.fees_within_cap());
+        pool.sell_fee_override = Some(0);
+        pool.protocol_fee_bps = 101;
+        assert!(!pool.fees_within_cap());
+    }
+
+    #[test]
+    fn price_accumulates_over_time() {
+        let mut pool = LiquidityPool {
+            token_reserve: 2_000,
+            sol_reserve: 1_000,
+            ..Default::default()
+        };
+        let half = 1u128 << 63;
+        assert_eq!(pool.spot_price_x64(), half);
+        
+        // The first update only starts the clock
+        pool.update_price_cumulative(100);
+        assert_eq!(pool.price_cumulative, 0);
+        pool.update_price_cumulative(110);
+        assert_eq!(pool.price_cumulative, 10 * half);
+        
+        pool.sol_reserve = 4_000;
+        assert_eq!(pool.price_cumulative_at(115), 10 * half + 5 * 4 * half);
+    }
+
+    #[test]
+    fn fee_holidays_only_lower_fees_while_running() {
+        let mut pool = LiquidityPool::default();
+        pool.fee_holidays[0] = FeeHoliday {
+            start: 100,
+            end: 200,
+            fee_rate: 10,
+        };
+        pool.fee_holidays[1] = FeeHoliday {
+            start: 150,
+            end: 300,
+            fee_rate: 0,
+        };
+        assert_eq!(pool.discounted_fee_rate(30, 99), 30);
+        assert_eq!(pool.discounted_fee_rate(30, 100), 10);
+        assert_eq!(pool.discounted_fee_rate(5, 100), 5);
+        assert_eq!(pool.discounted_fee_rate(30, 150), 0);
+        assert_eq!(pool.discounted_fee_rate(30, 300), 30);
+    }
+
+    #[test]
+    fn launch_window_requires_cooldown() {
+        let mut pool = LiquidityPool {
+            launch_window_end: 100,
+            ..Default::default()
+        };
+        assert!(!pool.in_launch_window(0));
+        
+        pool.launch_cooldown_slots = 2;
+        assert!(pool.in_launch_window(99));
+        assert!(!pool.in_launch_window(100));
+    }
+
+    #[test]
+    fn referral_fee_is_share_of_fee() {
+        let pool = LiquidityPool {
+            referral_fee_share_bps: 2500,
+            ..Default::default()
+        };
+        assert_eq!(pool.referral_fee(1000), 250);
+        assert_eq!(pool.referral_fee(3), 0);
+        assert_eq!(pool.referral_fee(u64::MAX), u64::MAX / 4);
+    }
+
+    #[test]
+    fn deployed_tokens_are_not_expected_in_the_vault() {
+        let pool = LiquidityPool {
+            token_reserve: 1_000,
+            strategy_deployed: 300,
+            ..Default::default()
+        };
+        assert_eq!(pool.expected_token_vault_balance(), 700);
+    }
+
+    #[test]
+    fn strategy_funds_above_the_cap_are_recallable() {
+        let mut pool = LiquidityPool {
+            token_reserve: 1_000,
+            strategy_cap_bps: 5000,
+            strategy_deployed: 400,
+            strategy_collateral: 200,
+            ..Default::default()
+        };
+        assert_eq!(pool.strategy_excess(), 0);
+        assert_eq!(pool.strategy_principal(50), 100);
+        
+        // Buys shrink the reserve the cap is a share of
+        pool.token_reserve = 600;
+        assert_eq!(pool.strategy_excess(), 100);
+        pool.strategy_cap_bps = 0;
+        assert_eq!(pool.strategy_excess(), 400);
+    }
+
+    #[test]
+    fn old_pools_read_flags_the_old_way_round() {
+        let mut pool = LiquidityPool {
+            flags: PoolFlags::WSOL | PoolFlags::OPPOSING_SWAPS,
+            lp_allowlist_enabled: true,
+            ..Default::default()
+        };
+        assert!(!pool.features_enabled(PoolFlags::WSOL));
+        assert!(pool.features_enabled(PoolFlags::REFERRALS | PoolFlags::LP_ALLOWLIST));
+        assert!(!pool.features_enabled(PoolFlags::ORACLE_GUARD));
+        
+        // The first update moves the pool over, keeping its features
+        pool.set_features(PoolFlags::WSOL, true);
+        assert_eq!(
+            pool.flags,
+            PoolFlags::ENABLED_BITS | (PoolFlags::DEFAULT & !PoolFlags::OPPOSING_SWAPS) | PoolFlags::LP_ALLOWLIST
+        );
+        pool.set_features(PoolFlags::LP_ALLOWLIST | PoolFlags::ROUTING, false);
+        assert!(!pool.lp_allowlist_enabled);
+        assert!(!pool.features_enabled(PoolFlags::ROUTING));
+        assert!(pool.features_enabled(PoolFlags::WSOL | PoolFlags::REFERRALS));
+    }
+
+    #[test]
+    fn internal_swaps_net_against_pool_credit() {
+        let mut pool = LiquidityPool {
+            token_reserve: 1_000,
+            sol_reserve: 1_000,
+            ..Default::default()
+        };
+        // No quote credit yet, so the whole output leaves the vault
+        assert_eq!(pool.book_internal_swap(true, 100, 90).unwrap(), 90);
+        assert_eq!(pool.internal_token_credit, 100);
+        // The way back is paid out of the token credit
+        assert_eq!(pool.book_internal_swap(false, 50, 60).unwrap(), 0);
+        assert_eq!((pool.internal_token_credit, pool.internal_quote_credit), (40, 50));
+        assert_eq!(pool.book_internal_swap(false, 10, 70).unwrap(), 30);
+        assert_eq!((pool.internal_token_credit, pool.internal_quote_credit), (0, 60));
+        
+        pool.token_reserve = 1_000;
+        pool.internal_token_credit = 400;
+        assert_eq!(pool.expected_token_vault_balance(), 600);
+        assert_eq!(pool.expected_quote_balance(), 940);
+    }
+
+    #[test]
+    fn creator_royalty_is_share_of_fee() {
+        let pool = LiquidityPool {
+            creator_royalty_bps: 1000,
+            ..Default::default()
+        };
+        assert_eq!(pool.creator_royalty(1000), 100);
+        assert_eq!(pool.creator_royalty(9), 0);
+        assert_eq!(LiquidityPool::default().creator_royalty(1000), 0);
+    }
+
+    #[test]
+    fn lp_penalty_ends_at_deadline() {
+        let pool = LiquidityPool {
+            lp_penalty_bps: 500,
+            lp_penalty_until: 100,
+            ..Default::default()
+        };
+        assert_eq!(pool.lp_withdrawal_penalty(1_000, 99), 50);
+        assert_eq!(pool.lp_withdrawal_penalty(1_000, 100), 0);
+    }
+
+    fn token_account(mint: Pubkey, owner: Pubkey, amount: u64) -> TokenAccount {
+        let account = spl_token::state::Account {
+            mint,
+            owner,
+            amount,
+            state: spl_token::state::AccountState::Initialized,
+            ..Default::default()
+        };
+        let mut data = vec![0; TokenAccount::LEN];
+        account.pack_into_slice(&mut data);
+        TokenAccount::try_deserialize_unchecked(&mut data.as_slice()).unwrap()
+    }
+
+    #[test]
+    fn admin_rights_follow_the_bound_nft() {
+        let admin = Pubkey::new_unique();
+        let holder = Pubkey::new_unique();
+        let mut pool = LiquidityPool {
+            admin: Some(admin),
+            ..Default::default()
+        };
+        assert!(pool.is_admin(&admin, None));
+        assert!(!pool.is_admin(&holder, None));
+        
+        pool.admin_nft_mint = Pubkey::new_unique();
+        let nft_account = token_account(pool.admin_nft_mint, holder, 1);
+        assert!(!pool.is_admin(&admin, None));
+        assert!(pool.is_admin(&holder, Some(&nft_account)));
+        assert!(!pool.is_admin(&admin, Some(&nft_account)));
+        assert!(!pool.is_admin(&holder, Some(&token_account(Pubkey::new_unique(), holder, 1))));
+        
+        // Renouncing authority revokes the NFT's rights too
+        pool.admin = None;
+        assert!(!pool.is_admin(&holder, Some(&nft_account)));
+    }
+
+    #[test]
+    fn lp_virtual_price_values_both_sides() {
+        let mut pool = LiquidityPool {
+            token_reserve: 4_000,
+            sol_reserve: 1_000,
+            lp_supply: 2_000,
+            ..Default::default()
+        };
+        // Tokens are worth as much as the SOL side at the spot price
+        assert_eq!(pool.lp_virtual_price_x64(), 1 << 64);
+        
+        pool.curve_type = CurveType::ConstantSum;
+        assert_eq!(pool.lp_virtual_price_x64(), (5 << 64) / 2);
+        
+        // A 6-decimal token against SOL is worth a thousand lamports a unit
+        pool.token_decimals = 6;
+        pool.quote_decimals = 9;
+        pool.decimals_recorded = true;
+        assert_eq!(pool.lp_virtual_price_x64(), (4_001_000 << 64) / 2_000);
+        assert_eq!(pool.constant_sum_price_x64(), 1_000 << 64);
+        
+        pool.lp_supply = 0;
+        assert_eq!(pool.lp_virtual_price_x64(), 0);
+    }
+
+    #[test]
+    fn pools_owing_fees_are_not_empty() {
+        let mut pool = LiquidityPool::default();
+        assert!(pool.is_empty());
+        
+        pool.protocol_fees_quote = 1;
+        assert!(!pool.is_empty());
+        pool.protocol_fees_quote = 0;
+        pool.strategy_deployed = 1;
+        assert!(!pool.is_empty());
+    }
+
+    #[test]
+    fn lp_fair_value_prices_tokens_at_the_given_price() {
+        let mut pool = LiquidityPool {
+            token_reserve: 4_000,
+            sol_reserve: 1_000,
+            lp_supply: 2_000,
+            ..Default::default()
+        };
+        assert_eq!(pool.lp_fair_value_x64(1 << 62), 1 << 64);
+        
+        // Skewed reserves are valued below spot, at the fair price
+        pool.token_reserve = 1_000;
+        pool.sol_reserve = 4_000;
+        assert_eq!(pool.lp_fair_value_x64(1 << 62), 1 << 64);
+        assert_eq!(pool.lp_virtual_price_x64(), 4 << 64);
+        assert_eq!(pool.lp_fair_value_x64(0), 0);
+    }
+
+    #[test]
+    fn fee_tiers_apply_by_trade_size() {
+        let tier = |min_trade_size, fee_rate| FeeTier {
+            min_trade_size,
+            fee_rate,
+        };
+        let mut pool = LiquidityPool {
+            lp_fee_bps: 30,
+            fee_window_base_rate: 30,
+            ..Default::default()
+        };
+        assert_eq!(pool.tiered_fee_rate(30, u64::MAX), 30);
+        
+        let tiers = [tier(1_000, 25), tier(10_000, 20), FeeTier::default(), FeeTier::default()];
+        pool.apply_fee_tiers(tiers, 0).unwrap();
+        assert_eq!(pool.tiered_fee_rate(30, 999), 30);
+        assert_eq!(pool.tiered_fee_rate(30, 1_000), 25);
+        assert_eq!(pool.tiered_fee_rate(30, 50_000), 20);
+        
+        // Breakpoints must ascend, with unused slots last
+        let unordered = [tier(10_000, 25), tier(1_000, 20), FeeTier::default(), FeeTier::default()];
+        assert!(pool.apply_fee_tiers(unordered, 0).is_err());
+        let gap = [tier(1_000, 25), FeeTier::default(), tier(10_000, 20), FeeTier::default()];
+        assert!(pool.apply_fee_tiers(gap, 0).is_err());
+        // Tier rates are limited like fee changes
+        let spike = [tier(1_000, 500), FeeTier::default(), FeeTier::default(), FeeTier::default()];
+        assert!(pool.apply_fee_tiers(spike, 0).is_err());
+    }
+
+    #[test]
+    fn fee_metrics_roll_off_old_buckets() {
+        let hour = 60 * 60;
+        let day = 24 * hour;
+        let mut metrics = FeeMetrics::default();
+        let start = 100 * day;
+        metrics.record(start, 10);
+        metrics.record(start + 5 * hour, 20);
+        assert_eq!(metrics.totals(start + 5 * hour), (30, 30));
+        // The first 4h bucket leaves the 24h window, the day stays in the 7d one
+        assert_eq!(metrics.totals(start + 24 * hour), (20, 30));
+        metrics.record(start + 3 * day, 5);
+        assert_eq!(metrics.totals(start + 3 * day), (5, 35));
+        assert_eq!(metrics.totals(start + 7 * day), (0, 5));
+        assert_eq!(metrics.totals(start + 30 * day), (0, 0));
+        // Reading does not clear anything
+        assert_eq!(metrics.totals(start + 3 * day), (5, 35));
+    }
+
+    #[test]
+    fn outflow_caps_slide_over_the_window() {
+        let mut caps = OutflowCaps {
+            window_secs: 100,
+            max_outflow_bps: [1000, 0],
+            ..OutflowCaps::default()
+        };
+        // 10% of the token reserve may leave per window, quote is uncapped
+        assert!(caps.record(OutflowCaps::TOKEN, 60, 1_000, 1_000));
+        assert!(!caps.record(OutflowCaps::TOKEN, 50, 940, 1_050));
+        assert!(caps.record(OutflowCaps::TOKEN, 40, 940, 1_050));
+        assert!(caps.record(OutflowCaps::QUOTE, 1_000_000, 1, 1_050));
+        // Half of the previous window still overlaps
+        assert_eq!(caps.outflow(OutflowCaps::TOKEN, 1_150), 50);
+        assert!(!caps.record(OutflowCaps::TOKEN, 50, 900, 1_150));
+        assert!(caps.record(OutflowCaps::TOKEN, 45, 900, 1_150));
+        assert_eq!(caps.outflow(OutflowCaps::TOKEN, 1_400), 0);
+        
+        caps.window_secs = 0;
+        assert!(caps.record(OutflowCaps::TOKEN, 1_000, 1_000, 1_400));
+    }
+
+    #[test]
+    fn epoch_revenue_is_reported_once_per_epoch() {
+        let mut revenue = EpochRevenue::default();
+        revenue.record(1_000, 3, 1, 2);
+        revenue.record(500, 1, 0, 1);
+        
+        let reported = revenue.take(10).unwrap();
+        assert_eq!((reported.since_epoch, reported.volume, reported.lp_fees), (0, 1_500, 4));
+        assert_eq!((reported.protocol_fees, reported.creator_fees), (1, 3));
+        assert_eq!(revenue, EpochRevenue { since_epoch: 10, ..Default::default() });
+        assert!(revenue.take(10).is_none());
+        assert!(revenue.take(11).is_some());
+    }
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+/// Entries created before `expires_at` and `rent_payer` existed are
+/// 80 bytes and must be grown with `resize_account` before use. The zeroed
+/// fields read as never expiring, with the rent going back to the admin.
+#[account]
+pub struct LpAllowlistEntry {
+    pub pool: Pubkey,              // Pool the entry belongs to
+    pub provider: Pubkey,          // Address allowed to provide liquidity
+    pub added_at: i64,            // Entry creation timestamp
+    pub expires_at: i64,          // Entry stops counting and may be pruned (0 = never)
+    pub rent_payer: Pubkey,        // Refunded when the entry is closed (default = the admin)
+}
+
+impl LpAllowlistEntry {
+    /// Account size including the discriminator
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 32;
+
+    /// Account the rent goes back to on close, `admin` for entries that
+    /// predate `rent_payer`
+    pub fn rent_refund_to(&self, admin: Pubkey) -> Pubkey {
+        if self.rent_payer == Pubkey::default() {
+            admin
+        } else {
+            self.rent_payer
+        }
+    }
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires_at != 0 && now >= self.expires_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entry_expires_at_deadline() {
+        let mut entry = LpAllowlistEntry {
+            pool: Pubkey::default(),
+            provider: Pubkey::default(),
+            added_at: 0,
+            expires_at: 0,
+            rent_payer: Pubkey::default(),
+        };
+        assert_eq!(8 + entry.try_to_vec().unwrap().len(), LpAllowlistEntry::LEN);
+        assert!(!entry.is_expired(i64::MAX));
+        
+        entry.expires_at = 100;
+        assert!(!entry.is_expired(99));
+        assert!(entry.is_expired(100));
+    }
+
+    #[test]
+    fn resized_entry_never_expires_and_refunds_admin() {
+        let entry = LpAllowlistEntry {
+            pool: Pubkey::new_unique(),
+            provider: Pubkey::new_unique(),
+            added_at: 7,
+            expires_at: 0,
+            rent_payer: Pubkey::default(),
+        };
+        // An entry written before the appended fields, grown with zeroes
+        let mut data = Vec::new();
+        entry.try_serialize(&mut data).unwrap();
+        data.truncate(LpAllowlistEntry::LEN - 8 - 32);
+        data.resize(LpAllowlistEntry::LEN, 0);
+        
+        let resized = LpAllowlistEntry::try_deserialize(&mut data.as_slice()).unwrap();
+        let admin = Pubkey::new_unique();
+        assert_eq!(resized.provider, entry.provider);
+        assert!(!resized.is_expired(i64::MAX));
+        assert_eq!(resized.rent_refund_to(admin), admin);
+        
+        let payer = Pubkey::new_unique();
+        let entry = LpAllowlistEntry { rent_payer: payer, ..resized };
+        assert_eq!(entry.rent_refund_to(admin), payer);
+    }
+}
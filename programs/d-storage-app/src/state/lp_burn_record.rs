@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct LpBurnRecord {
+    pub pool: Pubkey,              // Pool whose LP tokens were burned
+    pub burner: Pubkey,            // Wallet that burned them
+    pub total_burned: u64,         // LP tokens burned by this wallet in total
+    pub burn_count: u32,           // Number of burns
+    pub last_burn_amount: u64,     // LP tokens burned by the latest burn
+    pub last_share_bps: u16,       // Latest burn as a share of the LP supply before it
+    pub last_burn_at: i64,        // Latest burn timestamp
+}
+
+impl LpBurnRecord {
+    /// Account size including the discriminator
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 4 + 8 + 2 + 8;
+
+    /// Add a burn of `amount` LP tokens, `share_bps` of the supply before it
+    pub fn record(&mut self, pool: Pubkey, burner: Pubkey, amount: u64, share_bps: u16, now: i64) {
+        self.pool = pool;
+        self.burner = burner;
+        self.total_burned += amount;
+        self.burn_count += 1;
+        self.last_burn_amount = amount;
+        self.last_share_bps = share_bps;
+        self.last_burn_at = now;
+    }
+}
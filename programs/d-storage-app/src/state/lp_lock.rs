@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::LP_LOCK_REWARD_PRECISION;
+
+#[account]
+pub struct LpLock {
+    pub pool: Pubkey,              // Pool whose LP tokens are locked
+    pub owner: Pubkey,             // Locker receiving a share of withdrawal penalties
+    pub amount: u64,               // LP tokens held in the pool's lock vault
+    pub reward_debt: u128,         // Accumulator value already paid out on `amount`
+    pub compounder: Pubkey,        // May restake rewards for the owner, never withdraw (default = none)
+}
+
+impl LpLock {
+    /// Account size including the discriminator
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 16 + 32;
+
+    /// Penalty LP tokens earned since the last claim
+    pub fn pending_reward(&self, reward_per_share: u128) -> u64 {
+        (self.accrued(reward_per_share).saturating_sub(self.reward_debt)) as u64
+    }
+
+    pub fn is_compounder(&self, key: &Pubkey) -> bool {
+        self.compounder != Pubkey::default() && self.compounder == *key
+    }
+
+    /// Lock the pending rewards on top of the amount, returning them
+    pub fn compound(&mut self, reward_per_share: u128) -> u64 {
+        let reward = self.pending_reward(reward_per_share);
+        self.amount += reward;
+        self.sync_reward_debt(reward_per_share);
+        reward
+    }
+
+    /// Mark everything accrued on the current amount as paid out
+    pub fn sync_reward_debt(&mut self, reward_per_share: u128) {
+        self.reward_debt = self.accrued(reward_per_share);
+    }
+
+    fn accrued(&self, reward_per_share: u128) -> u128 {
+        self.amount as u128 * reward_per_share / LP_LOCK_REWARD_PRECISION
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::LiquidityPool;
+
+    fn lock(amount: u64) -> LpLock {
+        LpLock {
+            pool: Pubkey::default(),
+            owner: Pubkey::default(),
+            amount,
+            reward_debt: 0,
+            compounder: Pubkey::default(),
+        }
+    }
+
+    #[test]
+    fn len_matches_serialized_size() {
+        assert_eq!(8 + lock(0).try_to_vec().unwrap().len(), LpLock::LEN);
+    }
+
+    #[test]
+    fn penalties_are_shared_pro_rata() {
+        let mut pool = LiquidityPool {
+            lp_locked_total: 400,
+            ..Default::default()
+        };
+        let mut small = lock(100);
+        let large = lock(300);
+        
+        pool.distribute_lp_penalty(40);
+        assert_eq!(small.pending_reward(pool.lp_lock_reward_per_share), 10);
+        assert_eq!(large.pending_reward(pool.lp_lock_reward_per_share), 30);
+        
+        // Claimed rewards are not paid twice
+        small.sync_reward_debt(pool.lp_lock_reward_per_share);
+        assert_eq!(small.pending_reward(pool.lp_lock_reward_per_share), 0);
+        pool.distribute_lp_penalty(4);
+        assert_eq!(small.pending_reward(pool.lp_lock_reward_per_share), 1);
+    }
+
+    #[test]
+    fn late_lockers_do_not_share_earlier_penalties() {
+        let mut pool = LiquidityPool {
+            lp_locked_total: 100,
+            ..Default::default()
+        };
+        pool.distribute_lp_penalty(50);
+        
+        let mut late = lock(100);
+        late.sync_reward_debt(pool.lp_lock_reward_per_share);
+        assert_eq!(late.pending_reward(pool.lp_lock_reward_per_share), 0);
+    }
+
+    #[test]
+    fn compounding_locks_rewards_once() {
+        let mut pool = LiquidityPool {
+            lp_locked_total: 100,
+            ..Default::default()
+        };
+        let mut lock = lock(100);
+        pool.distribute_lp_penalty(20);
+        
+        assert_eq!(lock.compound(pool.lp_lock_reward_per_share), 20);
+        assert_eq!(lock.amount, 120);
+        assert_eq!(lock.compound(pool.lp_lock_reward_per_share), 0);
+        assert!(!lock.is_compounder(&Pubkey::default()));
+    }
+}
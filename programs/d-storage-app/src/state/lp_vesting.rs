@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct LpVesting {
+    pub pool: Pubkey,              // Pool whose initial LP is vesting
+    pub beneficiary: Pubkey,       // Creator allowed to claim vested LP
+    pub total: u64,                // LP tokens placed in the escrow
+    pub claimed: u64,              // LP tokens already released
+    pub start: i64,               // Vesting start (pool creation)
+    pub cliff_end: i64,           // Nothing vests before this time
+    pub end: i64,                 // Everything is vested from this time
+}
+
+impl LpVesting {
+    /// Account size including the discriminator
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 8 + 8 + 8;
+
+    /// LP tokens vested at `now`, linear from `start` once the cliff has passed
+    pub fn vested_amount(&self, now: i64) -> u64 {
+        if now < self.cliff_end {
+            return 0;
+        }
+        if now >= self.end {
+            return self.total;
+        }
+        (self.total as u128 * (now - self.start) as u128 / (self.end - self.start) as u128) as u64
+    }
+
+    /// Vested LP tokens not claimed yet
+    pub fn claimable(&self, now: i64) -> u64 {
+        self.vested_amount(now).saturating_sub(self.claimed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vesting() -> LpVesting {
+        LpVesting {
+            pool: Pubkey::default(),
+            beneficiary: Pubkey::default(),
+            total: 1_000,
+            claimed: 0,
+            start: 0,
+            cliff_end: 100,
+            end: 400,
+        }
+    }
+
+    #[test]
+    fn nothing_vests_before_the_cliff() {
+        assert_eq!(vesting().vested_amount(99), 0);
+        assert_eq!(vesting().vested_amount(100), 250);
+    }
+
+    #[test]
+    fn vesting_is_linear_until_the_end() {
+        assert_eq!(vesting().vested_amount(200), 500);
+        assert_eq!(vesting().vested_amount(400), 1_000);
+        assert_eq!(vesting().vested_amount(i64::MAX), 1_000);
+    }
+
+    #[test]
+    fn claimed_tokens_are_not_claimable_again() {
+        let mut vesting = vesting();
+        vesting.claimed = 500;
+        assert_eq!(vesting.claimable(200), 0);
+        assert_eq!(vesting.claimable(300), 250);
+    }
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+/// Balances of a migrating user's accounts before the source AMM withdrawal.
+/// Written by `begin_migration` and closed by `migrate_liquidity`, which may
+/// deposit no more than the accounts received in between.
+#[account]
+pub struct MigrationSnapshot {
+    pub user: Pubkey,              // Migrating wallet
+    pub token_account: Pubkey,     // Account the withdrawn tokens are paid into
+    pub token_balance: u64,       // Its balance before the withdrawal
+    pub quote_account: Pubkey,     // wSOL, or quote token account, paid the other side
+    pub quote_balance: u64,       // Its balance before the withdrawal
+    pub slot: u64,                // Slot of the `begin_migration` instruction
+    pub instruction_index: u16,   // Index of `begin_migration` in its transaction
+}
+
+impl MigrationSnapshot {
+    /// Account size including the discriminator
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 32 + 8 + 8 + 2;
+
+    /// Amounts the snapshotted accounts gained since the snapshot
+    pub fn received(&self, token_balance: u64, quote_balance: u64) -> (u64, u64) {
+        (
+            token_balance.saturating_sub(self.token_balance),
+            quote_balance.saturating_sub(self.quote_balance),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_balance_increases_count_as_received() {
+        let snapshot = MigrationSnapshot {
+            user: Pubkey::new_unique(),
+            token_account: Pubkey::new_unique(),
+            token_balance: 1_000,
+            quote_account: Pubkey::new_unique(),
+            quote_balance: 500,
+            slot: 7,
+            instruction_index: 1,
+        };
+        assert_eq!(snapshot.received(1_600, 900), (600, 400));
+        assert_eq!(snapshot.received(800, 500), (0, 0));
+    }
+}
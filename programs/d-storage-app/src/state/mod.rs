@@ -0,0 +1,65 @@
+pub mod auction_bid;
+pub mod breaker_history;
+pub mod compliance_config;
+pub mod crank_registry;
+pub mod creator_quota;
+pub mod custody_approval;
+pub mod deposit_receipt;
+pub mod exit_auction;
+pub mod fee_splitter;
+pub mod internal_balance;
+pub mod launch_auction;
+pub mod liquidity_pool;
+pub mod lp_allowlist_entry;
+pub mod lp_burn_record;
+pub mod lp_lock;
+pub mod lp_vesting;
+pub mod migration_snapshot;
+pub mod order_nonce;
+pub mod param_proposal;
+pub mod pool_index;
+pub mod pool_snapshot;
+pub mod price_observations;
+pub mod protocol_config;
+pub mod referral_code;
+pub mod reward_epoch;
+pub mod stake_discount;
+pub mod swap_cooldown;
+pub mod swap_stream;
+pub mod token_badge;
+pub mod token_registry;
+pub mod trade_receipt;
+pub mod tvl_history;
+
+pub use auction_bid::*;
+pub use breaker_history::*;
+pub use compliance_config::*;
+pub use crank_registry::*;
+pub use creator_quota::*;
+pub use custody_approval::*;
+pub use deposit_receipt::*;
+pub use exit_auction::*;
+pub use fee_splitter::*;
+pub use internal_balance::*;
+pub use launch_auction::*;
+pub use liquidity_pool::*;
+pub use lp_allowlist_entry::*;
+pub use lp_burn_record::*;
+pub use lp_lock::*;
+pub use lp_vesting::*;
+pub use migration_snapshot::*;
+pub use order_nonce::*;
+pub use param_proposal::*;
+pub use pool_index::*;
+pub use pool_snapshot::*;
+pub use price_observations::*;
+pub use protocol_config::*;
+pub use referral_code::*;
+pub use reward_epoch::*;
+pub use stake_discount::*;
+pub use swap_cooldown::*;
+pub use swap_stream::*;
+pub use token_badge::*;
+pub use token_registry::*;
+pub use trade_receipt::*;
+pub use tvl_history::*;
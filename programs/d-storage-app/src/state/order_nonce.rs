@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+/// Nonces filled before `expiry` and `rent_payer` existed are 56 bytes and
+/// cannot be closed, as nothing shows their order has expired.
+#[account]
+pub struct OrderNonce {
+    pub owner: Pubkey,             // Owner of the signed order
+    pub nonce: u64,                // Order nonce, the account existing marks it as used
+    pub filled_at: i64,           // Fill timestamp
+    pub expiry: i64,              // Order expiry, the nonce may be closed after it
+    pub rent_payer: Pubkey,        // Filler that paid the rent, refunded on close
+}
+
+impl OrderNonce {
+    /// Account size including the discriminator
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 8 + 32;
+
+    /// Whether the order can no longer be filled, so the nonce is no longer
+    /// needed to block a replay
+    pub fn is_expired(&self, now: i64) -> bool {
+        now > self.expiry
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nonce_closes_once_the_order_cannot_fill() {
+        let order_nonce = OrderNonce {
+            owner: Pubkey::new_unique(),
+            nonce: 1,
+            filled_at: 50,
+            expiry: 100,
+            rent_payer: Pubkey::new_unique(),
+        };
+        assert_eq!(8 + order_nonce.try_to_vec().unwrap().len(), OrderNonce::LEN);
+        // Settlement accepts the order up to and including its expiry
+        assert!(!order_nonce.is_expired(100));
+        assert!(order_nonce.is_expired(101));
+    }
+}
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+use super::LiquidityPool;
+
+/// Pool parameter change requested through a proposal
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ParamChange {
+    /// LP fee rate, subject to the per-window change limit
+    LpFee(u16),
+    /// Sell fee override (None = same as the LP fee)
+    SellFee(Option<u16>),
+    /// Share of the token reserve deployable to the strategy
+    StrategyCap(u16),
+    /// Share of the LP fee paid to referrers
+    ReferralFeeShare(u16),
+}
+
+impl ParamChange {
+    /// Serialized size: tag plus the largest payload
+    pub const LEN: usize = 1 + 3;
+
+    /// Range checks the matching admin instruction applies
+    pub fn validate(&self) -> Result<()> {
+        match *self {
+            ParamChange::LpFee(rate) => require!(rate <= 1000, ExchangeError::InvalidFeeRate),
+            ParamChange::SellFee(rate) => {
+                require!(rate.is_none_or(|rate| rate <= 1000), ExchangeError::InvalidFeeRate)
+            }
+            ParamChange::StrategyCap(cap_bps) => {
+                require!(cap_bps <= 10000, ExchangeError::InvalidStrategyCap)
+            }
+            ParamChange::ReferralFeeShare(share_bps) => {
+                require!(share_bps <= 10000, ExchangeError::InvalidReferralShare)
+            }
+        }
+        Ok(())
+    }
+
+    /// Apply the change to `pool`, with the same limits as the admin instructions
+    pub fn apply(&self, pool: &mut LiquidityPool, now: i64) -> Result<()> {
+        self.validate()?;
+        match *self {
+            ParamChange::LpFee(rate) => pool.apply_fee_change(rate, now)?,
+            ParamChange::SellFee(rate) => pool.apply_sell_fee_change(rate, now)?,
+            ParamChange::StrategyCap(cap_bps) => pool.strategy_cap_bps = cap_bps,
+            ParamChange::ReferralFeeShare(share_bps) => pool.referral_fee_share_bps = share_bps,
+        }
+        require!(pool.fees_within_cap(), ExchangeError::InvalidFeeRate);
+        Ok(())
+    }
+}
+
+/// Bonded request from anyone to change a pool parameter. Closed when the
+/// pool admin approves (bond refunded) or rejects it (bond slashed).
+#[account]
+pub struct ParamProposal {
+    pub pool: Pubkey,              // Pool the change applies to
+    pub id: u64,                   // Sequence number within the pool
+    pub proposer: Pubkey,          // Wallet that posted the bond
+    pub change: ParamChange,       // Requested change
+    pub bond: u64,                 // Lamports held in this account on top of rent
+    pub created_at: i64,          // Proposal timestamp
+}
+
+impl ParamProposal {
+    /// Account size including the discriminator
+    pub const LEN: usize = 8 + 32 + 8 + 32 + ParamChange::LEN + 8 + 8;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn len_fits_largest_change() {
+        assert_eq!(ParamChange::SellFee(Some(1000)).try_to_vec().unwrap().len(), ParamChange::LEN);
+    }
+
+    #[test]
+    fn approved_changes_keep_admin_limits() {
+        let mut pool = LiquidityPool {
+            lp_fee_bps: 30,
+            fee_window_base_rate: 30,
+            ..Default::default()
+        };
+        ParamChange::LpFee(60).apply(&mut pool, 1).unwrap();
+        assert_eq!(pool.lp_fee_bps, 60);
+        assert!(ParamChange::LpFee(500).apply(&mut pool, 2).is_err());
+        assert!(ParamChange::StrategyCap(10001).validate().is_err());
+    }
+}
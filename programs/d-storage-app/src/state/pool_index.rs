@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+
+#[account]
+pub struct PoolRegistry {
+    pub pool_count: u64,          // Number of pools in the index
+}
+
+impl PoolRegistry {
+    /// Account size including the discriminator
+    pub const LEN: usize = 8 + 8;
+
+    /// Index page the next pool is written to
+    pub fn next_page(&self) -> u64 {
+        self.pool_count / PoolIndexPage::PAGE_SIZE as u64
+    }
+
+    /// Append `pool` to `page`, which must be the page returned by
+    /// `next_page`. Returns the pool's position in the index.
+    pub fn append(&mut self, page: &mut PoolIndexPage, pool: Pubkey) -> Result<u64> {
+        let page_number = self.next_page();
+        if page.pools.is_empty() {
+            page.page = page_number;
+        }
+        require!(
+            page.page == page_number && page.pools.len() < PoolIndexPage::PAGE_SIZE,
+            ExchangeError::InvalidPoolIndexPage
+        );
+        page.pools.push(pool);
+        self.pool_count += 1;
+        Ok(self.pool_count - 1)
+    }
+}
+
+#[account]
+pub struct PoolIndexPage {
+    pub page: u64,                 // Page number, covers indexes page * PAGE_SIZE..
+    pub pools: Vec<Pubkey>,        // Pools in creation order
+}
+
+impl PoolIndexPage {
+    /// Pools per index page
+    pub const PAGE_SIZE: usize = 64;
+
+    /// Account size including the discriminator, sized for a full page
+    pub const LEN: usize = 8 + 8 + 4 + 32 * Self::PAGE_SIZE;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_page() -> PoolIndexPage {
+        PoolIndexPage {
+            page: 0,
+            pools: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn pools_fill_pages_in_order() {
+        let mut registry = PoolRegistry { pool_count: 0 };
+        let mut first = empty_page();
+        for i in 0..PoolIndexPage::PAGE_SIZE as u64 {
+            assert_eq!(registry.append(&mut first, Pubkey::new_unique()).unwrap(), i);
+        }
+        assert!(registry.append(&mut first, Pubkey::new_unique()).is_err());
+        
+        assert_eq!(registry.next_page(), 1);
+        let mut second = empty_page();
+        assert_eq!(registry.append(&mut second, Pubkey::new_unique()).unwrap(), 64);
+        assert_eq!(second.page, 1);
+    }
+
+    #[test]
+    fn full_page_fits_its_account() {
+        let page = PoolIndexPage {
+            page: 0,
+            pools: vec![Pubkey::default(); PoolIndexPage::PAGE_SIZE],
+        };
+        assert_eq!(8 + page.try_to_vec().unwrap().len(), PoolIndexPage::LEN);
+    }
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::SNAPSHOT_TWAP_WINDOW_SECS;
+use super::{LiquidityPool, Observation, PriceObservations};
+
+/// Compact copy of a pool's pricing state for lending protocols to read as
+/// collateral-pricing input without deserializing the full pool. LP tokens
+/// should be priced off `lp_fair_value_x64`, which spot swaps cannot move.
+#[account]
+pub struct PoolSnapshot {
+    pub pool: Pubkey,              // Pool being mirrored
+    pub token_reserve: u64,        // Token reserve at the last update
+    pub sol_reserve: u64,          // SOL/quote reserve at the last update
+    pub lp_supply: u64,            // LP supply at the last update
+    pub twap_x64: u128,            // Q64.64 TWAP over SNAPSHOT_TWAP_WINDOW_SECS, zero if not yet covered
+    pub frozen: bool,              // Swaps paused or pool sunset: do not price collateral off it
+    pub updated_slot: u64,         // Slot of the last update, for staleness checks
+    pub updated_at: i64,           // Timestamp of the last update
+    pub lp_fair_value_x64: u128,   // Q64.64 quote value of one LP unit at the TWAP, zero without one
+}
+
+impl PoolSnapshot {
+    /// Account size including the discriminator
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 8 + 16 + 1 + 8 + 8 + 16;
+
+    /// Copy the pool's current state, with the TWAP read from its observations
+    pub fn refresh(&mut self, pool: &LiquidityPool, observations: &PriceObservations, now: i64, slot: u64) {
+        let current = Observation {
+            timestamp: now,
+            price_cumulative: pool.price_cumulative_at(now),
+        };
+        self.token_reserve = pool.token_reserve;
+        self.sol_reserve = pool.sol_reserve;
+        self.lp_supply = pool.lp_supply;
+        self.twap_x64 = observations.twap(SNAPSHOT_TWAP_WINDOW_SECS, current).unwrap_or(0);
+        self.frozen = pool.swaps_paused || pool.is_sunset(now);
+        self.updated_slot = slot;
+        self.updated_at = now;
+        self.lp_fair_value_x64 = pool.lp_fair_value_x64(self.twap_x64);
+    }
+}
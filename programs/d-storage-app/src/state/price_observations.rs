@@ -0,0 +1,177 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Observation {
+    pub timestamp: i64,
+    pub price_cumulative: u128,
+}
+
+impl Observation {
+    pub const LEN: usize = 8 + 16;
+}
+
+/// Ring buffer of snapshots of a pool's price accumulator. The average price
+/// over any window still covered by the buffer is the accumulator difference
+/// divided by the window length.
+#[account]
+pub struct PriceObservations {
+    pub pool: Pubkey,              // Pool whose accumulator is sampled
+    pub next_index: u16,           // Slot the next observation is written to
+    pub observations: Vec<Observation>, // Grows to MAX_OBSERVATIONS, then wraps
+}
+
+impl PriceObservations {
+    /// With one observation per interval the buffer covers 24h
+    pub const MAX_OBSERVATIONS: usize = 288;
+    pub const MIN_INTERVAL_SECS: i64 = 5 * 60;
+
+    /// Account size including the discriminator
+    pub const LEN: usize = 8 + 32 + 2 + 4 + Observation::LEN * Self::MAX_OBSERVATIONS;
+
+    pub fn latest(&self) -> Option<&Observation> {
+        let len = self.observations.len();
+        if len == 0 {
+            return None;
+        }
+        self.observations.get((self.next_index as usize + len - 1) % len)
+    }
+
+    /// Record an observation unless the latest one is less than an interval old.
+    /// Returns whether one was written.
+    pub fn record(&mut self, observation: Observation) -> bool {
+        if self
+            .latest()
+            .is_some_and(|latest| observation.timestamp < latest.timestamp + Self::MIN_INTERVAL_SECS)
+        {
+            return false;
+        }
+        let index = self.next_index as usize;
+        if index < self.observations.len() {
+            self.observations[index] = observation;
+        } else {
+            self.observations.push(observation);
+        }
+        self.next_index = ((index + 1) % Self::MAX_OBSERVATIONS) as u16;
+        true
+    }
+
+    /// Observation `i` in chronological order
+    fn chronological(&self, i: usize) -> &Observation {
+        let oldest = if self.observations.len() < Self::MAX_OBSERVATIONS {
+            0
+        } else {
+            self.next_index as usize
+        };
+        &self.observations[(oldest + i) % self.observations.len()]
+    }
+
+    /// Accumulator value at `target`, interpolated between the surrounding
+    /// observations. `current` is the pool's accumulator now and closes the
+    /// range after the latest observation. None if `target` is older than
+    /// the buffer or in the future.
+    pub fn price_cumulative_at(&self, target: i64, current: Observation) -> Option<u128> {
+        if target > current.timestamp {
+            return None;
+        }
+        let len = self.observations.len();
+        if len == 0 || target < self.chronological(0).timestamp {
+            return (target == current.timestamp).then_some(current.price_cumulative);
+        }
+        
+        // Last observation at or before `target`
+        let (mut low, mut high) = (0, len - 1);
+        while low < high {
+            let mid = (low + high).div_ceil(2);
+            if self.chronological(mid).timestamp <= target {
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
+        let before = self.chronological(low);
+        let after = if low + 1 < len { self.chronological(low + 1) } else { &current };
+        if target == before.timestamp || after.timestamp <= before.timestamp {
+            return Some(before.price_cumulative);
+        }
+        
+        let delta = after.price_cumulative.wrapping_sub(before.price_cumulative);
+        let span = (after.timestamp - before.timestamp) as u128;
+        let elapsed = (target - before.timestamp) as u128;
+        Some(before.price_cumulative.wrapping_add(delta / span * elapsed + delta % span * elapsed / span))
+    }
+
+    /// Time-weighted average price (Q64.64) over the `window_secs` before
+    /// `current`, or None if the buffer does not reach back that far
+    pub fn twap(&self, window_secs: u32, current: Observation) -> Option<u128> {
+        if window_secs == 0 {
+            return None;
+        }
+        let start = self.price_cumulative_at(current.timestamp - window_secs as i64, current)?;
+        Some(current.price_cumulative.wrapping_sub(start) / window_secs as u128)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn observation(timestamp: i64, price_cumulative: u128) -> Observation {
+        Observation {
+            timestamp,
+            price_cumulative,
+        }
+    }
+
+    fn buffer() -> PriceObservations {
+        PriceObservations {
+            pool: Pubkey::default(),
+            next_index: 0,
+            observations: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn len_fits_full_buffer() {
+        let mut observations = buffer();
+        observations.observations = vec![Observation::default(); PriceObservations::MAX_OBSERVATIONS];
+        let data = observations.try_to_vec().unwrap();
+        assert_eq!(8 + data.len(), PriceObservations::LEN);
+    }
+
+    #[test]
+    fn records_at_most_once_per_interval_and_wraps() {
+        let interval = PriceObservations::MIN_INTERVAL_SECS;
+        let mut observations = buffer();
+        assert!(observations.record(observation(0, 0)));
+        assert!(!observations.record(observation(interval - 1, 1)));
+        
+        for i in 1..=PriceObservations::MAX_OBSERVATIONS as i64 {
+            assert!(observations.record(observation(i * interval, i as u128)));
+        }
+        assert_eq!(observations.observations.len(), PriceObservations::MAX_OBSERVATIONS);
+        assert_eq!(observations.next_index, 1);
+        assert_eq!(observations.chronological(0).timestamp, interval);
+        assert_eq!(observations.latest().unwrap().price_cumulative, PriceObservations::MAX_OBSERVATIONS as u128);
+    }
+
+    #[test]
+    fn twap_over_any_covered_window() {
+        let interval = PriceObservations::MIN_INTERVAL_SECS;
+        let mut observations = buffer();
+        // Price 10 for the first 10 intervals, then 20
+        let mut cumulative = 0u128;
+        for i in 0..=20 {
+            observations.record(observation(i * interval, cumulative));
+            cumulative += if i < 10 { 10 } else { 20 } * interval as u128;
+        }
+        let now = observation(20 * interval + 60, observations.latest().unwrap().price_cumulative + 20 * 60);
+        
+        assert_eq!(observations.twap(60, now), Some(20));
+        assert_eq!(observations.twap((10 * interval + 60) as u32, now), Some(20));
+        assert_eq!(observations.twap((20 * interval + 60) as u32, now), Some(15));
+        // Windows ending between observations are interpolated
+        assert_eq!(observations.twap((7 * interval + interval / 2 + 60) as u32, now), Some(20));
+        assert_eq!(observations.twap((20 * interval + 61) as u32, now), None);
+        assert_eq!(observations.twap(0, now), None);
+    }
+}
@@ -0,0 +1,204 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::DEFAULT_MAX_FEE_BPS;
+
+#[account]
+#[derive(Default)]
+pub struct ProtocolConfig {
+    pub admin: Pubkey,             // Protocol admin (token badges, protocol-wide settings)
+    pub created_at: i64,          // Config creation timestamp
+    pub max_creator_royalty_bps: u16, // Cap on the fee share pools may pay creators
+    pub guardians: [Pubkey; ProtocolConfig::MAX_GUARDIANS], // Keys allowed to pause pools (default = empty slot)
+    pub guardian_threshold: u8,    // Guardian signatures needed to pause (0 = guardian pause disabled)
+    pub max_launches_per_creator: u32, // Default token launches per creator (0 = unlimited)
+    pub status: ProtocolStatus,    // Emergency stop stage, withdrawals are always allowed
+    pub max_partner_fee_bps: u16,  // Cap on integrator surcharges on swap output (0 = disabled)
+    pub default_fee_bps: u16,      // Fee of pools created with a fee rate of 0
+    pub min_fee_bps: u16,          // Lowest pool fee allowed without the protocol admin
+    pub max_fee_bps: u16,          // Highest pool fee allowed without the protocol admin (0 = DEFAULT_MAX_FEE_BPS)
+    pub reap_grace_secs: u32,      // Time a pool must stay empty before it can be reaped (0 = disabled)
+}
+
+impl ProtocolConfig {
+    pub const MAX_GUARDIANS: usize = 3;
+
+    /// Account size including the discriminator and room for later settings
+    pub const LEN: usize = 8 + 32 + 8 + 2 + 32 * Self::MAX_GUARDIANS + 1 + 4 + 1 + 2 + 2 * 3 + 4 + 12;
+
+    /// Whether `signers` include a quorum of distinct guardians
+    pub fn guardian_quorum<'a>(&self, signers: impl Iterator<Item = &'a Pubkey>) -> bool {
+        let mut signed = [false; Self::MAX_GUARDIANS];
+        for signer in signers {
+            if let Some(index) = self
+                .guardians
+                .iter()
+                .position(|guardian| guardian == signer && *guardian != Pubkey::default())
+            {
+                signed[index] = true;
+            }
+        }
+        self.guardian_threshold > 0
+            && signed.iter().filter(|&&signed| signed).count() >= self.guardian_threshold as usize
+    }
+
+    pub fn swaps_enabled(&self) -> bool {
+        self.status.swaps_enabled()
+    }
+
+    /// Fee rate of a new pool, 0 picks the protocol default
+    pub fn resolve_fee(&self, fee_rate: u16) -> u16 {
+        if fee_rate == 0 {
+            self.default_fee_bps
+        } else {
+            fee_rate
+        }
+    }
+
+    pub fn max_fee(&self) -> u16 {
+        if self.max_fee_bps == 0 {
+            DEFAULT_MAX_FEE_BPS
+        } else {
+            self.max_fee_bps
+        }
+    }
+
+    pub fn fee_in_range(&self, fee_rate: u16) -> bool {
+        (self.min_fee_bps..=self.max_fee()).contains(&fee_rate)
+    }
+
+    /// Pools may use fees outside the protocol range only with the protocol admin co-signing
+    pub fn fee_allowed(&self, fee_rate: u16, co_signer: Option<Pubkey>) -> bool {
+        self.fee_in_range(fee_rate) || co_signer == Some(self.admin)
+    }
+
+    /// Like `fee_allowed`, but pools left outside a narrowed range may keep
+    /// their fee or move it toward the range
+    pub fn fee_change_allowed(&self, old_fee_rate: u16, new_fee_rate: u16, co_signer: Option<Pubkey>) -> bool {
+        let distance = |fee: u16| {
+            self.min_fee_bps
+                .saturating_sub(fee)
+                .max(fee.saturating_sub(self.max_fee()))
+        };
+        self.fee_allowed(new_fee_rate, co_signer) || distance(new_fee_rate) <= distance(old_fee_rate)
+    }
+
+    /// Deposits and pool creation, only once recovery is complete
+    pub fn deposits_enabled(&self) -> bool {
+        self.status.deposits_enabled()
+    }
+}
+
+/// Program-wide emergency stop. Recovery is staged: a halted protocol
+/// re-enables swaps first and deposits only after that.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ProtocolStatus {
+    #[default]
+    Live,
+    /// Only withdrawals are allowed
+    Halted,
+    /// Swaps and withdrawals are allowed, deposits stay blocked
+    SwapsOnly,
+}
+
+impl ProtocolStatus {
+    pub fn swaps_enabled(self) -> bool {
+        self != ProtocolStatus::Halted
+    }
+
+    pub fn deposits_enabled(self) -> bool {
+        self == ProtocolStatus::Live
+    }
+
+    pub fn can_transition_to(self, next: ProtocolStatus) -> bool {
+        matches!(
+            (self, next),
+            (ProtocolStatus::Live, ProtocolStatus::Halted)
+                | (ProtocolStatus::Halted, ProtocolStatus::SwapsOnly)
+                | (ProtocolStatus::SwapsOnly, ProtocolStatus::Live)
+                | (ProtocolStatus::SwapsOnly, ProtocolStatus::Halted)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn len_is_unchanged_by_later_settings() {
+        assert_eq!(ProtocolConfig::LEN, 8 + 32 + 8 + 2 + 126);
+    }
+
+    #[test]
+    fn guardian_quorum_counts_distinct_guardians() {
+        let guardians = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+        let mut config = ProtocolConfig {
+            admin: Pubkey::new_unique(),
+            created_at: 0,
+            max_creator_royalty_bps: 0,
+            guardians,
+            guardian_threshold: 0,
+            max_launches_per_creator: 0,
+            status: ProtocolStatus::Live,
+            max_partner_fee_bps: 0,
+            default_fee_bps: 0,
+            min_fee_bps: 0,
+            max_fee_bps: 0,
+            reap_grace_secs: 0,
+        };
+        assert!(!config.guardian_quorum(guardians.iter()));
+        
+        config.guardian_threshold = 2;
+        assert!(!config.guardian_quorum([guardians[0], guardians[0]].iter()));
+        assert!(!config.guardian_quorum([guardians[1], config.admin].iter()));
+        assert!(config.guardian_quorum([guardians[2], config.admin, guardians[0]].iter()));
+    }
+
+    #[test]
+    fn fees_outside_range_need_the_protocol_admin() {
+        let mut config = ProtocolConfig {
+            admin: Pubkey::new_unique(),
+            created_at: 0,
+            max_creator_royalty_bps: 0,
+            guardians: [Pubkey::default(); ProtocolConfig::MAX_GUARDIANS],
+            guardian_threshold: 0,
+            max_launches_per_creator: 0,
+            status: ProtocolStatus::Live,
+            max_partner_fee_bps: 0,
+            default_fee_bps: 30,
+            min_fee_bps: 0,
+            max_fee_bps: 0,
+            reap_grace_secs: 0,
+        };
+        assert_eq!(config.resolve_fee(0), 30);
+        assert_eq!(config.resolve_fee(100), 100);
+        assert!(config.fee_allowed(DEFAULT_MAX_FEE_BPS, None));
+        assert!(!config.fee_allowed(DEFAULT_MAX_FEE_BPS + 1, None));
+        
+        config.min_fee_bps = 10;
+        config.max_fee_bps = 100;
+        assert!(config.fee_allowed(10, None));
+        assert!(config.fee_allowed(100, None));
+        assert!(!config.fee_allowed(5, None));
+        assert!(!config.fee_allowed(101, Some(Pubkey::new_unique())));
+        assert!(config.fee_allowed(101, Some(config.admin)));
+        
+        // Fees above a lowered cap can come down without the admin
+        assert!(config.fee_change_allowed(500, 300, None));
+        assert!(config.fee_change_allowed(500, 500, None));
+        assert!(!config.fee_change_allowed(300, 500, None));
+        assert!(!config.fee_change_allowed(50, 101, None));
+    }
+
+    #[test]
+    fn recovery_reenables_swaps_before_deposits() {
+        use ProtocolStatus::*;
+        assert!(Live.can_transition_to(Halted));
+        assert!(!Halted.can_transition_to(Live));
+        assert!(Halted.can_transition_to(SwapsOnly));
+        assert!(SwapsOnly.can_transition_to(Live));
+        assert!(SwapsOnly.can_transition_to(Halted));
+        assert!(!Live.can_transition_to(SwapsOnly));
+        assert!(!Live.can_transition_to(Live));
+    }
+}
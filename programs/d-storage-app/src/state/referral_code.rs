@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct ReferralCode {
+    pub code: String,              // Short code used by frontends (`?ref=CODE`)
+    pub recipient: Pubkey,         // Address receiving referral fees
+    pub owner: Pubkey,             // Address allowed to update or close the code
+    pub created_at: i64,          // Code creation timestamp
+}
+
+impl ReferralCode {
+    /// Maximum length of a referral code
+    pub const MAX_CODE_LEN: usize = 16;
+
+    /// Account size including the discriminator, sized for the longest code
+    pub const LEN: usize = 8 + 4 + Self::MAX_CODE_LEN + 32 + 32 + 8;
+
+    /// Referral codes are short URL-safe strings
+    pub fn is_valid_code(code: &str) -> bool {
+        !code.is_empty()
+            && code.len() <= Self::MAX_CODE_LEN
+            && code.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codes_are_short_and_url_safe() {
+        assert!(ReferralCode::is_valid_code("alice"));
+        assert!(ReferralCode::is_valid_code("Team_42-x"));
+        assert!(ReferralCode::is_valid_code(&"a".repeat(ReferralCode::MAX_CODE_LEN)));
+        
+        assert!(!ReferralCode::is_valid_code(""));
+        assert!(!ReferralCode::is_valid_code(&"a".repeat(ReferralCode::MAX_CODE_LEN + 1)));
+        assert!(!ReferralCode::is_valid_code("a b"));
+        assert!(!ReferralCode::is_valid_code("a/b"));
+        assert!(!ReferralCode::is_valid_code("é"));
+    }
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct RewardEpoch {
+    pub pool: Pubkey,              // Pool whose LPs or stakers are rewarded
+    pub epoch: u64,                // Epoch number, chosen by the funder
+    pub reward_mint: Pubkey,       // Token paid out
+    pub funder: Pubkey,            // Wallet that funds the epoch and sets its root
+    pub merkle_root: [u8; 32],     // Root of (claimant, amount) leaves, zero until set
+    pub total_funded: u64,         // Rewards deposited into the vault
+    pub total_claimed: u64,        // Rewards paid out
+    pub bump: u8,                  // Bump of this PDA, which owns the vault
+}
+
+impl RewardEpoch {
+    /// Account size including the discriminator
+    pub const LEN: usize = 8 + 32 + 8 + 32 + 32 + 32 + 8 + 8 + 1;
+
+    pub fn has_root(&self) -> bool {
+        self.merkle_root != [0; 32]
+    }
+}
+
+#[account]
+pub struct RewardClaim {
+    pub reward_epoch: Pubkey,      // Epoch the claim was paid from
+    pub claimant: Pubkey,          // Wallet that claimed
+    pub amount: u64,               // Rewards paid
+    pub claimed_at: i64,          // Claim timestamp
+}
+
+impl RewardClaim {
+    /// Account size including the discriminator
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8;
+}
@@ -0,0 +1,184 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ExchangeError;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct StakeTier {
+    pub min_stake: u64,
+    pub discount_bps: u16,
+}
+
+impl StakeTier {
+    pub const LEN: usize = 8 + 2;
+}
+
+/// Protocol token staking for LP fee discounts
+#[account]
+pub struct StakeDiscountConfig {
+    pub stake_mint: Pubkey,        // Protocol token staked into the stake vault
+    pub cooldown_secs: u32,        // Warm-up for new stake, and wait before unstaked tokens can leave
+    pub tiers: Vec<StakeTier>,     // Ascending by stake and discount (empty = discounts off)
+}
+
+impl StakeDiscountConfig {
+    pub const MAX_TIERS: usize = 4;
+
+    /// Account size including the discriminator, sized for every tier
+    pub const LEN: usize = 8 + 32 + 4 + 4 + StakeTier::LEN * Self::MAX_TIERS;
+
+    pub fn validate_tiers(tiers: &[StakeTier]) -> Result<()> {
+        require!(tiers.len() <= Self::MAX_TIERS, ExchangeError::InvalidStakeTiers);
+        require!(
+            tiers.iter().all(|tier| tier.min_stake > 0 && tier.discount_bps <= 1000),
+            ExchangeError::InvalidStakeTiers
+        );
+        require!(
+            tiers.windows(2).all(|pair| {
+                pair[0].min_stake < pair[1].min_stake && pair[0].discount_bps <= pair[1].discount_bps
+            }),
+            ExchangeError::InvalidStakeTiers
+        );
+        Ok(())
+    }
+
+    /// LP fee discount of the highest tier `stake` reaches
+    pub fn discount_bps(&self, stake: u64) -> u16 {
+        self.tiers
+            .iter()
+            .rev()
+            .find(|tier| stake >= tier.min_stake)
+            .map_or(0, |tier| tier.discount_bps)
+    }
+}
+
+/// A wallet's stake. New stake only counts once it has warmed up, and
+/// unstaked tokens wait out the cooldown, so staking around a single trade
+/// does not pay off.
+#[account]
+pub struct StakePosition {
+    pub owner: Pubkey,
+    pub active: u64,               // Stake counting toward the discount tier
+    pub warming: u64,              // Stake that counts from `warm_at`
+    pub warm_at: i64,
+    pub pending: u64,              // Unstaked tokens withdrawable from `unlock_at`
+    pub unlock_at: i64,
+}
+
+impl StakePosition {
+    /// Account size including the discriminator
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 8 + 8 + 8;
+
+    fn settle(&mut self, now: i64) {
+        if now >= self.warm_at {
+            self.active += self.warming;
+            self.warming = 0;
+        }
+    }
+
+    /// Stake that counts toward the discount tier at `now`
+    pub fn effective_stake(&self, now: i64) -> u64 {
+        if now >= self.warm_at {
+            self.active + self.warming
+        } else {
+            self.active
+        }
+    }
+
+    /// Add stake, which warms up for `cooldown_secs`. Stake still warming
+    /// restarts its warm-up.
+    pub fn stake(&mut self, amount: u64, now: i64, cooldown_secs: u32) {
+        self.settle(now);
+        self.warming += amount;
+        self.warm_at = now + cooldown_secs as i64;
+    }
+
+    /// Move stake to pending, taking warming stake first. Pending tokens
+    /// restart their cooldown.
+    pub fn unstake(&mut self, amount: u64, now: i64, cooldown_secs: u32) -> Result<()> {
+        self.settle(now);
+        require!(amount <= self.active + self.warming, ExchangeError::InsufficientStake);
+        let from_warming = amount.min(self.warming);
+        self.warming -= from_warming;
+        self.active -= amount - from_warming;
+        self.pending += amount;
+        self.unlock_at = now + cooldown_secs as i64;
+        Ok(())
+    }
+
+    /// Take the pending tokens whose cooldown is over
+    pub fn withdraw_unlocked(&mut self, now: i64) -> u64 {
+        if now < self.unlock_at {
+            return 0;
+        }
+        std::mem::take(&mut self.pending)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position() -> StakePosition {
+        StakePosition {
+            owner: Pubkey::default(),
+            active: 0,
+            warming: 0,
+            warm_at: 0,
+            pending: 0,
+            unlock_at: 0,
+        }
+    }
+
+    fn tier(min_stake: u64, discount_bps: u16) -> StakeTier {
+        StakeTier {
+            min_stake,
+            discount_bps,
+        }
+    }
+
+    #[test]
+    fn len_fits_every_tier() {
+        let config = StakeDiscountConfig {
+            stake_mint: Pubkey::default(),
+            cooldown_secs: 0,
+            tiers: vec![tier(1, 1); StakeDiscountConfig::MAX_TIERS],
+        };
+        assert_eq!(8 + config.try_to_vec().unwrap().len(), StakeDiscountConfig::LEN);
+        assert_eq!(8 + position().try_to_vec().unwrap().len(), StakePosition::LEN);
+    }
+
+    #[test]
+    fn highest_reached_tier_applies() {
+        let config = StakeDiscountConfig {
+            stake_mint: Pubkey::default(),
+            cooldown_secs: 0,
+            tiers: vec![tier(100, 5), tier(1000, 10)],
+        };
+        assert!(StakeDiscountConfig::validate_tiers(&config.tiers).is_ok());
+        assert_eq!(config.discount_bps(99), 0);
+        assert_eq!(config.discount_bps(100), 5);
+        assert_eq!(config.discount_bps(5000), 10);
+        
+        assert!(StakeDiscountConfig::validate_tiers(&[tier(1000, 10), tier(100, 5)]).is_err());
+        assert!(StakeDiscountConfig::validate_tiers(&[tier(100, 1001)]).is_err());
+    }
+
+    #[test]
+    fn stake_counts_after_warm_up_and_leaves_after_cooldown() {
+        let mut position = position();
+        position.stake(100, 0, 60);
+        assert_eq!(position.effective_stake(59), 0);
+        assert_eq!(position.effective_stake(60), 100);
+        
+        position.stake(50, 70, 60);
+        assert_eq!(position.effective_stake(100), 100);
+        
+        // Warming stake is unstaked first
+        position.unstake(80, 100, 60).unwrap();
+        assert_eq!((position.active, position.warming), (70, 0));
+        assert!(position.unstake(71, 100, 60).is_err());
+        assert_eq!(position.withdraw_unlocked(159), 0);
+        assert_eq!(position.withdraw_unlocked(160), 80);
+        assert_eq!(position.withdraw_unlocked(160), 0);
+    }
+}
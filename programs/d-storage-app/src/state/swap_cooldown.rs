@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct SwapCooldown {
+    pub pool: Pubkey,              // Pool the tracker belongs to
+    pub wallet: Pubkey,            // Wallet being rate limited
+    pub last_swap_slot: u64,      // Slot of the wallet's last swap
+    pub rent_payer: Pubkey,        // Refunded on close (default = the wallet)
+}
+
+impl SwapCooldown {
+    /// Account size including the discriminator
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 32;
+
+    /// Account the rent goes back to on close
+    pub fn rent_refund_to(&self) -> Pubkey {
+        if self.rent_payer == Pubkey::default() {
+            self.wallet
+        } else {
+            self.rent_payer
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::STREAM_MAX_CATCHUP_SLOTS;
+use crate::instructions::swap::SwapDirection;
+
+/// Continuous conversion of an internal balance: up to `rate_per_slot` of the
+/// input converts for every slot elapsed, executed lazily by whoever touches
+/// the stream
+#[account]
+pub struct SwapStream {
+    pub owner: Pubkey,             // Internal balance owner
+    pub pool: Pubkey,
+    pub direction: SwapDirection,
+    pub rate_per_slot: u64,        // Input converted per elapsed slot at most
+    pub remaining: u64,            // Input left to convert
+    pub min_out_per_in_x64: u128,  // Limit price as output per input unit, Q64.64
+    pub last_slot: u64,            // Slot the stream last executed in
+    pub total_in: u64,
+    pub total_out: u64,
+    pub rent_payer: Pubkey,        // Refunded on close (default = the owner)
+}
+
+impl SwapStream {
+    /// Account size including the discriminator
+    pub const LEN: usize = 8 + 32 + 32 + 1 + 8 + 8 + 16 + 8 + 8 + 8 + 32;
+
+    /// Account the rent goes back to on close
+    pub fn rent_refund_to(&self) -> Pubkey {
+        if self.rent_payer == Pubkey::default() {
+            self.owner
+        } else {
+            self.rent_payer
+        }
+    }
+
+    /// Input due at `slot`, capped by what is left and the catch-up limit
+    pub fn due(&self, slot: u64) -> u64 {
+        let elapsed = slot.saturating_sub(self.last_slot).min(STREAM_MAX_CATCHUP_SLOTS);
+        self.rate_per_slot.saturating_mul(elapsed).min(self.remaining)
+    }
+
+    /// Smallest output accepted for `amount_in` at the limit price
+    pub fn min_amount_out(&self, amount_in: u64) -> u64 {
+        let out = amount_in as u128 * (self.min_out_per_in_x64 >> 64)
+            + ((amount_in as u128 * (self.min_out_per_in_x64 & u64::MAX as u128)) >> 64);
+        out.min(u64::MAX as u128) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn due_accrues_per_slot_up_to_the_catch_up_limit() {
+        let stream = SwapStream {
+            owner: Pubkey::new_unique(),
+            pool: Pubkey::new_unique(),
+            direction: SwapDirection::SolToToken,
+            rate_per_slot: 10,
+            remaining: 5_000,
+            min_out_per_in_x64: (1 << 64) / 2,
+            last_slot: 100,
+            total_in: 0,
+            total_out: 0,
+            rent_payer: Pubkey::default(),
+        };
+        assert_eq!(stream.due(100), 0);
+        assert_eq!(stream.due(103), 30);
+        assert_eq!(stream.due(100 + 10 * STREAM_MAX_CATCHUP_SLOTS), 10 * STREAM_MAX_CATCHUP_SLOTS);
+        assert_eq!(SwapStream { remaining: 25, ..stream.clone() }.due(200), 25);
+        assert_eq!(stream.min_amount_out(30), 15);
+    }
+}
@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct TokenBadge {
+    pub mint: Pubkey,              // Vetted token mint
+    pub issued_by: Pubkey,         // Protocol admin that issued the badge
+    pub issued_at: i64,           // Badge issue timestamp
+}
+
+impl TokenBadge {
+    /// Account size including the discriminator
+    pub const LEN: usize = 8 + 32 + 32 + 8;
+}
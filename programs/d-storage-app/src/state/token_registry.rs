@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct TokenRegistry {
+    pub token_mint: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    pub metadata_uri: String,
+    pub creator: Pubkey,
+    pub total_supply: u64,
+    pub is_active: bool,
+    pub created_at: i64,
+}
+
+impl TokenRegistry {
+    pub const MAX_NAME_LEN: usize = 32;
+    pub const MAX_SYMBOL_LEN: usize = 10;
+    pub const MAX_URI_LEN: usize = 200;
+
+    /// Account size including the discriminator, sized for the longest strings
+    pub const LEN: usize = 8
+        + 32
+        + 4 + Self::MAX_NAME_LEN
+        + 4 + Self::MAX_SYMBOL_LEN
+        + 1
+        + 4 + Self::MAX_URI_LEN
+        + 32
+        + 8
+        + 1
+        + 8;
+
+    /// Name and symbol are required, every string must fit the account
+    pub fn is_valid_metadata(name: &str, symbol: &str, metadata_uri: &str) -> bool {
+        !name.is_empty()
+            && name.len() <= Self::MAX_NAME_LEN
+            && !symbol.is_empty()
+            && symbol.len() <= Self::MAX_SYMBOL_LEN
+            && metadata_uri.len() <= Self::MAX_URI_LEN
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn len_fits_longest_metadata() {
+        let registry = TokenRegistry {
+            token_mint: Pubkey::default(),
+            name: "n".repeat(TokenRegistry::MAX_NAME_LEN),
+            symbol: "s".repeat(TokenRegistry::MAX_SYMBOL_LEN),
+            decimals: 9,
+            metadata_uri: "u".repeat(TokenRegistry::MAX_URI_LEN),
+            creator: Pubkey::default(),
+            total_supply: u64::MAX,
+            is_active: true,
+            created_at: 0,
+        };
+        let data = registry.try_to_vec().unwrap();
+        assert_eq!(8 + data.len(), TokenRegistry::LEN);
+    }
+
+    #[test]
+    fn metadata_must_fit() {
+        assert!(TokenRegistry::is_valid_metadata("Token", "TKN", ""));
+        assert!(!TokenRegistry::is_valid_metadata("", "TKN", ""));
+        assert!(!TokenRegistry::is_valid_metadata("Token", "", ""));
+        assert!(!TokenRegistry::is_valid_metadata(
+            "Token",
+            &"s".repeat(TokenRegistry::MAX_SYMBOL_LEN + 1),
+            ""
+        ));
+        assert!(!TokenRegistry::is_valid_metadata(
+            "Token",
+            "TKN",
+            &"u".repeat(TokenRegistry::MAX_URI_LEN + 1)
+        ));
+    }
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+/// Compact on-chain record of one executed swap, written on request so
+/// integrators can verify execution without relying on log retention
+#[account]
+pub struct TradeReceipt {
+    pub user: Pubkey,              // Swap signer, paid the rent
+    pub pool: Pubkey,              // Pool the swap executed against
+    pub receipt_id: u64,           // Caller-chosen id, seeds the receipt
+    pub params_hash: [u8; 32],     // Hash of the pool, user, id and swap arguments
+    pub token_in: Pubkey,          // Input mint (default = SOL)
+    pub token_out: Pubkey,         // Output mint (default = SOL)
+    pub amount_in: u64,
+    pub amount_out: u64,           // Received by the user, net of partner fees
+    pub price_x64: u128,           // Executed quote per token as Q64.64
+    pub slot: u64,
+    pub timestamp: i64,
+}
+
+impl TradeReceipt {
+    /// Account size including the discriminator
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 32 + 32 + 32 + 8 + 8 + 16 + 8 + 8;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn len_matches_serialized_size() {
+        let receipt = TradeReceipt {
+            user: Pubkey::new_unique(),
+            pool: Pubkey::new_unique(),
+            receipt_id: 7,
+            params_hash: [1; 32],
+            token_in: Pubkey::default(),
+            token_out: Pubkey::new_unique(),
+            amount_in: 1_000,
+            amount_out: 990,
+            price_x64: 1 << 64,
+            slot: 42,
+            timestamp: 1_700_000_000,
+        };
+        let data = receipt.try_to_vec().unwrap();
+        assert_eq!(8 + data.len(), TradeReceipt::LEN);
+    }
+}
@@ -0,0 +1,190 @@
+use anchor_lang::prelude::*;
+
+use super::{CurveType, LiquidityPool};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct TvlCheckpoint {
+    pub timestamp: i64,
+    pub token_reserve: u64,
+    pub sol_reserve: u64,
+    pub lp_supply: u64,
+    pub price_x64: u128,       // Quote per token the token reserve is valued at
+}
+
+impl TvlCheckpoint {
+    pub const LEN: usize = 8 + 8 + 8 + 8 + 16;
+
+    /// Checkpoint of the pool's current state. Constant-sum pools value
+    /// tokens at their fixed price, like the LP virtual price does.
+    pub fn of_pool(pool: &LiquidityPool, timestamp: i64) -> Self {
+        Self {
+            timestamp,
+            token_reserve: pool.token_reserve,
+            sol_reserve: pool.sol_reserve,
+            lp_supply: pool.lp_supply,
+            price_x64: match pool.curve_type {
+                CurveType::ConstantProduct => pool.spot_price_x64(),
+                CurveType::ConstantSum => pool.constant_sum_price_x64(),
+            },
+        }
+    }
+
+    /// Both reserves in quote terms, valuing tokens at `price_x64`
+    fn value_at(token_reserve: u64, sol_reserve: u64, price_x64: u128) -> u128 {
+        let token_value = (token_reserve as u128).saturating_mul(price_x64 >> 32) >> 32;
+        token_value + sol_reserve as u128
+    }
+
+    /// Total value locked in quote terms
+    pub fn tvl(&self) -> u128 {
+        Self::value_at(self.token_reserve, self.sol_reserve, self.price_x64)
+    }
+
+    /// Value of one LP base unit in quote terms as Q32.32, None without LP supply
+    fn lp_value_x32(value: u128, lp_supply: u64) -> Option<u128> {
+        (lp_supply > 0).then(|| (value << 32) / lp_supply as u128)
+    }
+}
+
+/// Ring buffer of daily TVL checkpoints. LP value growth between two
+/// checkpoints gives the APR, comparing it to holding the reserves gives
+/// the impermanent loss.
+#[account]
+pub struct TvlHistory {
+    pub pool: Pubkey,              // Pool whose TVL is sampled
+    pub next_index: u16,           // Slot the next checkpoint is written to
+    pub checkpoints: Vec<TvlCheckpoint>, // Grows to MAX_CHECKPOINTS, then wraps
+}
+
+impl TvlHistory {
+    pub const MAX_CHECKPOINTS: usize = 180;
+    pub const INTERVAL_SECS: i64 = 24 * 60 * 60;
+    pub const SECS_PER_YEAR: i64 = 365 * Self::INTERVAL_SECS;
+
+    /// Account size including the discriminator
+    pub const LEN: usize = 8 + 32 + 2 + 4 + TvlCheckpoint::LEN * Self::MAX_CHECKPOINTS;
+
+    pub fn latest(&self) -> Option<&TvlCheckpoint> {
+        let len = self.checkpoints.len();
+        if len == 0 {
+            return None;
+        }
+        self.checkpoints.get((self.next_index as usize + len - 1) % len)
+    }
+
+    /// Record a checkpoint unless one was already written for its day.
+    /// Returns whether it was written.
+    pub fn record(&mut self, checkpoint: TvlCheckpoint) -> bool {
+        let day = checkpoint.timestamp.div_euclid(Self::INTERVAL_SECS);
+        if self
+            .latest()
+            .is_some_and(|latest| day <= latest.timestamp.div_euclid(Self::INTERVAL_SECS))
+        {
+            return false;
+        }
+        let index = self.next_index as usize;
+        if index < self.checkpoints.len() {
+            self.checkpoints[index] = checkpoint;
+        } else {
+            self.checkpoints.push(checkpoint);
+        }
+        self.next_index = ((index + 1) % Self::MAX_CHECKPOINTS) as u16;
+        true
+    }
+
+    /// Latest checkpoint taken at or before `target`
+    pub fn at_or_before(&self, target: i64) -> Option<&TvlCheckpoint> {
+        self.checkpoints
+            .iter()
+            .filter(|checkpoint| checkpoint.timestamp <= target)
+            .max_by_key(|checkpoint| checkpoint.timestamp)
+    }
+
+    /// Annualized growth of the LP value from `from` to `to` in basis points,
+    /// fees included. None without LP supply or elapsed time.
+    pub fn apr_bps(from: &TvlCheckpoint, to: &TvlCheckpoint) -> Option<i64> {
+        let elapsed = to.timestamp - from.timestamp;
+        if elapsed <= 0 {
+            return None;
+        }
+        let start = TvlCheckpoint::lp_value_x32(from.tvl(), from.lp_supply)? as i128;
+        let end = TvlCheckpoint::lp_value_x32(to.tvl(), to.lp_supply)? as i128;
+        if start == 0 {
+            return None;
+        }
+        let growth_bps = (end - start).checked_mul(10000)? / start;
+        let apr_bps = growth_bps.checked_mul(Self::SECS_PER_YEAR as i128)? / elapsed as i128;
+        i64::try_from(apr_bps).ok()
+    }
+
+    /// Shortfall of the LP value at `to` against holding the reserves one LP
+    /// unit represented at `from`, in basis points. Fees earned offset the
+    /// loss, so this is net of fees and zero when they cover it.
+    pub fn impermanent_loss_bps(from: &TvlCheckpoint, to: &TvlCheckpoint) -> Option<u16> {
+        let held = TvlCheckpoint::value_at(from.token_reserve, from.sol_reserve, to.price_x64);
+        let hold_x32 = TvlCheckpoint::lp_value_x32(held, from.lp_supply)?;
+        let lp_x32 = TvlCheckpoint::lp_value_x32(to.tvl(), to.lp_supply)?;
+        if hold_x32 == 0 {
+            return None;
+        }
+        Some((hold_x32.saturating_sub(lp_x32).saturating_mul(10000) / hold_x32) as u16)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checkpoint(timestamp: i64, token_reserve: u64, sol_reserve: u64, lp_supply: u64) -> TvlCheckpoint {
+        TvlCheckpoint {
+            timestamp,
+            token_reserve,
+            sol_reserve,
+            lp_supply,
+            price_x64: ((sol_reserve as u128) << 64) / token_reserve as u128,
+        }
+    }
+
+    #[test]
+    fn len_fits_full_history() {
+        let history = TvlHistory {
+            pool: Pubkey::default(),
+            next_index: 0,
+            checkpoints: vec![TvlCheckpoint::default(); TvlHistory::MAX_CHECKPOINTS],
+        };
+        let data = history.try_to_vec().unwrap();
+        assert_eq!(8 + data.len(), TvlHistory::LEN);
+    }
+
+    #[test]
+    fn records_once_per_day() {
+        let day = TvlHistory::INTERVAL_SECS;
+        let mut history = TvlHistory {
+            pool: Pubkey::default(),
+            next_index: 0,
+            checkpoints: Vec::new(),
+        };
+        assert!(history.record(checkpoint(day - 1, 1, 1, 1)));
+        assert!(history.record(checkpoint(day, 1, 1, 1)));
+        assert!(!history.record(checkpoint(2 * day - 1, 1, 1, 1)));
+        assert_eq!(history.at_or_before(day - 1).unwrap().timestamp, day - 1);
+        assert_eq!(history.at_or_before(3 * day).unwrap().timestamp, day);
+        assert!(history.at_or_before(0).is_none());
+    }
+
+    #[test]
+    fn apr_and_impermanent_loss() {
+        let year = TvlHistory::SECS_PER_YEAR;
+        // Fees grow both reserves by 25% at a constant price
+        let from = checkpoint(0, 1_000_000, 1_000_000, 1_000_000);
+        let to = checkpoint(year, 1_250_000, 1_250_000, 1_000_000);
+        assert_eq!(TvlHistory::apr_bps(&from, &to), Some(2500));
+        assert_eq!(TvlHistory::impermanent_loss_bps(&from, &to), Some(0));
+        
+        // Price quadruples without fees: LP holds 2x the value, holding 2.5x
+        let to = checkpoint(year / 2, 500_000, 2_000_000, 1_000_000);
+        assert_eq!(TvlHistory::apr_bps(&from, &to), Some(20000));
+        assert_eq!(TvlHistory::impermanent_loss_bps(&from, &to), Some(2000));
+        assert_eq!(TvlHistory::apr_bps(&to, &from), None);
+    }
+}
@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+
+use crate::errors::ExchangeError;
+
+/// Accounts handed to a lending adapter for a strategy CPI
+pub struct StrategyCpi<'a, 'info> {
+    pub lending_program: &'a AccountInfo<'info>,
+    pub token_vault: &'a AccountInfo<'info>,
+    pub collateral_account: &'a AccountInfo<'info>,
+    pub pool_authority: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    /// Protocol specific accounts, in the order the adapter documents
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+    pub signer_seeds: &'a [&'a [&'a [u8]]],
+}
+
+/// Lending protocol integration used by the idle-reserve strategy
+pub trait LendingAdapter {
+    /// Supply `amount` tokens from the vault, minting collateral to the pool
+    fn deposit(&self, cpi: &StrategyCpi, amount: u64) -> Result<()>;
+    /// Redeem `collateral_amount` collateral back into the vault
+    fn withdraw(&self, cpi: &StrategyCpi, collateral_amount: u64) -> Result<()>;
+}
+
+pub const SPL_TOKEN_LENDING_PROGRAM_ID: Pubkey = pubkey!("LendZqTs7gn5CTSJU1jWKhKuVpjJGom45nnwPb2AMTi");
+
+/// SPL token-lending adapter. Remaining accounts: reserve, reserve liquidity
+/// supply, reserve collateral mint, lending market, lending market authority,
+/// clock sysvar. The client refreshes the reserve earlier in the transaction.
+pub struct SplTokenLendingAdapter;
+
+impl SplTokenLendingAdapter {
+    const DEPOSIT_RESERVE_LIQUIDITY: u8 = 4;
+    const REDEEM_RESERVE_COLLATERAL: u8 = 5;
+
+    fn invoke<'info>(
+        &self,
+        cpi: &StrategyCpi<'_, 'info>,
+        tag: u8,
+        amount: u64,
+        source: &AccountInfo<'info>,
+        destination: &AccountInfo<'info>,
+    ) -> Result<()> {
+        let [reserve, liquidity_supply, collateral_mint, lending_market, market_authority, clock] =
+            cpi.remaining_accounts
+        else {
+            return err!(ExchangeError::InvalidStrategyAccounts);
+        };
+        
+        // Deposit and redeem list the supply and collateral mint in opposite order
+        let (fourth, fifth) = if tag == Self::DEPOSIT_RESERVE_LIQUIDITY {
+            (liquidity_supply, collateral_mint)
+        } else {
+            (collateral_mint, liquidity_supply)
+        };
+        
+        let mut data = vec![tag];
+        data.extend_from_slice(&amount.to_le_bytes());
+        let ix = Instruction {
+            program_id: cpi.lending_program.key(),
+            accounts: vec![
+                AccountMeta::new(source.key(), false),
+                AccountMeta::new(destination.key(), false),
+                AccountMeta::new(reserve.key(), false),
+                AccountMeta::new(fourth.key(), false),
+                AccountMeta::new(fifth.key(), false),
+                AccountMeta::new_readonly(lending_market.key(), false),
+                AccountMeta::new_readonly(market_authority.key(), false),
+                AccountMeta::new_readonly(cpi.pool_authority.key(), true),
+                AccountMeta::new_readonly(clock.key(), false),
+                AccountMeta::new_readonly(cpi.token_program.key(), false),
+            ],
+            data,
+        };
+        invoke_signed(
+            &ix,
+            &[
+                source.clone(),
+                destination.clone(),
+                reserve.clone(),
+                fourth.clone(),
+                fifth.clone(),
+                lending_market.clone(),
+                market_authority.clone(),
+                cpi.pool_authority.clone(),
+                clock.clone(),
+                cpi.token_program.clone(),
+                cpi.lending_program.clone(),
+            ],
+            cpi.signer_seeds,
+        )?;
+        Ok(())
+    }
+}
+
+impl LendingAdapter for SplTokenLendingAdapter {
+    fn deposit(&self, cpi: &StrategyCpi, amount: u64) -> Result<()> {
+        self.invoke(
+            cpi,
+            Self::DEPOSIT_RESERVE_LIQUIDITY,
+            amount,
+            cpi.token_vault,
+            cpi.collateral_account,
+        )
+    }
+
+    fn withdraw(&self, cpi: &StrategyCpi, collateral_amount: u64) -> Result<()> {
+        self.invoke(
+            cpi,
+            Self::REDEEM_RESERVE_COLLATERAL,
+            collateral_amount,
+            cpi.collateral_account,
+            cpi.token_vault,
+        )
+    }
+}
+
+/// Adapter for a supported lending program
+pub fn lending_adapter(program_id: &Pubkey) -> Option<&'static dyn LendingAdapter> {
+    match *program_id {
+        SPL_TOKEN_LENDING_PROGRAM_ID => Some(&SplTokenLendingAdapter),
+        _ => None,
+    }
+}
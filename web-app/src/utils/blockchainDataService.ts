@@ -3,6 +3,9 @@ import {
   PublicKey,
   LAMPORTS_PER_SOL,
   Transaction,
+  TransactionInstruction,
+  TransactionMessage,
+  VersionedTransaction,
   ComputeBudgetProgram,
   SystemProgram,
   type BlockhashWithExpiryBlockHeight,
@@ -50,6 +53,8 @@ type WalletInput = Partial<MinimalWalletLike> & {
 };
 
 const PROGRAM_ID = new PublicKey((idl as FlexibleTokenExchange).address);
+// Anchor discriminator of the `lp_virtual_price` view instruction
+const LP_VIRTUAL_PRICE_DISCRIMINATOR = Buffer.from([194, 133, 145, 142, 106, 42, 202, 218]);
 
 export interface ExchangeToken {
   mint: string;
@@ -447,6 +452,54 @@ class BlockchainDataService {
     }
   }
 
+  // LP "virtual price": quote base units per LP base unit as Q64.64, read by
+  // simulating the on-chain `lp_virtual_price` view so every curve and quote
+  // mint is valued the way the program values it. The simulated fee payer is
+  // the wallet, so none is returned without one
+  async getLpVirtualPrice(
+    tokenMint: string,
+    wallet?: WalletInput
+  ): Promise<BN | null> {
+    try {
+      const payer = this.createWalletAdapter(wallet).publicKey;
+      if (!payer) {
+        return null;
+      }
+      const [poolPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("pool"), new PublicKey(tokenMint).toBytes()],
+        PROGRAM_ID
+      );
+      const instruction = new TransactionInstruction({
+        programId: PROGRAM_ID,
+        keys: [{ pubkey: poolPda, isSigner: false, isWritable: false }],
+        data: LP_VIRTUAL_PRICE_DISCRIMINATOR,
+      });
+      const { blockhash } = await this.connection.getLatestBlockhash("confirmed");
+      const message = new TransactionMessage({
+        payerKey: payer,
+        recentBlockhash: blockhash,
+        instructions: [instruction],
+      }).compileToV0Message();
+      const simulation = await this.connection.simulateTransaction(
+        new VersionedTransaction(message),
+        { sigVerify: false, commitment: "confirmed" }
+      );
+      const returnData = simulation.value.returnData;
+      if (
+        simulation.value.err ||
+        !returnData ||
+        returnData.programId !== PROGRAM_ID.toBase58()
+      ) {
+        return null;
+      }
+      // Little-endian u128, its trailing zero bytes trimmed by the runtime
+      return new BN(Buffer.from(returnData.data[0], "base64"), "le");
+    } catch (error) {
+      console.warn(`Error reading LP virtual price for token ${tokenMint}:`, error);
+      return null;
+    }
+  }
+
   // Filter tokens that have existing liquidity pools
   async getTokensWithPools(
     tokens: Array<{ mint: string; symbol: string }>,